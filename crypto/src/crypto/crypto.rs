@@ -7,6 +7,8 @@ use sentc_crypto_common::crypto::{EncryptedHead, GeneratedSymKeyHeadServerOutput
 use sentc_crypto_common::user::UserPublicKeyData;
 use sentc_crypto_core::cryptomat::{CryptoAlg, SymKeyComposer, SymKeyGen};
 use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SkWrapper, SymKeyComposerWrapper, SymKeyGenWrapper, SymKeyWrapper};
+use sentc_crypto_utils::head::{BinaryHead, HeadFormat};
+use sentc_crypto_utils::policy::CryptoPolicy;
 use serde::{Deserialize, Serialize};
 
 use crate::util::public::handle_server_response;
@@ -17,11 +19,19 @@ Get the head and the data.
 
 This can not only be used internally, to get the used key_id
  */
-pub fn split_head_and_encrypted_data<'a, T: Deserialize<'a>>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkError>
+pub fn split_head_and_encrypted_data<'a, T: Deserialize<'a> + BinaryHead>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkError>
 {
 	Ok(sentc_crypto_utils::split_head_and_encrypted_data(data_with_head)?)
 }
 
+/**
+Get the json head and the data for heads without the binary format.
+ */
+pub fn split_head_and_encrypted_json<'a, T: Deserialize<'a>>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkError>
+{
+	Ok(sentc_crypto_utils::split_head_and_encrypted_json(data_with_head)?)
+}
+
 /**
 Get head from string.
 
@@ -49,6 +59,19 @@ pub fn put_head_and_encrypted_data<T: Serialize>(head: &T, encrypted: &[u8]) ->
 	Ok(out)
 }
 
+/**
+Put the head and the data together with the json or the compact binary head.
+
+The binary head is useful for many small encrypted values where the json head is bigger than the data.
+ */
+pub fn put_head_and_encrypted_data_with_format<T: Serialize + BinaryHead>(head: &T, encrypted: &[u8], format: HeadFormat)
+	-> Result<Vec<u8>, SdkError>
+{
+	Ok(sentc_crypto_utils::put_head_and_encrypted_data_with_format(
+		head, encrypted, format,
+	)?)
+}
+
 /**
 Get the head from string
 
@@ -150,6 +173,26 @@ impl<SGen: SymKeyGenWrapper, SC: SymKeyComposerWrapper, P: PkFromUserKeyWrapper>
 		Ok(SC::from_inner(key, encrypted_symmetric_key_info.key_id.to_string()))
 	}
 
+	/**
+	Like decrypt_sym_key_by_private_key but the algorithms of the private key and the symmetric key must be allowed by the crypto policy.
+	 */
+	pub fn decrypt_sym_key_by_private_key_with_policy(
+		private_key: &impl SkWrapper,
+		encrypted_symmetric_key_info: &GeneratedSymKeyHeadServerOutput,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<SC::SymmetricKeyWrapper, SdkError>
+	{
+		let encrypted_sym_key = Base64::decode_vec(&encrypted_symmetric_key_info.encrypted_key_string).map_err(|_| SdkError::KeyDecryptFailed)?;
+
+		let key = crypto_policy.decrypt_sym_key_by_private_key::<SC::Composer>(
+			private_key.get_key(),
+			&encrypted_sym_key,
+			encrypted_symmetric_key_info.alg.as_str(),
+		)?;
+
+		Ok(SC::from_inner(key, encrypted_symmetric_key_info.key_id.to_string()))
+	}
+
 	/**
 	# Simulates the server key output
 
@@ -207,9 +250,15 @@ impl<SGen: SymKeyGenWrapper, SC: SymKeyComposerWrapper, P: PkFromUserKeyWrapper>
 #[cfg(test)]
 mod test
 {
-	use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SkCryptoWrapper, SymKeyCrypto};
+	use alloc::string::ToString;
+
+	use sentc_crypto_utils::cryptomat::{KeyToString, PkFromUserKeyWrapper, SignaturePolicy, SkCryptoWrapper, SymKeyCrypto, SIGN_HEAD_BOUND_SUFFIX};
+	use sentc_crypto_utils::error::SdkUtilError;
+	use sentc_crypto_utils::head::{HeadFormat, BINARY_HEAD_MAGIC};
+	use sentc_crypto_utils::policy::CryptoPolicy;
 
-	use crate::group::test_fn::create_group;
+	use super::*;
+	use crate::group::test_fn::{create_group, TestSecretKey, TestSymmetricKey};
 	use crate::user::test_fn::create_user;
 
 	#[cfg(feature = "std_keys")]
@@ -442,6 +491,352 @@ mod test
 		assert_eq!(text.as_bytes(), decrypted)
 	}
 
+	#[test]
+	fn test_decrypt_sym_with_require_signature_policy()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		let encrypted = group_key
+			.encrypt_with_sign(text.as_bytes(), &user.user_keys[0].sign_key)
+			.unwrap();
+
+		let decrypted = group_key
+			.decrypt(&encrypted, SignaturePolicy::Require(verify_key))
+			.unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//unsigned data must fail
+		let encrypted = group_key.encrypt(text.as_bytes()).unwrap();
+
+		assert!(matches!(
+			group_key.decrypt(&encrypted, SignaturePolicy::Require(verify_key)),
+			Err(SdkUtilError::SignatureRequired)
+		));
+
+		//data signed by another user must fail
+		let user_2 = create_user();
+
+		let encrypted = group_key
+			.encrypt_with_sign(text.as_bytes(), &user_2.user_keys[0].sign_key)
+			.unwrap();
+
+		assert!(matches!(
+			group_key.decrypt(&encrypted, SignaturePolicy::Require(verify_key)),
+			Err(SdkUtilError::SigFoundNotKey)
+		));
+	}
+
+	#[test]
+	fn test_decrypt_sym_with_verify_if_signed_policy()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+
+		let encrypted = group_key
+			.encrypt_with_sign(text.as_bytes(), &user.user_keys[0].sign_key)
+			.unwrap();
+
+		//signed data without a verify key must fail
+		assert!(matches!(
+			group_key.decrypt(&encrypted, SignaturePolicy::VerifyIfSigned(None)),
+			Err(SdkUtilError::SignatureVerifyKeyMissing)
+		));
+
+		//but is still accepted by the lenient policy
+		let decrypted = group_key.decrypt(&encrypted, None).unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		let decrypted = group_key
+			.decrypt(
+				&encrypted,
+				SignaturePolicy::VerifyIfSigned(Some(&user.user_keys[0].exported_verify_key)),
+			)
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//unsigned data is fine
+		let encrypted = group_key.encrypt(text.as_bytes()).unwrap();
+
+		let decrypted = group_key
+			.decrypt(&encrypted, SignaturePolicy::VerifyIfSigned(None))
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+	}
+
+	#[test]
+	fn test_decrypt_asym_with_require_signature_policy()
+	{
+		let user = create_user();
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		let encrypted = TestPublicKey::encrypt_with_user_key(&user.user_keys[0].exported_public_key, text.as_bytes()).unwrap();
+
+		assert!(matches!(
+			user.user_keys[0]
+				.private_key
+				.decrypt(&encrypted, SignaturePolicy::Require(verify_key)),
+			Err(SdkUtilError::SignatureRequired)
+		));
+
+		let encrypted = TestPublicKey::encrypt_with_user_key_with_sign(
+			&user.user_keys[0].exported_public_key,
+			text.as_bytes(),
+			&user.user_keys[0].sign_key,
+		)
+		.unwrap();
+
+		let decrypted = user.user_keys[0]
+			.private_key
+			.decrypt(&encrypted, SignaturePolicy::Require(verify_key))
+			.unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted)
+	}
+
+	#[test]
+	fn test_decrypt_with_crypto_policy()
+	{
+		let user = create_user();
+		let user_keys = &user.user_keys[0];
+
+		let (_, key_data, _, _, _) = create_group(user_keys);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+
+		let sym = [group_key.get_key().get_alg_str()];
+		let sign = [user_keys.exported_verify_key.verify_key_alg.as_str()];
+
+		let encrypted = group_key
+			.encrypt_with_sign(text.as_bytes(), &user_keys.sign_key)
+			.unwrap();
+
+		let policy = CryptoPolicy {
+			sym: Some(&sym),
+			sign: Some(&sign),
+			..Default::default()
+		};
+
+		let decrypted = group_key
+			.decrypt_with_crypto_policy(&encrypted, Some(&user_keys.exported_verify_key), &policy)
+			.unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//the sign alg is checked even if the signature is not verified
+		let policy = CryptoPolicy {
+			sym: Some(&sym),
+			sign: Some(&[]),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			group_key.decrypt_with_crypto_policy(&encrypted, None, &policy),
+			Err(SdkUtilError::AlgNotAllowed(_))
+		));
+
+		let encrypted = TestPublicKey::encrypt_with_user_key(&user_keys.exported_public_key, text.as_bytes()).unwrap();
+
+		let policy = CryptoPolicy {
+			post_quantum: Some(&[]),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			user_keys
+				.private_key
+				.decrypt_with_crypto_policy(&encrypted, None, &policy),
+			Err(SdkUtilError::AlgNotPostQuantum(_))
+		));
+		assert!(matches!(
+			TestPublicKey::from_user_key_with_policy(&user_keys.exported_public_key, &policy),
+			Err(SdkUtilError::AlgNotPostQuantum(_))
+		));
+
+		let asym = [user_keys.exported_public_key.public_key_alg.as_str()];
+
+		let policy = CryptoPolicy {
+			asym: Some(&asym),
+			..Default::default()
+		};
+
+		let decrypted = user_keys
+			.private_key
+			.decrypt_with_crypto_policy(&encrypted, None, &policy)
+			.unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		policy
+			.public_key_from_pem::<TestSecretKey>(
+				&user_keys.exported_public_key.public_key_pem,
+				&user_keys.exported_public_key.public_key_alg,
+			)
+			.unwrap();
+
+		//the alg of the symmetric key from the server is checked before the key is imported
+		let (_, encrypted_key) = TestKeyGenerator::generate_non_register_sym_key_by_public_key(&user_keys.exported_public_key).unwrap();
+
+		TestKeyGenerator::decrypt_sym_key_by_private_key_with_policy(&user_keys.private_key, &encrypted_key, &policy).unwrap();
+
+		let policy = CryptoPolicy {
+			sym: Some(&[]),
+			..Default::default()
+		};
+
+		assert!(matches!(
+			TestKeyGenerator::decrypt_sym_key_by_private_key_with_policy(&user_keys.private_key, &encrypted_key, &policy),
+			Err(SdkError::Util(SdkUtilError::AlgNotAllowed(_)))
+		));
+	}
+
+	#[test]
+	fn test_legacy_signature_by_default()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		//older versions must still be able to read signed data, so the sign alg has no head suffix
+		let (head, encrypted) = group_key
+			.encrypt_raw_with_sign(text.as_bytes(), &user.user_keys[0].sign_key)
+			.unwrap();
+
+		assert!(!head
+			.sign
+			.as_ref()
+			.unwrap()
+			.alg
+			.ends_with(SIGN_HEAD_BOUND_SUFFIX));
+
+		let decrypted = group_key
+			.decrypt_raw(&encrypted, &head, Some(verify_key))
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		let decrypted = group_key.decrypt_raw(&encrypted, &head, None).unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		let (head, encrypted) = TestPublicKey::encrypt_raw_with_user_key_with_sign(
+			&user.user_keys[0].exported_public_key,
+			text.as_bytes(),
+			&user.user_keys[0].sign_key,
+		)
+		.unwrap();
+
+		assert!(!head
+			.sign
+			.as_ref()
+			.unwrap()
+			.alg
+			.ends_with(SIGN_HEAD_BOUND_SUFFIX));
+
+		let decrypted = user.user_keys[0]
+			.private_key
+			.decrypt_raw(&encrypted, &head, Some(verify_key))
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+	}
+
+	#[test]
+	fn test_signature_bound_to_sym_head()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		let (mut head, encrypted) = group_key
+			.encrypt_raw_with_bound_sign(text.as_bytes(), &user.user_keys[0].sign_key)
+			.unwrap();
+
+		assert!(head
+			.sign
+			.as_ref()
+			.unwrap()
+			.alg
+			.ends_with(SIGN_HEAD_BOUND_SUFFIX));
+
+		//the head is not used for the binding, the id of the decrypting key is
+		head.id = "456".to_string();
+
+		let decrypted = group_key
+			.decrypt_raw(&encrypted, &head, Some(verify_key))
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//the same key under another id, the data was not signed for this key
+		let mut other_key: TestSymmetricKey = group_key.to_string_ref().unwrap().parse().unwrap();
+		other_key.key_id = "456".to_string();
+
+		assert!(matches!(
+			other_key.decrypt_raw(&encrypted, &head, Some(verify_key)),
+			Err(SdkUtilError::VerifyFailed)
+		));
+
+		//without a verify key the data can still be decrypted
+		let decrypted = other_key.decrypt_raw(&encrypted, &head, None).unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+	}
+
+	#[test]
+	fn test_signature_bound_to_receiver()
+	{
+		let user = create_user();
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		let (mut head, encrypted) = TestPublicKey::encrypt_raw_with_user_key_with_bound_sign(
+			&user.user_keys[0].exported_public_key,
+			text.as_bytes(),
+			&user.user_keys[0].sign_key,
+		)
+		.unwrap();
+
+		let decrypted = user.user_keys[0]
+			.private_key
+			.decrypt_raw(&encrypted, &head, Some(verify_key))
+			.unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//re-target the head to another receiver, the signature is still bound to the receiver of the sender
+		head.id = "456".to_string();
+
+		let mut other_key: TestSecretKey = user.user_keys[0]
+			.private_key
+			.to_string_ref()
+			.unwrap()
+			.parse()
+			.unwrap();
+		other_key.key_id = "456".to_string();
+
+		assert!(matches!(
+			other_key.decrypt_raw(&encrypted, &head, Some(verify_key)),
+			Err(SdkUtilError::VerifyFailed)
+		));
+	}
+
 	#[test]
 	fn test_encrypt_decrypt_asym()
 	{
@@ -650,4 +1045,169 @@ mod test
 
 		assert_eq!(key.key.as_ref(), decrypted_key.key.as_ref());
 	}
+
+	#[test]
+	fn test_binary_head()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+
+		let (head, encrypted) = group_key
+			.encrypt_raw_with_sign(text.as_bytes(), &user.user_keys[0].sign_key)
+			.unwrap();
+
+		let json = put_head_and_encrypted_data_with_format(&head, &encrypted, HeadFormat::Json).unwrap();
+		let binary = put_head_and_encrypted_data_with_format(&head, &encrypted, HeadFormat::Binary).unwrap();
+
+		assert_eq!(json[0], b'{');
+		assert_eq!(binary[0], BINARY_HEAD_MAGIC);
+		assert!(binary.len() < json.len());
+
+		//both formats are detected when splitting
+		for data in [&json, &binary] {
+			let (split_head, split_data): (EncryptedHead, &[u8]) = split_head_and_encrypted_data(data).unwrap();
+
+			assert_eq!(split_head.id, head.id);
+			assert_eq!(split_head.sign.as_ref().unwrap().id, head.sign.as_ref().unwrap().id);
+			assert_eq!(split_head.sign.as_ref().unwrap().alg, head.sign.as_ref().unwrap().alg);
+			assert_eq!(split_data, encrypted.as_slice());
+
+			let decrypted = group_key
+				.decrypt(data, SignaturePolicy::Require(&user.user_keys[0].exported_verify_key))
+				.unwrap();
+
+			assert_eq!(decrypted, text.as_bytes());
+		}
+	}
+
+	#[test]
+	fn test_binary_head_without_sign()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let (head, encrypted) = group_key.encrypt_raw(b"hello").unwrap();
+
+		let binary = put_head_and_encrypted_data_with_format(&head, &encrypted, HeadFormat::Binary).unwrap();
+
+		let (split_head, _): (EncryptedHead, &[u8]) = split_head_and_encrypted_data(&binary).unwrap();
+
+		assert_eq!(split_head.id, head.id);
+		assert!(split_head.sign.is_none());
+
+		assert_eq!(group_key.decrypt(&binary, None).unwrap(), b"hello");
+	}
+
+	#[test]
+	fn test_binary_head_by_writers()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$";
+		let verify_key = &user.user_keys[0].exported_verify_key;
+
+		let encrypted = group_key
+			.encrypt_with_sign_with_format(text.as_bytes(), &user.user_keys[0].sign_key, HeadFormat::Binary)
+			.unwrap();
+
+		assert_eq!(encrypted[0], BINARY_HEAD_MAGIC);
+
+		let decrypted = group_key
+			.decrypt(&encrypted, SignaturePolicy::Require(verify_key))
+			.unwrap();
+		assert_eq!(decrypted, text.as_bytes());
+
+		let encrypted = group_key
+			.encrypt_string_with_format(text, HeadFormat::Binary)
+			.unwrap();
+
+		assert_eq!(group_key.decrypt_string(&encrypted, None).unwrap(), text);
+
+		let encrypted = TestPublicKey::encrypt_with_user_key_with_sign_with_format(
+			&user.user_keys[0].exported_public_key,
+			text.as_bytes(),
+			&user.user_keys[0].sign_key,
+			HeadFormat::Binary,
+		)
+		.unwrap();
+
+		assert_eq!(encrypted[0], BINARY_HEAD_MAGIC);
+
+		let decrypted = user.user_keys[0]
+			.private_key
+			.decrypt(&encrypted, SignaturePolicy::Require(verify_key))
+			.unwrap();
+		assert_eq!(decrypted, text.as_bytes());
+	}
+
+	#[test]
+	fn test_binary_head_invalid()
+	{
+		let user = create_user();
+
+		let (_, key_data, _, _, _) = create_group(&user.user_keys[0]);
+		let group_key = &key_data[0].group_key;
+
+		let (head, encrypted) = group_key.encrypt_raw(b"hello").unwrap();
+
+		let binary = put_head_and_encrypted_data_with_format(&head, &encrypted, HeadFormat::Binary).unwrap();
+
+		//truncated head
+		let res = split_head_and_encrypted_data::<EncryptedHead>(&binary[..5]);
+		assert!(matches!(
+			res,
+			Err(SdkError::Util(SdkUtilError::DecodeEncryptedDataFailed))
+		));
+
+		//unknown version
+		let mut wrong_version = binary.clone();
+		wrong_version[1] = 2;
+
+		let res = split_head_and_encrypted_data::<EncryptedHead>(&wrong_version);
+		assert!(matches!(
+			res,
+			Err(SdkError::Util(SdkUtilError::DecodeEncryptedDataFailed))
+		));
+
+		//a field longer than the u16 length prefix
+		let long_head = EncryptedHead {
+			id: "a".repeat(u16::MAX as usize + 1),
+			sign: None,
+		};
+
+		let res = put_head_and_encrypted_data_with_format(&long_head, &encrypted, HeadFormat::Binary);
+		assert!(matches!(res, Err(SdkError::Util(SdkUtilError::HeadFieldTooLong))));
+	}
+
+	#[test]
+	fn test_split_json_head()
+	{
+		#[derive(Serialize, Deserialize)]
+		struct OwnHead
+		{
+			version: u8,
+		}
+
+		let data = put_head_and_encrypted_data(
+			&OwnHead {
+				version: 1,
+			},
+			b"hello",
+		)
+		.unwrap();
+
+		let (head, rest): (OwnHead, &[u8]) = split_head_and_encrypted_json(&data).unwrap();
+
+		assert_eq!(head.version, 1);
+		assert_eq!(rest, b"hello");
+	}
 }
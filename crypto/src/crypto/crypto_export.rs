@@ -4,7 +4,9 @@ use alloc::vec::Vec;
 use sentc_crypto_common::crypto::{EncryptedHead, GeneratedSymKeyHeadServerOutput};
 use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_std_keys::util::{PublicKey, SecretKey, SignKey, SymmetricKey};
-use sentc_crypto_utils::cryptomat::{KeyToString, PkFromUserKeyWrapper, SkCryptoWrapper, SymKeyCrypto};
+use sentc_crypto_utils::cryptomat::{KeyToString, PkFromUserKeyWrapper, SignaturePolicy, SkCryptoWrapper, SymKeyCrypto};
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::head::HeadFormat;
 
 use crate::keys::std::StdKeyGenerator;
 use crate::SdkError;
@@ -27,6 +29,37 @@ pub(crate) fn prepare_verify_key(verify_key_data: Option<&str>) -> Result<Option
 	Ok(k)
 }
 
+/**
+Get the signature policy from its name for the decrypt functions with policy.
+
+- lenient: verify the signature if a verify key is given, else the signature is removed. Unsigned data is accepted.
+  The decrypt functions without policy always use this.
+- verify_if_signed: signed data must be verified, so a verify key is required for it. Unsigned data is accepted.
+- require: the data must be signed by the given verify key.
+ */
+pub(crate) fn prepare_signature_policy<'a>(verify_key: Option<&'a UserVerifyKeyData>, policy: &str) -> Result<SignaturePolicy<'a>, SdkError>
+{
+	match (policy, verify_key) {
+		("lenient", vk) => Ok(SignaturePolicy::Lenient(vk)),
+		("verify_if_signed", vk) => Ok(SignaturePolicy::VerifyIfSigned(vk)),
+		("require", Some(vk)) => Ok(SignaturePolicy::Require(vk)),
+		("require", None) => Err(SdkUtilError::SignatureVerifyKeyMissing.into()),
+		_ => Err(SdkError::SignaturePolicyNotFound),
+	}
+}
+
+/**
+Get the head format from its name for the encrypt functions with format: json or binary.
+ */
+pub(crate) fn prepare_head_format(format: &str) -> Result<HeadFormat, SdkError>
+{
+	match format {
+		"json" => Ok(HeadFormat::Json),
+		"binary" => Ok(HeadFormat::Binary),
+		_ => Err(SdkError::HeadFormatNotFound),
+	}
+}
+
 pub fn split_head_and_encrypted_data(data_with_head: &[u8]) -> Result<(EncryptedHead, &[u8]), String>
 {
 	Ok(super::crypto::split_head_and_encrypted_data(data_with_head)?)
@@ -79,14 +112,29 @@ pub fn encrypt_raw_symmetric_with_aad(key: &str, data: &[u8], aad: &[u8], sign_k
 }
 
 pub fn decrypt_raw_symmetric(key: &str, encrypted_data: &[u8], head: &str, verify_key_data: Option<&str>) -> Result<Vec<u8>, String>
+{
+	decrypt_raw_symmetric_with_policy(key, encrypted_data, head, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_raw_symmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_raw_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	head: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
 	let head = EncryptedHead::from_string(head).map_err(SdkError::JsonParseFailed)?;
 
-	Ok(key.decrypt_raw(encrypted_data, &head, verify_key.as_ref())?)
+	Ok(key.decrypt_raw(encrypted_data, &head, policy)?)
 }
 
 pub fn decrypt_raw_symmetric_with_aad(
@@ -96,14 +144,30 @@ pub fn decrypt_raw_symmetric_with_aad(
 	aad: &[u8],
 	verify_key_data: Option<&str>,
 ) -> Result<Vec<u8>, String>
+{
+	decrypt_raw_symmetric_with_aad_with_policy(key, encrypted_data, head, aad, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_raw_symmetric_with_aad but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_raw_symmetric_with_aad_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	head: &str,
+	aad: &[u8],
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
 	let head = EncryptedHead::from_string(head).map_err(SdkError::JsonParseFailed)?;
 
-	Ok(key.decrypt_raw_with_aad(encrypted_data, aad, &head, verify_key.as_ref())?)
+	Ok(key.decrypt_raw_with_aad(encrypted_data, aad, &head, policy)?)
 }
 
 pub fn encrypt_raw_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Option<&str>) -> Result<(String, Vec<u8>), String>
@@ -125,14 +189,29 @@ pub fn encrypt_raw_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key
 }
 
 pub fn decrypt_raw_asymmetric(private_key: &str, encrypted_data: &[u8], head: &str, verify_key_data: Option<&str>) -> Result<Vec<u8>, String>
+{
+	decrypt_raw_asymmetric_with_policy(private_key, encrypted_data, head, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_raw_asymmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_raw_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &[u8],
+	head: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let private_key: SecretKey = private_key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
 	let head = EncryptedHead::from_string(head).map_err(SdkError::JsonParseFailed)?;
 
-	Ok(private_key.decrypt_raw(encrypted_data, &head, verify_key.as_ref())?)
+	Ok(private_key.decrypt_raw(encrypted_data, &head, policy)?)
 }
 
 pub fn encrypt_symmetric(key: &str, data: &[u8], sign_key: Option<&str>) -> Result<Vec<u8>, String>
@@ -147,6 +226,22 @@ pub fn encrypt_symmetric(key: &str, data: &[u8], sign_key: Option<&str>) -> Resu
 	}
 }
 
+/**
+Like encrypt_symmetric but with the json or the compact binary head, see prepare_head_format.
+ */
+pub fn encrypt_symmetric_with_format(key: &str, data: &[u8], sign_key: Option<&str>, head_format: &str) -> Result<Vec<u8>, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+
+	match sign_key {
+		None => Ok(key.encrypt_with_format(data, format)?),
+		Some(sk) => Ok(key.encrypt_with_sign_with_format(data, &sk, format)?),
+	}
+}
+
 pub fn encrypt_symmetric_with_aad(key: &str, data: &[u8], aad: &[u8], sign_key: Option<&str>) -> Result<Vec<u8>, String>
 {
 	let key: SymmetricKey = key.parse()?;
@@ -159,22 +254,63 @@ pub fn encrypt_symmetric_with_aad(key: &str, data: &[u8], aad: &[u8], sign_key:
 	}
 }
 
+/**
+Like encrypt_symmetric with a sign key but the signature is bound to the key id, older versions can't decrypt this data.
+ */
+pub fn encrypt_symmetric_with_bound_sign(key: &str, data: &[u8], sign_key: &str) -> Result<Vec<u8>, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let sign_key: SignKey = sign_key.parse()?;
+
+	Ok(key.encrypt_with_bound_sign(data, &sign_key)?)
+}
+
 pub fn decrypt_symmetric(key: &str, encrypted_data: &[u8], verify_key_data: Option<&str>) -> Result<Vec<u8>, String>
+{
+	decrypt_symmetric_with_policy(key, encrypted_data, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_symmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(key.decrypt(encrypted_data, verify_key.as_ref())?)
+	Ok(key.decrypt(encrypted_data, policy)?)
 }
 
 pub fn decrypt_symmetric_with_aad(key: &str, encrypted_data: &[u8], aad: &[u8], verify_key_data: Option<&str>) -> Result<Vec<u8>, String>
+{
+	decrypt_symmetric_with_aad_with_policy(key, encrypted_data, aad, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_symmetric_with_aad but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_symmetric_with_aad_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	aad: &[u8],
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(key.decrypt_with_aad(encrypted_data, aad, verify_key.as_ref())?)
+	Ok(key.decrypt_with_aad(encrypted_data, aad, policy)?)
 }
 
 pub fn encrypt_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Option<&str>) -> Result<Vec<u8>, String>
@@ -195,13 +331,73 @@ pub fn encrypt_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Op
 	}
 }
 
+/**
+Like encrypt_asymmetric but with the json or the compact binary head, see prepare_head_format.
+ */
+pub fn encrypt_asymmetric_with_format(reply_public_key_data: &str, data: &[u8], sign_key: Option<&str>, head_format: &str)
+	-> Result<Vec<u8>, String>
+{
+	let reply_public_key_data = UserPublicKeyData::from_string(reply_public_key_data).map_err(SdkError::JsonParseFailed)?;
+
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+
+	match sign_key {
+		None => {
+			Ok(PublicKey::encrypt_with_user_key_with_format(
+				&reply_public_key_data,
+				data,
+				format,
+			)?)
+		},
+		Some(sk) => {
+			Ok(PublicKey::encrypt_with_user_key_with_sign_with_format(
+				&reply_public_key_data,
+				data,
+				&sk,
+				format,
+			)?)
+		},
+	}
+}
+
+/**
+Like encrypt_asymmetric with a sign key but the signature is bound to the receiver, older versions can't decrypt this data.
+ */
+pub fn encrypt_asymmetric_with_bound_sign(reply_public_key_data: &str, data: &[u8], sign_key: &str) -> Result<Vec<u8>, String>
+{
+	let reply_public_key_data = UserPublicKeyData::from_string(reply_public_key_data).map_err(SdkError::JsonParseFailed)?;
+
+	let sign_key: SignKey = sign_key.parse()?;
+
+	Ok(PublicKey::encrypt_with_user_key_with_bound_sign(
+		&reply_public_key_data,
+		data,
+		&sign_key,
+	)?)
+}
+
 pub fn decrypt_asymmetric(private_key: &str, encrypted_data: &[u8], verify_key_data: Option<&str>) -> Result<Vec<u8>, String>
+{
+	decrypt_asymmetric_with_policy(private_key, encrypted_data, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_asymmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &[u8],
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<Vec<u8>, String>
 {
 	let private_key: SecretKey = private_key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(private_key.decrypt(encrypted_data, verify_key.as_ref())?)
+	Ok(private_key.decrypt(encrypted_data, policy)?)
 }
 
 pub fn encrypt_string_symmetric(key: &str, data: &str, sign_key: Option<&str>) -> Result<String, String>
@@ -216,6 +412,22 @@ pub fn encrypt_string_symmetric(key: &str, data: &str, sign_key: Option<&str>) -
 	}
 }
 
+/**
+Like encrypt_string_symmetric but with the json or the compact binary head, see prepare_head_format.
+ */
+pub fn encrypt_string_symmetric_with_format(key: &str, data: &str, sign_key: Option<&str>, head_format: &str) -> Result<String, String>
+{
+	let key: SymmetricKey = key.parse()?;
+
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+
+	match sign_key {
+		None => Ok(key.encrypt_string_with_format(data, format)?),
+		Some(sk) => Ok(key.encrypt_string_with_sign_with_format(data, &sk, format)?),
+	}
+}
+
 pub fn encrypt_string_symmetric_with_aad(key: &str, data: &str, aad: &str, sign_key: Option<&str>) -> Result<String, String>
 {
 	let key: SymmetricKey = key.parse()?;
@@ -229,21 +441,50 @@ pub fn encrypt_string_symmetric_with_aad(key: &str, data: &str, aad: &str, sign_
 }
 
 pub fn decrypt_string_symmetric(key: &str, encrypted_data: &str, verify_key_data: Option<&str>) -> Result<String, String>
+{
+	decrypt_string_symmetric_with_policy(key, encrypted_data, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_string_symmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_string_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<String, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(key.decrypt_string(encrypted_data, verify_key.as_ref())?)
+	Ok(key.decrypt_string(encrypted_data, policy)?)
 }
 
 pub fn decrypt_string_symmetric_with_aad(key: &str, encrypted_data: &str, aad: &str, verify_key_data: Option<&str>) -> Result<String, String>
+{
+	decrypt_string_symmetric_with_aad_with_policy(key, encrypted_data, aad, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_string_symmetric_with_aad but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_string_symmetric_with_aad_with_policy(
+	key: &str,
+	encrypted_data: &str,
+	aad: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<String, String>
 {
 	let key: SymmetricKey = key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(key.decrypt_string_with_aad(encrypted_data, aad, verify_key.as_ref())?)
+	Ok(key.decrypt_string_with_aad(encrypted_data, aad, policy)?)
 }
 
 pub fn encrypt_string_asymmetric(reply_public_key_data: &str, data: &str, sign_key: Option<&str>) -> Result<String, String>
@@ -264,13 +505,61 @@ pub fn encrypt_string_asymmetric(reply_public_key_data: &str, data: &str, sign_k
 	}
 }
 
+/**
+Like encrypt_string_asymmetric but with the json or the compact binary head, see prepare_head_format.
+ */
+pub fn encrypt_string_asymmetric_with_format(
+	reply_public_key_data: &str,
+	data: &str,
+	sign_key: Option<&str>,
+	head_format: &str,
+) -> Result<String, String>
+{
+	let reply_public_key_data = UserPublicKeyData::from_string(reply_public_key_data).map_err(SdkError::JsonParseFailed)?;
+
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+
+	match sign_key {
+		None => {
+			Ok(PublicKey::encrypt_string_with_user_key_with_format(
+				&reply_public_key_data,
+				data,
+				format,
+			)?)
+		},
+		Some(sk) => {
+			Ok(PublicKey::encrypt_string_with_user_key_with_sign_with_format(
+				&reply_public_key_data,
+				data,
+				&sk,
+				format,
+			)?)
+		},
+	}
+}
+
 pub fn decrypt_string_asymmetric(private_key: &str, encrypted_data: &str, verify_key_data: Option<&str>) -> Result<String, String>
+{
+	decrypt_string_asymmetric_with_policy(private_key, encrypted_data, verify_key_data, "lenient")
+}
+
+/**
+Like decrypt_string_asymmetric but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_string_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<String, String>
 {
 	let private_key: SecretKey = private_key.parse()?;
 
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 
-	Ok(private_key.decrypt_string(encrypted_data, verify_key.as_ref())?)
+	Ok(private_key.decrypt_string(encrypted_data, policy)?)
 }
 
 //__________________________________________________________________________________________________
@@ -552,6 +841,37 @@ mod test
 		assert_eq!(text.as_bytes(), decrypted);
 	}
 
+	#[test]
+	fn test_decrypt_sym_with_signature_policy()
+	{
+		let user = create_user_export();
+		let user_keys = &user.user_keys[0];
+
+		let (_, key_data, _, _, _) = create_group_export(user_keys);
+		let group_key = &key_data[0].group_key;
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀";
+
+		let encrypted = encrypt_symmetric(group_key, text.as_bytes(), None).unwrap();
+		let encrypted_signed = encrypt_symmetric(group_key, text.as_bytes(), Some(user_keys.sign_key.as_str())).unwrap();
+
+		let verify_key = Some(user_keys.exported_verify_key.as_str());
+
+		let decrypted = decrypt_symmetric_with_policy(group_key, &encrypted_signed, verify_key, "require").unwrap();
+		assert_eq!(text.as_bytes(), decrypted);
+
+		//not signed
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted, verify_key, "require").is_err());
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted, verify_key, "verify_if_signed").is_ok());
+
+		//signed but no verify key
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted_signed, None, "require").is_err());
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted_signed, None, "verify_if_signed").is_err());
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted_signed, None, "lenient").is_ok());
+
+		assert!(decrypt_symmetric_with_policy(group_key, &encrypted_signed, verify_key, "strict").is_err());
+	}
+
 	#[test]
 	fn test_encrypt_decrypt_asym()
 	{
@@ -8,4 +8,4 @@ pub use self::crypto::*;
 #[cfg(feature = "export")]
 pub use self::crypto_export::*;
 #[cfg(feature = "export")]
-pub(crate) use self::crypto_export::{prepare_sign_key, prepare_verify_key};
+pub(crate) use self::crypto_export::{prepare_sign_key, prepare_signature_policy, prepare_verify_key};
@@ -0,0 +1,64 @@
+//! Searchable encryption across multiple hmac keys of a group.
+//!
+//! A group can have more than one hmac key (e.g. after a key was replaced).
+//! Data that was hashed with an older key can only be found with a search hash of the same key.
+//!
+//! Use search_with_all_keys to create the search hashes for every key of the group in one call
+//! and reindex_searchable to create new hashes of already indexed data with the newest key.
+//! After every item is re-indexed the old hmac key can be removed.
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::content_searchable::SearchableCreateOutput;
+use sentc_crypto_core::cryptomat::CryptoAlg;
+use sentc_crypto_utils::cryptomat::SearchableKeyWrapper;
+
+use crate::SdkError;
+
+/**
+Create a search hash for every given hmac key.
+
+The output contains one entry per key with the key id, so the server can look up each hash with the right key id.
+ */
+pub fn search_with_all_keys<K: SearchableKeyWrapper>(keys: &[K], data: &str) -> Result<Vec<SearchableCreateOutput>, SdkError>
+{
+	let mut out = Vec::with_capacity(keys.len());
+
+	for key in keys {
+		let hash = key.search(data)?;
+
+		out.push(SearchableCreateOutput {
+			hashes: vec![hash],
+			alg: key.get_key().get_alg_str().to_string(),
+			key_id: key.get_id().to_string(),
+		});
+	}
+
+	Ok(out)
+}
+
+/**
+Create the hashes of the data again with the newest hmac key.
+
+The old outputs are the hashes that are already stored for this data.
+If one of them was already created by the newest key, no new hashes are needed and None is returned.
+Otherwise, the returned output replaces all old outputs.
+
+Use the same full and limit values as for the old outputs, otherwise the search results will differ.
+ */
+pub fn reindex_searchable<K: SearchableKeyWrapper>(
+	newest_key: &K,
+	data: &str,
+	old_outputs: &[SearchableCreateOutput],
+	full: bool,
+	limit: Option<usize>,
+) -> Result<Option<SearchableCreateOutput>, SdkError>
+{
+	if old_outputs.iter().any(|o| o.key_id == newest_key.get_id()) {
+		return Ok(None);
+	}
+
+	Ok(Some(newest_key.create_searchable(data, full, limit)?))
+}
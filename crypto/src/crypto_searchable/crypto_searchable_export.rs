@@ -2,8 +2,11 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use sentc_crypto_common::content_searchable::SearchableCreateOutput;
-use sentc_crypto_std_keys::util::HmacKey;
+use sentc_crypto_std_keys::util::{HmacFormatExport, HmacKey};
 use sentc_crypto_utils::cryptomat::SearchableKeyWrapper;
+use serde_json::from_str;
+
+use crate::SdkError;
 
 pub fn create_searchable_raw(key: &str, data: &str, full: bool, limit: Option<usize>) -> Result<Vec<String>, String>
 {
@@ -26,9 +29,88 @@ pub fn search(key: &str, data: &str) -> Result<String, String>
 	Ok(key.search(data)?)
 }
 
+/**
+Create a search hash for every hmac key of the group.
+
+The keys are a json array of the exported hmac keys.
+ */
+pub fn search_with_all_keys(keys: &str, data: &str) -> Result<Vec<SearchableCreateOutput>, String>
+{
+	let keys: Vec<HmacFormatExport> = from_str(keys).map_err(SdkError::JsonParseFailed)?;
+
+	let keys = keys
+		.into_iter()
+		.map(|k| k.try_into())
+		.collect::<Result<Vec<HmacKey>, _>>()?;
+
+	Ok(super::crypto_searchable::search_with_all_keys(&keys, data)?)
+}
+
+pub fn reindex_searchable(
+	newest_key: &str,
+	data: &str,
+	old_outputs: &[SearchableCreateOutput],
+	full: bool,
+	limit: Option<usize>,
+) -> Result<Option<SearchableCreateOutput>, String>
+{
+	let newest_key: HmacKey = newest_key.parse()?;
+
+	Ok(super::crypto_searchable::reindex_searchable(
+		&newest_key,
+		data,
+		old_outputs,
+		full,
+		limit,
+	)?)
+}
+
+pub fn create_blind_index(key: &str, data: &str, bits: u16) -> Result<SearchableCreateOutput, String>
+{
+	let key: HmacKey = key.parse()?;
+
+	Ok(key.create_blind_index(data, bits)?)
+}
+
+pub fn create_blind_index_compound(key: &str, fields: &[String], bits: u16) -> Result<SearchableCreateOutput, String>
+{
+	let key: HmacKey = key.parse()?;
+
+	let fields: Vec<&str> = fields.iter().map(|f| f.as_str()).collect();
+
+	Ok(key.create_blind_index_compound(&fields, bits)?)
+}
+
+pub fn search_blind_index(key: &str, data: &str, bits: u16) -> Result<String, String>
+{
+	let key: HmacKey = key.parse()?;
+
+	Ok(key.search_blind_index(data, bits)?)
+}
+
+pub fn search_blind_index_compound(key: &str, fields: &[String], bits: u16) -> Result<String, String>
+{
+	let key: HmacKey = key.parse()?;
+
+	let fields: Vec<&str> = fields.iter().map(|f| f.as_str()).collect();
+
+	Ok(key.search_blind_index_compound(&fields, bits)?)
+}
+
+pub fn search_blind_index_candidates(key: &str, values: &[String], bits: u16) -> Result<Vec<String>, String>
+{
+	let key: HmacKey = key.parse()?;
+
+	let values: Vec<&str> = values.iter().map(|f| f.as_str()).collect();
+
+	Ok(key.search_blind_index_candidates(&values, bits)?)
+}
+
 #[cfg(test)]
 mod test
 {
+	use alloc::string::ToString;
+
 	use super::*;
 	use crate::group::test_fn::create_group_export;
 	use crate::user::test_fn::create_user_export;
@@ -138,4 +220,68 @@ mod test
 
 		assert!(!out.hashes.contains(&search_str2));
 	}
+
+	#[test]
+	fn test_search_with_all_keys()
+	{
+		let user = create_user_export();
+		let (_, _, _, hmac_keys, _) = create_group_export(&user.user_keys[0]);
+		let (_, _, _, hmac_keys2, _) = create_group_export(&user.user_keys[0]);
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀 😎";
+
+		let out = create_searchable(&hmac_keys[0], text, false, None).unwrap();
+
+		let keys = alloc::format!("[{},{}]", hmac_keys2[0], hmac_keys[0]);
+
+		let search_out = search_with_all_keys(&keys, "123").unwrap();
+
+		assert_eq!(search_out.len(), 2);
+
+		assert!(!out.hashes.contains(&search_out[0].hashes[0]));
+		assert!(out.hashes.contains(&search_out[1].hashes[0]));
+	}
+
+	#[test]
+	fn test_reindex_searchable()
+	{
+		let user = create_user_export();
+		let (_, _, _, hmac_keys, _) = create_group_export(&user.user_keys[0]);
+		let (_, _, _, hmac_keys2, _) = create_group_export(&user.user_keys[0]);
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀 😎";
+
+		let old_out = create_searchable(&hmac_keys[0], text, false, None).unwrap();
+
+		let new_out = reindex_searchable(&hmac_keys2[0], text, &[old_out], false, None)
+			.unwrap()
+			.unwrap();
+
+		let search_str = search(&hmac_keys2[0], "123").unwrap();
+
+		assert!(new_out.hashes.contains(&search_str));
+	}
+
+	#[test]
+	fn test_blind_index()
+	{
+		let user = create_user_export();
+		let (_, _, _, hmac_keys, _) = create_group_export(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let out = create_blind_index(hmac_key, "DE", 8).unwrap();
+
+		assert_eq!(out.hashes.len(), 1);
+
+		let bucket = search_blind_index(hmac_key, "DE", 8).unwrap();
+
+		assert_eq!(out.hashes[0], bucket);
+
+		let out = create_blind_index_compound(hmac_key, &["DE".to_string(), "1990".to_string()], 12).unwrap();
+
+		let bucket = search_blind_index_compound(hmac_key, &["DE".to_string(), "1990".to_string()], 12).unwrap();
+
+		assert_eq!(out.hashes[0], bucket);
+	}
 }
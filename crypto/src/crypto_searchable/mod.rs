@@ -1,14 +1,19 @@
+pub(crate) mod crypto_searchable;
 #[cfg(feature = "export")]
 mod crypto_searchable_export;
 
+#[cfg(not(feature = "export"))]
+pub use self::crypto_searchable::*;
 #[cfg(feature = "export")]
 pub use self::crypto_searchable_export::*;
 
 #[cfg(test)]
 mod test
 {
+	use base64ct::{Base64UrlUnpadded, Encoding};
 	use sentc_crypto_utils::cryptomat::SearchableKeyWrapper;
 
+	use super::crypto_searchable::{reindex_searchable, search_with_all_keys};
 	use crate::group::test_fn::create_group;
 	use crate::user::test_fn::create_user;
 
@@ -117,4 +122,132 @@ mod test
 
 		assert!(!out.hashes.contains(&search_str2));
 	}
+
+	#[test]
+	fn test_search_with_all_keys()
+	{
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+		let (_, _, _, hmac_keys2, _) = create_group(&user.user_keys[0]);
+
+		//simulate a group with an old and a new hmac key
+		let keys = [hmac_keys2.into_iter().next().unwrap(), hmac_keys.into_iter().next().unwrap()];
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀 😎";
+
+		//the data was indexed with the old key
+		let out = keys[1].create_searchable(text, false, None).unwrap();
+
+		let search_out = search_with_all_keys(&keys, "123").unwrap();
+
+		assert_eq!(search_out.len(), 2);
+		assert_eq!(search_out[0].key_id, keys[0].key_id);
+		assert_eq!(search_out[1].key_id, keys[1].key_id);
+
+		//only the hash of the old key should be found
+		assert!(!out.hashes.contains(&search_out[0].hashes[0]));
+		assert!(out.hashes.contains(&search_out[1].hashes[0]));
+	}
+
+	#[test]
+	fn test_reindex_searchable()
+	{
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+		let (_, _, _, hmac_keys2, _) = create_group(&user.user_keys[0]);
+
+		let old_key = &hmac_keys[0];
+		let newest_key = &hmac_keys2[0];
+
+		let text = "123*+^êéèüöß@€&$ 👍 🚀 😎";
+
+		let old_out = old_key.create_searchable(text, false, None).unwrap();
+
+		let new_out = reindex_searchable(newest_key, text, &[old_out], false, None)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(new_out.hashes.len(), 39);
+
+		//now the data can be found with the newest key
+		let search_str = newest_key.search("123").unwrap();
+		assert!(new_out.hashes.contains(&search_str));
+
+		//no re-index needed when the data was already indexed with the newest key
+		let out = reindex_searchable(newest_key, text, &[new_out], false, None).unwrap();
+		assert!(out.is_none());
+	}
+
+	#[test]
+	fn test_blind_index_truncation()
+	{
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		//the full length is the same as the normal search hash
+		let full = hmac_key.search_blind_index("DE", 256).unwrap();
+		assert_eq!(full, hmac_key.search("DE").unwrap());
+
+		let out = hmac_key.create_blind_index("DE", 12).unwrap();
+
+		assert_eq!(out.hashes.len(), 1);
+		assert_eq!(out.hashes[0], hmac_key.search_blind_index("DE", 12).unwrap());
+
+		//12 bits are 2 bytes and the last 4 bits are masked
+		let bytes = Base64UrlUnpadded::decode_vec(&out.hashes[0]).unwrap();
+		assert_eq!(bytes.len(), 2);
+		assert_eq!(bytes[1] & 0x0f, 0);
+
+		assert!(hmac_key.search_blind_index("DE", 0).is_err());
+		assert!(hmac_key.search_blind_index("DE", 257).is_err());
+	}
+
+	#[test]
+	fn test_blind_index_buckets()
+	{
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		//with only one bit there are only two buckets, so values must collide
+		let values = ["a", "b", "c", "d", "e"];
+
+		let buckets = hmac_key.search_blind_index_candidates(&values, 1).unwrap();
+
+		assert!(buckets.len() <= 2);
+
+		for value in values {
+			let out = hmac_key.create_blind_index(value, 1).unwrap();
+			assert!(buckets.contains(&out.hashes[0]));
+		}
+	}
+
+	#[test]
+	fn test_blind_index_compound()
+	{
+		let user = create_user();
+		let (_, _, _, hmac_keys, _) = create_group(&user.user_keys[0]);
+
+		let hmac_key = &hmac_keys[0];
+
+		let out = hmac_key
+			.create_blind_index_compound(&["DE", "1990"], 256)
+			.unwrap();
+
+		let bucket = hmac_key
+			.search_blind_index_compound(&["DE", "1990"], 256)
+			.unwrap();
+
+		assert_eq!(out.hashes[0], bucket);
+
+		//the same bytes split in another way must not be the same index
+		let other = hmac_key
+			.search_blind_index_compound(&["DE1", "990"], 256)
+			.unwrap();
+
+		assert_ne!(bucket, other);
+	}
 }
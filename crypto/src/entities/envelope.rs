@@ -0,0 +1,73 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::head::{BinaryHead, HeadReader, HeadWriter, BINARY_HEAD_TYPE_ENVELOPE};
+use serde::{Deserialize, Serialize};
+
+/**
+The content key wrapped for one recipient.
+
+The id is the id of the public key of the recipient. The key is the base64 encoded wrapped content key.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EnvelopeRecipient
+{
+	pub id: String,
+	pub key: String,
+}
+
+/**
+The head of a multi-recipient envelope. The alg is the alg of the content key.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EnvelopeHead
+{
+	pub alg: String,
+	pub recipients: Vec<EnvelopeRecipient>,
+}
+
+impl BinaryHead for EnvelopeHead
+{
+	const HEAD_TYPE: u8 = BINARY_HEAD_TYPE_ENVELOPE;
+
+	fn write_fields(&self, writer: &mut HeadWriter) -> Result<(), SdkUtilError>
+	{
+		writer.write_str(&self.alg)?;
+
+		let count: u16 = self
+			.recipients
+			.len()
+			.try_into()
+			.map_err(|_| SdkUtilError::JsonToStringFailed)?;
+
+		writer.write_u16(count);
+
+		for recipient in &self.recipients {
+			writer.write_str(&recipient.id)?;
+			writer.write_str(&recipient.key)?;
+		}
+
+		Ok(())
+	}
+
+	fn read_fields(reader: &mut HeadReader) -> Result<Self, SdkUtilError>
+	{
+		let alg = reader.read_str()?;
+		let count = reader.read_u16()?;
+
+		let mut recipients = Vec::with_capacity(count as usize);
+
+		for _ in 0..count {
+			recipients.push(EnvelopeRecipient {
+				id: reader.read_str()?,
+				key: reader.read_str()?,
+			});
+		}
+
+		Ok(Self {
+			alg,
+			recipients,
+		})
+	}
+}
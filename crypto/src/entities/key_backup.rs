@@ -0,0 +1,70 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::{DeviceId, GroupId, UserId};
+use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
+use sentc_crypto_utils::user::DeviceKeyDataExport;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::group::{GroupKeyData, GroupKeyDataExport, GroupOutDataHmacKeyExport};
+use crate::entities::user::{UserDataExport, UserDataInt, UserKeyDataExport};
+
+/**
+The passphrase encrypted backup container.
+
+The keys are encrypted with the key derived from the passphrase and the salt.
+The version, the salt and the alg are authenticated too.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct EncryptedKeyBackup
+{
+	pub version: u8,
+	pub salt: String,
+	pub encrypted_alg: String,
+	pub encrypted_keys: String,
+}
+
+/**
+The content of the backup before the encryption.
+
+Jwt and refresh token are not part of the backup, the user must log in again after the import.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct KeyBackupPayload
+{
+	pub user_id: UserId,
+	pub device_id: DeviceId,
+	pub user_keys: Vec<UserKeyDataExport>,
+	pub device_keys: DeviceKeyDataExport,
+	pub hmac_keys: Vec<GroupOutDataHmacKeyExport>,
+	pub group_keys: Vec<KeyBackupGroupKeysExport>,
+}
+
+pub struct KeyBackupGroupKeys<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper>
+{
+	pub group_id: GroupId,
+	pub keys: Vec<GroupKeyData<S, Sk, Pk>>,
+}
+
+pub struct KeyBackupInt<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>
+{
+	pub user: UserDataInt<S, Sk, Pk, SiK, Vk>,
+	pub group_keys: Vec<KeyBackupGroupKeys<S, Sk, Pk>>,
+}
+
+//==================================================================================================
+//export
+
+#[derive(Serialize, Deserialize)]
+pub struct KeyBackupGroupKeysExport
+{
+	pub group_id: GroupId,
+	pub keys: Vec<GroupKeyDataExport>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct KeyBackupExport
+{
+	pub user: UserDataExport,
+	pub group_keys: Vec<KeyBackupGroupKeysExport>,
+}
@@ -0,0 +1,28 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+/**
+A key that was seen for a user.
+
+The hash is the base64 encoded sha256 of the key alg and the key, so a key with the same id but other content is detected.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PinnedKey
+{
+	pub key_id: String,
+	pub key_hash: String,
+}
+
+/**
+The seen keys of a user, one pin for each key id.
+
+A user has more than one key after a key rotation, so the keys are pinned by their id.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserKeyPin
+{
+	pub verify_keys: Vec<PinnedKey>,
+	pub public_keys: Vec<PinnedKey>,
+}
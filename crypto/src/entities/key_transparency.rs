@@ -0,0 +1,51 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+/**
+The signature of the log key over the tree size and the root hash.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyLogTreeHeadSig
+{
+	pub sign_key_id: String,
+	pub sign_alg: String,
+	pub sig: String,
+}
+
+/**
+The state of the key log at a given size.
+
+The root hash is base64 encoded. Store the last verified tree head and use it for the next verification.
+The tree head must be signed by the log key, older tree heads without signature are refused.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeyLogTreeHead
+{
+	pub tree_size: u64,
+	pub root_hash: String,
+	#[serde(default)]
+	pub sig: Option<KeyLogTreeHeadSig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeyLogInclusionProof
+{
+	pub leaf_index: u64,
+	pub tree_size: u64,
+	pub audit_path: Vec<String>,
+}
+
+/**
+The proof from the server for a single key.
+
+The consistency proof goes from the tree size the client requested to the size of the tree head.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeyLogProofServerOutput
+{
+	pub tree_head: KeyLogTreeHead,
+	pub inclusion: KeyLogInclusionProof,
+	pub consistency: Vec<String>,
+}
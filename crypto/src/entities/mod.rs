@@ -1,2 +1,7 @@
+pub mod envelope;
 pub mod group;
+pub mod key_backup;
+pub mod key_pin;
+pub mod key_transparency;
+pub mod signature;
 pub mod user;
@@ -0,0 +1,28 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+/**
+A signature over a document that is not attached to the document.
+
+The signature covers the document, the signer user id and the time, so the metadata can't be changed.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DetachedSignature
+{
+	pub signer_user_id: String,
+	pub verify_key_id: String,
+	pub alg: String,
+	pub time: u128,
+	pub sig: String,
+}
+
+/**
+All signatures over one document, e.g. a contract signed by several users.
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct DocumentSignatures
+{
+	pub signatures: Vec<DetachedSignature>,
+}
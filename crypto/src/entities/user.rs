@@ -1,8 +1,8 @@
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-use sentc_crypto_common::group::GroupHmacData;
-use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_common::group::{GroupHmacData, GroupKeysForNewMember, KeyRotationData};
+use sentc_crypto_common::user::{KeyDerivedData, MasterKey, UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_common::{DeviceId, SymKeyId, UserId};
 use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
 pub use sentc_crypto_utils::user::DeviceKeyDataExport;
@@ -191,3 +191,45 @@ impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: Veri
 		})
 	}
 }
+
+//__________________________________________________________________________________________________
+
+/**
+The recovery key data for the server.
+
+Like the password derived data of the register, but the master key is encrypted by the recovery key.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct RecoveryKeyData
+{
+	pub client_random_value: String,
+	pub hashed_authentication_key: String,
+	pub encrypted_master_key: String,
+	pub encrypted_master_key_alg: String,
+	pub derived_alg: String,
+}
+
+/**
+The server input to migrate the keys of a user to another key family.
+
+The device keys of the current device are replaced with new key pairs (encrypted by a new master key from the password),
+all previous user keys are encrypted by the new device public key and the user keys are rotated.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct UserKeyMigrationData
+{
+	pub master_key: MasterKey,
+	pub derived: KeyDerivedData,
+	pub user_keys: Vec<GroupKeysForNewMember>,
+	pub key_rotation: KeyRotationData,
+}
+
+/**
+A shamir share of the recovery key, encrypted for a guardian.
+ */
+#[derive(Serialize, Deserialize)]
+pub struct GuardianRecoveryShare
+{
+	pub guardian_public_key_id: String,
+	pub encrypted_share: String,
+}
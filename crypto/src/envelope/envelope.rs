@@ -0,0 +1,106 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_core::cryptomat::{CryptoAlg, SymKey, SymKeyComposer, SymKeyGen};
+use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SkWrapper};
+
+use crate::crypto::crypto::{put_head_and_encrypted_data, split_head_and_encrypted_data};
+use crate::entities::envelope::{EnvelopeHead, EnvelopeRecipient};
+use crate::SdkError;
+
+pub struct Envelope<S, SC, P>
+{
+	_s: PhantomData<S>,
+	_sc: PhantomData<SC>,
+	_p: PhantomData<P>,
+}
+
+impl<S: SymKeyGen, SC: SymKeyComposer, P: PkFromUserKeyWrapper> Envelope<S, SC, P>
+{
+	/**
+	Encrypt the data once for all recipients.
+
+	A new content key encrypts the data. The content key is wrapped with the public key of every recipient.
+	The recipients can use different key algorithms.
+	 */
+	pub fn encrypt(data: &[u8], recipients: &[UserPublicKeyData]) -> Result<Vec<u8>, SdkError>
+	{
+		if recipients.is_empty() {
+			return Err(SdkError::EnvelopeNoRecipients);
+		}
+
+		let content_key = S::generate()?;
+
+		let recipients = recipients
+			.iter()
+			.map(|r| {
+				let public_key = P::from_user_key(r)?;
+
+				let wrapped_key = content_key.encrypt_key_with_master_key(&public_key)?;
+
+				Ok(EnvelopeRecipient {
+					id: r.public_key_id.clone(),
+					key: Base64::encode_string(&wrapped_key),
+				})
+			})
+			.collect::<Result<Vec<_>, SdkError>>()?;
+
+		let head = EnvelopeHead {
+			alg: content_key.get_alg_str().to_string(),
+			recipients,
+		};
+
+		let encrypted = content_key.encrypt(data)?;
+
+		put_head_and_encrypted_data(&head, &encrypted)
+	}
+
+	/**
+	Decrypt the data with the private key of one recipient. The recipient entry is found by the id of the private key.
+	 */
+	pub fn decrypt(encrypted_data: &[u8], private_key: &impl SkWrapper) -> Result<Vec<u8>, SdkError>
+	{
+		let (head, encrypted) = split_head_and_encrypted_data::<EnvelopeHead>(encrypted_data)?;
+
+		let recipient = head
+			.recipients
+			.iter()
+			.find(|r| r.id == private_key.get_id())
+			.ok_or(SdkError::EnvelopeRecipientNotFound)?;
+
+		let wrapped_key = Base64::decode_vec(&recipient.key).map_err(|_| SdkError::KeyDecryptFailed)?;
+
+		let content_key = SC::decrypt_key_by_master_key(private_key.get_key(), &wrapped_key, &head.alg)?;
+
+		Ok(content_key.decrypt(encrypted)?)
+	}
+
+	pub fn encrypt_string(data: &str, recipients: &[UserPublicKeyData]) -> Result<String, SdkError>
+	{
+		let encrypted = Self::encrypt(data.as_bytes(), recipients)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	pub fn decrypt_string(encrypted_data: &str, private_key: &impl SkWrapper) -> Result<String, SdkError>
+	{
+		let encrypted = Base64::decode_vec(encrypted_data).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+		let decrypted = Self::decrypt(&encrypted, private_key)?;
+
+		String::from_utf8(decrypted).map_err(|_| SdkError::DecodeEncryptedDataFailed)
+	}
+
+	/**
+	Get the ids of the public keys of all recipients without decrypting the data.
+	 */
+	pub fn get_recipient_ids(encrypted_data: &[u8]) -> Result<Vec<String>, SdkError>
+	{
+		let (head, _) = split_head_and_encrypted_data::<EnvelopeHead>(encrypted_data)?;
+
+		Ok(head.recipients.into_iter().map(|r| r.id).collect())
+	}
+}
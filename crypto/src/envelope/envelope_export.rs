@@ -0,0 +1,84 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_std_keys::util::SecretKey;
+
+use crate::keys::std::StdEnvelope;
+use crate::SdkError;
+
+fn import_recipients(recipients: &str) -> Result<Vec<UserPublicKeyData>, SdkError>
+{
+	serde_json::from_str(recipients).map_err(SdkError::JsonParseFailed)
+}
+
+/**
+Encrypt the data for all recipients. The recipients are a json array of the public keys of the users.
+ */
+pub fn encrypt_envelope(data: &[u8], recipients: &str) -> Result<Vec<u8>, String>
+{
+	let recipients = import_recipients(recipients)?;
+
+	Ok(StdEnvelope::encrypt(data, &recipients)?)
+}
+
+pub fn decrypt_envelope(encrypted_data: &[u8], private_key: &str) -> Result<Vec<u8>, String>
+{
+	let private_key: SecretKey = private_key.parse()?;
+
+	Ok(StdEnvelope::decrypt(encrypted_data, &private_key)?)
+}
+
+pub fn encrypt_envelope_string(data: &str, recipients: &str) -> Result<String, String>
+{
+	let recipients = import_recipients(recipients)?;
+
+	Ok(StdEnvelope::encrypt_string(data, &recipients)?)
+}
+
+pub fn decrypt_envelope_string(encrypted_data: &str, private_key: &str) -> Result<String, String>
+{
+	let private_key: SecretKey = private_key.parse()?;
+
+	Ok(StdEnvelope::decrypt_string(encrypted_data, &private_key)?)
+}
+
+pub fn get_envelope_recipient_ids(encrypted_data: &[u8]) -> Result<Vec<String>, String>
+{
+	Ok(StdEnvelope::get_recipient_ids(encrypted_data)?)
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::string::ToString;
+
+	use super::*;
+	use crate::user::test_fn::create_user_export;
+
+	#[test]
+	fn test_encrypt_envelope()
+	{
+		let user_1 = create_user_export();
+		let user_2 = create_user_export();
+
+		let recipients = "[".to_string() + &user_1.user_keys[0].exported_public_key + "," + &user_2.user_keys[0].exported_public_key + "]";
+
+		let encrypted = encrypt_envelope(b"hello world", &recipients).unwrap();
+
+		assert_eq!(
+			decrypt_envelope(&encrypted, &user_1.user_keys[0].private_key).unwrap(),
+			b"hello world"
+		);
+		assert_eq!(
+			decrypt_envelope(&encrypted, &user_2.user_keys[0].private_key).unwrap(),
+			b"hello world"
+		);
+
+		let encrypted = encrypt_envelope_string("hello", &recipients).unwrap();
+		assert_eq!(
+			decrypt_envelope_string(&encrypted, &user_2.user_keys[0].private_key).unwrap(),
+			"hello"
+		);
+	}
+}
@@ -0,0 +1,76 @@
+//! Encrypt data once for several users without creating a group.
+//!
+//! The data is encrypted with a new content key. The head of the envelope contains the content key
+//! wrapped with the public key of every recipient, indexed by the public key id.
+//! A recipient decrypts the data with the private key of the matching entry.
+
+pub(crate) mod envelope;
+#[cfg(feature = "export")]
+mod envelope_export;
+
+pub use self::envelope::Envelope;
+#[cfg(feature = "export")]
+pub use self::envelope_export::*;
+
+#[cfg(test)]
+mod test
+{
+	use alloc::vec::Vec;
+
+	use sentc_crypto_common::user::UserPublicKeyData;
+
+	use crate::user::test_fn::create_user;
+	use crate::SdkError;
+
+	#[cfg(feature = "std_keys")]
+	pub type TestEnvelope = crate::keys::std::StdEnvelope;
+	#[cfg(all(feature = "fips_keys", not(feature = "std_keys")))]
+	pub type TestEnvelope = crate::keys::fips::FipsEnvelope;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	pub type TestEnvelope = crate::keys::rec::RecEnvelope;
+
+	#[test]
+	fn test_encrypt_for_multiple_recipients()
+	{
+		let users = [create_user(), create_user(), create_user()];
+		let other_user = create_user();
+
+		let recipients: Vec<UserPublicKeyData> = users
+			.iter()
+			.map(|u| u.user_keys[0].exported_public_key.clone())
+			.collect();
+
+		let encrypted = TestEnvelope::encrypt(b"hello world", &recipients).unwrap();
+
+		let ids = TestEnvelope::get_recipient_ids(&encrypted).unwrap();
+		assert_eq!(ids.len(), 3);
+
+		for user in &users {
+			assert!(ids.contains(&user.user_keys[0].exported_public_key.public_key_id));
+
+			let decrypted = TestEnvelope::decrypt(&encrypted, &user.user_keys[0].private_key).unwrap();
+			assert_eq!(decrypted, b"hello world");
+		}
+
+		let err = TestEnvelope::decrypt(&encrypted, &other_user.user_keys[0].private_key);
+		assert!(matches!(err, Err(SdkError::EnvelopeRecipientNotFound)));
+	}
+
+	#[test]
+	fn test_encrypt_string()
+	{
+		let user = create_user();
+
+		let encrypted = TestEnvelope::encrypt_string("hello", &[user.user_keys[0].exported_public_key.clone()]).unwrap();
+
+		let decrypted = TestEnvelope::decrypt_string(&encrypted, &user.user_keys[0].private_key).unwrap();
+		assert_eq!(decrypted, "hello");
+	}
+
+	#[test]
+	fn test_no_recipients()
+	{
+		let err = TestEnvelope::encrypt(b"hello world", &[]);
+		assert!(matches!(err, Err(SdkError::EnvelopeNoRecipients)));
+	}
+}
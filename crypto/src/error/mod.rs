@@ -23,11 +23,40 @@ pub enum SdkError
 	KeyRotationServerOutputWrong,
 	KeyRotationEncryptError(String),
 
+	KeyLogProofWrongFormat,
+	KeyNotInKeyLog,
+	KeyLogInconsistent,
+	KeyLogTreeHeadNotSigned,
+
+	KeyPinIdentityChanged(String),
+	KeyPinSafetyNumberMismatch,
+	KeyPinStoreFailed,
+
+	RecoveryShareNotSigned,
+
+	KeyBackupWrongFormat,
+	KeyBackupVersionNotSupported,
+	KeyBackupDecryptFailed,
+
+	KeyStoreFailed,
+	KeyStoreDecryptFailed,
+
+	SignatureVerifyKeyNotFound(String),
+	SignatureNoSignatures,
+	SignatureDuplicateSigner(String),
+	SignatureAlgMismatch,
+	SignaturePolicyNotFound,
+	HeadFormatNotFound,
+
+	EnvelopeNoRecipients,
+	EnvelopeRecipientNotFound,
+
 	AlgNotFound,
 
 	GroupRank,
 	GroupUserKickRank,
 	GroupPermission,
+	GroupKeyNotFound(String),
 }
 
 /**
@@ -96,6 +125,97 @@ pub fn err_to_msg(error: SdkError) -> String
 			)
 		},
 
+		//key transparency error
+		SdkError::KeyLogProofWrongFormat => out_error("client_140", "The proof of the key log has a wrong format"),
+		SdkError::KeyNotInKeyLog => {
+			out_error(
+				"client_141",
+				"The key is not in the key log. The server may have handed out a wrong key",
+			)
+		},
+		SdkError::KeyLogInconsistent => {
+			out_error(
+				"client_142",
+				"The key log is not consistent with the last known state",
+			)
+		},
+		SdkError::KeyLogTreeHeadNotSigned => {
+			out_error(
+				"client_143",
+				"The tree head of the key log is not signed by the log key",
+			)
+		},
+
+		//key pin error
+		SdkError::KeyPinIdentityChanged(user_id) => {
+			out_error(
+				"client_150",
+				&("The keys of this user changed since the first use. Compare the safety number before trusting the new keys. User: ".to_string() +
+					&user_id),
+			)
+		},
+		SdkError::KeyPinSafetyNumberMismatch => out_error("client_151", "The compared safety number doesn't match the keys"),
+		SdkError::KeyPinStoreFailed => out_error("client_152", "Can't read or write the key pin store"),
+
+		//social recovery error
+		SdkError::RecoveryShareNotSigned => out_error("client_160", "The recovery share is not signed by the owner"),
+
+		//key backup error
+		SdkError::KeyBackupWrongFormat => out_error("client_170", "The key backup has a wrong format"),
+		SdkError::KeyBackupVersionNotSupported => out_error("client_171", "The version of the key backup is not supported"),
+		SdkError::KeyBackupDecryptFailed => {
+			out_error(
+				"client_172",
+				"Can't decrypt the key backup. Maybe the password is wrong or the backup was changed",
+			)
+		},
+
+		//key store error
+		SdkError::KeyStoreFailed => out_error("client_180", "Can't read or write the key store"),
+		SdkError::KeyStoreDecryptFailed => {
+			out_error(
+				"client_181",
+				"Can't decrypt the key store entry. Maybe the device key is wrong or the entry was changed",
+			)
+		},
+
+		//signature error
+		SdkError::SignatureVerifyKeyNotFound(user_id) => {
+			out_error(
+				"client_190",
+				&("No verify key found for the signature of this user: ".to_string() + &user_id),
+			)
+		},
+		SdkError::SignatureNoSignatures => out_error("client_191", "The document has no signatures"),
+		SdkError::SignatureDuplicateSigner(user_id) => {
+			out_error(
+				"client_192",
+				&("The document is signed more than once by this user: ".to_string() + &user_id),
+			)
+		},
+		SdkError::SignatureAlgMismatch => {
+			out_error(
+				"client_193",
+				"The algorithm of the signature doesn't match the verify key",
+			)
+		},
+		SdkError::SignaturePolicyNotFound => {
+			out_error(
+				"client_194",
+				"Signature policy not found. Please choose between lenient, verify_if_signed and require",
+			)
+		},
+
+		//envelope error
+		SdkError::EnvelopeNoRecipients => out_error("client_195", "No recipients for the envelope"),
+		SdkError::EnvelopeRecipientNotFound => out_error("client_196", "The private key is not a recipient of the envelope"),
+		SdkError::HeadFormatNotFound => {
+			out_error(
+				"client_197",
+				"Head format not found. Please choose between json and binary",
+			)
+		},
+
 		//group error
 		SdkError::GroupRank => {
 			out_error(
@@ -113,5 +233,11 @@ pub fn err_to_msg(error: SdkError) -> String
 				&("Key rotation failed for this account with this error message: ".to_string() + &e),
 			)
 		},
+		SdkError::GroupKeyNotFound(key_id) => {
+			out_error(
+				"client_205",
+				&("The group key was not found. Key id: ".to_string() + &key_id),
+			)
+		},
 	}
 }
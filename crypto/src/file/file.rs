@@ -4,12 +4,19 @@ use core::marker::PhantomData;
 
 use base64ct::{Base64, Encoding};
 use sentc_crypto_common::file::{BelongsToType, FileHead, FileNameUpdate, FileRegisterInput, FileRegisterOutput};
-use sentc_crypto_common::user::UserVerifyKeyData;
 use sentc_crypto_common::{FileId, FileSessionId};
 use sentc_crypto_core::cryptomat::{CryptoAlg, SymKey, SymKeyComposer, SymKeyGen};
-use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
-
-use crate::crypto::crypto::{put_head_and_encrypted_data, split_head_and_encrypted_data};
+use sentc_crypto_utils::cryptomat::{
+	sign_with_encrypted_head,
+	verify_with_policy,
+	SignKWrapper,
+	SignaturePolicy,
+	SymKeyWrapper,
+	VerifyKFromUserKeyWrapper,
+};
+use sentc_crypto_utils::head::HeadFormat;
+
+use crate::crypto::crypto::{put_head_and_encrypted_data_with_format, split_head_and_encrypted_data};
 use crate::util::public::handle_server_response;
 use crate::SdkError;
 
@@ -102,6 +109,69 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 	}
 
 	pub fn encrypt_file_part(pre_content_key: &impl SymKey, part: &[u8], sign_key: Option<&SignK>) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
+	{
+		Self::encrypt_file_part_int(pre_content_key, part, sign_key, false, HeadFormat::Json)
+	}
+
+	/**
+	Like encrypt_file_part_start but with the json or the compact binary file head, see HeadFormat.
+	 */
+	pub fn encrypt_file_part_start_with_format(
+		key: &impl SymKeyWrapper,
+		part: &[u8],
+		sign_key: Option<&SignK>,
+		format: HeadFormat,
+	) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
+	{
+		Self::encrypt_file_part_int(key.get_key(), part, sign_key, false, format)
+	}
+
+	/**
+	Like encrypt_file_part but with the json or the compact binary file head.
+	 */
+	pub fn encrypt_file_part_with_format(
+		pre_content_key: &impl SymKey,
+		part: &[u8],
+		sign_key: Option<&SignK>,
+		format: HeadFormat,
+	) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
+	{
+		Self::encrypt_file_part_int(pre_content_key, part, sign_key, false, format)
+	}
+
+	/**
+	Like encrypt_file_part_start but the signature is bound to the encrypted file key, see sign_with_encrypted_head.
+
+	Older versions can't decrypt these parts.
+	 */
+	pub fn encrypt_file_part_start_with_bound_sign(
+		key: &impl SymKeyWrapper,
+		part: &[u8],
+		sign_key: &SignK,
+	) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
+	{
+		Self::encrypt_file_part_int(key.get_key(), part, Some(sign_key), true, HeadFormat::Json)
+	}
+
+	/**
+	Like encrypt_file_part but the signature is bound to the encrypted file key.
+	 */
+	pub fn encrypt_file_part_with_bound_sign(
+		pre_content_key: &impl SymKey,
+		part: &[u8],
+		sign_key: &SignK,
+	) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
+	{
+		Self::encrypt_file_part_int(pre_content_key, part, Some(sign_key), true, HeadFormat::Json)
+	}
+
+	fn encrypt_file_part_int(
+		pre_content_key: &impl SymKey,
+		part: &[u8],
+		sign_key: Option<&SignK>,
+		bound_sign: bool,
+		format: HeadFormat,
+	) -> Result<(Vec<u8>, S::SymmetricKey), SdkError>
 	{
 		/*
 		Just create a normal core key without id
@@ -114,7 +184,11 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 
 		//sign the data
 		let sign = if let Some(sk) = sign_key {
-			let (sign_head, data_with_sign) = sk.sign_with_head(&encrypted_part)?;
+			let (sign_head, data_with_sign) = if bound_sign {
+				sign_with_encrypted_head(sk, &encrypted_key_string, &encrypted_part)?
+			} else {
+				sk.sign_with_head(&encrypted_part)?
+			};
 			encrypted_part = data_with_sign;
 			Some(sign_head)
 		} else {
@@ -128,22 +202,25 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 			sym_key_alg: file_key.get_alg_str().to_string(),
 		};
 
-		Ok((put_head_and_encrypted_data(&file_head, &encrypted_part)?, file_key))
+		Ok((
+			put_head_and_encrypted_data_with_format(&file_head, &encrypted_part, format)?,
+			file_key,
+		))
 	}
 
-	pub fn decrypt_file_part_start(
+	pub fn decrypt_file_part_start<'p>(
 		key: &impl SymKeyWrapper,
 		part: &[u8],
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<(Vec<u8>, SC::SymmetricKey), SdkError>
 	{
-		Self::decrypt_file_part(key.get_key(), part, verify_key)
+		Self::decrypt_file_part(key.get_key(), part, policy)
 	}
 
-	pub fn decrypt_file_part(
+	pub fn decrypt_file_part<'p>(
 		pre_content_key: &impl SymKey,
 		part: &[u8],
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<(Vec<u8>, SC::SymmetricKey), SdkError>
 	{
 		let (head, encrypted_part) = split_head_and_encrypted_data::<FileHead>(part)?;
@@ -153,22 +230,11 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 
 		let file_key = SC::decrypt_key_by_sym_key(pre_content_key, &encrypted_key, &head.sym_key_alg)?;
 
-		let decrypted_part = match &head.sign {
-			None => file_key.decrypt(encrypted_part)?, //no sig used, go ahead
-			Some(h) => {
-				match verify_key {
-					None => {
-						//just split the data, use the alg here
-						let (_, encrypted_data_without_sig) = VC::split_sig_and_data(h.alg.as_str(), encrypted_part)?;
-						file_key.decrypt(encrypted_data_without_sig)?
-					},
-					Some(vk) => {
-						let encrypted_data_without_sig = VC::verify_with_user_key(vk, encrypted_part, h)?;
-						file_key.decrypt(encrypted_data_without_sig)?
-					},
-				}
-			},
-		};
+		//the file key has no id. The signature is bound to the encrypted file key,
+		//which was just decrypted by the pre content key, so a part can't be moved to another key chain.
+		let encrypted_part = verify_with_policy::<VC>(encrypted_part, head.sign.as_ref(), &head.key, policy.into())?;
+
+		let decrypted_part = file_key.decrypt(encrypted_part)?;
 
 		Ok((decrypted_part, file_key))
 	}
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use sentc_crypto_common::file::BelongsToType;
 use sentc_crypto_std_keys::util::SymmetricKey;
 
-use crate::crypto::{prepare_sign_key, prepare_verify_key};
+use crate::crypto::{prepare_head_format, prepare_sign_key, prepare_signature_policy, prepare_verify_key};
 use crate::keys::std::StdFileEncryptor;
 use crate::util::{export_core_sym_key_to_string, import_core_sym_key};
 use crate::SdkError;
@@ -69,12 +69,63 @@ pub fn encrypt_file_part(pre_content_key: &str, part: &[u8], sign_key: Option<&s
 	Ok((encrypted_part, exported_file_key))
 }
 
+/**
+Like encrypt_file_part_start but with the json or the compact binary file head, see prepare_head_format.
+ */
+pub fn encrypt_file_part_start_with_format(key: &str, part: &[u8], sign_key: Option<&str>, head_format: &str) -> Result<(Vec<u8>, String), String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+	let key: SymmetricKey = key.parse()?;
+
+	let (encrypted_part, file_key) = StdFileEncryptor::encrypt_file_part_start_with_format(&key, part, sign_key.as_ref(), format)?;
+
+	let exported_file_key = export_core_sym_key_to_string(file_key)?;
+
+	Ok((encrypted_part, exported_file_key))
+}
+
+/**
+Like encrypt_file_part but with the json or the compact binary file head, see prepare_head_format.
+ */
+pub fn encrypt_file_part_with_format(
+	pre_content_key: &str,
+	part: &[u8],
+	sign_key: Option<&str>,
+	head_format: &str,
+) -> Result<(Vec<u8>, String), String>
+{
+	let sign_key = prepare_sign_key(sign_key)?;
+	let format = prepare_head_format(head_format)?;
+	let key = import_core_sym_key(pre_content_key)?;
+
+	let (encrypted_part, file_key) = StdFileEncryptor::encrypt_file_part_with_format(&key, part, sign_key.as_ref(), format)?;
+
+	let exported_file_key = export_core_sym_key_to_string(file_key)?;
+
+	Ok((encrypted_part, exported_file_key))
+}
+
 pub fn decrypt_file_part_start(key: &str, part: &[u8], verify_key: Option<&str>) -> Result<(Vec<u8>, String), String>
+{
+	decrypt_file_part_start_with_policy(key, part, verify_key, "lenient")
+}
+
+/**
+Like decrypt_file_part_start but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_file_part_start_with_policy(
+	key: &str,
+	part: &[u8],
+	verify_key: Option<&str>,
+	signature_policy: &str,
+) -> Result<(Vec<u8>, String), String>
 {
 	let verify_key = prepare_verify_key(verify_key)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 	let key: SymmetricKey = key.parse()?;
 
-	let (decrypted, next_key) = StdFileEncryptor::decrypt_file_part_start(&key, part, verify_key.as_ref())?;
+	let (decrypted, next_key) = StdFileEncryptor::decrypt_file_part_start(&key, part, policy)?;
 
 	let exported_file_key = export_core_sym_key_to_string(next_key)?;
 
@@ -82,11 +133,25 @@ pub fn decrypt_file_part_start(key: &str, part: &[u8], verify_key: Option<&str>)
 }
 
 pub fn decrypt_file_part(pre_content_key: &str, part: &[u8], verify_key: Option<&str>) -> Result<(Vec<u8>, String), String>
+{
+	decrypt_file_part_with_policy(pre_content_key, part, verify_key, "lenient")
+}
+
+/**
+Like decrypt_file_part but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub fn decrypt_file_part_with_policy(
+	pre_content_key: &str,
+	part: &[u8],
+	verify_key: Option<&str>,
+	signature_policy: &str,
+) -> Result<(Vec<u8>, String), String>
 {
 	let verify_key = prepare_verify_key(verify_key)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 	let key = import_core_sym_key(pre_content_key)?;
 
-	let (decrypted, next_key) = StdFileEncryptor::decrypt_file_part(&key, part, verify_key.as_ref())?;
+	let (decrypted, next_key) = StdFileEncryptor::decrypt_file_part(&key, part, policy)?;
 
 	let exported_file_key = export_core_sym_key_to_string(next_key)?;
 
@@ -21,6 +21,7 @@ use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use base64ct::{Base64, Encoding};
+use sentc_crypto_common::crypto::EncryptedHead;
 use sentc_crypto_common::group::{
 	CreateData,
 	DoneKeyRotationData,
@@ -46,6 +47,7 @@ use sentc_crypto_utils::cryptomat::{
 	SignComposerWrapper,
 	SignKWrapper,
 	SignKeyPairWrapper,
+	SignaturePolicy,
 	SkWrapper,
 	SortableKeyComposerWrapper,
 	StaticKeyComposerWrapper,
@@ -56,8 +58,11 @@ use sentc_crypto_utils::cryptomat::{
 	VerifyKFromUserKeyWrapper,
 };
 use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::policy::{AlgUsage, CryptoPolicy};
 
 use crate::entities::group::{GroupKeyData, GroupOutData, GroupOutDataLight};
+use crate::group::GroupKeyResolver;
+use crate::key_store::{EncryptedKeyStore, KeyStore};
 use crate::util::public::handle_server_response;
 use crate::SdkError;
 
@@ -265,12 +270,42 @@ where
 		sign_key: Option<&SignC::SignKWrapper>,
 		starter: UserId,
 	) -> Result<String, SdkError>
+	{
+		let rotation_out = Self::key_rotation_typed(previous_group_key, invoker_public_key, user_group, sign_key, starter)?;
+
+		rotation_out
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
+	pub fn key_rotation_typed(
+		previous_group_key: &impl SymKeyWrapper,
+		invoker_public_key: &impl PkWrapper,
+		user_group: bool,
+		sign_key: Option<&SignC::SignKWrapper>,
+		starter: UserId,
+	) -> Result<KeyRotationData, SdkError>
+	{
+		Self::key_rotation_typed_with_sign_composer::<SignC>(previous_group_key, invoker_public_key, user_group, sign_key, starter)
+	}
+
+	/**
+	Like key_rotation_typed but the group key can be signed by a sign key of another key family,
+	e.g. the std sign key of a user who migrates the user keys to rec keys.
+	 */
+	pub(crate) fn key_rotation_typed_with_sign_composer<GSignC: SignComposerWrapper>(
+		previous_group_key: &impl SymKeyWrapper,
+		invoker_public_key: &impl PkWrapper,
+		user_group: bool,
+		sign_key: Option<&GSignC::SignKWrapper>,
+		starter: UserId,
+	) -> Result<KeyRotationData, SdkError>
 	{
 		let out = core_group::key_rotation::<
 			SGen::KeyGen,
 			StGen::KeyGen,
 			SignGen::KeyGen,
-			<<SignC as SignComposerWrapper>::SignKWrapper as SignKWrapper>::Inner,
+			<<GSignC as SignComposerWrapper>::SignKWrapper as SignKWrapper>::Inner,
 		>(
 			previous_group_key.get_key(),
 			invoker_public_key.get_key(),
@@ -314,12 +349,12 @@ where
 		};
 
 		let group_key_sig = if let Some(s) = out.group_key_sig {
-			Some(SignC::sig_to_string(s))
+			Some(GSignC::sig_to_string(s))
 		} else {
 			None
 		};
 
-		let rotation_out = KeyRotationData {
+		Ok(KeyRotationData {
 			encrypted_group_key_by_user,
 			group_key_alg: out.group_key_alg.to_string(),
 			encrypted_group_key_alg: out.encrypted_group_key_alg.to_string(),
@@ -341,11 +376,7 @@ where
 			verify_key,
 			keypair_sign_alg,
 			public_key_sig,
-		};
-
-		rotation_out
-			.to_string()
-			.map_err(|_| SdkError::JsonToStringFailed)
+		})
 	}
 
 	pub fn done_key_rotation(
@@ -429,11 +460,14 @@ where
 
 	/**
 	Call this fn for each key, with the right private key
+
+	The signature policy is used for the group key signature of a key rotation.
+	With VerifyIfSigned a signed key needs a verify key and with Require the key must be signed.
 	 */
-	pub fn decrypt_group_keys(
+	pub fn decrypt_group_keys<'p>(
 		private_key: &impl SkWrapper,
 		server_output: GroupKeyServerOutput,
-		verify_key: Option<&UserVerifyKeyData>,
+		key_policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<GroupKeyData<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>, SdkError>
 	{
 		//the user_public_key_id is used to get the right private key
@@ -441,13 +475,21 @@ where
 		let encrypted_private_key =
 			Base64::decode_vec(server_output.encrypted_private_group_key.as_str()).map_err(|_| SdkUtilError::DerivedKeyWrongFormat)?;
 
-		let (verify_key, sig) = if let (Some(vk), Some(sig)) = (verify_key, server_output.group_key_sig) {
-			(
-				Some(SignC::vk_inner_from_pem(&vk.verify_key_pem, &vk.verify_key_alg)?),
-				Some(SignC::sig_from_string(&sig, &vk.verify_key_alg)?),
-			)
-		} else {
-			(None, None)
+		let (verify_key, sig) = match (key_policy.into(), server_output.group_key_sig) {
+			(SignaturePolicy::Require(_), None) => return Err(SdkUtilError::SignatureRequired.into()),
+			(SignaturePolicy::VerifyIfSigned(None), Some(_)) => return Err(SdkUtilError::SignatureVerifyKeyMissing.into()),
+			(policy, Some(sig)) => {
+				match policy.verify_key() {
+					Some(vk) => {
+						(
+							Some(SignC::vk_inner_from_pem(&vk.verify_key_pem, &vk.verify_key_alg)?),
+							Some(SignC::sig_from_string(&sig, &vk.verify_key_alg)?),
+						)
+					},
+					None => (None, None),
+				}
+			},
+			(_, None) => (None, None),
 		};
 
 		let (group_key, private_group_key) = core_group::get_group::<SC::Composer, StC::Composer, SignC::InnerVk>(
@@ -484,6 +526,160 @@ where
 		})
 	}
 
+	/**
+	Like decrypt_group_keys but every algorithm must be allowed by the crypto policy.
+
+	This checks the private key that decrypts the group key too, so the server can't send a group key encrypted by an older and weaker key.
+	 */
+	pub fn decrypt_group_keys_with_policy<'p>(
+		private_key: &impl SkWrapper,
+		server_output: GroupKeyServerOutput,
+		key_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<GroupKeyData<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>, SdkError>
+	{
+		let key_policy = key_policy.into();
+
+		check_group_key_policy(crypto_policy, private_key, &server_output, key_policy.verify_key())?;
+
+		Self::decrypt_group_keys(private_key, server_output, key_policy)
+	}
+
+	/**
+	Like decrypt_group_keys_with_policy but look up the key in the key store first.
+
+	If the key is not in the store, it is decrypted and stored.
+	A stored key is checked with the crypto policy too, it can be stored before the policy was changed.
+	 */
+	pub fn decrypt_group_keys_cached<'p, K: SymKeyWrapper, St: KeyStore>(
+		store: &mut EncryptedKeyStore<K, St>,
+		group_id: &str,
+		private_key: &impl SkWrapper,
+		server_output: GroupKeyServerOutput,
+		key_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<GroupKeyData<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>, SdkError>
+	{
+		if let Some(key) = store.get_group_key(group_id, &server_output.group_key_id)? {
+			crypto_policy.check(AlgUsage::Sym, key.group_key.get_key().get_alg_str())?;
+			crypto_policy.check(AlgUsage::Asym, key.private_group_key.get_key().get_alg_str())?;
+
+			return Ok(key);
+		}
+
+		let key = Self::decrypt_group_keys_with_policy(private_key, server_output, key_policy, crypto_policy)?;
+
+		store.set_group_key(group_id, &key)?;
+
+		Ok(key)
+	}
+
+	pub fn get_key_from_store<K: SymKeyWrapper, St: KeyStore>(
+		store: &EncryptedKeyStore<K, St>,
+		group_id: &str,
+		key_id: &str,
+	) -> Result<Option<GroupKeyData<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>>, SdkError>
+	{
+		store.get_group_key(group_id, key_id)
+	}
+
+	/**
+	Get all stored keys of the group, e.g. after a restart of the app.
+	 */
+	pub fn get_keys_from_store<K: SymKeyWrapper, St: KeyStore>(
+		store: &EncryptedKeyStore<K, St>,
+		group_id: &str,
+	) -> Result<Vec<GroupKeyData<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>>, SdkError>
+	{
+		store.get_group_keys(group_id)
+	}
+
+	/**
+	Get the key of the encrypted head.
+
+	If the key is not in the resolver, fetch_key is called with the group id and the key id to get the key from the server
+	(e.g. with get_group_key). The key is decrypted with the private key and cached in the resolver.
+
+	Every algorithm must be allowed by the crypto policy, like in decrypt_group_keys_with_policy.
+	 */
+	pub fn resolve_key<'a, 'k>(
+		resolver: &'a mut GroupKeyResolver<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>,
+		head: &EncryptedHead,
+		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'k>>,
+		crypto_policy: &CryptoPolicy,
+		fetch_key: impl FnOnce(&str, &str) -> Result<GroupKeyServerOutput, SdkError>,
+	) -> Result<&'a SC::SymmetricKeyWrapper, SdkError>
+	{
+		if let Some(key_id) = resolver.missing_key_id(head) {
+			let server_output = fetch_key(resolver.group_id(), key_id)?;
+
+			if server_output.group_key_id != key_id {
+				return Err(SdkError::GroupKeyNotFound(key_id.to_string()));
+			}
+
+			resolver.insert(Self::decrypt_group_keys_with_policy(
+				private_key,
+				server_output,
+				key_policy,
+				crypto_policy,
+			)?);
+		}
+
+		let key = resolver
+			.get_key(&head.id)
+			.ok_or_else(|| SdkError::GroupKeyNotFound(head.id.clone()))?;
+
+		//keys from the key store are not checked yet
+		crypto_policy.check(AlgUsage::Sym, key.get_key().get_alg_str())?;
+
+		Ok(key)
+	}
+
+	/**
+	Decrypt the data with the key of the head. The key is resolved like in resolve_key.
+
+	The key policy is used for the group key signature, the data policy is used for the data signature.
+	The crypto policy is checked for the group key and the signature of the data.
+	 */
+	pub fn decrypt_auto<'k, 'p>(
+		resolver: &mut GroupKeyResolver<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>,
+		encrypted_data_with_head: &[u8],
+		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'k>>,
+		data_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+		fetch_key: impl FnOnce(&str, &str) -> Result<GroupKeyServerOutput, SdkError>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let (head, encrypted_data) = crate::crypto::crypto::split_head_and_encrypted_data::<EncryptedHead>(encrypted_data_with_head)?;
+
+		let key = Self::resolve_key(resolver, &head, private_key, key_policy, crypto_policy, fetch_key)?;
+
+		crypto_policy.check_head(&head)?;
+
+		Ok(key.decrypt_raw(encrypted_data, &head, data_policy)?)
+	}
+
+	pub fn decrypt_string_auto<'k, 'p>(
+		resolver: &mut GroupKeyResolver<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>,
+		encrypted_data_with_head: &str,
+		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'k>>,
+		data_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+		fetch_key: impl FnOnce(&str, &str) -> Result<GroupKeyServerOutput, SdkError>,
+	) -> Result<String, SdkError>
+	{
+		let head = crate::crypto::crypto::split_head_and_encrypted_string(encrypted_data_with_head)?;
+
+		let key = Self::resolve_key(resolver, &head, private_key, key_policy, crypto_policy, fetch_key)?;
+
+		crypto_policy.check_head(&head)?;
+
+		Ok(key.decrypt_string(encrypted_data_with_head, data_policy)?)
+	}
+
 	pub fn prepare_group_keys_for_new_member(
 		requester_public_key_data: &UserPublicKeyData,
 		group_keys: &[&impl SymKeyWrapper],
@@ -692,11 +888,37 @@ pub fn prepare_change_rank(user_id: &str, new_rank: i32, admin_rank: i32) -> Res
 	.map_err(|_| SdkError::JsonToStringFailed)
 }
 
+/**
+Check every algorithm of the group key output and the private key that decrypts it with the crypto policy.
+ */
+pub(crate) fn check_group_key_policy(
+	crypto_policy: &CryptoPolicy,
+	private_key: &impl SkWrapper,
+	server_output: &GroupKeyServerOutput,
+	verify_key: Option<&UserVerifyKeyData>,
+) -> Result<(), SdkError>
+{
+	crypto_policy.check(AlgUsage::Asym, private_key.get_key().get_alg_str())?;
+	crypto_policy.check(AlgUsage::Sym, &server_output.group_key_alg)?;
+	crypto_policy.check(AlgUsage::Asym, &server_output.keypair_encrypt_alg)?;
+
+	if let Some(alg) = &server_output.keypair_sign_alg {
+		crypto_policy.check(AlgUsage::Sign, alg)?;
+	}
+
+	if let Some(vk) = verify_key {
+		crypto_policy.check_verify_key(vk)?;
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod test
 {
 	use alloc::string::ToString;
 	use alloc::vec;
+	use core::sync::atomic::{AtomicUsize, Ordering};
 
 	use base64ct::{Base64, Encoding};
 	use sentc_crypto_common::group::{
@@ -710,6 +932,7 @@ mod test
 	};
 	use sentc_crypto_common::ServerOutput;
 	use sentc_crypto_core::cryptomat::Pk;
+	use sentc_crypto_utils::cryptomat::SymKeyCrypto;
 
 	use super::*;
 	use crate::group::test_fn::{create_group, TestGroup};
@@ -1369,9 +1592,11 @@ mod test
 			public_key_sig_key_id: None,
 		};
 
+		let server_key_output = serde_json::to_string(&server_key_output).unwrap();
+
 		let out = TestGroup::decrypt_group_keys(
 			&user.user_keys[0].private_key,
-			server_key_output,
+			GroupKeyServerOutput::from_string(&server_key_output).unwrap(),
 			Some(&user.user_keys[0].exported_verify_key),
 		)
 		.unwrap();
@@ -1383,5 +1608,213 @@ mod test
 			new_group_key_direct.group_key.key.as_ref(),
 			out.group_key.key.as_ref()
 		);
+
+		//__________________________________________________________________________________________
+		//signature policies for the group key signature
+
+		TestGroup::decrypt_group_keys(
+			&user.user_keys[0].private_key,
+			GroupKeyServerOutput::from_string(&server_key_output).unwrap(),
+			SignaturePolicy::Require(&user.user_keys[0].exported_verify_key),
+		)
+		.unwrap();
+
+		let err = TestGroup::decrypt_group_keys(
+			&user.user_keys[0].private_key,
+			GroupKeyServerOutput::from_string(&server_key_output).unwrap(),
+			SignaturePolicy::VerifyIfSigned(None),
+		);
+
+		assert!(matches!(
+			err,
+			Err(SdkError::Util(SdkUtilError::SignatureVerifyKeyMissing))
+		));
+
+		//the server removes the signature
+		let mut unsigned_output = GroupKeyServerOutput::from_string(&server_key_output).unwrap();
+		unsigned_output.group_key_sig = None;
+
+		let err = TestGroup::decrypt_group_keys(
+			&user.user_keys[0].private_key,
+			unsigned_output,
+			SignaturePolicy::Require(&user.user_keys[0].exported_verify_key),
+		);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::SignatureRequired))));
+	}
+
+	#[test]
+	fn test_decrypt_auto_with_key_resolver()
+	{
+		let user = create_user();
+		let user_keys = &user.user_keys[0];
+
+		let (_, key_data, group_server_out, _, _) = create_group(user_keys);
+		let group_server_out_str = group_server_out.to_string().unwrap();
+
+		let encrypted = key_data[0]
+			.group_key
+			.encrypt_with_sign(b"hello world", &user_keys.sign_key)
+			.unwrap();
+		let encrypted_string = key_data[0].group_key.encrypt_string("hello").unwrap();
+
+		let mut resolver = GroupKeyResolver::new("123");
+
+		let mut fetched = None;
+
+		let decrypted = TestGroup::decrypt_auto(
+			&mut resolver,
+			&encrypted,
+			&user_keys.private_key,
+			Some(&user_keys.exported_verify_key),
+			Some(&user_keys.exported_verify_key),
+			&CryptoPolicy::default(),
+			|group_id, key_id| {
+				fetched = Some((group_id.to_string(), key_id.to_string()));
+
+				Ok(group_server_out.keys.into_iter().next().unwrap())
+			},
+		)
+		.unwrap();
+
+		assert_eq!(decrypted, b"hello world");
+		assert_eq!(fetched, Some(("123".to_string(), "123".to_string())));
+
+		//the key is cached now
+		let decrypted = TestGroup::decrypt_string_auto(
+			&mut resolver,
+			&encrypted_string,
+			&user_keys.private_key,
+			None,
+			None,
+			&CryptoPolicy::default(),
+			|_, _| panic!("key should be cached"),
+		)
+		.unwrap();
+
+		assert_eq!(decrypted, "hello");
+
+		//the cached key is checked with the crypto policy too
+		let err = TestGroup::decrypt_string_auto(
+			&mut resolver,
+			&encrypted_string,
+			&user_keys.private_key,
+			None,
+			None,
+			&CryptoPolicy {
+				sym: Some(&[]),
+				..Default::default()
+			},
+			|_, _| panic!("key should be cached"),
+		);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotAllowed(_)))));
+
+		//the server returns another key
+		let mut group_server_out = GroupServerData::from_string(&group_server_out_str).unwrap();
+		group_server_out.keys[0].group_key_id = "456".to_string();
+
+		let mut resolver = GroupKeyResolver::new("123");
+
+		let err = TestGroup::decrypt_auto(
+			&mut resolver,
+			&encrypted,
+			&user_keys.private_key,
+			None,
+			None,
+			&CryptoPolicy::default(),
+			|_, _| Ok(group_server_out.keys.into_iter().next().unwrap()),
+		);
+
+		assert!(matches!(err, Err(SdkError::GroupKeyNotFound(id)) if id == "123"));
+
+		//the server returns a key of a not allowed algorithm
+		let group_server_out = GroupServerData::from_string(&group_server_out_str).unwrap();
+
+		let mut resolver = GroupKeyResolver::new("123");
+
+		let err = TestGroup::decrypt_auto(
+			&mut resolver,
+			&encrypted,
+			&user_keys.private_key,
+			None,
+			None,
+			&CryptoPolicy {
+				post_quantum: Some(&[]),
+				..Default::default()
+			},
+			|_, _| Ok(group_server_out.keys.into_iter().next().unwrap()),
+		);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotPostQuantum(_)))));
+		assert!(!resolver.contains("123"));
+	}
+
+	static DEPRECATED_ALG_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+	#[test]
+	fn test_decrypt_group_keys_with_crypto_policy()
+	{
+		let user = create_user();
+		let user_keys = &user.user_keys[0];
+
+		let (_, key_data, group_server_out, _, _) = create_group(user_keys);
+		let group_server_out_str = group_server_out.to_string().unwrap();
+
+		let fetch_key = || {
+			GroupServerData::from_string(&group_server_out_str)
+				.unwrap()
+				.keys
+				.into_iter()
+				.next()
+				.unwrap()
+		};
+
+		let private_key_alg = user_keys.private_key.get_key().get_alg_str();
+		let group_key_alg = key_data[0].group_key.get_key().get_alg_str();
+		let group_key_pair_alg = group_server_out.keys[0].keypair_encrypt_alg.as_str();
+
+		let asym = [private_key_alg, group_key_pair_alg];
+		let sym = [group_key_alg];
+
+		let policy = CryptoPolicy {
+			sym: Some(&sym),
+			asym: Some(&asym),
+			deprecated: &[private_key_alg],
+			on_deprecated: Some(|usage, _| {
+				assert_eq!(usage, AlgUsage::Asym);
+
+				DEPRECATED_ALG_CALLS.fetch_add(1, Ordering::SeqCst);
+			}),
+			..Default::default()
+		};
+
+		let out = TestGroup::decrypt_group_keys_with_policy(&user_keys.private_key, fetch_key(), None, &policy).unwrap();
+
+		assert_eq!(
+			out.group_key.get_key().as_ref(),
+			key_data[0].group_key.get_key().as_ref()
+		);
+		assert!(DEPRECATED_ALG_CALLS.load(Ordering::SeqCst) > 0);
+
+		//the user key is not allowed to decrypt the group key
+		let policy = CryptoPolicy {
+			sym: Some(&sym),
+			asym: Some(&[]),
+			..Default::default()
+		};
+
+		let err = TestGroup::decrypt_group_keys_with_policy(&user_keys.private_key, fetch_key(), None, &policy);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotAllowed(alg))) if alg == private_key_alg));
+
+		let policy = CryptoPolicy {
+			post_quantum: Some(&[]),
+			..Default::default()
+		};
+
+		let err = TestGroup::decrypt_group_keys_with_policy(&user_keys.private_key, fetch_key(), None, &policy);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotPostQuantum(_)))));
 	}
 }
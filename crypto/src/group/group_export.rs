@@ -15,6 +15,7 @@ use sentc_crypto_std_keys::util::{PublicKey, SecretKey, SignKey, SymKeyFormatExp
 use sentc_crypto_utils::cryptomat::KeyToString;
 use serde_json::from_str;
 
+use crate::crypto::{prepare_signature_policy, prepare_verify_key};
 use crate::entities::group::{GroupKeyDataExport, GroupOutDataExport, GroupOutDataKeyExport, GroupOutDataLightExport};
 use crate::keys::std::StdGroup;
 use crate::SdkError;
@@ -178,6 +179,28 @@ pub fn decrypt_group_keys(private_key: &str, server_key_output: &str, verify_key
 	Ok(result.try_into()?)
 }
 
+/**
+Like decrypt_group_keys but with a signature policy for the group key signature, see prepare_signature_policy.
+ */
+pub fn decrypt_group_keys_with_policy(
+	private_key: &str,
+	server_key_output: &str,
+	verify_key: Option<&str>,
+	signature_policy: &str,
+) -> Result<GroupKeyDataExport, String>
+{
+	let verify_key = prepare_verify_key(verify_key)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
+
+	let server_key_output = GroupKeyServerOutput::from_string(server_key_output).map_err(SdkError::JsonParseFailed)?;
+
+	let private_key: SecretKey = private_key.parse()?;
+
+	let result = StdGroup::decrypt_group_keys(&private_key, server_key_output, policy)?;
+
+	Ok(result.try_into()?)
+}
+
 /**
 Call this fn for pagination key fetch
  */
@@ -0,0 +1,87 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use sentc_crypto_common::crypto::EncryptedHead;
+use sentc_crypto_utils::cryptomat::{PkWrapper, SkWrapper, SymKeyWrapper};
+
+use crate::entities::group::GroupKeyData;
+
+/**
+The keys of one group, indexed by the key id.
+
+Used by Group::resolve_key and Group::decrypt_auto to get the key of an encrypted head.
+Keys that are not known yet are fetched and decrypted on demand and then cached in the resolver.
+ */
+pub struct GroupKeyResolver<S, Sk, Pk>
+{
+	group_id: String,
+	keys: BTreeMap<String, GroupKeyData<S, Sk, Pk>>,
+}
+
+impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper> GroupKeyResolver<S, Sk, Pk>
+{
+	pub fn new(group_id: &str) -> Self
+	{
+		Self {
+			group_id: group_id.to_string(),
+			keys: BTreeMap::new(),
+		}
+	}
+
+	/**
+	Start with already decrypted keys, e.g. from the key store.
+	 */
+	pub fn from_keys(group_id: &str, keys: Vec<GroupKeyData<S, Sk, Pk>>) -> Self
+	{
+		let mut resolver = Self::new(group_id);
+
+		for key in keys {
+			resolver.insert(key);
+		}
+
+		resolver
+	}
+
+	pub fn group_id(&self) -> &str
+	{
+		&self.group_id
+	}
+
+	pub fn insert(&mut self, key: GroupKeyData<S, Sk, Pk>)
+	{
+		self.keys.insert(key.group_key.get_id().to_string(), key);
+	}
+
+	pub fn contains(&self, key_id: &str) -> bool
+	{
+		self.keys.contains_key(key_id)
+	}
+
+	pub fn get_key(&self, key_id: &str) -> Option<&S>
+	{
+		self.keys.get(key_id).map(|k| &k.group_key)
+	}
+
+	pub fn get_key_data(&self, key_id: &str) -> Option<&GroupKeyData<S, Sk, Pk>>
+	{
+		self.keys.get(key_id)
+	}
+
+	/**
+	The key id to fetch from the server or None if the key of the head is already known.
+	 */
+	pub fn missing_key_id<'a>(&self, head: &'a EncryptedHead) -> Option<&'a str>
+	{
+		if self.contains(&head.id) {
+			None
+		} else {
+			Some(&head.id)
+		}
+	}
+
+	pub fn into_keys(self) -> Vec<GroupKeyData<S, Sk, Pk>>
+	{
+		self.keys.into_values().collect()
+	}
+}
@@ -3,6 +3,7 @@ pub(crate) mod group;
 #[cfg(feature = "export")]
 mod group_export;
 mod group_rank_check;
+mod key_resolver;
 
 pub use self::group::Group;
 #[cfg(not(feature = "export"))]
@@ -10,6 +11,7 @@ pub use self::group::*;
 #[cfg(feature = "export")]
 pub use self::group_export::*;
 pub use self::group_rank_check::*;
+pub use self::key_resolver::GroupKeyResolver;
 
 #[cfg(test)]
 pub(crate) mod test_fn
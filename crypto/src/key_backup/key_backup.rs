@@ -0,0 +1,180 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::group::GroupHmacData;
+use sentc_crypto_core::cryptomat::{CryptoAlg, PwHash, PwPrepareExport, SymKey};
+use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
+use sentc_crypto_utils::user::DeviceKeyDataExport;
+
+use crate::entities::group::{GroupKeyDataExport, GroupOutDataHmacKeyExport};
+use crate::entities::key_backup::{EncryptedKeyBackup, KeyBackupGroupKeys, KeyBackupGroupKeysExport, KeyBackupInt, KeyBackupPayload};
+use crate::entities::user::{UserDataInt, UserKeyDataExport};
+use crate::SdkError;
+
+pub const KEY_BACKUP_VERSION: u8 = 1;
+
+fn backup_aad(version: u8, salt: &[u8], alg: &str) -> Vec<u8>
+{
+	let mut aad = Vec::with_capacity(1 + salt.len() + alg.len());
+
+	aad.push(version);
+	aad.extend_from_slice(salt);
+	aad.extend_from_slice(alg.as_bytes());
+
+	aad
+}
+
+pub(crate) fn encrypt_backup<PwH: PwHash>(password: &str, payload: &KeyBackupPayload) -> Result<String, SdkError>
+{
+	let payload = serde_json::to_vec(payload).map_err(|_| SdkError::JsonToStringFailed)?;
+
+	let (salt, key) = PwH::password_to_encrypt(password.as_bytes())?;
+
+	let salt = salt.prepare_export();
+	let alg = key.get_alg_str();
+
+	let encrypted = key.encrypt_with_aad(&payload, &backup_aad(KEY_BACKUP_VERSION, salt, alg))?;
+
+	serde_json::to_string(&EncryptedKeyBackup {
+		version: KEY_BACKUP_VERSION,
+		salt: Base64::encode_string(salt),
+		encrypted_alg: alg.to_string(),
+		encrypted_keys: Base64::encode_string(&encrypted),
+	})
+	.map_err(|_| SdkError::JsonToStringFailed)
+}
+
+pub(crate) fn decrypt_backup<PwH: PwHash>(password: &str, backup: &str) -> Result<KeyBackupPayload, SdkError>
+{
+	let backup: EncryptedKeyBackup = serde_json::from_str(backup).map_err(|_| SdkError::KeyBackupWrongFormat)?;
+
+	if backup.version != KEY_BACKUP_VERSION {
+		return Err(SdkError::KeyBackupVersionNotSupported);
+	}
+
+	let salt = Base64::decode_vec(&backup.salt).map_err(|_| SdkError::KeyBackupWrongFormat)?;
+	let encrypted = Base64::decode_vec(&backup.encrypted_keys).map_err(|_| SdkError::KeyBackupWrongFormat)?;
+
+	let key = PwH::password_to_decrypt(password.as_bytes(), &salt)?;
+
+	if key.get_alg_str() != backup.encrypted_alg {
+		return Err(SdkError::KeyBackupWrongFormat);
+	}
+
+	let payload = key
+		.decrypt_with_aad(&encrypted, &backup_aad(backup.version, &salt, &backup.encrypted_alg))
+		.map_err(|_| SdkError::KeyBackupDecryptFailed)?;
+
+	serde_json::from_slice(&payload).map_err(|_| SdkError::KeyBackupWrongFormat)
+}
+
+pub struct KeyBackup<S, Sk, Pk, SiK, Vk, PwH>
+{
+	_s: PhantomData<S>,
+	_sk: PhantomData<Sk>,
+	_pk: PhantomData<Pk>,
+	_sik: PhantomData<SiK>,
+	_vk: PhantomData<Vk>,
+	_pwh: PhantomData<PwH>,
+}
+
+impl<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper, PwH: PwHash> KeyBackup<S, Sk, Pk, SiK, Vk, PwH>
+{
+	/**
+	Create a passphrase encrypted backup of the user keys and the keys of the given groups.
+
+	Only the passed groups are in the backup.
+	 */
+	pub fn create_backup(
+		password: &str,
+		user: &UserDataInt<S, Sk, Pk, SiK, Vk>,
+		group_keys: &[KeyBackupGroupKeys<S, Sk, Pk>],
+	) -> Result<String, SdkError>
+	{
+		let payload = KeyBackupPayload {
+			user_id: user.user_id.clone(),
+			device_id: user.device_id.clone(),
+			user_keys: user
+				.user_keys
+				.iter()
+				.map(UserKeyDataExport::try_from)
+				.collect::<Result<_, _>>()?,
+			device_keys: DeviceKeyDataExport::try_from(&user.device_keys)?,
+			hmac_keys: user
+				.hmac_keys
+				.iter()
+				.map(|k| {
+					Ok(GroupOutDataHmacKeyExport {
+						group_key_id: k.encrypted_hmac_encryption_key_id.clone(),
+						key_data: serde_json::to_string(k).map_err(|_| SdkError::JsonToStringFailed)?,
+					})
+				})
+				.collect::<Result<_, SdkError>>()?,
+			group_keys: group_keys
+				.iter()
+				.map(|g| {
+					Ok(KeyBackupGroupKeysExport {
+						group_id: g.group_id.clone(),
+						keys: g
+							.keys
+							.iter()
+							.map(GroupKeyDataExport::try_from)
+							.collect::<Result<_, _>>()?,
+					})
+				})
+				.collect::<Result<_, SdkError>>()?,
+		};
+
+		encrypt_backup::<PwH>(password, &payload)
+	}
+
+	/**
+	Decrypt the backup and import the keys.
+
+	The jwt and the refresh token of the user are empty, the user must log in again.
+	 */
+	pub fn import_backup(password: &str, backup: &str) -> Result<KeyBackupInt<S, Sk, Pk, SiK, Vk>, SdkError>
+	{
+		let payload = decrypt_backup::<PwH>(password, backup)?;
+
+		let user = UserDataInt {
+			jwt: String::new(),
+			refresh_token: String::new(),
+			user_id: payload.user_id,
+			device_id: payload.device_id,
+			user_keys: payload
+				.user_keys
+				.into_iter()
+				.map(|k| k.try_into())
+				.collect::<Result<_, SdkError>>()?,
+			device_keys: payload.device_keys.try_into()?,
+			hmac_keys: payload
+				.hmac_keys
+				.into_iter()
+				.map(|k| serde_json::from_str::<GroupHmacData>(&k.key_data).map_err(|_| SdkError::KeyBackupWrongFormat))
+				.collect::<Result<_, SdkError>>()?,
+		};
+
+		let group_keys = payload
+			.group_keys
+			.into_iter()
+			.map(|g| {
+				Ok(KeyBackupGroupKeys {
+					group_id: g.group_id,
+					keys: g
+						.keys
+						.into_iter()
+						.map(|k| k.try_into())
+						.collect::<Result<_, SdkError>>()?,
+				})
+			})
+			.collect::<Result<_, SdkError>>()?;
+
+		Ok(KeyBackupInt {
+			user,
+			group_keys,
+		})
+	}
+}
@@ -0,0 +1,86 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_std_keys::core::PwHasherGetter;
+
+use crate::entities::key_backup::{KeyBackupExport, KeyBackupGroupKeysExport, KeyBackupPayload};
+use crate::entities::user::UserDataExport;
+use crate::SdkError;
+
+/**
+Create a passphrase encrypted backup of the user keys and the keys of the given groups.
+
+The user data is the exported user data as json string. The group keys are a json array of KeyBackupGroupKeysExport
+or an empty string for no groups.
+ */
+pub fn create_key_backup(password: &str, user_data: &str, group_keys: &str) -> Result<String, String>
+{
+	let user: UserDataExport = serde_json::from_str(user_data).map_err(SdkError::JsonParseFailed)?;
+
+	let group_keys: Vec<KeyBackupGroupKeysExport> = if group_keys.is_empty() {
+		Vec::new()
+	} else {
+		serde_json::from_str(group_keys).map_err(SdkError::JsonParseFailed)?
+	};
+
+	let payload = KeyBackupPayload {
+		user_id: user.user_id,
+		device_id: user.device_id,
+		user_keys: user.user_keys,
+		device_keys: user.device_keys,
+		hmac_keys: user.hmac_keys,
+		group_keys,
+	};
+
+	Ok(super::key_backup::encrypt_backup::<PwHasherGetter>(
+		password, &payload,
+	)?)
+}
+
+/**
+Decrypt the backup.
+
+The jwt and the refresh token of the user are empty, the user must log in again.
+ */
+pub fn import_key_backup(password: &str, backup: &str) -> Result<KeyBackupExport, String>
+{
+	let payload = super::key_backup::decrypt_backup::<PwHasherGetter>(password, backup)?;
+
+	Ok(KeyBackupExport {
+		user: UserDataExport {
+			user_keys: payload.user_keys,
+			device_keys: payload.device_keys,
+			jwt: String::new(),
+			refresh_token: String::new(),
+			user_id: payload.user_id,
+			device_id: payload.device_id,
+			hmac_keys: payload.hmac_keys,
+		},
+		group_keys: payload.group_keys,
+	})
+}
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+	use crate::user::test_fn::create_user_export;
+
+	#[test]
+	fn test_create_and_import_backup()
+	{
+		let user = create_user_export();
+		let user_string = serde_json::to_string(&user).unwrap();
+
+		let backup = create_key_backup("backup password", &user_string, "").unwrap();
+
+		let imported = import_key_backup("backup password", &backup).unwrap();
+
+		assert_eq!(imported.user.user_id, user.user_id);
+		assert_eq!(imported.user.user_keys[0].private_key, user.user_keys[0].private_key);
+		assert_eq!(imported.user.device_keys.private_key, user.device_keys.private_key);
+		assert_eq!(imported.group_keys.len(), 0);
+
+		assert!(import_key_backup("other password", &backup).is_err());
+	}
+}
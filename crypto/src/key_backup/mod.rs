@@ -0,0 +1,120 @@
+//! Passphrase protected backup of the user keys.
+//!
+//! The backup contains the user keys, the device keys, the hmac keys of the user and the keys of the selected groups.
+//! It is encrypted with a key derived from the passphrase (see PwHash::password_to_encrypt).
+//! The encryption is authenticated, a changed backup or a wrong passphrase fails the import.
+//!
+//! The backup doesn't contain the jwt or the refresh token. The user must log in again after the import.
+
+pub(crate) mod key_backup;
+#[cfg(feature = "export")]
+mod key_backup_export;
+
+pub use self::key_backup::KeyBackup;
+#[cfg(feature = "export")]
+pub use self::key_backup_export::*;
+
+#[cfg(test)]
+mod test
+{
+	use alloc::format;
+	use alloc::string::ToString;
+
+	use sentc_crypto_utils::cryptomat::{KeyToString, SymKeyWrapper};
+
+	use super::*;
+	use crate::entities::key_backup::KeyBackupGroupKeys;
+	use crate::group::test_fn::create_group;
+	use crate::user::test_fn::create_user;
+	use crate::SdkError;
+
+	#[cfg(feature = "std_keys")]
+	pub type TestKeyBackup = crate::keys::std::StdKeyBackup;
+	#[cfg(all(feature = "fips_keys", not(feature = "std_keys")))]
+	pub type TestKeyBackup = crate::keys::fips::FipsKeyBackup;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	pub type TestKeyBackup = crate::keys::rec::RecKeyBackup;
+
+	#[test]
+	fn test_create_and_import_backup()
+	{
+		let user = create_user();
+		let (data, group_keys, ..) = create_group(&user.user_keys[0]);
+
+		let group_key_id = group_keys[0].group_key.get_id().to_string();
+		let group_key = group_keys[0].group_key.to_string_ref().unwrap();
+
+		let backup = TestKeyBackup::create_backup(
+			"backup password",
+			&user,
+			&[KeyBackupGroupKeys {
+				group_id: data.group_id.clone(),
+				keys: group_keys,
+			}],
+		)
+		.unwrap();
+
+		let imported = TestKeyBackup::import_backup("backup password", &backup).unwrap();
+
+		assert_eq!(imported.user.user_id, user.user_id);
+		assert_eq!(imported.user.device_id, user.device_id);
+		assert_eq!(imported.user.jwt, "");
+		assert_eq!(imported.user.user_keys.len(), user.user_keys.len());
+		assert_eq!(
+			imported.user.user_keys[0]
+				.private_key
+				.to_string_ref()
+				.unwrap(),
+			user.user_keys[0].private_key.to_string_ref().unwrap()
+		);
+		assert_eq!(
+			imported.user.device_keys.sign_key.to_string_ref().unwrap(),
+			user.device_keys.sign_key.to_string_ref().unwrap()
+		);
+		assert_eq!(imported.user.hmac_keys.len(), user.hmac_keys.len());
+
+		assert_eq!(imported.group_keys.len(), 1);
+		assert_eq!(imported.group_keys[0].group_id, data.group_id);
+		assert_eq!(imported.group_keys[0].keys[0].group_key.get_id(), group_key_id);
+		assert_eq!(
+			imported.group_keys[0].keys[0]
+				.group_key
+				.to_string_ref()
+				.unwrap(),
+			group_key
+		);
+	}
+
+	#[test]
+	fn test_not_import_with_wrong_password()
+	{
+		let user = create_user();
+
+		let backup = TestKeyBackup::create_backup("backup password", &user, &[]).unwrap();
+
+		let err = TestKeyBackup::import_backup("other password", &backup);
+
+		assert!(matches!(err, Err(SdkError::KeyBackupDecryptFailed)));
+	}
+
+	#[test]
+	fn test_not_import_changed_backup()
+	{
+		let user = create_user();
+
+		let backup = TestKeyBackup::create_backup("backup password", &user, &[]).unwrap();
+
+		let mut backup: crate::entities::key_backup::EncryptedKeyBackup = serde_json::from_str(&backup).unwrap();
+
+		//unknown version
+		backup.version = 2;
+		let err = TestKeyBackup::import_backup("backup password", &serde_json::to_string(&backup).unwrap());
+		assert!(matches!(err, Err(SdkError::KeyBackupVersionNotSupported)));
+
+		//other salt
+		backup.version = key_backup::KEY_BACKUP_VERSION;
+		backup.salt = format!("AAAA{}", backup.salt);
+		let err = TestKeyBackup::import_backup("backup password", &serde_json::to_string(&backup).unwrap());
+		assert!(err.is_err());
+	}
+}
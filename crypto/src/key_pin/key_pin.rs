@@ -0,0 +1,133 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sha2::{Digest, Sha256};
+
+use crate::entities::key_pin::{PinnedKey, UserKeyPin};
+use crate::key_pin::KeyPinStore;
+use crate::SdkError;
+
+fn pinned_key(key_id: &str, key_alg: &str, key_pem: &str) -> PinnedKey
+{
+	let mut hasher = Sha256::new();
+
+	hasher.update((key_alg.len() as u32).to_be_bytes());
+	hasher.update(key_alg.as_bytes());
+	hasher.update(key_pem.as_bytes());
+
+	PinnedKey {
+		key_id: key_id.to_string(),
+		key_hash: Base64::encode_string(&hasher.finalize()),
+	}
+}
+
+pub(crate) fn pinned_verify_key(verify_key: &UserVerifyKeyData) -> PinnedKey
+{
+	pinned_key(
+		&verify_key.verify_key_id,
+		&verify_key.verify_key_alg,
+		&verify_key.verify_key_pem,
+	)
+}
+
+pub(crate) fn pinned_public_key(public_key: &UserPublicKeyData) -> PinnedKey
+{
+	pinned_key(
+		&public_key.public_key_id,
+		&public_key.public_key_alg,
+		&public_key.public_key_pem,
+	)
+}
+
+/**
+The data of a verify key that is signed when the user rotates the keys.
+ */
+pub(crate) fn verify_key_sign_data(verify_key: &UserVerifyKeyData) -> Vec<u8>
+{
+	let mut data = Vec::with_capacity(8 + verify_key.verify_key_id.len() + verify_key.verify_key_alg.len() + verify_key.verify_key_pem.len());
+
+	data.extend_from_slice(&(verify_key.verify_key_id.len() as u32).to_be_bytes());
+	data.extend_from_slice(verify_key.verify_key_id.as_bytes());
+	data.extend_from_slice(&(verify_key.verify_key_alg.len() as u32).to_be_bytes());
+	data.extend_from_slice(verify_key.verify_key_alg.as_bytes());
+	data.extend_from_slice(verify_key.verify_key_pem.as_bytes());
+
+	data
+}
+
+pub(crate) fn is_pinned_verify_key(pin: &UserKeyPin, verify_key: &UserVerifyKeyData) -> bool
+{
+	let key = pinned_verify_key(verify_key);
+
+	pin.verify_keys.iter().any(|p| *p == key)
+}
+
+/**
+Check a key against the pin with the same key id.
+
+The first key of a user is pinned on first use.
+After that, a key with a new key id is only pinned if signed returns true for the actual pins of the user.
+ */
+pub(crate) fn check_pin<S, F>(store: &mut S, user_id: &str, key: PinnedKey, verify_key: bool, signed: F) -> Result<(), SdkError>
+where
+	S: KeyPinStore,
+	F: FnOnce(&UserKeyPin) -> Result<bool, SdkError>,
+{
+	let mut pin = store.get_pin(user_id)?.unwrap_or_default();
+
+	let pins = if verify_key { &pin.verify_keys } else { &pin.public_keys };
+
+	match pins.iter().find(|p| p.key_id == key.key_id) {
+		Some(p) if *p == key => return Ok(()),
+		Some(_) => return Err(SdkError::KeyPinIdentityChanged(user_id.to_string())),
+		None => {},
+	}
+
+	let first_use = pin.verify_keys.is_empty() && pin.public_keys.is_empty();
+
+	//a new key id of a known user must be signed by a pinned verify key, otherwise the server could hand out its own key
+	if !first_use && !signed(&pin)? {
+		return Err(SdkError::KeyPinIdentityChanged(user_id.to_string()));
+	}
+
+	if verify_key {
+		pin.verify_keys.push(key);
+	} else {
+		pin.public_keys.push(key);
+	}
+
+	store.set_pin(user_id, pin)
+}
+
+fn replace_key(pins: &mut Vec<PinnedKey>, key: PinnedKey)
+{
+	pins.retain(|p| p.key_id != key.key_id);
+	pins.push(key);
+}
+
+/**
+Replace the pins of the key ids of the given keys.
+
+Only call this after the safety number was compared. The pins of the other verify key ids of this user are kept.
+If no public key is set, all public key pins are removed and the next seen public keys are pinned.
+ */
+pub(crate) fn replace_pin<S: KeyPinStore>(
+	store: &mut S,
+	user_id: &str,
+	verify_key: &UserVerifyKeyData,
+	public_key: Option<&UserPublicKeyData>,
+) -> Result<(), SdkError>
+{
+	let mut pin = store.get_pin(user_id)?.unwrap_or_default();
+
+	replace_key(&mut pin.verify_keys, pinned_verify_key(verify_key));
+
+	match public_key {
+		Some(public_key) => replace_key(&mut pin.public_keys, pinned_public_key(public_key)),
+		None => pin.public_keys.clear(),
+	}
+
+	store.set_pin(user_id, pin)
+}
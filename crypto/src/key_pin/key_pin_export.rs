@@ -0,0 +1,96 @@
+use alloc::string::String;
+
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+
+use crate::key_pin::MemoryKeyPinStore;
+use crate::keys::std::StdUser;
+use crate::SdkError;
+
+/**
+Check the verify key of a user against the pinned verify key.
+
+The store is the exported MemoryKeyPinStore (an empty string for a new store). Returns the new exported store.
+A verify key with a new key id needs the verify key of the previous sign key and the signature of the new verify key.
+ */
+pub fn check_verify_key_pin(store: &str, user_id: &str, verify_key: &str, signed_by: Option<(&str, &str)>) -> Result<String, String>
+{
+	let mut store = import_store(store)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	let signed_by = match signed_by {
+		Some((k, sig)) => {
+			Some((
+				UserVerifyKeyData::from_string(k).map_err(SdkError::JsonParseFailed)?,
+				sig,
+			))
+		},
+		None => None,
+	};
+
+	StdUser::check_verify_key_pin(
+		&mut store,
+		user_id,
+		&verify_key,
+		signed_by.as_ref().map(|(k, sig)| (k, *sig)),
+	)?;
+
+	Ok(store.to_string()?)
+}
+
+/**
+Check the public key of a user against the pinned public key.
+
+The store is the exported MemoryKeyPinStore (an empty string for a new store). Returns the new exported store.
+The verify key is the key that signed the public key.
+ */
+pub fn check_public_key_pin(store: &str, user_id: &str, public_key: &str, verify_key: Option<&str>) -> Result<String, String>
+{
+	let mut store = import_store(store)?;
+	let public_key = UserPublicKeyData::from_string(public_key).map_err(SdkError::JsonParseFailed)?;
+
+	let verify_key = match verify_key {
+		Some(k) => Some(UserVerifyKeyData::from_string(k).map_err(SdkError::JsonParseFailed)?),
+		None => None,
+	};
+
+	StdUser::check_public_key_pin(&mut store, user_id, &public_key, verify_key.as_ref())?;
+
+	Ok(store.to_string()?)
+}
+
+pub(crate) fn import_store(store: &str) -> Result<MemoryKeyPinStore, SdkError>
+{
+	if store.is_empty() {
+		return Ok(MemoryKeyPinStore::new());
+	}
+
+	MemoryKeyPinStore::from_string(store)
+}
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+	use crate::user::test_fn::create_user_export;
+
+	#[test]
+	fn test_pin_on_first_use()
+	{
+		let user_1 = create_user_export();
+		let user_2 = create_user_export();
+
+		let store = check_public_key_pin(
+			"",
+			"user_1",
+			&user_1.user_keys[0].exported_public_key,
+			Some(&user_1.user_keys[0].exported_verify_key),
+		)
+		.unwrap();
+
+		let store = check_verify_key_pin(&store, "user_1", &user_1.user_keys[0].exported_verify_key, None).unwrap();
+
+		let err = check_verify_key_pin(&store, "user_1", &user_2.user_keys[0].exported_verify_key, None);
+
+		assert!(err.is_err());
+	}
+}
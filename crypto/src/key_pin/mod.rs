@@ -0,0 +1,268 @@
+//! Trust on first use pinning for the keys of other users.
+//!
+//! The keys of a user are pinned by their key id when the user is seen first and stored in a KeyPinStore.
+//! When the server returns another key for a pinned key id later, the check fails with KeyPinIdentityChanged.
+//!
+//! After the first use, a key with a new key id is only pinned when it is signed by a pinned verify key of the user:
+//! a public key by its public_key_sig and a new verify key by a signature of the previous sign key (see User::sign_verify_key).
+//! Otherwise the check fails with KeyPinIdentityChanged too.
+//! The keys of older key ids stay valid after a key rotation of the user.
+//! Changed keys are only trusted again after comparing the safety number with the other user (see User::retrust_user_keys).
+//!
+//! The MemoryKeyPinStore can be exported to a string to persist it.
+//! With the feature pin_store_file the FileKeyPinStore writes every change into a json file.
+
+pub(crate) mod key_pin;
+#[cfg(feature = "export")]
+mod key_pin_export;
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "export")]
+pub use self::key_pin_export::*;
+use crate::entities::key_pin::UserKeyPin;
+use crate::SdkError;
+
+pub trait KeyPinStore
+{
+	fn get_pin(&self, user_id: &str) -> Result<Option<UserKeyPin>, SdkError>;
+
+	fn set_pin(&mut self, user_id: &str, pin: UserKeyPin) -> Result<(), SdkError>;
+}
+
+#[derive(Default)]
+pub struct MemoryKeyPinStore
+{
+	pins: BTreeMap<String, UserKeyPin>,
+}
+
+impl MemoryKeyPinStore
+{
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	pub fn from_string(store: &str) -> Result<Self, SdkError>
+	{
+		Ok(Self {
+			pins: serde_json::from_str(store)?,
+		})
+	}
+
+	pub fn to_string(&self) -> Result<String, SdkError>
+	{
+		serde_json::to_string(&self.pins).map_err(|_| SdkError::JsonToStringFailed)
+	}
+}
+
+impl KeyPinStore for MemoryKeyPinStore
+{
+	fn get_pin(&self, user_id: &str) -> Result<Option<UserKeyPin>, SdkError>
+	{
+		Ok(self.pins.get(user_id).cloned())
+	}
+
+	fn set_pin(&mut self, user_id: &str, pin: UserKeyPin) -> Result<(), SdkError>
+	{
+		self.pins.insert(user_id.to_string(), pin);
+
+		Ok(())
+	}
+}
+
+/**
+A pin store that saves the pins as json in a file.
+
+The file is read when opening the store and written after every change.
+ */
+#[cfg(feature = "pin_store_file")]
+pub struct FileKeyPinStore
+{
+	path: std::path::PathBuf,
+	inner: MemoryKeyPinStore,
+}
+
+#[cfg(feature = "pin_store_file")]
+impl FileKeyPinStore
+{
+	/**
+	Open the store. If the file doesn't exist yet, the store is empty and the file is created with the first pin.
+	 */
+	pub fn open<P: Into<std::path::PathBuf>>(path: P) -> Result<Self, SdkError>
+	{
+		let path = path.into();
+
+		let inner = match std::fs::read_to_string(&path) {
+			Ok(s) => MemoryKeyPinStore::from_string(&s)?,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => MemoryKeyPinStore::new(),
+			Err(_) => return Err(SdkError::KeyPinStoreFailed),
+		};
+
+		Ok(Self {
+			path,
+			inner,
+		})
+	}
+}
+
+#[cfg(feature = "pin_store_file")]
+impl KeyPinStore for FileKeyPinStore
+{
+	fn get_pin(&self, user_id: &str) -> Result<Option<UserKeyPin>, SdkError>
+	{
+		self.inner.get_pin(user_id)
+	}
+
+	fn set_pin(&mut self, user_id: &str, pin: UserKeyPin) -> Result<(), SdkError>
+	{
+		self.inner.set_pin(user_id, pin)?;
+
+		crate::util::write_file_atomic(&self.path, self.inner.to_string()?.as_bytes()).map_err(|_| SdkError::KeyPinStoreFailed)
+	}
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::string::ToString;
+
+	use sentc_crypto_common::user::UserVerifyKeyData;
+
+	use super::*;
+	use crate::user::test_fn::{create_user, TestUser};
+
+	#[test]
+	fn test_pin_on_first_use()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+
+		let mut store = MemoryKeyPinStore::new();
+
+		let verify_key = &user_1.user_keys[0].exported_verify_key;
+		let public_key = &user_1.user_keys[0].exported_public_key;
+
+		TestUser::check_public_key_pin(&mut store, "user_1", public_key, Some(verify_key)).unwrap();
+
+		//same keys again
+		TestUser::check_verify_key_pin(&mut store, "user_1", verify_key, None).unwrap();
+		TestUser::check_public_key_pin(&mut store, "user_1", public_key, Some(verify_key)).unwrap();
+
+		//the server returns the keys of another user
+		let err = TestUser::check_verify_key_pin(&mut store, "user_1", &user_2.user_keys[0].exported_verify_key, None);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(id)) if id == "user_1"));
+
+		let err = TestUser::check_public_key_pin(&mut store, "user_1", &user_2.user_keys[0].exported_public_key, None);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+
+		//same id but other key
+		let fake_key = UserVerifyKeyData {
+			verify_key_pem: user_2.user_keys[0]
+				.exported_verify_key
+				.verify_key_pem
+				.clone(),
+			verify_key_alg: verify_key.verify_key_alg.clone(),
+			verify_key_id: verify_key.verify_key_id.clone(),
+		};
+
+		let err = TestUser::check_verify_key_pin(&mut store, "user_1", &fake_key, None);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+	}
+
+	#[test]
+	fn test_pin_after_key_rotation()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+
+		let mut store = MemoryKeyPinStore::new();
+
+		let old_verify_key = &user_1.user_keys[0].exported_verify_key;
+
+		TestUser::check_verify_key_pin(&mut store, "user_1", old_verify_key, None).unwrap();
+
+		//the server hands out its own key under a new key id
+		let mut new_verify_key = user_2.user_keys[0].exported_verify_key.clone();
+		new_verify_key.verify_key_id = "new_key_id".to_string();
+
+		let err = TestUser::check_verify_key_pin(&mut store, "user_1", &new_verify_key, None);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+
+		//signed by a key that is not pinned
+		let sig = TestUser::sign_verify_key(&user_2.user_keys[0].sign_key, &new_verify_key).unwrap();
+
+		let err = TestUser::check_verify_key_pin(
+			&mut store,
+			"user_1",
+			&new_verify_key,
+			Some((&user_2.user_keys[0].exported_verify_key, &sig)),
+		);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+
+		//the user rotated the keys and signed the new verify key with the previous sign key
+		let sig = TestUser::sign_verify_key(&user_1.user_keys[0].sign_key, &new_verify_key).unwrap();
+
+		TestUser::check_verify_key_pin(&mut store, "user_1", &new_verify_key, Some((old_verify_key, &sig))).unwrap();
+
+		//the old key is still valid, e.g. for older signatures
+		TestUser::check_verify_key_pin(&mut store, "user_1", old_verify_key, None).unwrap();
+		TestUser::check_verify_key_pin(&mut store, "user_1", &new_verify_key, None).unwrap();
+
+		//but the new key id can't be changed anymore
+		let mut fake_key = old_verify_key.clone();
+		fake_key.verify_key_id = "new_key_id".to_string();
+
+		let err = TestUser::check_verify_key_pin(&mut store, "user_1", &fake_key, Some((old_verify_key, &sig)));
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+	}
+
+	#[test]
+	fn test_pin_new_public_key()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+
+		let mut store = MemoryKeyPinStore::new();
+
+		let verify_key = &user_1.user_keys[0].exported_verify_key;
+
+		TestUser::check_verify_key_pin(&mut store, "user_1", verify_key, None).unwrap();
+
+		//a public key under a new id that is not signed by the pinned verify key
+		let mut public_key = user_2.user_keys[0].exported_public_key.clone();
+		public_key.public_key_sig_key_id = Some(verify_key.verify_key_id.clone());
+
+		let err = TestUser::check_public_key_pin(&mut store, "user_1", &public_key, Some(verify_key));
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+
+		let err = TestUser::check_public_key_pin(&mut store, "user_1", &public_key, None);
+		assert!(matches!(err, Err(SdkError::KeyPinIdentityChanged(_))));
+
+		//signed by the pinned verify key
+		TestUser::check_public_key_pin(
+			&mut store,
+			"user_1",
+			&user_1.user_keys[0].exported_public_key,
+			Some(verify_key),
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_store_to_string()
+	{
+		let user_1 = create_user();
+
+		let mut store = MemoryKeyPinStore::new();
+
+		TestUser::check_verify_key_pin(&mut store, "user_1", &user_1.user_keys[0].exported_verify_key, None).unwrap();
+
+		let exported = store.to_string().unwrap();
+
+		let store_2 = MemoryKeyPinStore::from_string(&exported).unwrap();
+
+		assert_eq!(store.get_pin("user_1").unwrap(), store_2.get_pin("user_1").unwrap());
+	}
+}
@@ -0,0 +1,234 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
+
+use crate::entities::group::{GroupKeyData, GroupKeyDataExport};
+use crate::entities::user::{UserKeyDataExport, UserKeyDataInt};
+use crate::key_store::KeyStore;
+use crate::SdkError;
+
+//the ids are length prefixed, so an id with a colon can't be confused with another id pair
+fn key_aad(kind: &str, id: &str, key_id: &str) -> String
+{
+	format!("{}:{}:{}:{}:{}", kind, id.len(), id, key_id.len(), key_id)
+}
+
+fn group_key_aad(group_id: &str, key_id: &str) -> String
+{
+	key_aad("group_key", group_id, key_id)
+}
+
+fn user_key_aad(user_id: &str, key_id: &str) -> String
+{
+	key_aad("user_key", user_id, key_id)
+}
+
+pub(super) fn group_index(group_id: &str) -> String
+{
+	format!("group:{}", group_id)
+}
+
+pub(super) fn user_index(user_id: &str) -> String
+{
+	format!("user:{}", user_id)
+}
+
+/**
+A key store that encrypts every entry with the device bound key before it is written to the inner store.
+
+The device bound key should never be stored next to the store, e.g. keep it in the key chain of the os.
+The group id and the key id are used as aad, so an entry can't be moved to another index.
+Group keys and user keys are stored under different indexes in the inner store.
+ */
+pub struct EncryptedKeyStore<K, St>
+{
+	device_key: K,
+	store: St,
+}
+
+impl<K: SymKeyWrapper, St: KeyStore> EncryptedKeyStore<K, St>
+{
+	pub fn new(device_key: K, store: St) -> Self
+	{
+		Self {
+			device_key,
+			store,
+		}
+	}
+
+	fn encrypt_entry(&self, entry: &impl serde::Serialize, aad: &str) -> Result<String, SdkError>
+	{
+		let entry = serde_json::to_string(entry).map_err(|_| SdkError::JsonToStringFailed)?;
+
+		Ok(self.device_key.encrypt_string_with_aad(&entry, aad)?)
+	}
+
+	fn decrypt_entry<T: serde::de::DeserializeOwned>(&self, entry: &str, aad: &str) -> Result<T, SdkError>
+	{
+		let entry = self
+			.device_key
+			.decrypt_string_with_aad(entry, aad, None)
+			.map_err(|_| SdkError::KeyStoreDecryptFailed)?;
+
+		Ok(serde_json::from_str(&entry)?)
+	}
+
+	pub(crate) fn set_group_key_export(&mut self, group_id: &str, key: &GroupKeyDataExport) -> Result<(), SdkError>
+	{
+		let entry = self.encrypt_entry(key, &group_key_aad(group_id, &key.group_key_id))?;
+
+		self.store
+			.set_entry(&group_index(group_id), &key.group_key_id, entry)
+	}
+
+	pub(crate) fn get_group_key_export(&self, group_id: &str, key_id: &str) -> Result<Option<GroupKeyDataExport>, SdkError>
+	{
+		match self.store.get_entry(&group_index(group_id), key_id)? {
+			Some(entry) => Ok(Some(self.decrypt_entry(&entry, &group_key_aad(group_id, key_id))?)),
+			None => Ok(None),
+		}
+	}
+
+	pub(crate) fn get_group_keys_export(&self, group_id: &str) -> Result<Vec<GroupKeyDataExport>, SdkError>
+	{
+		self.store
+			.get_entries(&group_index(group_id))?
+			.into_iter()
+			.map(|(key_id, entry)| self.decrypt_entry(&entry, &group_key_aad(group_id, &key_id)))
+			.collect()
+	}
+
+	pub(crate) fn set_user_key_export(&mut self, user_id: &str, key: &UserKeyDataExport) -> Result<(), SdkError>
+	{
+		let entry = self.encrypt_entry(key, &user_key_aad(user_id, &key.group_key_id))?;
+
+		self.store
+			.set_entry(&user_index(user_id), &key.group_key_id, entry)
+	}
+
+	pub(crate) fn get_user_key_export(&self, user_id: &str, key_id: &str) -> Result<Option<UserKeyDataExport>, SdkError>
+	{
+		match self.store.get_entry(&user_index(user_id), key_id)? {
+			Some(entry) => Ok(Some(self.decrypt_entry(&entry, &user_key_aad(user_id, key_id))?)),
+			None => Ok(None),
+		}
+	}
+
+	pub(crate) fn get_user_keys_export(&self, user_id: &str) -> Result<Vec<UserKeyDataExport>, SdkError>
+	{
+		self.store
+			.get_entries(&user_index(user_id))?
+			.into_iter()
+			.map(|(key_id, entry)| self.decrypt_entry(&entry, &user_key_aad(user_id, &key_id)))
+			.collect()
+	}
+
+	pub fn set_group_key<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper>(
+		&mut self,
+		group_id: &str,
+		key: &GroupKeyData<S, Sk, Pk>,
+	) -> Result<(), SdkError>
+	{
+		self.set_group_key_export(group_id, &key.try_into()?)
+	}
+
+	pub fn get_group_key<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper>(
+		&self,
+		group_id: &str,
+		key_id: &str,
+	) -> Result<Option<GroupKeyData<S, Sk, Pk>>, SdkError>
+	{
+		self.get_group_key_export(group_id, key_id)?
+			.map(|key| key.try_into())
+			.transpose()
+	}
+
+	/**
+	Get all stored keys of a group.
+	 */
+	pub fn get_group_keys<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper>(&self, group_id: &str) -> Result<Vec<GroupKeyData<S, Sk, Pk>>, SdkError>
+	{
+		self.get_group_keys_export(group_id)?
+			.into_iter()
+			.map(|key| key.try_into())
+			.collect()
+	}
+
+	pub fn set_user_key<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>(
+		&mut self,
+		user_id: &str,
+		key: &UserKeyDataInt<S, Sk, Pk, SiK, Vk>,
+	) -> Result<(), SdkError>
+	{
+		self.set_user_key_export(user_id, &key.try_into()?)
+	}
+
+	pub fn get_user_key<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>(
+		&self,
+		user_id: &str,
+		key_id: &str,
+	) -> Result<Option<UserKeyDataInt<S, Sk, Pk, SiK, Vk>>, SdkError>
+	{
+		self.get_user_key_export(user_id, key_id)?
+			.map(|key| key.try_into())
+			.transpose()
+	}
+
+	/**
+	Get all stored keys of the user.
+	 */
+	pub fn get_user_keys<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<UserKeyDataInt<S, Sk, Pk, SiK, Vk>>, SdkError>
+	{
+		self.get_user_keys_export(user_id)?
+			.into_iter()
+			.map(|key| key.try_into())
+			.collect()
+	}
+
+	/**
+	Remove a single key, e.g. after the key was deleted in the group.
+	 */
+	pub fn evict_key(&mut self, group_id: &str, key_id: &str) -> Result<(), SdkError>
+	{
+		self.store.remove_entry(&group_index(group_id), key_id)
+	}
+
+	/**
+	Remove all keys of a group, e.g. after leaving the group.
+	 */
+	pub fn evict_group(&mut self, group_id: &str) -> Result<(), SdkError>
+	{
+		self.store.remove_group(&group_index(group_id))
+	}
+
+	pub fn evict_user_key(&mut self, user_id: &str, key_id: &str) -> Result<(), SdkError>
+	{
+		self.store.remove_entry(&user_index(user_id), key_id)
+	}
+
+	/**
+	Remove all keys of a user.
+	 */
+	pub fn evict_user(&mut self, user_id: &str) -> Result<(), SdkError>
+	{
+		self.store.remove_group(&user_index(user_id))
+	}
+
+	/**
+	Remove all keys. Call this at logout.
+	 */
+	pub fn wipe(&mut self) -> Result<(), SdkError>
+	{
+		self.store.wipe()
+	}
+
+	pub fn into_inner(self) -> St
+	{
+		self.store
+	}
+}
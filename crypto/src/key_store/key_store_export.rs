@@ -0,0 +1,167 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_std_keys::util::SymmetricKey;
+
+use crate::entities::group::GroupKeyDataExport;
+use crate::entities::user::UserKeyDataExport;
+use crate::key_store::key_store::{group_index, user_index};
+use crate::key_store::{EncryptedKeyStore, MemoryKeyStore};
+use crate::SdkError;
+
+fn open_store(store: &str, device_key: &str) -> Result<EncryptedKeyStore<SymmetricKey, MemoryKeyStore>, SdkError>
+{
+	let store = if store.is_empty() {
+		MemoryKeyStore::new()
+	} else {
+		MemoryKeyStore::from_string(store)?
+	};
+
+	Ok(EncryptedKeyStore::new(device_key.parse()?, store))
+}
+
+/**
+Store an exported group key.
+
+The store is the exported MemoryKeyStore (an empty string for a new store). Returns the new exported store.
+ */
+pub fn set_group_key(store: &str, device_key: &str, group_id: &str, group_key: &str) -> Result<String, String>
+{
+	let mut store = open_store(store, device_key)?;
+	let group_key: GroupKeyDataExport = serde_json::from_str(group_key).map_err(SdkError::JsonParseFailed)?;
+
+	store.set_group_key_export(group_id, &group_key)?;
+
+	Ok(store.into_inner().to_string()?)
+}
+
+pub fn get_group_key(store: &str, device_key: &str, group_id: &str, key_id: &str) -> Result<Option<GroupKeyDataExport>, String>
+{
+	let store = open_store(store, device_key)?;
+
+	Ok(store.get_group_key_export(group_id, key_id)?)
+}
+
+pub fn get_group_keys(store: &str, device_key: &str, group_id: &str) -> Result<Vec<GroupKeyDataExport>, String>
+{
+	let store = open_store(store, device_key)?;
+
+	Ok(store.get_group_keys_export(group_id)?)
+}
+
+/**
+Store an exported user key under the user id.
+
+Returns the new exported store.
+ */
+pub fn set_user_key(store: &str, device_key: &str, user_id: &str, user_key: &str) -> Result<String, String>
+{
+	let mut store = open_store(store, device_key)?;
+	let user_key: UserKeyDataExport = serde_json::from_str(user_key).map_err(SdkError::JsonParseFailed)?;
+
+	store.set_user_key_export(user_id, &user_key)?;
+
+	Ok(store.into_inner().to_string()?)
+}
+
+pub fn get_user_key(store: &str, device_key: &str, user_id: &str, key_id: &str) -> Result<Option<UserKeyDataExport>, String>
+{
+	let store = open_store(store, device_key)?;
+
+	Ok(store.get_user_key_export(user_id, key_id)?)
+}
+
+pub fn get_user_keys(store: &str, device_key: &str, user_id: &str) -> Result<Vec<UserKeyDataExport>, String>
+{
+	let store = open_store(store, device_key)?;
+
+	Ok(store.get_user_keys_export(user_id)?)
+}
+
+fn remove_index(store: &str, index: &str) -> Result<String, SdkError>
+{
+	let mut store = if store.is_empty() {
+		MemoryKeyStore::new()
+	} else {
+		MemoryKeyStore::from_string(store)?
+	};
+
+	super::KeyStore::remove_group(&mut store, index)?;
+
+	store.to_string()
+}
+
+/**
+Remove all keys of a group. Returns the new exported store.
+
+To wipe the whole store at logout, just delete the exported store.
+ */
+pub fn evict_group(store: &str, group_id: &str) -> Result<String, String>
+{
+	Ok(remove_index(store, &group_index(group_id))?)
+}
+
+/**
+Remove all keys of a user. Returns the new exported store.
+ */
+pub fn evict_user(store: &str, user_id: &str) -> Result<String, String>
+{
+	Ok(remove_index(store, &user_index(user_id))?)
+}
+
+#[cfg(test)]
+mod test
+{
+	use super::*;
+	use crate::group::test_fn::create_group_export;
+	use crate::user::test_fn::create_user_export;
+
+	#[test]
+	fn test_store_group_and_user_keys()
+	{
+		let user = create_user_export();
+		let device_key = create_user_export().user_keys[0].group_key.clone();
+
+		let (_, group_keys, _, _, _) = create_group_export(&user.user_keys[0]);
+		let key_id = group_keys[0].group_key_id.clone();
+
+		let store = set_group_key(
+			"",
+			&device_key,
+			"group_1",
+			&serde_json::to_string(&group_keys[0]).unwrap(),
+		)
+		.unwrap();
+		let store = set_user_key(
+			&store,
+			&device_key,
+			"user_1",
+			&serde_json::to_string(&user.user_keys[0]).unwrap(),
+		)
+		.unwrap();
+
+		let key = get_group_key(&store, &device_key, "group_1", &key_id)
+			.unwrap()
+			.unwrap();
+		assert_eq!(key.group_key, group_keys[0].group_key);
+
+		let keys = get_user_keys(&store, &device_key, "user_1").unwrap();
+		assert_eq!(keys.len(), 1);
+		assert_eq!(keys[0].sign_key, user.user_keys[0].sign_key);
+
+		//wrong device key
+		let other_device_key = create_user_export().user_keys[0].group_key.clone();
+		assert!(get_group_key(&store, &other_device_key, "group_1", &key_id).is_err());
+
+		let store = evict_group(&store, "group_1").unwrap();
+		assert!(get_group_keys(&store, &device_key, "group_1")
+			.unwrap()
+			.is_empty());
+		assert_eq!(get_user_keys(&store, &device_key, "user_1").unwrap().len(), 1);
+
+		let store = evict_user(&store, "user_1").unwrap();
+		assert!(get_user_keys(&store, &device_key, "user_1")
+			.unwrap()
+			.is_empty());
+	}
+}
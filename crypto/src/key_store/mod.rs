@@ -0,0 +1,454 @@
+//! Local storage for the decrypted group and user keys.
+//!
+//! Without a store, the keys must be fetched and decrypted again after every restart of the app.
+//! The EncryptedKeyStore encrypts every key with a device bound symmetric key before it is written to a KeyStore.
+//! The keys are indexed by the group id (or the user id for the user keys) and the key id.
+//! Group and user keys are stored in different namespaces, so a group id can't hit the keys of a user with the same id.
+//!
+//! The MemoryKeyStore can be exported to a string to persist it.
+//! With the feature key_store_file the FileKeyStore writes every change into a json file.
+//!
+//! Call wipe at logout to remove all keys of the user.
+
+pub(crate) mod key_store;
+#[cfg(feature = "export")]
+mod key_store_export;
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+pub use self::key_store::EncryptedKeyStore;
+#[cfg(feature = "export")]
+pub use self::key_store_export::*;
+use crate::SdkError;
+
+/**
+A store for the encrypted key entries.
+
+The entries are already encrypted by the EncryptedKeyStore.
+ */
+pub trait KeyStore
+{
+	fn get_entry(&self, group_id: &str, key_id: &str) -> Result<Option<String>, SdkError>;
+
+	/**
+	Returns all entries of the group with the key id.
+	 */
+	fn get_entries(&self, group_id: &str) -> Result<Vec<(String, String)>, SdkError>;
+
+	fn set_entry(&mut self, group_id: &str, key_id: &str, entry: String) -> Result<(), SdkError>;
+
+	fn remove_entry(&mut self, group_id: &str, key_id: &str) -> Result<(), SdkError>;
+
+	fn remove_group(&mut self, group_id: &str) -> Result<(), SdkError>;
+
+	fn wipe(&mut self) -> Result<(), SdkError>;
+}
+
+#[derive(Default)]
+pub struct MemoryKeyStore
+{
+	entries: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MemoryKeyStore
+{
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+
+	pub fn from_string(store: &str) -> Result<Self, SdkError>
+	{
+		Ok(Self {
+			entries: serde_json::from_str(store)?,
+		})
+	}
+
+	pub fn to_string(&self) -> Result<String, SdkError>
+	{
+		serde_json::to_string(&self.entries).map_err(|_| SdkError::JsonToStringFailed)
+	}
+}
+
+impl KeyStore for MemoryKeyStore
+{
+	fn get_entry(&self, group_id: &str, key_id: &str) -> Result<Option<String>, SdkError>
+	{
+		Ok(self
+			.entries
+			.get(group_id)
+			.and_then(|keys| keys.get(key_id))
+			.cloned())
+	}
+
+	fn get_entries(&self, group_id: &str) -> Result<Vec<(String, String)>, SdkError>
+	{
+		Ok(match self.entries.get(group_id) {
+			Some(keys) => keys.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+			None => Vec::new(),
+		})
+	}
+
+	fn set_entry(&mut self, group_id: &str, key_id: &str, entry: String) -> Result<(), SdkError>
+	{
+		self.entries
+			.entry(group_id.to_string())
+			.or_default()
+			.insert(key_id.to_string(), entry);
+
+		Ok(())
+	}
+
+	fn remove_entry(&mut self, group_id: &str, key_id: &str) -> Result<(), SdkError>
+	{
+		if let Some(keys) = self.entries.get_mut(group_id) {
+			keys.remove(key_id);
+
+			if keys.is_empty() {
+				self.entries.remove(group_id);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn remove_group(&mut self, group_id: &str) -> Result<(), SdkError>
+	{
+		self.entries.remove(group_id);
+
+		Ok(())
+	}
+
+	fn wipe(&mut self) -> Result<(), SdkError>
+	{
+		self.entries.clear();
+
+		Ok(())
+	}
+}
+
+/**
+A key store that saves the encrypted entries as json in a file.
+
+The file is read when opening the store and written after every change.
+The file is written to a temp file first and then renamed, so a crash while writing keeps the old store. Wipe deletes the file.
+ */
+#[cfg(feature = "key_store_file")]
+pub struct FileKeyStore
+{
+	path: std::path::PathBuf,
+	inner: MemoryKeyStore,
+}
+
+#[cfg(feature = "key_store_file")]
+impl FileKeyStore
+{
+	/**
+	Open the store. If the file doesn't exist yet, the store is empty and the file is created with the first entry.
+	 */
+	pub fn open<P: Into<std::path::PathBuf>>(path: P) -> Result<Self, SdkError>
+	{
+		let path = path.into();
+
+		let inner = match std::fs::read_to_string(&path) {
+			Ok(s) => MemoryKeyStore::from_string(&s)?,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => MemoryKeyStore::new(),
+			Err(_) => return Err(SdkError::KeyStoreFailed),
+		};
+
+		Ok(Self {
+			path,
+			inner,
+		})
+	}
+
+	fn write(&self) -> Result<(), SdkError>
+	{
+		crate::util::write_file_atomic(&self.path, self.inner.to_string()?.as_bytes()).map_err(|_| SdkError::KeyStoreFailed)
+	}
+}
+
+#[cfg(feature = "key_store_file")]
+impl KeyStore for FileKeyStore
+{
+	fn get_entry(&self, group_id: &str, key_id: &str) -> Result<Option<String>, SdkError>
+	{
+		self.inner.get_entry(group_id, key_id)
+	}
+
+	fn get_entries(&self, group_id: &str) -> Result<Vec<(String, String)>, SdkError>
+	{
+		self.inner.get_entries(group_id)
+	}
+
+	fn set_entry(&mut self, group_id: &str, key_id: &str, entry: String) -> Result<(), SdkError>
+	{
+		self.inner.set_entry(group_id, key_id, entry)?;
+
+		self.write()
+	}
+
+	fn remove_entry(&mut self, group_id: &str, key_id: &str) -> Result<(), SdkError>
+	{
+		self.inner.remove_entry(group_id, key_id)?;
+
+		self.write()
+	}
+
+	fn remove_group(&mut self, group_id: &str) -> Result<(), SdkError>
+	{
+		self.inner.remove_group(group_id)?;
+
+		self.write()
+	}
+
+	fn wipe(&mut self) -> Result<(), SdkError>
+	{
+		self.inner.wipe()?;
+
+		match std::fs::remove_file(&self.path) {
+			Ok(_) => Ok(()),
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+			Err(_) => Err(SdkError::KeyStoreFailed),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test
+{
+	use sentc_crypto_common::group::GroupServerData;
+	use sentc_crypto_utils::cryptomat::{KeyToString, SymKeyCrypto, SymKeyWrapper};
+	use sentc_crypto_utils::error::SdkUtilError;
+	use sentc_crypto_utils::policy::CryptoPolicy;
+
+	use super::*;
+	use crate::group::test_fn::{create_group, TestGroup, TestSymmetricKey};
+	use crate::user::test_fn::{create_user, TestUser};
+
+	fn device_key() -> String
+	{
+		let mut device = create_user();
+
+		device.user_keys.remove(0).group_key.to_string().unwrap()
+	}
+
+	fn open_store(device_key: &str, store: MemoryKeyStore) -> EncryptedKeyStore<TestSymmetricKey, MemoryKeyStore>
+	{
+		EncryptedKeyStore::new(device_key.parse().unwrap(), store)
+	}
+
+	#[test]
+	fn test_store_group_keys()
+	{
+		let user = create_user();
+
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let mut store = open_store(&device_key(), MemoryKeyStore::new());
+
+		store.set_group_key("group_1", &group_keys[0]).unwrap();
+
+		let key_id = group_keys[0].group_key.get_id();
+
+		let key = TestGroup::get_key_from_store(&store, "group_1", key_id)
+			.unwrap()
+			.unwrap();
+		assert_eq!(key.group_key.get_id(), key_id);
+
+		let encrypted = key.group_key.encrypt_string("hello").unwrap();
+		assert_eq!(
+			group_keys[0]
+				.group_key
+				.decrypt_string(&encrypted, None)
+				.unwrap(),
+			"hello"
+		);
+
+		let keys = TestGroup::get_keys_from_store(&store, "group_1").unwrap();
+		assert_eq!(keys.len(), 1);
+
+		//not in the store
+		assert!(TestGroup::get_key_from_store(&store, "group_1", "other_key")
+			.unwrap()
+			.is_none());
+		assert!(TestGroup::get_key_from_store(&store, "group_2", key_id)
+			.unwrap()
+			.is_none());
+	}
+
+	#[test]
+	fn test_entry_bound_to_index_and_device()
+	{
+		let user = create_user();
+		let device_key = device_key();
+
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let mut store = open_store(&device_key, MemoryKeyStore::new());
+
+		store.set_group_key("group_1", &group_keys[0]).unwrap();
+
+		let key_id = group_keys[0].group_key.get_id();
+
+		//move the entry to another group
+		let mut inner = store.into_inner();
+		let entry = inner.get_entry("group:group_1", key_id).unwrap().unwrap();
+		inner.set_entry("group:group_2", key_id, entry).unwrap();
+
+		let store = open_store(&device_key, inner);
+
+		assert!(matches!(
+			TestGroup::get_key_from_store(&store, "group_2", key_id),
+			Err(SdkError::KeyStoreDecryptFailed)
+		));
+
+		//other device
+		let store = open_store(&self::device_key(), store.into_inner());
+
+		assert!(matches!(
+			TestGroup::get_key_from_store(&store, "group_1", key_id),
+			Err(SdkError::KeyStoreDecryptFailed)
+		));
+	}
+
+	#[test]
+	fn test_decrypt_group_keys_cached()
+	{
+		let user = create_user();
+		let other_user = create_user();
+
+		let (_, group_keys, group_server_out, _, _) = create_group(&user.user_keys[0]);
+		let group_server_out_str = group_server_out.to_string().unwrap();
+
+		let mut store = open_store(&device_key(), MemoryKeyStore::new());
+
+		let key = TestGroup::decrypt_group_keys_cached(
+			&mut store,
+			"group_1",
+			&user.user_keys[0].private_key,
+			group_server_out.keys.into_iter().next().unwrap(),
+			None,
+			&CryptoPolicy::default(),
+		)
+		.unwrap();
+
+		assert_eq!(key.group_key.get_id(), group_keys[0].group_key.get_id());
+
+		//the key is loaded from the store, so the private key is not used
+		let group_server_out = GroupServerData::from_string(&group_server_out_str).unwrap();
+
+		let key = TestGroup::decrypt_group_keys_cached(
+			&mut store,
+			"group_1",
+			&other_user.user_keys[0].private_key,
+			group_server_out.keys.into_iter().next().unwrap(),
+			None,
+			&CryptoPolicy::default(),
+		)
+		.unwrap();
+
+		let encrypted = key.group_key.encrypt_string("hello").unwrap();
+		assert_eq!(
+			group_keys[0]
+				.group_key
+				.decrypt_string(&encrypted, None)
+				.unwrap(),
+			"hello"
+		);
+
+		//the stored key is checked with the crypto policy too
+		let group_server_out = GroupServerData::from_string(&group_server_out_str).unwrap();
+
+		let err = TestGroup::decrypt_group_keys_cached(
+			&mut store,
+			"group_1",
+			&user.user_keys[0].private_key,
+			group_server_out.keys.into_iter().next().unwrap(),
+			None,
+			&CryptoPolicy {
+				sym: Some(&[]),
+				..Default::default()
+			},
+		);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotAllowed(_)))));
+	}
+
+	#[test]
+	fn test_store_user_keys_and_wipe()
+	{
+		let user = create_user();
+
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let mut store = open_store(&device_key(), MemoryKeyStore::new());
+
+		store.set_user_key("user_1", &user.user_keys[0]).unwrap();
+		store.set_group_key("group_1", &group_keys[0]).unwrap();
+
+		let keys = TestUser::get_keys_from_store(&store, "user_1").unwrap();
+		assert_eq!(keys.len(), 1);
+		assert_eq!(keys[0].group_key.get_id(), user.user_keys[0].group_key.get_id());
+		assert_eq!(
+			keys[0].exported_verify_key.verify_key_id,
+			user.user_keys[0].exported_verify_key.verify_key_id
+		);
+
+		//leave the group
+		store.evict_group("group_1").unwrap();
+		assert!(TestGroup::get_keys_from_store(&store, "group_1")
+			.unwrap()
+			.is_empty());
+
+		store.set_group_key("group_1", &group_keys[0]).unwrap();
+
+		//leaving a group with the same id as the user keeps the user keys
+		store.evict_group("user_1").unwrap();
+		assert_eq!(
+			TestUser::get_keys_from_store(&store, "user_1")
+				.unwrap()
+				.len(),
+			1
+		);
+
+		store.evict_user("user_1").unwrap();
+		assert!(TestUser::get_keys_from_store(&store, "user_1")
+			.unwrap()
+			.is_empty());
+
+		store.set_user_key("user_1", &user.user_keys[0]).unwrap();
+
+		//logout
+		store.wipe().unwrap();
+
+		assert!(TestGroup::get_keys_from_store(&store, "group_1")
+			.unwrap()
+			.is_empty());
+		assert!(TestUser::get_keys_from_store(&store, "user_1")
+			.unwrap()
+			.is_empty());
+	}
+
+	#[test]
+	fn test_store_to_string()
+	{
+		let user = create_user();
+		let device_key = device_key();
+
+		let (_, group_keys, _, _, _) = create_group(&user.user_keys[0]);
+
+		let mut store = open_store(&device_key, MemoryKeyStore::new());
+
+		store.set_group_key("group_1", &group_keys[0]).unwrap();
+
+		let exported = store.into_inner().to_string().unwrap();
+
+		let store = open_store(&device_key, MemoryKeyStore::from_string(&exported).unwrap());
+
+		let keys = TestGroup::get_keys_from_store(&store, "group_1").unwrap();
+		assert_eq!(keys.len(), 1);
+	}
+}
@@ -0,0 +1,454 @@
+//! Client side verification of the key transparency log.
+//!
+//! The server appends every registered user public and verify key to an append-only merkle log (like RFC 9162).
+//! When fetching a key of another user, the client checks that the key is included in the log
+//! and that the log is consistent with the last tree head the client has seen.
+//! A server that hands out a substituted key must either add it to the log (visible to everyone)
+//! or fail the verification.
+//!
+//! Every tree head is signed by the log key. The verify key of the log must be known by the app (e.g. shipped with it)
+//! and not fetched from the same server. The signature is checked before the proofs.
+//!
+//! The first fetch has no last tree head, so the client can only check the signature and the inclusion.
+//! A log that shows different trees to different clients is only detected after comparing tree heads with other clients.
+//! After the first fetch, always pass the stored tree head, so the log can't go back to another tree.
+//!
+//! The server side helpers to build the tree heads and the proofs are in the server mod.
+
+#[cfg(any(feature = "server", test))]
+pub mod server;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::crypto::SignHead;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_utils::cryptomat::VerifyKFromUserKeyWrapper;
+use sha2::{Digest, Sha256};
+
+use crate::entities::key_transparency::{KeyLogInclusionProof, KeyLogProofServerOutput, KeyLogTreeHead};
+use crate::SdkError;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+const TREE_HEAD_SIGN_CONTEXT: &[u8] = b"sentc-key-log-tree-head-v1";
+
+const LOG_ENTRY_PUBLIC_KEY: u8 = 0;
+const LOG_ENTRY_VERIFY_KEY: u8 = 1;
+
+fn update_len_prefixed(hasher: &mut Sha256, data: &str)
+{
+	hasher.update((data.len() as u32).to_be_bytes());
+	hasher.update(data.as_bytes());
+}
+
+fn log_leaf_hash(entry_type: u8, user_id: &str, key_id: &str, key_alg: &str, key_pem: &str) -> Vec<u8>
+{
+	let mut hasher = Sha256::new();
+
+	hasher.update([LEAF_PREFIX, entry_type]);
+	update_len_prefixed(&mut hasher, user_id);
+	update_len_prefixed(&mut hasher, key_id);
+	update_len_prefixed(&mut hasher, key_alg);
+	update_len_prefixed(&mut hasher, key_pem);
+
+	hasher.finalize().to_vec()
+}
+
+pub(crate) fn node_hash(left: &[u8], right: &[u8]) -> Vec<u8>
+{
+	let mut hasher = Sha256::new();
+
+	hasher.update([NODE_PREFIX]);
+	hasher.update(left);
+	hasher.update(right);
+
+	hasher.finalize().to_vec()
+}
+
+/**
+The signed data of a tree head: the tree size and the length prefixed root hash.
+ */
+pub(crate) fn tree_head_sign_data(tree_head: &KeyLogTreeHead) -> Vec<u8>
+{
+	let mut data = Vec::with_capacity(TREE_HEAD_SIGN_CONTEXT.len() + 12 + tree_head.root_hash.len());
+
+	data.extend_from_slice(TREE_HEAD_SIGN_CONTEXT);
+	data.extend_from_slice(&tree_head.tree_size.to_be_bytes());
+	data.extend_from_slice(&(tree_head.root_hash.len() as u32).to_be_bytes());
+	data.extend_from_slice(tree_head.root_hash.as_bytes());
+
+	data
+}
+
+fn decode_hash(hash: &str) -> Result<Vec<u8>, SdkError>
+{
+	Base64::decode_vec(hash).map_err(|_| SdkError::KeyLogProofWrongFormat)
+}
+
+fn decode_path(path: &[String]) -> Result<Vec<Vec<u8>>, SdkError>
+{
+	path.iter().map(|p| decode_hash(p)).collect()
+}
+
+/**
+The leaf hash of a user public key in the key log.
+ */
+pub fn public_key_log_leaf(user_id: &str, public_key: &UserPublicKeyData) -> Vec<u8>
+{
+	log_leaf_hash(
+		LOG_ENTRY_PUBLIC_KEY,
+		user_id,
+		&public_key.public_key_id,
+		&public_key.public_key_alg,
+		&public_key.public_key_pem,
+	)
+}
+
+/**
+The leaf hash of a user verify key in the key log.
+ */
+pub fn verify_key_log_leaf(user_id: &str, verify_key: &UserVerifyKeyData) -> Vec<u8>
+{
+	log_leaf_hash(
+		LOG_ENTRY_VERIFY_KEY,
+		user_id,
+		&verify_key.verify_key_id,
+		&verify_key.verify_key_alg,
+		&verify_key.verify_key_pem,
+	)
+}
+
+/**
+Check if the leaf is in the tree of the tree head.
+ */
+pub fn verify_inclusion(leaf_hash: &[u8], proof: &KeyLogInclusionProof, tree_head: &KeyLogTreeHead) -> Result<bool, SdkError>
+{
+	if proof.tree_size != tree_head.tree_size {
+		return Err(SdkError::KeyLogProofWrongFormat);
+	}
+
+	let root = decode_hash(&tree_head.root_hash)?;
+	let path = decode_path(&proof.audit_path)?;
+
+	if proof.leaf_index >= proof.tree_size {
+		return Ok(false);
+	}
+
+	let mut f_n = proof.leaf_index;
+	let mut s_n = proof.tree_size - 1;
+	let mut r = leaf_hash.to_vec();
+
+	for p in path {
+		if s_n == 0 {
+			return Ok(false);
+		}
+
+		if f_n & 1 == 1 || f_n == s_n {
+			r = node_hash(&p, &r);
+
+			while f_n & 1 == 0 && f_n != 0 {
+				f_n >>= 1;
+				s_n >>= 1;
+			}
+		} else {
+			r = node_hash(&r, &p);
+		}
+
+		f_n >>= 1;
+		s_n >>= 1;
+	}
+
+	Ok(s_n == 0 && r == root)
+}
+
+/**
+Check if the new tree head is an append only extension of the old tree head.
+ */
+pub fn verify_consistency(old_tree_head: &KeyLogTreeHead, new_tree_head: &KeyLogTreeHead, proof: &[String]) -> Result<bool, SdkError>
+{
+	let old_root = decode_hash(&old_tree_head.root_hash)?;
+	let new_root = decode_hash(&new_tree_head.root_hash)?;
+	let mut path = decode_path(proof)?;
+
+	let old_size = old_tree_head.tree_size;
+	let new_size = new_tree_head.tree_size;
+
+	if old_size > new_size {
+		return Ok(false);
+	}
+
+	if old_size == new_size {
+		return Ok(path.is_empty() && old_root == new_root);
+	}
+
+	if old_size == 0 {
+		//an empty tree is consistent with every tree
+		return Ok(path.is_empty());
+	}
+
+	if old_size.is_power_of_two() {
+		path.insert(0, old_root.clone());
+	}
+
+	let mut f_n = old_size - 1;
+	let mut s_n = new_size - 1;
+
+	while f_n & 1 == 1 {
+		f_n >>= 1;
+		s_n >>= 1;
+	}
+
+	let (first, rest) = match path.split_first() {
+		Some(p) => p,
+		None => return Ok(false),
+	};
+
+	let mut f_r = first.clone();
+	let mut s_r = first.clone();
+
+	for c in rest {
+		if s_n == 0 {
+			return Ok(false);
+		}
+
+		if f_n & 1 == 1 || f_n == s_n {
+			f_r = node_hash(c, &f_r);
+			s_r = node_hash(c, &s_r);
+
+			while f_n & 1 == 0 && f_n != 0 {
+				f_n >>= 1;
+				s_n >>= 1;
+			}
+		} else {
+			s_r = node_hash(&s_r, c);
+		}
+
+		f_n >>= 1;
+		s_n >>= 1;
+	}
+
+	Ok(f_r == old_root && s_r == new_root && s_n == 0)
+}
+
+/**
+Check the signature of the tree head with the verify key of the log.
+ */
+pub fn verify_tree_head<V: VerifyKFromUserKeyWrapper>(tree_head: &KeyLogTreeHead, log_verify_key: &UserVerifyKeyData) -> Result<(), SdkError>
+{
+	let sig = tree_head
+		.sig
+		.as_ref()
+		.ok_or(SdkError::KeyLogTreeHeadNotSigned)?;
+
+	let sign_head = SignHead {
+		id: sig.sign_key_id.clone(),
+		alg: sig.sign_alg.clone(),
+	};
+
+	V::verify_detached(log_verify_key, &tree_head_sign_data(tree_head), &sig.sig, &sign_head)?;
+
+	Ok(())
+}
+
+/**
+Verify the proof of the server for a key.
+
+The tree head must be signed by the log verify key.
+The last tree head is the tree head from the last successful verification.
+It is only None for the first fetch, see the module doc. If it is set, the new tree head must be consistent with it.
+
+Returns the new tree head, which should be stored for the next verification.
+ */
+pub fn verify_key_log_proof<V: VerifyKFromUserKeyWrapper>(
+	leaf_hash: &[u8],
+	proof: &KeyLogProofServerOutput,
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<KeyLogTreeHead, SdkError>
+{
+	verify_tree_head::<V>(&proof.tree_head, log_verify_key)?;
+
+	if !verify_inclusion(leaf_hash, &proof.inclusion, &proof.tree_head)? {
+		return Err(SdkError::KeyNotInKeyLog);
+	}
+
+	if let Some(last) = last_tree_head {
+		if !verify_consistency(last, &proof.tree_head, &proof.consistency)? {
+			return Err(SdkError::KeyLogInconsistent);
+		}
+	}
+
+	Ok(proof.tree_head.clone())
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::format;
+	use alloc::string::ToString;
+
+	use sentc_crypto_utils::error::SdkUtilError;
+
+	use super::server::*;
+	use super::*;
+	use crate::user::test_fn::create_user;
+
+	#[cfg(feature = "std_keys")]
+	type TestVerifyKey = sentc_crypto_std_keys::util::VerifyKey;
+	#[cfg(all(feature = "fips_keys", not(feature = "std_keys")))]
+	type TestVerifyKey = sentc_crypto_fips_keys::util::VerifyKey;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	type TestVerifyKey = sentc_crypto_rec_keys::util::VerifyKey;
+
+	fn create_leaves(count: usize) -> Vec<Vec<u8>>
+	{
+		(0..count)
+			.map(|i| {
+				let key = UserVerifyKeyData {
+					verify_key_pem: format!("pem_{}", i),
+					verify_key_alg: "alg".to_string(),
+					verify_key_id: format!("key_{}", i),
+				};
+
+				verify_key_log_leaf("user", &key)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_inclusion()
+	{
+		for size in 1..20 {
+			let leaves = create_leaves(size);
+			let tree_head = create_tree_head(&leaves);
+
+			for (i, leaf) in leaves.iter().enumerate() {
+				let proof = create_inclusion_proof(&leaves, i as u64).unwrap();
+
+				assert!(verify_inclusion(leaf, &proof, &tree_head).unwrap());
+
+				//wrong leaf
+				let other = &leaves[(i + 1) % size];
+
+				if size > 1 {
+					assert!(!verify_inclusion(other, &proof, &tree_head).unwrap());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_consistency()
+	{
+		let leaves = create_leaves(20);
+
+		for new_size in 1..=20 {
+			let new_tree_head = create_tree_head(&leaves[..new_size]);
+
+			for old_size in 0..=new_size {
+				let old_tree_head = create_tree_head(&leaves[..old_size]);
+				let proof = create_consistency_proof(&leaves[..new_size], old_size as u64).unwrap();
+
+				assert!(verify_consistency(&old_tree_head, &new_tree_head, &proof).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn test_not_consistent_after_changed_leaf()
+	{
+		let leaves = create_leaves(10);
+		let old_tree_head = create_tree_head(&leaves[..5]);
+
+		let mut changed_leaves = leaves.clone();
+		changed_leaves[2] = create_leaves(11)[10].clone();
+
+		let new_tree_head = create_tree_head(&changed_leaves);
+		let proof = create_consistency_proof(&changed_leaves, 5).unwrap();
+
+		assert!(!verify_consistency(&old_tree_head, &new_tree_head, &proof).unwrap());
+	}
+
+	#[test]
+	fn test_verify_key_log_proof()
+	{
+		let log = create_user();
+		let log_key = &log.user_keys[0];
+
+		let leaves = create_leaves(7);
+		let last_tree_head = create_tree_head(&leaves[..3]);
+
+		let key = UserVerifyKeyData {
+			verify_key_pem: "pem_5".to_string(),
+			verify_key_alg: "alg".to_string(),
+			verify_key_id: "key_5".to_string(),
+		};
+
+		let leaf = verify_key_log_leaf("user", &key);
+
+		let proof = create_key_log_proof(&leaves, 5, 3, &log_key.sign_key)
+			.unwrap()
+			.unwrap();
+
+		let tree_head = verify_key_log_proof::<TestVerifyKey>(&leaf, &proof, Some(&last_tree_head), &log_key.exported_verify_key).unwrap();
+
+		assert_eq!(tree_head.tree_size, 7);
+
+		//substituted key
+		let key = UserVerifyKeyData {
+			verify_key_pem: "other_pem".to_string(),
+			verify_key_alg: "alg".to_string(),
+			verify_key_id: "key_5".to_string(),
+		};
+
+		let leaf = verify_key_log_leaf("user", &key);
+
+		let err = verify_key_log_proof::<TestVerifyKey>(&leaf, &proof, Some(&last_tree_head), &log_key.exported_verify_key);
+
+		assert!(matches!(err, Err(SdkError::KeyNotInKeyLog)));
+	}
+
+	#[test]
+	fn test_tree_head_signature()
+	{
+		let log = create_user();
+		let log_key = &log.user_keys[0];
+
+		let other = create_user();
+
+		let leaves = create_leaves(7);
+		let leaf = &leaves[5];
+
+		let proof = create_key_log_proof(&leaves, 5, 0, &log_key.sign_key)
+			.unwrap()
+			.unwrap();
+
+		verify_key_log_proof::<TestVerifyKey>(leaf, &proof, None, &log_key.exported_verify_key).unwrap();
+
+		//the tree head is not signed
+		let mut unsigned_proof = proof.clone();
+		unsigned_proof.tree_head.sig = None;
+
+		let err = verify_key_log_proof::<TestVerifyKey>(leaf, &unsigned_proof, None, &log_key.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::KeyLogTreeHeadNotSigned)));
+
+		//signed by another key, e.g. a tree head of the server itself
+		let mut other_proof = create_key_log_proof(&leaves, 5, 0, &other.user_keys[0].sign_key)
+			.unwrap()
+			.unwrap();
+		other_proof.tree_head.sig.as_mut().unwrap().sign_key_id = log_key.exported_verify_key.verify_key_id.clone();
+
+		let err = verify_key_log_proof::<TestVerifyKey>(leaf, &other_proof, None, &log_key.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::VerifyFailed))));
+
+		//a changed tree size with the old signature
+		let mut changed_proof = proof;
+		changed_proof.tree_head.tree_size = 8;
+
+		let err = verify_key_log_proof::<TestVerifyKey>(leaf, &changed_proof, None, &log_key.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::VerifyFailed))));
+	}
+}
@@ -0,0 +1,188 @@
+//! Server side helpers for the key transparency log.
+//!
+//! The leaves are the leaf hashes from public_key_log_leaf and verify_key_log_leaf
+//! in the order they were appended to the log.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_utils::cryptomat::SignKWrapper;
+use sha2::{Digest, Sha256};
+
+use super::{node_hash, tree_head_sign_data};
+use crate::entities::key_transparency::{KeyLogInclusionProof, KeyLogProofServerOutput, KeyLogTreeHead, KeyLogTreeHeadSig};
+use crate::SdkError;
+
+//the largest power of two smaller than n
+fn split_point(n: usize) -> usize
+{
+	let mut k = 1;
+
+	while k << 1 < n {
+		k <<= 1;
+	}
+
+	k
+}
+
+fn tree_hash(leaves: &[Vec<u8>]) -> Vec<u8>
+{
+	match leaves.len() {
+		0 => Sha256::digest([]).to_vec(),
+		1 => leaves[0].clone(),
+		n => {
+			let k = split_point(n);
+
+			node_hash(&tree_hash(&leaves[..k]), &tree_hash(&leaves[k..]))
+		},
+	}
+}
+
+fn inclusion_path(index: usize, leaves: &[Vec<u8>]) -> Vec<Vec<u8>>
+{
+	let n = leaves.len();
+
+	if n <= 1 {
+		return Vec::new();
+	}
+
+	let k = split_point(n);
+
+	if index < k {
+		let mut path = inclusion_path(index, &leaves[..k]);
+		path.push(tree_hash(&leaves[k..]));
+		path
+	} else {
+		let mut path = inclusion_path(index - k, &leaves[k..]);
+		path.push(tree_hash(&leaves[..k]));
+		path
+	}
+}
+
+fn consistency_sub_proof(m: usize, leaves: &[Vec<u8>], complete: bool) -> Vec<Vec<u8>>
+{
+	let n = leaves.len();
+
+	if m == n {
+		return if complete { Vec::new() } else { vec![tree_hash(leaves)] };
+	}
+
+	let k = split_point(n);
+
+	if m <= k {
+		let mut proof = consistency_sub_proof(m, &leaves[..k], complete);
+		proof.push(tree_hash(&leaves[k..]));
+		proof
+	} else {
+		let mut proof = consistency_sub_proof(m - k, &leaves[k..], false);
+		proof.push(tree_hash(&leaves[..k]));
+		proof
+	}
+}
+
+fn encode_path(path: Vec<Vec<u8>>) -> Vec<String>
+{
+	path.iter().map(|p| Base64::encode_string(p)).collect()
+}
+
+/**
+Create the tree head of all leaves in the log.
+
+The tree head is not signed, use sign_tree_head before it is returned to the client.
+ */
+pub fn create_tree_head(leaves: &[Vec<u8>]) -> KeyLogTreeHead
+{
+	KeyLogTreeHead {
+		tree_size: leaves.len() as u64,
+		root_hash: Base64::encode_string(&tree_hash(leaves)),
+		sig: None,
+	}
+}
+
+/**
+Sign the tree head with the log key. The clients verify it with the verify key of the log.
+ */
+pub fn sign_tree_head(tree_head: &mut KeyLogTreeHead, log_sign_key: &impl SignKWrapper) -> Result<(), SdkError>
+{
+	let (sign_head, sig) = log_sign_key.sign_detached(&tree_head_sign_data(tree_head))?;
+
+	tree_head.sig = Some(KeyLogTreeHeadSig {
+		sign_key_id: sign_head.id,
+		sign_alg: sign_head.alg,
+		sig,
+	});
+
+	Ok(())
+}
+
+/**
+Create the inclusion proof of the leaf at the index.
+
+Returns None if the index is not in the log.
+ */
+pub fn create_inclusion_proof(leaves: &[Vec<u8>], leaf_index: u64) -> Option<KeyLogInclusionProof>
+{
+	if leaf_index >= leaves.len() as u64 {
+		return None;
+	}
+
+	Some(KeyLogInclusionProof {
+		leaf_index,
+		tree_size: leaves.len() as u64,
+		audit_path: encode_path(inclusion_path(leaf_index as usize, leaves)),
+	})
+}
+
+/**
+Create the consistency proof from the old tree size to the actual log.
+
+Returns None if the old size is bigger than the log.
+ */
+pub fn create_consistency_proof(leaves: &[Vec<u8>], old_size: u64) -> Option<Vec<String>>
+{
+	let old_size = old_size as usize;
+
+	if old_size > leaves.len() {
+		return None;
+	}
+
+	if old_size == 0 || old_size == leaves.len() {
+		return Some(Vec::new());
+	}
+
+	Some(encode_path(consistency_sub_proof(old_size, leaves, true)))
+}
+
+/**
+Create the whole proof for a key which is returned to the client.
+
+The old size is the tree size of the last tree head of the client. The tree head is signed by the log sign key.
+
+Returns None if the index or the old size is not in the log.
+ */
+pub fn create_key_log_proof(
+	leaves: &[Vec<u8>],
+	leaf_index: u64,
+	old_size: u64,
+	log_sign_key: &impl SignKWrapper,
+) -> Result<Option<KeyLogProofServerOutput>, SdkError>
+{
+	let (inclusion, consistency) = match (
+		create_inclusion_proof(leaves, leaf_index),
+		create_consistency_proof(leaves, old_size),
+	) {
+		(Some(i), Some(c)) => (i, c),
+		_ => return Ok(None),
+	};
+
+	let mut tree_head = create_tree_head(leaves);
+	sign_tree_head(&mut tree_head, log_sign_key)?;
+
+	Ok(Some(KeyLogProofServerOutput {
+		tree_head,
+		inclusion,
+		consistency,
+	}))
+}
@@ -5,9 +5,14 @@ use sentc_crypto_fips_keys::util::{HmacKey, PublicKey, SecretKey, SignKey, Sorta
 
 use crate::crypto::KeyGenerator;
 use crate::entities::group::GroupKeyData;
+use crate::entities::key_backup::KeyBackupInt;
 use crate::entities::user::{UserDataInt, UserKeyDataInt};
+use crate::envelope::Envelope;
 use crate::file::FileEncryptor;
-use crate::group::Group;
+use crate::group::{Group, GroupKeyResolver};
+use crate::key_backup::KeyBackup;
+use crate::signature::DocumentSigner;
+use crate::social_recovery::SocialRecovery;
 use crate::user::User;
 
 pub type FipsGroup = Group<
@@ -27,6 +32,8 @@ pub type FipsGroup = Group<
 
 pub type FipsGroupKeyData = GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
+pub type FipsGroupKeyResolver = GroupKeyResolver<SymmetricKey, SecretKey, PublicKey>;
+
 pub type FipsUser = User<
 	SymmetricKey,
 	SecretKey,
@@ -51,6 +58,16 @@ pub type FipsKeyGenerator = KeyGenerator<SymmetricKey, SymmetricKey, PublicKey>;
 
 pub type FipsFileEncryptor = FileEncryptor<Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
 
+pub type FipsSocialRecovery = SocialRecovery<PublicKey>;
+
+pub type FipsDocumentSigner = DocumentSigner<VerifyKey>;
+
+pub type FipsEnvelope = Envelope<Aes256GcmKey, Aes256GcmKey, PublicKey>;
+
+pub type FipsKeyBackup = KeyBackup<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, PwHasherGetter>;
+
+pub type FipsKeyBackupInt = KeyBackupInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
+
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	SymmetricKey,
@@ -4,9 +4,14 @@ use sentc_crypto_rec_keys::util::{HmacKey, PublicKey, SecretKey, SignKey, Sortab
 
 use crate::crypto::KeyGenerator;
 use crate::entities::group::GroupKeyData;
+use crate::entities::key_backup::KeyBackupInt;
 use crate::entities::user::{UserDataInt, UserKeyDataInt};
+use crate::envelope::Envelope;
 use crate::file::FileEncryptor;
-use crate::group::Group;
+use crate::group::{Group, GroupKeyResolver};
+use crate::key_backup::KeyBackup;
+use crate::signature::DocumentSigner;
+use crate::social_recovery::SocialRecovery;
 use crate::user::User;
 
 pub type RecGroup = Group<
@@ -26,6 +31,8 @@ pub type RecGroup = Group<
 
 pub type RecGroupKeyData = GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
+pub type RecGroupKeyResolver = GroupKeyResolver<SymmetricKey, SecretKey, PublicKey>;
+
 pub type RecUser = User<
 	SymmetricKey,
 	SecretKey,
@@ -50,6 +57,16 @@ pub type RecKeyGenerator = KeyGenerator<SymmetricKey, SymmetricKey, PublicKey>;
 
 pub type RecFileEncryptor = FileEncryptor<Aes256GcmKey, Aes256GcmKey, SignKey, VerifyKey>;
 
+pub type RecSocialRecovery = SocialRecovery<PublicKey>;
+
+pub type RecDocumentSigner = DocumentSigner<VerifyKey>;
+
+pub type RecEnvelope = Envelope<Aes256GcmKey, Aes256GcmKey, PublicKey>;
+
+pub type RecKeyBackup = KeyBackup<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, PwHasher>;
+
+pub type RecKeyBackupInt = KeyBackupInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
+
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type FipsPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	SymmetricKey,
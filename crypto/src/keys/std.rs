@@ -1,7 +1,7 @@
 use sentc_crypto_std_keys::core::PwHasherGetter;
 use sentc_crypto_std_keys::util::{HmacKey, PublicKey, SecretKey, SignKey, SortableKey, SymmetricKey, VerifyKey};
 
-use crate::{crypto, entities, file, group, user};
+use crate::{crypto, entities, envelope, file, group, key_backup, signature, social_recovery, user};
 
 pub type StdGroup = group::Group<
 	SymmetricKey,
@@ -20,6 +20,8 @@ pub type StdGroup = group::Group<
 
 pub type StdGroupKeyData = entities::group::GroupKeyData<SymmetricKey, SecretKey, PublicKey>;
 
+pub type StdGroupKeyResolver = group::GroupKeyResolver<SymmetricKey, SecretKey, PublicKey>;
+
 pub type StdUser = user::User<
 	SymmetricKey,
 	SecretKey,
@@ -45,6 +47,16 @@ pub type StdKeyGenerator = crypto::KeyGenerator<SymmetricKey, SymmetricKey, Publ
 pub type StdFileEncryptor =
 	file::FileEncryptor<sentc_crypto_std_keys::core::SymmetricKey, sentc_crypto_std_keys::core::SymmetricKey, SignKey, VerifyKey>;
 
+pub type StdSocialRecovery = social_recovery::SocialRecovery<PublicKey>;
+
+pub type StdDocumentSigner = signature::DocumentSigner<VerifyKey>;
+
+pub type StdEnvelope = envelope::Envelope<sentc_crypto_std_keys::core::SymmetricKey, sentc_crypto_std_keys::core::SymmetricKey, PublicKey>;
+
+pub type StdKeyBackup = key_backup::KeyBackup<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey, PwHasherGetter>;
+
+pub type StdKeyBackupInt = entities::key_backup::KeyBackupInt<SymmetricKey, SecretKey, PublicKey, SignKey, VerifyKey>;
+
 #[cfg(any(feature = "full_rustls", feature = "full_wasm"))]
 pub type StdPreLoginOut = crate::util_req_full::user::PreLoginOut<
 	SymmetricKey,
@@ -58,16 +58,27 @@
 //! To get the online actions add the feature:
 //! * full_rustls to use rustls
 //! * full_wasm to use the web assembly requests
+//!
+//! To encrypt single fields of a struct add the feature derive and use the SentcEncrypt derive macro.
 
 extern crate alloc;
+#[cfg(any(feature = "pin_store_file", feature = "key_store_file"))]
+extern crate std;
 
 pub mod crypto;
 pub mod crypto_searchable;
 pub mod crypto_sortable;
 pub mod entities;
+pub mod envelope;
 mod error;
 pub mod file;
 pub mod group;
+pub mod key_backup;
+pub mod key_pin;
+pub mod key_store;
+pub mod key_transparency;
+pub mod signature;
+pub mod social_recovery;
 pub mod user;
 pub mod util;
 
@@ -77,14 +88,18 @@ pub mod util_req_full;
 
 /**
 For server testing export every common
-because using common from path via submodule resolve in version conflicts when using it in tests
+because using common from path via submodule resolve in version conflicts when using it in tests.
+
+The derive macro uses the common types from here too.
  */
-#[cfg(feature = "server_test")]
+#[cfg(any(feature = "server_test", feature = "derive"))]
 pub use sentc_crypto_common as sdk_common;
 /**
 Reexport of the crypto core crate to access the raw types
 */
 pub use sentc_crypto_core as sdk_core;
+#[cfg(feature = "derive")]
+pub use sentc_crypto_derive::SentcEncrypt;
 #[cfg(feature = "fips_keys")]
 pub use sentc_crypto_fips_keys as fips_keys;
 #[cfg(feature = "rec_keys")]
@@ -94,3 +109,14 @@ pub use sentc_crypto_std_keys as std_keys;
 pub use sentc_crypto_utils as sdk_utils;
 
 pub use self::error::{err_to_msg, SdkError};
+
+/**
+The alloc types for the code of the derive macro, because this crate is no_std.
+ */
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod derive_alloc
+{
+	pub use alloc::string::{String, ToString};
+	pub use alloc::vec::Vec;
+}
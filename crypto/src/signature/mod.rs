@@ -0,0 +1,143 @@
+//! Detached signatures for documents that are stored without encryption, e.g. contracts.
+//!
+//! A signature contains the signer user id, the verify key id, the alg and the time.
+//! Several users can sign the same document. All signatures of a document are collected in DocumentSignatures
+//! and are verified against the fetched verify keys of the signers.
+
+pub(crate) mod signature;
+#[cfg(feature = "export")]
+mod signature_export;
+
+pub use self::signature::DocumentSigner;
+#[cfg(feature = "export")]
+pub use self::signature_export::*;
+
+#[cfg(test)]
+mod test
+{
+	use alloc::string::ToString;
+
+	use sentc_crypto_utils::error::SdkUtilError;
+
+	use crate::entities::signature::DocumentSignatures;
+	use crate::user::test_fn::create_user;
+	use crate::SdkError;
+
+	#[cfg(feature = "std_keys")]
+	pub type TestDocumentSigner = crate::keys::std::StdDocumentSigner;
+	#[cfg(all(feature = "fips_keys", not(feature = "std_keys")))]
+	pub type TestDocumentSigner = crate::keys::fips::FipsDocumentSigner;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	pub type TestDocumentSigner = crate::keys::rec::RecDocumentSigner;
+
+	#[test]
+	fn test_sign_and_verify_document()
+	{
+		let user = create_user();
+		let user_keys = &user.user_keys[0];
+
+		let data = b"contract";
+
+		let signature = TestDocumentSigner::sign_document(data, &user_keys.sign_key, "user_1", 123).unwrap();
+
+		assert_eq!(signature.signer_user_id, "user_1");
+		assert_eq!(signature.verify_key_id, user_keys.exported_verify_key.verify_key_id);
+		assert_eq!(signature.alg, user_keys.exported_verify_key.verify_key_alg);
+		assert_eq!(signature.time, 123);
+
+		TestDocumentSigner::verify_document_signature(data, &signature, &user_keys.exported_verify_key).unwrap();
+
+		//changed document
+		let err = TestDocumentSigner::verify_document_signature(b"other contract", &signature, &user_keys.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::VerifyFailed))));
+
+		//changed metadata
+		let mut changed = signature.clone();
+		changed.time = 124;
+
+		let err = TestDocumentSigner::verify_document_signature(data, &changed, &user_keys.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::VerifyFailed))));
+
+		let mut changed = signature.clone();
+		changed.signer_user_id = "user_2".into();
+
+		let err = TestDocumentSigner::verify_document_signature(data, &changed, &user_keys.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::VerifyFailed))));
+	}
+
+	#[test]
+	fn test_multi_party_signatures()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+		let user_3 = create_user();
+
+		let data = b"contract";
+
+		let mut signatures = DocumentSignatures::default();
+
+		TestDocumentSigner::add_document_signature(&mut signatures, data, &user_1.user_keys[0].sign_key, "user_1", 1).unwrap();
+		TestDocumentSigner::add_document_signature(&mut signatures, data, &user_2.user_keys[0].sign_key, "user_2", 2).unwrap();
+
+		assert_eq!(signatures.signatures.len(), 2);
+
+		let verify_keys = [
+			("user_1".to_string(), user_1.user_keys[0].exported_verify_key.clone()),
+			("user_2".to_string(), user_2.user_keys[0].exported_verify_key.clone()),
+		];
+
+		TestDocumentSigner::verify_document_signatures(data, &signatures, &verify_keys).unwrap();
+
+		//missing verify key of a signer
+		let err = TestDocumentSigner::verify_document_signatures(data, &signatures, &verify_keys[..1]);
+		assert!(matches!(err, Err(SdkError::SignatureVerifyKeyNotFound(id)) if id == "user_2"));
+
+		//a signature of another key
+		let mut wrong = DocumentSignatures::default();
+		TestDocumentSigner::add_document_signature(&mut wrong, data, &user_3.user_keys[0].sign_key, "user_1", 1).unwrap();
+
+		let err = TestDocumentSigner::verify_document_signatures(data, &wrong, &verify_keys);
+		assert!(matches!(err, Err(SdkError::SignatureVerifyKeyNotFound(_))));
+
+		//a signer whose key is known signs in the name of another user
+		let mut forged = DocumentSignatures::default();
+		TestDocumentSigner::add_document_signature(&mut forged, data, &user_2.user_keys[0].sign_key, "user_1", 1).unwrap();
+
+		let err = TestDocumentSigner::verify_document_signatures(data, &forged, &verify_keys);
+		assert!(matches!(err, Err(SdkError::SignatureVerifyKeyNotFound(id)) if id == "user_1"));
+
+		//the same signer twice
+		let mut duplicate = DocumentSignatures::default();
+		TestDocumentSigner::add_document_signature(&mut duplicate, data, &user_1.user_keys[0].sign_key, "user_1", 1).unwrap();
+		TestDocumentSigner::add_document_signature(&mut duplicate, data, &user_1.user_keys[0].sign_key, "user_1", 2).unwrap();
+
+		let err = TestDocumentSigner::verify_document_signatures(data, &duplicate, &verify_keys);
+		assert!(matches!(err, Err(SdkError::SignatureDuplicateSigner(id)) if id == "user_1"));
+	}
+
+	#[test]
+	fn test_verify_without_signatures()
+	{
+		let user = create_user();
+
+		let verify_keys = [("user_1".to_string(), user.user_keys[0].exported_verify_key.clone())];
+
+		let err = TestDocumentSigner::verify_document_signatures(b"contract", &DocumentSignatures::default(), &verify_keys);
+		assert!(matches!(err, Err(SdkError::SignatureNoSignatures)));
+	}
+
+	#[test]
+	fn test_signature_alg_must_match_the_verify_key()
+	{
+		let user = create_user();
+		let user_keys = &user.user_keys[0];
+
+		let data = b"contract";
+
+		let mut signature = TestDocumentSigner::sign_document(data, &user_keys.sign_key, "user_1", 1).unwrap();
+		signature.alg = "other_alg".into();
+
+		let err = TestDocumentSigner::verify_document_signature(data, &signature, &user_keys.exported_verify_key);
+		assert!(matches!(err, Err(SdkError::SignatureAlgMismatch)));
+	}
+}
@@ -0,0 +1,131 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use sentc_crypto_common::crypto::SignHead;
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_common::UserId;
+use sentc_crypto_utils::cryptomat::{SignKWrapper, VerifyKFromUserKeyWrapper};
+use sha2::{Digest, Sha256};
+
+use crate::entities::signature::{DetachedSignature, DocumentSignatures};
+use crate::SdkError;
+
+const SIGNATURE_CONTEXT: &[u8] = b"sentc_detached_signature_v1";
+
+fn signed_payload(data: &[u8], signer_user_id: &str, time: u128) -> Vec<u8>
+{
+	let mut hasher = Sha256::new();
+
+	hasher.update(SIGNATURE_CONTEXT);
+	hasher.update((signer_user_id.len() as u32).to_be_bytes());
+	hasher.update(signer_user_id.as_bytes());
+	hasher.update(time.to_be_bytes());
+	hasher.update(data);
+
+	hasher.finalize().to_vec()
+}
+
+pub struct DocumentSigner<VC>
+{
+	_vc: PhantomData<VC>,
+}
+
+impl<VC: VerifyKFromUserKeyWrapper> DocumentSigner<VC>
+{
+	/**
+	Sign a document without encrypting it.
+
+	The time should be the current time in milliseconds.
+	 */
+	pub fn sign_document(data: &[u8], sign_key: &impl SignKWrapper, signer_user_id: &str, time: u128) -> Result<DetachedSignature, SdkError>
+	{
+		let (head, sig) = sign_key.sign_detached(&signed_payload(data, signer_user_id, time))?;
+
+		Ok(DetachedSignature {
+			signer_user_id: signer_user_id.to_string(),
+			verify_key_id: head.id,
+			alg: head.alg,
+			time,
+			sig,
+		})
+	}
+
+	/**
+	Sign the document and add the signature to the other signatures of the document.
+	 */
+	pub fn add_document_signature(
+		signatures: &mut DocumentSignatures,
+		data: &[u8],
+		sign_key: &impl SignKWrapper,
+		signer_user_id: &str,
+		time: u128,
+	) -> Result<(), SdkError>
+	{
+		let signature = Self::sign_document(data, sign_key, signer_user_id, time)?;
+
+		signatures.signatures.push(signature);
+
+		Ok(())
+	}
+
+	/**
+	Verify one signature with the verify key of the signer.
+	 */
+	pub fn verify_document_signature(data: &[u8], signature: &DetachedSignature, verify_key: &UserVerifyKeyData) -> Result<(), SdkError>
+	{
+		if signature.alg != verify_key.verify_key_alg {
+			return Err(SdkError::SignatureAlgMismatch);
+		}
+
+		let head = SignHead {
+			id: signature.verify_key_id.clone(),
+			alg: signature.alg.clone(),
+		};
+
+		Ok(VC::verify_detached(
+			verify_key,
+			&signed_payload(data, &signature.signer_user_id, signature.time),
+			&signature.sig,
+			&head,
+		)?)
+	}
+
+	/**
+	Verify all signatures of the document.
+
+	The verify keys are the fetched verify keys of the signers together with the user id of the signer.
+	Every signature needs a verify key of the signer user with the same key id,
+	so a signer can't sign in the name of another user.
+
+	A document without signatures or with more than one signature of the same user is rejected.
+	 */
+	pub fn verify_document_signatures(
+		data: &[u8],
+		signatures: &DocumentSignatures,
+		verify_keys: &[(UserId, UserVerifyKeyData)],
+	) -> Result<(), SdkError>
+	{
+		if signatures.signatures.is_empty() {
+			return Err(SdkError::SignatureNoSignatures);
+		}
+
+		for (i, signature) in signatures.signatures.iter().enumerate() {
+			if signatures.signatures[..i]
+				.iter()
+				.any(|s| s.signer_user_id == signature.signer_user_id)
+			{
+				return Err(SdkError::SignatureDuplicateSigner(signature.signer_user_id.clone()));
+			}
+
+			let (_, verify_key) = verify_keys
+				.iter()
+				.find(|(user_id, k)| *user_id == signature.signer_user_id && k.verify_key_id == signature.verify_key_id)
+				.ok_or_else(|| SdkError::SignatureVerifyKeyNotFound(signature.signer_user_id.clone()))?;
+
+			Self::verify_document_signature(data, signature, verify_key)?;
+		}
+
+		Ok(())
+	}
+}
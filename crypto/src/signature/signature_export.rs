@@ -0,0 +1,121 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_common::UserId;
+use sentc_crypto_std_keys::util::SignKey;
+
+use crate::entities::signature::{DetachedSignature, DocumentSignatures};
+use crate::keys::std::StdDocumentSigner;
+use crate::SdkError;
+
+fn import_signatures(signatures: &str) -> Result<DocumentSignatures, SdkError>
+{
+	if signatures.is_empty() {
+		return Ok(DocumentSignatures::default());
+	}
+
+	serde_json::from_str(signatures).map_err(SdkError::JsonParseFailed)
+}
+
+/**
+Sign a document without encrypting it. Returns the signature as json string.
+ */
+pub fn sign_document(data: &[u8], sign_key: &str, signer_user_id: &str, time: u128) -> Result<String, String>
+{
+	let sign_key: SignKey = sign_key.parse()?;
+
+	let signature = StdDocumentSigner::sign_document(data, &sign_key, signer_user_id, time)?;
+
+	Ok(serde_json::to_string(&signature).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+/**
+Sign the document and add the signature to the signatures of the document (an empty string for the first signature).
+
+Returns the new signatures as json string.
+ */
+pub fn add_document_signature(signatures: &str, data: &[u8], sign_key: &str, signer_user_id: &str, time: u128) -> Result<String, String>
+{
+	let mut signatures = import_signatures(signatures)?;
+	let sign_key: SignKey = sign_key.parse()?;
+
+	StdDocumentSigner::add_document_signature(&mut signatures, data, &sign_key, signer_user_id, time)?;
+
+	Ok(serde_json::to_string(&signatures).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+pub fn verify_document_signature(data: &[u8], signature: &str, verify_key: &str) -> Result<(), String>
+{
+	let signature: DetachedSignature = serde_json::from_str(signature).map_err(SdkError::JsonParseFailed)?;
+	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdDocumentSigner::verify_document_signature(
+		data,
+		&signature,
+		&verify_key,
+	)?)
+}
+
+/**
+Verify all signatures of the document.
+
+The verify keys are a json array of pairs of the signer user id and the verify key of the signer,
+e.g. [["user_1", {verify key}], ["user_2", {verify key}]].
+ */
+pub fn verify_document_signatures(data: &[u8], signatures: &str, verify_keys: &str) -> Result<(), String>
+{
+	let signatures = import_signatures(signatures)?;
+	let verify_keys: Vec<(UserId, UserVerifyKeyData)> = serde_json::from_str(verify_keys).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdDocumentSigner::verify_document_signatures(
+		data,
+		&signatures,
+		&verify_keys,
+	)?)
+}
+
+#[cfg(test)]
+mod test
+{
+	use alloc::string::ToString;
+
+	use super::*;
+	use crate::user::test_fn::create_user_export;
+
+	#[test]
+	fn test_sign_and_verify_document()
+	{
+		let user_1 = create_user_export();
+		let user_2 = create_user_export();
+
+		let data = b"contract";
+
+		let signatures = add_document_signature("", data, &user_1.user_keys[0].sign_key, "user_1", 1).unwrap();
+		let signatures = add_document_signature(&signatures, data, &user_2.user_keys[0].sign_key, "user_2", 2).unwrap();
+
+		let verify_keys = "[[\"user_1\",".to_string() +
+			&user_1.user_keys[0].exported_verify_key +
+			"],[\"user_2\"," +
+			&user_2.user_keys[0].exported_verify_key +
+			"]]";
+
+		verify_document_signatures(data, &signatures, &verify_keys).unwrap();
+
+		//the keys must belong to the signer
+		let swapped_keys = "[[\"user_1\",".to_string() +
+			&user_2.user_keys[0].exported_verify_key +
+			"],[\"user_2\"," +
+			&user_1.user_keys[0].exported_verify_key +
+			"]]";
+
+		assert!(verify_document_signatures(data, &signatures, &swapped_keys).is_err());
+
+		assert!(verify_document_signatures(b"other contract", &signatures, &verify_keys).is_err());
+
+		let signature = sign_document(data, &user_1.user_keys[0].sign_key, "user_1", 1).unwrap();
+
+		verify_document_signature(data, &signature, &user_1.user_keys[0].exported_verify_key).unwrap();
+		assert!(verify_document_signature(data, &signature, &user_2.user_keys[0].exported_verify_key).is_err());
+	}
+}
@@ -0,0 +1,109 @@
+//! Social recovery of an account with trusted contacts (guardians).
+//!
+//! The recovery key of the user (see User::register_with_recovery_key) is split into Shamir shares.
+//! Every share is encrypted with the public key of a guardian and signed by the owner.
+//!
+//! To recover the account:
+//! 1. the recovering device sends a public key to the guardians (e.g. the public key of a new device)
+//! 2. every guardian verifies the share with the verify key of the owner and encrypts it again for this public key
+//! 3. the recovering device combines the shares to the recovery key and uses it in User::recover_with_recovery_key
+
+pub(crate) mod social_recovery;
+#[cfg(feature = "export")]
+mod social_recovery_export;
+
+pub use self::social_recovery::SocialRecovery;
+#[cfg(feature = "export")]
+pub use self::social_recovery_export::*;
+
+#[cfg(test)]
+mod test
+{
+	use alloc::string::String;
+	use alloc::vec::Vec;
+
+	use sentc_crypto_core::user::generate_recovery_key;
+
+	use crate::user::test_fn::create_user;
+
+	#[cfg(feature = "std_keys")]
+	pub type TestSocialRecovery = crate::keys::std::StdSocialRecovery;
+	#[cfg(all(feature = "fips_keys", not(feature = "std_keys")))]
+	pub type TestSocialRecovery = crate::keys::fips::FipsSocialRecovery;
+	#[cfg(all(feature = "rec_keys", not(feature = "std_keys")))]
+	pub type TestSocialRecovery = crate::keys::rec::RecSocialRecovery;
+
+	#[test]
+	fn test_social_recovery()
+	{
+		let owner = create_user();
+		let guardians = [create_user(), create_user(), create_user()];
+		let new_device = create_user();
+
+		let owner_keys = &owner.user_keys[0];
+		let new_device_keys = &new_device.user_keys[0];
+
+		let recovery_key = generate_recovery_key().unwrap();
+
+		let guardian_public_keys: Vec<_> = guardians
+			.iter()
+			.map(|g| g.user_keys[0].exported_public_key.clone())
+			.collect();
+
+		let shares = TestSocialRecovery::create_recovery_shares(&recovery_key, 2, &guardian_public_keys, &owner_keys.sign_key).unwrap();
+
+		assert_eq!(shares.len(), 3);
+
+		//the guardians 1 and 3 help
+		let reencrypted: Vec<String> = [0, 2]
+			.iter()
+			.map(|i| {
+				TestSocialRecovery::reencrypt_recovery_share(
+					&guardians[*i].user_keys[0].private_key,
+					&shares[*i].encrypted_share,
+					&owner_keys.exported_verify_key,
+					&new_device_keys.exported_public_key,
+				)
+				.unwrap()
+			})
+			.collect();
+
+		let combined = TestSocialRecovery::combine_recovery_shares(
+			&new_device_keys.private_key,
+			&[reencrypted[0].as_str(), reencrypted[1].as_str()],
+		)
+		.unwrap();
+
+		assert_eq!(combined, recovery_key);
+
+		//one share is not enough
+		assert!(TestSocialRecovery::combine_recovery_shares(&new_device_keys.private_key, &[reencrypted[0].as_str()]).is_err());
+	}
+
+	#[test]
+	fn test_not_reencrypt_share_of_other_owner()
+	{
+		let owner = create_user();
+		let other = create_user();
+		let guardian = create_user();
+
+		let recovery_key = generate_recovery_key().unwrap();
+
+		let shares = TestSocialRecovery::create_recovery_shares(
+			&recovery_key,
+			1,
+			&[guardian.user_keys[0].exported_public_key.clone()],
+			&other.user_keys[0].sign_key,
+		)
+		.unwrap();
+
+		let err = TestSocialRecovery::reencrypt_recovery_share(
+			&guardian.user_keys[0].private_key,
+			&shares[0].encrypted_share,
+			&owner.user_keys[0].exported_verify_key,
+			&owner.user_keys[0].exported_public_key,
+		);
+
+		assert!(err.is_err());
+	}
+}
@@ -0,0 +1,100 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use base64ct::{Base64, Encoding};
+use sentc_crypto_common::crypto::EncryptedHead;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_core::{shamir, user as core_user};
+use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SignKWrapper, SkCryptoWrapper};
+
+use crate::crypto::crypto::split_head_and_encrypted_data;
+use crate::entities::user::GuardianRecoveryShare;
+use crate::SdkError;
+
+pub struct SocialRecovery<P>
+{
+	_p: PhantomData<P>,
+}
+
+impl<P: PkFromUserKeyWrapper> SocialRecovery<P>
+{
+	/**
+	Split the recovery key into one share per guardian. Any threshold shares can rebuild the recovery key.
+
+	Every share is encrypted with the public key of the guardian and signed with the sign key of the owner.
+	 */
+	pub fn create_recovery_shares(
+		recovery_key: &str,
+		threshold: u8,
+		guardians: &[UserPublicKeyData],
+		sign_key: &impl SignKWrapper,
+	) -> Result<Vec<GuardianRecoveryShare>, SdkError>
+	{
+		let share_count = u8::try_from(guardians.len()).map_err(|_| sentc_crypto_core::Error::ShamirInvalidThreshold)?;
+
+		let recovery_key = core_user::recovery_key_to_bytes(recovery_key)?;
+
+		let shares = shamir::split_secret(&recovery_key, threshold, share_count)?;
+
+		guardians
+			.iter()
+			.zip(shares)
+			.map(|(guardian, share)| {
+				let encrypted = P::encrypt_with_user_key_with_sign(guardian, &share, sign_key)?;
+
+				Ok(GuardianRecoveryShare {
+					guardian_public_key_id: guardian.public_key_id.clone(),
+					encrypted_share: Base64::encode_string(&encrypted),
+				})
+			})
+			.collect()
+	}
+
+	/**
+	Called by the guardian: verify the share of the owner and encrypt it for the recovering device.
+
+	Fails if the share was not signed by the owner.
+	 */
+	pub fn reencrypt_recovery_share(
+		private_key: &impl SkCryptoWrapper,
+		encrypted_share: &str,
+		owner_verify_key: &UserVerifyKeyData,
+		recovery_public_key: &UserPublicKeyData,
+	) -> Result<String, SdkError>
+	{
+		let encrypted_share = Base64::decode_vec(encrypted_share).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+		let (head, encrypted_share): (EncryptedHead, &[u8]) = split_head_and_encrypted_data(&encrypted_share)?;
+
+		//decrypt would accept unsigned data too
+		if head.sign.is_none() {
+			return Err(SdkError::RecoveryShareNotSigned);
+		}
+
+		let share = private_key.decrypt_raw(encrypted_share, &head, Some(owner_verify_key))?;
+
+		let encrypted = P::encrypt_with_user_key(recovery_public_key, &share)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	/**
+	Called by the recovering device: decrypt the shares from the guardians and rebuild the recovery key.
+	 */
+	pub fn combine_recovery_shares(private_key: &impl SkCryptoWrapper, encrypted_shares: &[&str]) -> Result<String, SdkError>
+	{
+		let shares = encrypted_shares
+			.iter()
+			.map(|s| {
+				let encrypted = Base64::decode_vec(s).map_err(|_| SdkError::DecodeEncryptedDataFailed)?;
+
+				Ok(private_key.decrypt(&encrypted, None)?)
+			})
+			.collect::<Result<Vec<_>, SdkError>>()?;
+
+		let recovery_key = shamir::combine_shares(&shares)?;
+
+		Ok(core_user::recovery_key_from_bytes(&recovery_key)?)
+	}
+}
@@ -0,0 +1,50 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_std_keys::util::{SecretKey, SignKey};
+
+use crate::keys::std::StdSocialRecovery;
+use crate::SdkError;
+
+/**
+Split the recovery key into one share per guardian.
+
+The guardians are a json array of the user public key data. Returns the shares as json array.
+ */
+pub fn create_recovery_shares(recovery_key: &str, threshold: u8, guardians: &str, sign_key: &str) -> Result<String, String>
+{
+	let guardians: Vec<UserPublicKeyData> = serde_json::from_str(guardians).map_err(SdkError::JsonParseFailed)?;
+	let sign_key: SignKey = sign_key.parse()?;
+
+	let shares = StdSocialRecovery::create_recovery_shares(recovery_key, threshold, &guardians, &sign_key)?;
+
+	Ok(serde_json::to_string(&shares).map_err(|_| SdkError::JsonToStringFailed)?)
+}
+
+pub fn reencrypt_recovery_share(private_key: &str, encrypted_share: &str, owner_verify_key: &str, recovery_public_key: &str)
+	-> Result<String, String>
+{
+	let private_key: SecretKey = private_key.parse()?;
+	let owner_verify_key = UserVerifyKeyData::from_string(owner_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let recovery_public_key = UserPublicKeyData::from_string(recovery_public_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdSocialRecovery::reencrypt_recovery_share(
+		&private_key,
+		encrypted_share,
+		&owner_verify_key,
+		&recovery_public_key,
+	)?)
+}
+
+pub fn combine_recovery_shares(private_key: &str, encrypted_shares: &[String]) -> Result<String, String>
+{
+	let private_key: SecretKey = private_key.parse()?;
+
+	let encrypted_shares: Vec<&str> = encrypted_shares.iter().map(|s| s.as_str()).collect();
+
+	Ok(StdSocialRecovery::combine_recovery_shares(
+		&private_key,
+		&encrypted_shares,
+	)?)
+}
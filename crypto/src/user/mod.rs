@@ -15,9 +15,9 @@ pub(crate) mod user;
 #[cfg(feature = "export")]
 mod user_export;
 
-pub use self::user::User;
 #[cfg(not(feature = "export"))]
 pub use self::user::*;
+pub use self::user::{done_key_fetch_exported, User, UserKeyFetch};
 #[cfg(feature = "export")]
 pub use self::user_export::*;
 
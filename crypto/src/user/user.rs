@@ -20,14 +20,17 @@
 
 use alloc::borrow::ToOwned;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use base64ct::{Base64, Base64UrlUnpadded, Encoding};
 use sentc_crypto_common::group::GroupKeyServerOutput;
 use sentc_crypto_common::user::{
+	ChangePasswordData,
 	DoneLoginServerOutput,
 	KeyDerivedData,
 	MasterKey,
+	PrepareLoginSaltServerOutput,
 	RegisterData,
 	RegisterServerOutput,
 	ResetPasswordData,
@@ -41,7 +44,20 @@ use sentc_crypto_common::user::{
 	VerifyLoginOutput,
 };
 use sentc_crypto_common::{DeviceId, UserId};
-use sentc_crypto_core::cryptomat::{DeriveMasterKeyForAuth, Pk, PwHash, SearchableKeyGen, SignKeyComposer, SortableKeyGen, StaticKeyPair};
+use sentc_crypto_core::cryptomat::{
+	ClientRandomValue,
+	DeriveMasterKeyForAuth,
+	HashedAuthenticationKey,
+	Pk,
+	PwHash,
+	SearchableKeyGen,
+	SignK,
+	SignKeyComposer,
+	SignKeyPair,
+	SortableKeyGen,
+	StaticKeyPair,
+	VerifyK,
+};
 use sentc_crypto_core::user as core_user;
 use sentc_crypto_utils::cryptomat::{
 	PkFromUserKeyWrapper,
@@ -49,6 +65,7 @@ use sentc_crypto_utils::cryptomat::{
 	SignComposerWrapper,
 	SignKWrapper,
 	SignKeyPairWrapper,
+	SignaturePolicy,
 	SkWrapper,
 	SortableKeyComposerWrapper,
 	StaticKeyComposerWrapper,
@@ -59,11 +76,14 @@ use sentc_crypto_utils::cryptomat::{
 	VerifyKFromUserKeyWrapper,
 };
 use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::policy::CryptoPolicy;
 use sentc_crypto_utils::user::{DeviceKeyDataInt, UserPreVerifyLogin};
-use sentc_crypto_utils::{client_random_value_to_string, hashed_authentication_key_to_string};
+use sentc_crypto_utils::{client_random_value_to_string, derive_auth_key_for_auth_to_string, hashed_authentication_key_to_string};
 
-use crate::entities::user::{UserDataInt, UserKeyDataInt};
-use crate::group::Group;
+use crate::entities::user::{RecoveryKeyData, UserDataInt, UserKeyDataExport, UserKeyDataInt, UserKeyMigrationData};
+use crate::group::{check_group_key_policy, Group};
+use crate::key_pin::{key_pin, KeyPinStore};
+use crate::key_store::{EncryptedKeyStore, KeyStore};
 use crate::util::public::handle_server_response;
 use crate::SdkError;
 
@@ -118,7 +138,31 @@ where
 	/// It can be used to add more data to the server, like the first and lastname of the user, etc.
 	pub fn register_typed(user_identifier: &str, password: &str) -> Result<RegisterData, SdkError>
 	{
-		let (device, raw_public_key) = Self::prepare_register_device_private_internally(user_identifier, password)?;
+		let out = core_user::register::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen, PwH>(password)?;
+
+		Self::register_typed_internally(user_identifier, out)
+	}
+
+	/// Like register but creates a recovery key for the user too.
+	///
+	/// Returns the register data for the server, the recovery key and the recovery data for the server.
+	/// Show the recovery key only once to the user and never send it to the server.
+	pub fn register_with_recovery_key(user_identifier: &str, password: &str) -> Result<(String, String, String), SdkError>
+	{
+		let (out, recovery) = core_user::register_with_recovery_key::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen, PwH>(password)?;
+
+		let register_out = Self::register_typed_internally(user_identifier, out)?
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)?;
+
+		let (recovery_key, recovery_data) = export_recovery_key_output(recovery)?;
+
+		Ok((register_out, recovery_key, recovery_data))
+	}
+
+	fn register_typed_internally(user_identifier: &str, out: CoreRegisterOutput<StGen, SignGen, PwH>) -> Result<RegisterData, SdkError>
+	{
+		let (device, raw_public_key) = Self::prepare_register_device_from_output(user_identifier, out)?;
 
 		//6. create the user group
 		//6.1 get a "fake" public key from the register data for group create
@@ -153,6 +197,20 @@ where
 	{
 		let out = core_user::register::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen, PwH>(password)?;
 
+		Self::prepare_register_device_from_output(device_identifier, out)
+	}
+
+	fn prepare_register_device_from_output(
+		device_identifier: &str,
+		out: CoreRegisterOutput<StGen, SignGen, PwH>,
+	) -> Result<
+		(
+			UserDeviceRegisterInput,
+			<<StGen as StaticKeyPairWrapper>::KeyGen as StaticKeyPair>::PublicKey,
+		),
+		SdkError,
+	>
+	{
 		//transform the register output into json
 
 		//1. encode the encrypted data to base64
@@ -322,7 +380,8 @@ where
 			user_keys: server_output
 				.user_keys
 				.into_iter()
-				.map(|i| Self::done_login_internally_with_user_out(&device_keys.private_key, i))
+				//the verify keys of the user are not known before the login, so the group key signatures can't be checked here
+				.map(|i| Self::done_login_internally_with_user_out(&device_keys.private_key, i, SignaturePolicy::Lenient(None)))
 				.collect::<Result<_, _>>()?,
 			hmac_keys: server_output.hmac_keys,
 			device_keys,
@@ -334,18 +393,77 @@ where
 	}
 
 	/// When there are more than 50 keys in the user group, fetch the rest with this fn.
-	pub fn done_key_fetch(
+	///
+	/// The signature policy is used for the signature of a rotated user key, like in Group::decrypt_group_keys.
+	pub fn done_key_fetch<'p>(
 		private_key: &impl SkWrapper,
 		server_output: &str,
+		key_policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
 	{
 		let out: GroupKeyServerOutput = handle_server_response(server_output)?;
 
-		let key = Self::done_login_internally_with_user_out(private_key, out)?;
+		let key = Self::done_login_internally_with_user_out(private_key, out, key_policy.into())?;
+
+		Ok(key)
+	}
+
+	/**
+	Like done_key_fetch but every algorithm of the user key and the device key must be allowed by the crypto policy.
+	 */
+	pub fn done_key_fetch_with_policy<'p>(
+		private_key: &impl SkWrapper,
+		server_output: &str,
+		key_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		let out: GroupKeyServerOutput = handle_server_response(server_output)?;
+
+		let key_policy = key_policy.into();
+
+		check_group_key_policy(crypto_policy, private_key, &out, key_policy.verify_key())?;
+
+		Self::done_login_internally_with_user_out(private_key, out, key_policy)
+	}
+
+	/**
+	Like done_key_fetch but look up the key in the key store first.
+
+	If the key is not in the store, it is decrypted and stored under the user id.
+	 */
+	pub fn done_key_fetch_cached<'p, K: SymKeyWrapper, St: KeyStore>(
+		store: &mut EncryptedKeyStore<K, St>,
+		user_id: &str,
+		private_key: &impl SkWrapper,
+		server_output: &str,
+		key_policy: impl Into<SignaturePolicy<'p>>,
+	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
+	{
+		let out: GroupKeyServerOutput = handle_server_response(server_output)?;
+
+		if let Some(key) = store.get_user_key(user_id, &out.group_key_id)? {
+			return Ok(key);
+		}
+
+		let key = Self::done_login_internally_with_user_out(private_key, out, key_policy.into())?;
+
+		store.set_user_key(user_id, &key)?;
 
 		Ok(key)
 	}
 
+	/**
+	Get all stored keys of the user, e.g. after a restart of the app.
+	 */
+	pub fn get_keys_from_store<K: SymKeyWrapper, St: KeyStore>(
+		store: &EncryptedKeyStore<K, St>,
+		user_id: &str,
+	) -> Result<Vec<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>>, SdkError>
+	{
+		store.get_user_keys(user_id)
+	}
+
 	/**
 	Get the user keys from the user group
 
@@ -357,6 +475,7 @@ where
 	fn done_login_internally_with_user_out(
 		private_key: &impl SkWrapper,
 		user_group_key: GroupKeyServerOutput,
+		key_policy: SignaturePolicy,
 	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
 	{
 		let keypair_sign_id = user_group_key.keypair_sign_id.to_owned();
@@ -379,7 +498,7 @@ where
 				let keys = Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::decrypt_group_keys(
 					private_key,
 					user_group_key,
-					None,
+					key_policy,
 				)?;
 
 				let sign_key = SignC::Composer::decrypt_by_master_key(keys.group_key.get_key(), &encrypted_sign_key, &keypair_sign_alg)?;
@@ -469,6 +588,146 @@ where
 		data.to_string().map_err(|_| SdkError::JsonToStringFailed)
 	}
 
+	/**
+	Create a new recovery key for a logged-in user.
+
+	Needs the password and the same server outputs as change_password. A new recovery key replaces the old one.
+
+	Returns the recovery key and the recovery data for the server.
+	 */
+	pub fn create_recovery_key(
+		password: &str,
+		server_output_prep_login: &str,
+		server_output_done_login: DoneLoginServerOutput,
+	) -> Result<(String, String), SdkError>
+	{
+		let server_output_prep_login: PrepareLoginSaltServerOutput = handle_server_response(server_output_prep_login)?;
+
+		let encrypted_master_key = Base64::decode_vec(
+			server_output_done_login
+				.device_keys
+				.encrypted_master_key
+				.as_str(),
+		)
+		.map_err(|_| SdkUtilError::DerivedKeyWrongFormat)?;
+		let salt = Base64::decode_vec(server_output_prep_login.salt_string.as_str()).map_err(|_| SdkUtilError::DecodeSaltFailed)?;
+
+		let out = core_user::create_recovery_key::<PwH>(
+			password,
+			&salt,
+			&encrypted_master_key,
+			server_output_prep_login.derived_encryption_key_alg.as_str(),
+		)?;
+
+		export_recovery_key_output(out)
+	}
+
+	/**
+	Set a new password with the recovery key.
+
+	The key pairs of the user stay the same, unlike reset_password.
+
+	The server output of the prep recovery contains the salt of the recovery data (like the prep login for the password)
+	and the encrypted master key is the base64 encoded master key from the recovery data.
+
+	Returns the data for the server in the same format as change_password.
+	The old auth key is derived from the recovery key.
+	 */
+	pub fn recover_with_recovery_key(
+		recovery_key: &str,
+		new_password: &str,
+		server_output_prep_recovery: &str,
+		encrypted_master_key: &str,
+	) -> Result<String, SdkError>
+	{
+		let server_output_prep_recovery: PrepareLoginSaltServerOutput = handle_server_response(server_output_prep_recovery)?;
+
+		let encrypted_master_key = Base64::decode_vec(encrypted_master_key).map_err(|_| SdkUtilError::DerivedKeyWrongFormat)?;
+		let salt = Base64::decode_vec(server_output_prep_recovery.salt_string.as_str()).map_err(|_| SdkUtilError::DecodeSaltFailed)?;
+
+		let out = core_user::recover_with_recovery_key::<PwH>(
+			recovery_key,
+			new_password,
+			&salt,
+			&encrypted_master_key,
+			server_output_prep_recovery
+				.derived_encryption_key_alg
+				.as_str(),
+		)?;
+
+		ChangePasswordData {
+			new_derived_alg: out.derived_alg.to_string(),
+			new_encrypted_master_key: Base64::encode_string(&out.encrypted_master_key),
+			new_client_random_value: client_random_value_to_string(&out.client_random_value),
+			new_hashed_authentication_key: hashed_authentication_key_to_string(&out.hashed_authentication_key_bytes),
+			new_encrypted_master_key_alg: out.encrypted_master_key_alg.to_string(),
+			old_auth_key: derive_auth_key_for_auth_to_string(&out.old_auth_key),
+		}
+		.to_string()
+		.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
+	/**
+	# Migrate the user keys to the key family of this user
+
+	The previous user keys can be of any other key family, e.g. std keys when this is a rec user.
+
+	1. Create new device keys of this family for the password. They replace the keys of the current device.
+	2. Encrypt all previous user keys (including the newest) with the new device public key.
+	3. Rotate the user keys with the new device public key as invoker.
+	   The other devices finish the rotation with done_key_rotation like a normal user key rotation.
+
+	The new user keys are signed with the sign key of the newest user key, which can be of the previous key family.
+	The other devices can verify the rotation with the verify key they already know.
+
+	After the migration, log in again with the password to get the new device keys.
+	Until every device is migrated, use done_key_fetch_exported with traverse_keys! to decrypt user keys of both families.
+	 */
+	pub fn prepare_key_migration<PSign>(
+		password: &str,
+		device_id: &str,
+		user_id: UserId,
+		previous_user_keys: &[&impl SymKeyWrapper],
+		newest_user_key: &impl SymKeyWrapper,
+		sign_key: &PSign,
+	) -> Result<String, SdkError>
+	where
+		PSign: SignKWrapper + SignComposerWrapper<SignKWrapper = PSign>,
+	{
+		let out = core_user::register::<SGen::KeyGen, StGen::KeyGen, SignGen::KeyGen, PwH>(password)?;
+
+		let (device, raw_public_key) = Self::prepare_register_device_from_output(device_id, out)?;
+
+		//the public key id of a device is the device id
+		let device_public_key = StGen::pk_from_inner(raw_public_key, device_id.to_string());
+
+		let user_keys =
+			Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::prepare_group_keys_for_new_member_with_group_public_key(
+				&device_public_key,
+				previous_user_keys,
+				false,
+				None,
+			)?
+			.keys;
+
+		let key_rotation =
+			Group::<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC>::key_rotation_typed_with_sign_composer::<PSign>(
+				newest_user_key,
+				&device_public_key,
+				true,
+				Some(sign_key),
+				user_id,
+			)?;
+
+		serde_json::to_string(&UserKeyMigrationData {
+			master_key: device.master_key,
+			derived: device.derived,
+			user_keys,
+			key_rotation,
+		})
+		.map_err(|_| SdkError::JsonToStringFailed)
+	}
+
 	/**
 	Create a safety number
 
@@ -500,6 +759,183 @@ where
 		Ok(Base64UrlUnpadded::encode_string(&number))
 	}
 
+	/**
+	Create a numeric safety number of 60 digits for two users.
+
+	Unlike create_safety_number the order of the users doesn't matter here.
+	 */
+	pub fn create_numeric_safety_number(
+		verify_key_1: &UserVerifyKeyData,
+		user_id_1: &str,
+		verify_key_2: &UserVerifyKeyData,
+		user_id_2: &str,
+	) -> Result<String, SdkError>
+	{
+		let verify_key_1 = SignC::vk_inner_from_pem(&verify_key_1.verify_key_pem, &verify_key_1.verify_key_alg)?;
+		let verify_key_2 = SignC::vk_inner_from_pem(&verify_key_2.verify_key_pem, &verify_key_2.verify_key_alg)?;
+
+		Ok(core_user::numeric_safety_number(
+			&verify_key_1,
+			user_id_1,
+			&verify_key_2,
+			user_id_2,
+		))
+	}
+
+	/**
+	Create the bytes for a qr code of the safety number.
+
+	The own user must be the user who shows the code.
+	 */
+	pub fn create_safety_number_qr(
+		own_verify_key: &UserVerifyKeyData,
+		own_user_id: &str,
+		other_verify_key: &UserVerifyKeyData,
+		other_user_id: &str,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let own_verify_key = SignC::vk_inner_from_pem(&own_verify_key.verify_key_pem, &own_verify_key.verify_key_alg)?;
+		let other_verify_key = SignC::vk_inner_from_pem(&other_verify_key.verify_key_pem, &other_verify_key.verify_key_alg)?;
+
+		Ok(core_user::safety_number_qr_payload(
+			&own_verify_key,
+			own_user_id,
+			&other_verify_key,
+			other_user_id,
+		))
+	}
+
+	/**
+	Check the scanned qr code of the other user.
+
+	Returns false if the code was created with other keys or user ids.
+	 */
+	pub fn verify_safety_number_qr(
+		scanned_payload: &[u8],
+		own_verify_key: &UserVerifyKeyData,
+		own_user_id: &str,
+		other_verify_key: &UserVerifyKeyData,
+		other_user_id: &str,
+	) -> Result<bool, SdkError>
+	{
+		let own_verify_key = SignC::vk_inner_from_pem(&own_verify_key.verify_key_pem, &own_verify_key.verify_key_alg)?;
+		let other_verify_key = SignC::vk_inner_from_pem(&other_verify_key.verify_key_pem, &other_verify_key.verify_key_alg)?;
+
+		Ok(core_user::verify_safety_number_qr_payload(
+			scanned_payload,
+			&own_verify_key,
+			own_user_id,
+			&other_verify_key,
+			other_user_id,
+		)?)
+	}
+
+	/**
+	Check the verify key of a user against the pinned verify key with the same key id.
+
+	The keys of a new user are pinned on first use.
+	A verify key with a new key id is only pinned when it is signed by an already pinned verify key of this user.
+	signed_by is the verify key of the previous sign key and the signature from sign_verify_key.
+	Otherwise KeyPinIdentityChanged is returned and the keys must be trusted again with retrust_user_keys.
+	 */
+	pub fn check_verify_key_pin<S: KeyPinStore>(
+		store: &mut S,
+		user_id: &str,
+		verify_key: &UserVerifyKeyData,
+		signed_by: Option<(&UserVerifyKeyData, &str)>,
+	) -> Result<(), SdkError>
+	{
+		key_pin::check_pin(store, user_id, key_pin::pinned_verify_key(verify_key), true, |pin| {
+			let (signed_by_key, sig) = match signed_by {
+				Some(s) => s,
+				None => return Ok(false),
+			};
+
+			if !key_pin::is_pinned_verify_key(pin, signed_by_key) {
+				return Ok(false);
+			}
+
+			let raw_verify_key = SignC::vk_inner_from_pem(&signed_by_key.verify_key_pem, &signed_by_key.verify_key_alg)?;
+			let sig = SignC::sig_from_string(sig, &signed_by_key.verify_key_alg)?;
+
+			Ok(raw_verify_key.verify_only(&sig, &key_pin::verify_key_sign_data(verify_key))?)
+		})
+	}
+
+	/**
+	Check the public key of a user against the pinned public key with the same key id.
+
+	The verify key is the key of the public_key_sig_key_id. It is checked against the pins first.
+	A public key with a new key id of an already pinned user is only pinned when it is signed by this verify key.
+	Otherwise KeyPinIdentityChanged is returned.
+	 */
+	pub fn check_public_key_pin<S: KeyPinStore>(
+		store: &mut S,
+		user_id: &str,
+		public_key: &UserPublicKeyData,
+		verify_key: Option<&UserVerifyKeyData>,
+	) -> Result<(), SdkError>
+	{
+		if let Some(verify_key) = verify_key {
+			Self::check_verify_key_pin(store, user_id, verify_key, None)?;
+		}
+
+		key_pin::check_pin(store, user_id, key_pin::pinned_public_key(public_key), false, |pin| {
+			let verify_key = match verify_key {
+				Some(k) => k,
+				None => return Ok(false),
+			};
+
+			if public_key.public_key_sig_key_id.as_deref() != Some(verify_key.verify_key_id.as_str()) ||
+				!key_pin::is_pinned_verify_key(pin, verify_key)
+			{
+				return Ok(false);
+			}
+
+			Self::verify_user_public_key(verify_key, public_key)
+		})
+	}
+
+	/**
+	Sign the new verify key after a key rotation with the previous sign key.
+
+	Other users need this signature to pin the new verify key (see check_verify_key_pin).
+	 */
+	pub fn sign_verify_key(sign_key: &SignC::SignKWrapper, new_verify_key: &UserVerifyKeyData) -> Result<String, SdkError>
+	{
+		let sig = sign_key
+			.get_key()
+			.sign_only(key_pin::verify_key_sign_data(new_verify_key))?;
+
+		Ok(SignC::sig_to_string(sig))
+	}
+
+	/**
+	Trust the new keys of a user after the identity changed.
+
+	The safety number must be the numeric safety number that both users compared (see create_numeric_safety_number).
+	The pin is only replaced if the number matches the keys.
+	 */
+	#[allow(clippy::too_many_arguments)]
+	pub fn retrust_user_keys<S: KeyPinStore>(
+		store: &mut S,
+		own_verify_key: &UserVerifyKeyData,
+		own_user_id: &str,
+		other_verify_key: &UserVerifyKeyData,
+		other_user_id: &str,
+		other_public_key: Option<&UserPublicKeyData>,
+		compared_safety_number: &str,
+	) -> Result<(), SdkError>
+	{
+		let number = Self::create_numeric_safety_number(own_verify_key, own_user_id, other_verify_key, other_user_id)?;
+
+		if number != compared_safety_number {
+			return Err(SdkError::KeyPinSafetyNumberMismatch);
+		}
+
+		key_pin::replace_pin(store, other_user_id, other_verify_key, other_public_key)
+	}
+
 	pub fn verify_user_public_key(verify_key: &UserVerifyKeyData, public_key: &UserPublicKeyData) -> Result<bool, SdkError>
 	{
 		let raw_verify_key = SignC::vk_inner_from_pem(&verify_key.verify_key_pem, &verify_key.verify_key_alg)?;
@@ -519,6 +955,124 @@ where
 	}
 }
 
+/**
+Decrypt a user key with the key family of a user type and export it.
+
+Implemented for every user type. The export format is the same for every key family.
+ */
+pub trait UserKeyFetch
+{
+	fn done_key_fetch_exported(private_key: &impl SkWrapper, server_output: &str, key_policy: SignaturePolicy)
+		-> Result<UserKeyDataExport, SdkError>;
+
+	fn done_key_fetch_exported_with_policy(
+		private_key: &impl SkWrapper,
+		server_output: &str,
+		key_policy: SignaturePolicy,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<UserKeyDataExport, SdkError>;
+}
+
+impl<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH> UserKeyFetch
+	for User<SGen, StGen, SignGen, SearchGen, SortGen, SC, StC, SignC, SearchC, SortC, PC, VC, PwH>
+where
+	SGen: SymKeyGenWrapper,
+	StGen: StaticKeyPairWrapper,
+	SignGen: SignKeyPairWrapper,
+	SearchGen: SearchableKeyGen,
+	SortGen: SortableKeyGen,
+	SC: SymKeyComposerWrapper,
+	StC: StaticKeyComposerWrapper,
+	SignC: SignComposerWrapper,
+	SearchC: SearchableKeyComposerWrapper,
+	SortC: SortableKeyComposerWrapper,
+	PC: PkFromUserKeyWrapper,
+	VC: VerifyKFromUserKeyWrapper,
+	PwH: PwHash,
+{
+	fn done_key_fetch_exported(private_key: &impl SkWrapper, server_output: &str, key_policy: SignaturePolicy)
+		-> Result<UserKeyDataExport, SdkError>
+	{
+		Self::done_key_fetch(private_key, server_output, key_policy)?.try_into()
+	}
+
+	fn done_key_fetch_exported_with_policy(
+		private_key: &impl SkWrapper,
+		server_output: &str,
+		key_policy: SignaturePolicy,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<UserKeyDataExport, SdkError>
+	{
+		Self::done_key_fetch_with_policy(private_key, server_output, key_policy, crypto_policy)?.try_into()
+	}
+}
+
+/**
+Like done_key_fetch but for the key family of the given user type and the key is exported.
+
+Use it with traverse_keys! while the user keys are migrated to another key family:
+
+```ignore
+let key = traverse_keys!(done_key_fetch_exported, (&private_key, server_output, key_policy), [RecUser, StdUser])?;
+```
+ */
+pub fn done_key_fetch_exported<U: UserKeyFetch>(
+	private_key: &impl SkWrapper,
+	server_output: &str,
+	key_policy: SignaturePolicy,
+) -> Result<UserKeyDataExport, SdkError>
+{
+	U::done_key_fetch_exported(private_key, server_output, key_policy)
+}
+
+/**
+Like done_key_fetch_exported but with the crypto policy.
+
+Use the policy form of traverse_keys! to check the key of every tried key family:
+
+```ignore
+let key = traverse_keys!(
+	done_key_fetch_exported_with_policy,
+	(&private_key, server_output, key_policy),
+	[RecUser, StdUser],
+	policy: &crypto_policy
+)?;
+```
+ */
+pub fn done_key_fetch_exported_with_policy<U: UserKeyFetch>(
+	private_key: &impl SkWrapper,
+	server_output: &str,
+	key_policy: SignaturePolicy,
+	crypto_policy: &CryptoPolicy,
+) -> Result<UserKeyDataExport, SdkError>
+{
+	U::done_key_fetch_exported_with_policy(private_key, server_output, key_policy, crypto_policy)
+}
+
+type CoreRegisterOutput<StGen, SignGen, PwH> = core_user::RegisterOutPut<
+	<<StGen as StaticKeyPairWrapper>::KeyGen as StaticKeyPair>::PublicKey,
+	<<SignGen as SignKeyPairWrapper>::KeyGen as SignKeyPair>::VerifyKey,
+	<PwH as PwHash>::CRV,
+	<PwH as PwHash>::HAK,
+>;
+
+fn export_recovery_key_output<CRV: ClientRandomValue, HAK: HashedAuthenticationKey>(
+	out: core_user::RecoveryKeyOutput<CRV, HAK>,
+) -> Result<(String, String), SdkError>
+{
+	let data = RecoveryKeyData {
+		client_random_value: client_random_value_to_string(&out.client_random_value),
+		hashed_authentication_key: hashed_authentication_key_to_string(&out.hashed_authentication_key_bytes),
+		encrypted_master_key: Base64::encode_string(&out.encrypted_master_key),
+		encrypted_master_key_alg: out.encrypted_master_key_alg.to_string(),
+		derived_alg: out.derived_alg.to_string(),
+	};
+
+	let data = serde_json::to_string(&data).map_err(|_| SdkError::JsonToStringFailed)?;
+
+	Ok((out.recovery_key, data))
+}
+
 /**
 Prepare the server input for the check
  */
@@ -604,7 +1158,7 @@ mod test
 
 	use alloc::string::ToString;
 
-	use sentc_crypto_common::group::CreateData;
+	use sentc_crypto_common::group::{CreateData, DoneKeyRotationData, KeyRotationInput};
 	use sentc_crypto_common::user::{
 		ChangePasswordData,
 		RegisterData,
@@ -613,9 +1167,11 @@ mod test
 		UserDeviceRegisterOutput,
 	};
 	use sentc_crypto_common::ServerOutput;
+	use sentc_crypto_core::cryptomat::Sk;
 	use serde_json::to_string;
 
 	use super::*;
+	use crate::key_pin::MemoryKeyPinStore;
 	use crate::user::test_fn::{create_user, simulate_server_done_login, simulate_server_prepare_login, simulate_verify_login, TestUser};
 
 	#[test]
@@ -703,6 +1259,96 @@ mod test
 		);
 	}
 
+	#[test]
+	fn test_recover_with_recovery_key()
+	{
+		let username = "admin";
+		let password = "abc*èéöäüê";
+		let new_password = "abcdfg";
+
+		let (out, recovery_key, recovery_data) = TestUser::register_with_recovery_key(username, password).unwrap();
+
+		let mut register_data = RegisterData::from_string(out.as_str()).unwrap();
+		let recovery_data: RecoveryKeyData = serde_json::from_str(&recovery_data).unwrap();
+
+		//the server creates the salt like for the login but with the recovery data
+		let prep_recovery = simulate_server_prepare_login(&KeyDerivedData {
+			client_random_value: recovery_data.client_random_value,
+			derived_alg: recovery_data.derived_alg,
+			..RegisterData::from_string(out.as_str())
+				.unwrap()
+				.device
+				.derived
+		});
+
+		let recover_out = TestUser::recover_with_recovery_key(
+			&recovery_key,
+			new_password,
+			&prep_recovery,
+			&recovery_data.encrypted_master_key,
+		)
+		.unwrap();
+		let recover_out = ChangePasswordData::from_string(recover_out.as_str()).unwrap();
+
+		//login with the new password and the old key pairs
+		register_data.device.derived.client_random_value = recover_out.new_client_random_value;
+		register_data.device.derived.derived_alg = recover_out.new_derived_alg;
+		register_data.device.master_key.encrypted_master_key = recover_out.new_encrypted_master_key;
+
+		let server_output = simulate_server_prepare_login(&register_data.device.derived);
+		let (_, auth_key, master_key_encryption_key) = TestUser::prepare_login(username, new_password, server_output.as_str()).unwrap();
+
+		let server_output = simulate_server_done_login(register_data);
+
+		TestUser::done_login(
+			&master_key_encryption_key,
+			auth_key,
+			username.to_string(),
+			server_output,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_create_recovery_key()
+	{
+		let username = "admin";
+		let password = "abc*èéöäüê";
+
+		let out = TestUser::register(username, password).unwrap();
+		let out = RegisterData::from_string(out.as_str()).unwrap();
+
+		let derived = RegisterData::from_string(&out.to_string().unwrap())
+			.unwrap()
+			.device
+			.derived;
+
+		let prep_server_output = simulate_server_prepare_login(&out.device.derived);
+		let done_server_output = simulate_server_done_login(out);
+
+		let (recovery_key, recovery_data) = TestUser::create_recovery_key(password, &prep_server_output, done_server_output).unwrap();
+		let recovery_data: RecoveryKeyData = serde_json::from_str(&recovery_data).unwrap();
+
+		let prep_recovery = simulate_server_prepare_login(&KeyDerivedData {
+			client_random_value: recovery_data.client_random_value,
+			derived_alg: recovery_data.derived_alg,
+			..derived
+		});
+
+		//a wrong recovery key can't decrypt the master key
+		let wrong_key = core_user::generate_recovery_key().unwrap();
+
+		assert!(TestUser::recover_with_recovery_key(&wrong_key, "abc", &prep_recovery, &recovery_data.encrypted_master_key).is_err());
+
+		TestUser::recover_with_recovery_key(
+			&recovery_key,
+			"abc",
+			&prep_recovery,
+			&recovery_data.encrypted_master_key,
+		)
+		.unwrap();
+	}
+
 	#[test]
 	fn test_new_device()
 	{
@@ -876,6 +1522,127 @@ mod test
 		assert_ne!(number, number_3);
 	}
 
+	#[test]
+	fn test_numeric_safety_number()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+
+		let number = TestUser::create_numeric_safety_number(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		let number_2 = TestUser::create_numeric_safety_number(
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+		)
+		.unwrap();
+
+		assert_eq!(number.len(), 60);
+		assert_eq!(number, number_2);
+	}
+
+	#[test]
+	fn test_safety_number_qr()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+
+		let payload = TestUser::create_safety_number_qr(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		let verify = TestUser::verify_safety_number_qr(
+			&payload,
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+		)
+		.unwrap();
+
+		assert!(verify);
+
+		let verify = TestUser::verify_safety_number_qr(
+			&payload,
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+			&user_1.user_keys[0].exported_verify_key,
+			"abc3",
+		)
+		.unwrap();
+
+		assert!(!verify);
+	}
+
+	#[test]
+	fn test_retrust_user_keys()
+	{
+		let user_1 = create_user();
+		let user_2 = create_user();
+		let user_3 = create_user();
+
+		let mut store = MemoryKeyPinStore::new();
+
+		TestUser::check_verify_key_pin(&mut store, "abc2", &user_2.user_keys[0].exported_verify_key, None).unwrap();
+
+		//the identity of user 2 changed
+		assert!(TestUser::check_verify_key_pin(&mut store, "abc2", &user_3.user_keys[0].exported_verify_key, None).is_err());
+
+		//compared the number of the old keys
+		let wrong_number = TestUser::create_numeric_safety_number(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		let err = TestUser::retrust_user_keys(
+			&mut store,
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_3.user_keys[0].exported_verify_key,
+			"abc2",
+			None,
+			&wrong_number,
+		);
+
+		assert!(matches!(err, Err(SdkError::KeyPinSafetyNumberMismatch)));
+
+		let number = TestUser::create_numeric_safety_number(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_3.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		TestUser::retrust_user_keys(
+			&mut store,
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_3.user_keys[0].exported_verify_key,
+			"abc2",
+			Some(&user_3.user_keys[0].exported_public_key),
+			&number,
+		)
+		.unwrap();
+
+		TestUser::check_verify_key_pin(&mut store, "abc2", &user_3.user_keys[0].exported_verify_key, None).unwrap();
+		TestUser::check_public_key_pin(&mut store, "abc2", &user_3.user_keys[0].exported_public_key, None).unwrap();
+	}
+
 	#[test]
 	fn test_verify_public_key()
 	{
@@ -889,4 +1656,288 @@ mod test
 
 		assert!(verify);
 	}
+
+	#[test]
+	fn test_key_migration()
+	{
+		let username = "admin";
+		let password = "12345";
+
+		let user = create_user();
+		let user_key = &user.user_keys[0];
+
+		let out = TestUser::prepare_key_migration(
+			password,
+			&user.device_id,
+			user.user_id.clone(),
+			&[&user_key.group_key],
+			&user_key.group_key,
+			&user_key.sign_key,
+		)
+		.unwrap();
+		let out: UserKeyMigrationData = serde_json::from_str(&out).unwrap();
+
+		//login with the new device keys
+		let mut register_data = RegisterData::from_string(&TestUser::register(username, password).unwrap()).unwrap();
+		register_data.device.master_key = out.master_key;
+		register_data.device.derived = out.derived;
+
+		let server_output = simulate_server_prepare_login(&register_data.device.derived);
+		let (_, auth_key, master_key_encryption_key) = TestUser::prepare_login(username, password, &server_output).unwrap();
+
+		let server_output = simulate_server_done_login(register_data);
+		let new_device_keys = TestUser::done_login(
+			&master_key_encryption_key,
+			auth_key,
+			username.to_string(),
+			server_output,
+		)
+		.unwrap()
+		.device_keys;
+
+		//the previous user key is encrypted by the new device public key
+		assert_eq!(out.user_keys.len(), 1);
+
+		let encrypted_user_key = Base64::decode_vec(&out.user_keys[0].encrypted_group_key).unwrap();
+		let decrypted_user_key = new_device_keys
+			.private_key
+			.get_key()
+			.decrypt(&encrypted_user_key)
+			.unwrap();
+
+		assert_eq!(decrypted_user_key, user_key.group_key.key.as_ref());
+
+		//the new device gets the rotated user key directly
+		let rotation = out.key_rotation;
+
+		//the rotation is signed with the sign key of the previous user key
+		assert_eq!(rotation.signed_by_user_id.as_ref(), Some(&user.user_id));
+		assert_eq!(
+			rotation.signed_by_user_sign_key_id.as_ref(),
+			Some(&user_key.sign_key.key_id)
+		);
+
+		let server_output = ServerOutput {
+			status: true,
+			err_msg: None,
+			err_code: None,
+			result: Some(GroupKeyServerOutput {
+				encrypted_group_key: rotation.encrypted_group_key_by_user.clone(),
+				group_key_alg: rotation.group_key_alg.clone(),
+				group_key_id: "new_user_key".to_string(),
+				encrypted_private_group_key: rotation.encrypted_private_group_key.clone(),
+				public_group_key: rotation.public_group_key.clone(),
+				keypair_encrypt_alg: rotation.keypair_encrypt_alg.clone(),
+				key_pair_id: "new_key_pair".to_string(),
+				user_public_key_id: rotation.invoker_public_key_id.clone(),
+				time: 0,
+				signed_by_user_id: rotation.signed_by_user_id.clone(),
+				signed_by_user_sign_key_id: rotation.signed_by_user_sign_key_id.clone(),
+				group_key_sig: rotation.group_key_sig.clone(),
+				encrypted_sign_key: rotation.encrypted_sign_key.clone(),
+				verify_key: rotation.verify_key.clone(),
+				keypair_sign_alg: rotation.keypair_sign_alg.clone(),
+				keypair_sign_id: Some("new_sign_key".to_string()),
+				public_key_sig: rotation.public_key_sig.clone(),
+				public_key_sig_key_id: Some("new_sign_key".to_string()),
+			}),
+		}
+		.to_string()
+		.unwrap();
+
+		let key_output: GroupKeyServerOutput = handle_server_response(&server_output).unwrap();
+		crate::group::test_fn::TestGroup::decrypt_group_keys(
+			&new_device_keys.private_key,
+			key_output,
+			Some(&user_key.exported_verify_key),
+		)
+		.unwrap();
+
+		let new_user_key = TestUser::done_key_fetch(
+			&new_device_keys.private_key,
+			&server_output,
+			Some(&user_key.exported_verify_key),
+		)
+		.unwrap();
+		let exported = done_key_fetch_exported::<TestUser>(
+			&new_device_keys.private_key,
+			&server_output,
+			SignaturePolicy::Require(&user_key.exported_verify_key),
+		)
+		.unwrap();
+
+		assert_eq!(exported.group_key_id, "new_user_key");
+
+		//the other devices finish the rotation with their own device keys and the previous user key
+		let encrypted_ephemeral_key = Base64::decode_vec(&rotation.encrypted_ephemeral_key).unwrap();
+		let encrypted_ephemeral_key_by_device = user
+			.device_keys
+			.public_key
+			.key
+			.encrypt(&encrypted_ephemeral_key)
+			.unwrap();
+
+		let done_rotation = crate::group::test_fn::TestGroup::done_key_rotation(
+			&user.device_keys.private_key,
+			&user.device_keys.public_key,
+			&user_key.group_key,
+			KeyRotationInput {
+				encrypted_ephemeral_key_by_group_key_and_public_key: Base64::encode_string(&encrypted_ephemeral_key_by_device),
+				encrypted_group_key_by_ephemeral: rotation.encrypted_group_key_by_ephemeral,
+				ephemeral_alg: rotation.ephemeral_alg,
+				encrypted_eph_key_key_id: "".to_string(),
+				previous_group_key_id: rotation.previous_group_key_id,
+				time: 0,
+				new_group_key_id: "new_user_key".to_string(),
+				error: None,
+			},
+		)
+		.unwrap();
+		let done_rotation = DoneKeyRotationData::from_string(&done_rotation).unwrap();
+
+		let encrypted_new_user_key = Base64::decode_vec(&done_rotation.encrypted_new_group_key).unwrap();
+		let decrypted_new_user_key = user
+			.device_keys
+			.private_key
+			.get_key()
+			.decrypt(&encrypted_new_user_key)
+			.unwrap();
+
+		assert_eq!(decrypted_new_user_key, new_user_key.group_key.key.as_ref());
+	}
+
+	#[test]
+	#[cfg(all(feature = "std_keys", feature = "rec_keys"))]
+	fn test_key_migration_from_std_to_rec_keys()
+	{
+		use crate::keys::rec::{RecGroup, RecUser};
+		use crate::keys::std::StdUser;
+		use crate::traverse_keys;
+
+		//the test user is a std user when std keys are enabled
+		let user = create_user();
+		let user_key = &user.user_keys[0];
+
+		let out = RecUser::prepare_key_migration(
+			"12345",
+			&user.device_id,
+			user.user_id.clone(),
+			&[&user_key.group_key],
+			&user_key.group_key,
+			&user_key.sign_key,
+		)
+		.unwrap();
+		let out: UserKeyMigrationData = serde_json::from_str(&out).unwrap();
+		let rotation = out.key_rotation;
+
+		//the rec rotation is signed with the std sign key of the user
+		assert_eq!(rotation.signed_by_user_id.as_ref(), Some(&user.user_id));
+		assert_eq!(
+			rotation.signed_by_user_sign_key_id.as_ref(),
+			Some(&user_key.sign_key.key_id)
+		);
+		assert!(rotation.group_key_sig.is_some());
+
+		//a not migrated std device finishes the rotation with the previous std user key
+		let encrypted_ephemeral_key = Base64::decode_vec(&rotation.encrypted_ephemeral_key).unwrap();
+		let encrypted_ephemeral_key_by_device = user
+			.device_keys
+			.public_key
+			.key
+			.encrypt(&encrypted_ephemeral_key)
+			.unwrap();
+
+		let done_rotation = RecGroup::done_key_rotation(
+			&user.device_keys.private_key,
+			&user.device_keys.public_key,
+			&user_key.group_key,
+			KeyRotationInput {
+				encrypted_ephemeral_key_by_group_key_and_public_key: Base64::encode_string(&encrypted_ephemeral_key_by_device),
+				encrypted_group_key_by_ephemeral: rotation.encrypted_group_key_by_ephemeral,
+				ephemeral_alg: rotation.ephemeral_alg,
+				encrypted_eph_key_key_id: "".to_string(),
+				previous_group_key_id: rotation.previous_group_key_id,
+				time: 0,
+				new_group_key_id: "new_user_key".to_string(),
+				error: None,
+			},
+		)
+		.unwrap();
+		let done_rotation = DoneKeyRotationData::from_string(&done_rotation).unwrap();
+
+		//the new rec user key is decrypted with the std device key
+		let server_output = ServerOutput {
+			status: true,
+			err_msg: None,
+			err_code: None,
+			result: Some(GroupKeyServerOutput {
+				encrypted_group_key: done_rotation.encrypted_new_group_key,
+				group_key_alg: rotation.group_key_alg,
+				group_key_id: "new_user_key".to_string(),
+				encrypted_private_group_key: rotation.encrypted_private_group_key,
+				public_group_key: rotation.public_group_key,
+				keypair_encrypt_alg: rotation.keypair_encrypt_alg,
+				key_pair_id: "new_key_pair".to_string(),
+				user_public_key_id: done_rotation.public_key_id,
+				time: 0,
+				signed_by_user_id: None,
+				signed_by_user_sign_key_id: None,
+				group_key_sig: None,
+				encrypted_sign_key: rotation.encrypted_sign_key,
+				verify_key: rotation.verify_key,
+				keypair_sign_alg: rotation.keypair_sign_alg,
+				keypair_sign_id: Some("new_sign_key".to_string()),
+				public_key_sig: rotation.public_key_sig,
+				public_key_sig_key_id: Some("new_sign_key".to_string()),
+			}),
+		}
+		.to_string()
+		.unwrap();
+
+		//the std user can't decrypt the rec key, so traverse_keys must fall through to the rec user
+		assert!(done_key_fetch_exported::<StdUser>(
+			&user.device_keys.private_key,
+			&server_output,
+			SignaturePolicy::Lenient(None)
+		)
+		.is_err());
+
+		let new_user_key = traverse_keys!(
+			done_key_fetch_exported,
+			(
+				&user.device_keys.private_key,
+				&server_output,
+				SignaturePolicy::Lenient(None)
+			),
+			[StdUser, RecUser]
+		)
+		.unwrap();
+
+		assert_eq!(new_user_key.group_key_id, "new_user_key");
+
+		let new_user_key = traverse_keys!(
+			done_key_fetch_exported_with_policy,
+			(&user.device_keys.private_key, &server_output, SignaturePolicy::Lenient(None)),
+			[StdUser, RecUser],
+			policy: &CryptoPolicy::default()
+		)
+		.unwrap();
+
+		assert_eq!(new_user_key.group_key_id, "new_user_key");
+
+		//a policy error is returned and the key is not tried with the next user type
+		let policy = CryptoPolicy {
+			post_quantum: Some(&[]),
+			..Default::default()
+		};
+
+		let err = traverse_keys!(
+			done_key_fetch_exported_with_policy,
+			(&user.device_keys.private_key, &server_output, SignaturePolicy::Lenient(None)),
+			[StdUser, RecUser],
+			policy: &policy
+		);
+
+		assert!(matches!(err, Err(SdkError::Util(SdkUtilError::AlgNotPostQuantum(_)))));
+	}
 }
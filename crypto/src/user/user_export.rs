@@ -8,6 +8,7 @@ use sentc_crypto_std_keys::util::{MasterKeyFormat, PublicKey, SecretKey, SignKey
 use sentc_crypto_utils::user;
 use serde_json::from_str;
 
+use crate::crypto::{prepare_signature_policy, prepare_verify_key};
 use crate::entities::user::{UserDataExport, UserKeyDataExport};
 use crate::keys::std::StdUser;
 use crate::{group, SdkError};
@@ -120,7 +121,27 @@ pub fn done_key_fetch(private_key: &str, server_output: &str) -> Result<UserKeyD
 {
 	let private_key: SecretKey = private_key.parse()?;
 
-	let key = StdUser::done_key_fetch(&private_key, server_output)?;
+	let key = StdUser::done_key_fetch(&private_key, server_output, None::<&UserVerifyKeyData>)?;
+
+	Ok(key.try_into()?)
+}
+
+/**
+Like done_key_fetch but with a signature policy for the signature of the user group key, see prepare_signature_policy.
+ */
+pub fn done_key_fetch_with_policy(
+	private_key: &str,
+	server_output: &str,
+	verify_key: Option<&str>,
+	signature_policy: &str,
+) -> Result<UserKeyDataExport, String>
+{
+	let verify_key = prepare_verify_key(verify_key)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
+
+	let private_key: SecretKey = private_key.parse()?;
+
+	let key = StdUser::done_key_fetch(&private_key, server_output, policy)?;
 
 	Ok(key.try_into()?)
 }
@@ -145,6 +166,39 @@ pub fn change_password(
 	StdUser::change_password(old_pw, new_pw, server_output_prep_login, server_output_done_login)
 }
 
+pub fn register_with_recovery_key(user_identifier: &str, password: &str) -> Result<(String, String, String), String>
+{
+	Ok(StdUser::register_with_recovery_key(user_identifier, password)?)
+}
+
+pub fn create_recovery_key(
+	password: &str,
+	server_output_prep_login: &str,
+	server_output_done_login: DoneLoginServerOutput,
+) -> Result<(String, String), String>
+{
+	Ok(StdUser::create_recovery_key(
+		password,
+		server_output_prep_login,
+		server_output_done_login,
+	)?)
+}
+
+pub fn recover_with_recovery_key(
+	recovery_key: &str,
+	new_password: &str,
+	server_output_prep_recovery: &str,
+	encrypted_master_key: &str,
+) -> Result<String, String>
+{
+	Ok(StdUser::recover_with_recovery_key(
+		recovery_key,
+		new_password,
+		server_output_prep_recovery,
+		encrypted_master_key,
+	)?)
+}
+
 pub fn reset_password(new_password: &str, decrypted_private_key: &str, decrypted_sign_key: &str) -> Result<String, String>
 {
 	let decrypted_private_key: SecretKey = decrypted_private_key.parse()?;
@@ -173,6 +227,102 @@ pub fn create_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: O
 	)?)
 }
 
+pub fn create_numeric_safety_number(verify_key_1: &str, user_id_1: &str, verify_key_2: &str, user_id_2: &str) -> Result<String, String>
+{
+	let verify_key_1 = UserVerifyKeyData::from_string(verify_key_1).map_err(SdkError::JsonParseFailed)?;
+	let verify_key_2 = UserVerifyKeyData::from_string(verify_key_2).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::create_numeric_safety_number(
+		&verify_key_1,
+		user_id_1,
+		&verify_key_2,
+		user_id_2,
+	)?)
+}
+
+pub fn create_safety_number_qr(own_verify_key: &str, own_user_id: &str, other_verify_key: &str, other_user_id: &str) -> Result<Vec<u8>, String>
+{
+	let own_verify_key = UserVerifyKeyData::from_string(own_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let other_verify_key = UserVerifyKeyData::from_string(other_verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::create_safety_number_qr(
+		&own_verify_key,
+		own_user_id,
+		&other_verify_key,
+		other_user_id,
+	)?)
+}
+
+pub fn verify_safety_number_qr(
+	scanned_payload: &[u8],
+	own_verify_key: &str,
+	own_user_id: &str,
+	other_verify_key: &str,
+	other_user_id: &str,
+) -> Result<bool, String>
+{
+	let own_verify_key = UserVerifyKeyData::from_string(own_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let other_verify_key = UserVerifyKeyData::from_string(other_verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::verify_safety_number_qr(
+		scanned_payload,
+		&own_verify_key,
+		own_user_id,
+		&other_verify_key,
+		other_user_id,
+	)?)
+}
+
+/**
+Trust the new keys of a user after the identity changed.
+
+The store is the exported key pin store. Returns the new exported store.
+ */
+pub fn retrust_user_keys(
+	store: &str,
+	own_verify_key: &str,
+	own_user_id: &str,
+	other_verify_key: &str,
+	other_user_id: &str,
+	other_public_key: Option<&str>,
+	compared_safety_number: &str,
+) -> Result<String, String>
+{
+	let mut store = crate::key_pin::import_store(store)?;
+
+	let own_verify_key = UserVerifyKeyData::from_string(own_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let other_verify_key = UserVerifyKeyData::from_string(other_verify_key).map_err(SdkError::JsonParseFailed)?;
+	let other_public_key = match other_public_key {
+		Some(k) => Some(UserPublicKeyData::from_string(k).map_err(SdkError::JsonParseFailed)?),
+		None => None,
+	};
+
+	StdUser::retrust_user_keys(
+		&mut store,
+		&own_verify_key,
+		own_user_id,
+		&other_verify_key,
+		other_user_id,
+		other_public_key.as_ref(),
+		compared_safety_number,
+	)?;
+
+	Ok(store.to_string()?)
+}
+
+/**
+Sign the new verify key after a key rotation with the previous sign key.
+
+Other users need the signature to pin the new verify key.
+ */
+pub fn sign_verify_key(sign_key: &str, new_verify_key: &str) -> Result<String, String>
+{
+	let sign_key: SignKey = sign_key.parse()?;
+	let new_verify_key = UserVerifyKeyData::from_string(new_verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	Ok(StdUser::sign_verify_key(&sign_key, &new_verify_key)?)
+}
+
 pub fn verify_user_public_key(verify_key: &str, public_key: &str) -> Result<bool, String>
 {
 	let verify_key = UserVerifyKeyData::from_string(verify_key).map_err(SdkError::JsonParseFailed)?;
@@ -461,6 +611,70 @@ mod test
 		assert_ne!(number, number_3);
 	}
 
+	#[test]
+	fn test_safety_number_qr()
+	{
+		let user_1 = create_user_export();
+		let user_2 = create_user_export();
+
+		let number = create_numeric_safety_number(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		assert_eq!(number.len(), 60);
+
+		let payload = create_safety_number_qr(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		let verify = verify_safety_number_qr(
+			&payload,
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+		)
+		.unwrap();
+
+		assert!(verify);
+	}
+
+	#[test]
+	fn test_retrust_user_keys()
+	{
+		let user_1 = create_user_export();
+		let user_2 = create_user_export();
+
+		let number = create_numeric_safety_number(
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+		)
+		.unwrap();
+
+		let store = retrust_user_keys(
+			"",
+			&user_1.user_keys[0].exported_verify_key,
+			"abc1",
+			&user_2.user_keys[0].exported_verify_key,
+			"abc2",
+			None,
+			&number,
+		)
+		.unwrap();
+
+		crate::key_pin::check_verify_key_pin(&store, "abc2", &user_2.user_keys[0].exported_verify_key, None).unwrap();
+	}
+
 	#[test]
 	fn test_verify_public_key()
 	{
@@ -6,3 +6,57 @@ mod util_non_rust;
 
 #[cfg(feature = "export")]
 pub(crate) use self::util_non_rust::{export_core_sym_key_to_string, import_core_sym_key};
+
+/**
+Try a fn with the key types in order until one of them knows the alg of the key.
+
+Used while the keys of a user or group are migrated from one key family to another.
+
+With `policy: &crypto_policy` at the end, the crypto policy is passed as last arg to the fn, e.g. to done_key_fetch_exported_with_policy.
+A key of a not allowed algorithm is not tried with the next types.
+ */
+#[macro_export]
+macro_rules! traverse_keys {
+	// Policy form: pass the crypto policy as last arg to every try
+	($method:ident, ($($arg:expr),*), [$($t:ty),*], policy: $policy:expr) => {
+		$crate::traverse_keys!($method, ($($arg,)* $policy), [$($t),*])
+	};
+	// Base case: No more types to try, return an error
+	($method:ident, ($($arg:expr),*), []) => {
+		Err($crate::SdkError::AlgNotFound)
+	};
+	 // Recursive case: Try the first type, if it fails with AlgNotFound, try the next types
+	 // Crypto policy errors (AlgNotAllowed, AlgNotPostQuantum) are returned and not tried with the next types
+	($method:ident, ($($arg:expr),*), [$first:ty $(, $rest:ty)*]) => {
+		match $method::<$first>($($arg),*) {
+            Ok(val) => Ok(val),
+            Err(err) => match err {
+               $crate::SdkError::Util($crate::sdk_utils::error::SdkUtilError::Base($crate::sdk_core::Error::AlgNotFound)) |
+			   $crate::SdkError::Util($crate::sdk_utils::error::SdkUtilError::AlgNotFound) |
+			   $crate::SdkError::AlgNotFound => $crate::traverse_keys!($method, ($($arg),*), [$($rest),*]),
+                _ => Err(err),
+            }
+        }
+	};
+}
+
+/**
+Write the content into a temp file next to the path and rename it afterwards.
+
+A crash while writing leaves the old file and not a half written one.
+ */
+#[cfg(any(feature = "pin_store_file", feature = "key_store_file"))]
+pub(crate) fn write_file_atomic(path: &std::path::Path, content: &[u8]) -> std::io::Result<()>
+{
+	use std::io::Write;
+
+	let mut tmp = path.as_os_str().to_owned();
+	tmp.push(".tmp");
+	let tmp = std::path::PathBuf::from(tmp);
+
+	let mut file = std::fs::File::create(&tmp)?;
+	file.write_all(content)?;
+	file.sync_all()?;
+
+	std::fs::rename(&tmp, path)
+}
@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 
 use sentc_crypto_std_keys::util::SymmetricKey;
 
-use crate::crypto::{prepare_sign_key, prepare_verify_key};
+use crate::crypto::{prepare_sign_key, prepare_signature_policy, prepare_verify_key};
 use crate::keys::std::StdFileEncryptor;
 use crate::util::{export_core_sym_key_to_string, import_core_sym_key};
 
@@ -15,12 +15,38 @@ pub async fn download_and_decrypt_file_part_start(
 	content_key: &str,
 	verify_key_data: Option<&str>,
 ) -> Result<(Vec<u8>, String), String>
+{
+	download_and_decrypt_file_part_start_with_policy(
+		base_url,
+		url_prefix,
+		auth_token,
+		part_id,
+		content_key,
+		verify_key_data,
+		"lenient",
+	)
+	.await
+}
+
+/**
+Like download_and_decrypt_file_part_start but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub async fn download_and_decrypt_file_part_start_with_policy(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	part_id: &str,
+	content_key: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<(Vec<u8>, String), String>
 {
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 	let key: SymmetricKey = content_key.parse()?;
 
 	let (decrypted, next_key) =
-		StdFileEncryptor::download_and_decrypt_file_part_start(base_url, url_prefix, auth_token, part_id, &key, verify_key.as_ref()).await?;
+		StdFileEncryptor::download_and_decrypt_file_part_start(base_url, url_prefix, auth_token, part_id, &key, policy).await?;
 
 	let exported_file_key = export_core_sym_key_to_string(next_key)?;
 
@@ -35,12 +61,37 @@ pub async fn download_and_decrypt_file_part(
 	pre_key: &str,
 	verify_key_data: Option<&str>,
 ) -> Result<(Vec<u8>, String), String>
+{
+	download_and_decrypt_file_part_with_policy(
+		base_url,
+		url_prefix,
+		auth_token,
+		part_id,
+		pre_key,
+		verify_key_data,
+		"lenient",
+	)
+	.await
+}
+
+/**
+Like download_and_decrypt_file_part but with a signature policy for signed data, see prepare_signature_policy.
+ */
+pub async fn download_and_decrypt_file_part_with_policy(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: &str,
+	part_id: &str,
+	pre_key: &str,
+	verify_key_data: Option<&str>,
+	signature_policy: &str,
+) -> Result<(Vec<u8>, String), String>
 {
 	let verify_key = prepare_verify_key(verify_key_data)?;
+	let policy = prepare_signature_policy(verify_key.as_ref(), signature_policy)?;
 	let key = import_core_sym_key(pre_key)?;
 
-	let (decrypted, next_key) =
-		StdFileEncryptor::download_and_decrypt_file_part(base_url, url_prefix, auth_token, part_id, &key, verify_key.as_ref()).await?;
+	let (decrypted, next_key) = StdFileEncryptor::download_and_decrypt_file_part(base_url, url_prefix, auth_token, part_id, &key, policy).await?;
 
 	let exported_file_key = export_core_sym_key_to_string(next_key)?;
 
@@ -7,9 +7,8 @@ use alloc::vec::Vec;
 #[cfg(feature = "export")]
 pub use file_export::*;
 use sentc_crypto_common::file::{FileData, FilePartListItem};
-use sentc_crypto_common::user::UserVerifyKeyData;
 use sentc_crypto_core::cryptomat::{SymKey, SymKeyComposer, SymKeyGen};
-use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+use sentc_crypto_utils::cryptomat::{SignKWrapper, SignaturePolicy, SymKeyWrapper, VerifyKFromUserKeyWrapper};
 use sentc_crypto_utils::http::{make_req, make_req_buffer, make_req_buffer_body, HttpMethod};
 use sentc_crypto_utils::{handle_general_server_response, handle_server_response};
 
@@ -18,13 +17,13 @@ use crate::SdkError;
 
 impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserKeyWrapper> FileEncryptor<S, SC, SignK, VC>
 {
-	pub async fn download_and_decrypt_file_part_start(
+	pub async fn download_and_decrypt_file_part_start<'p>(
 		base_url: String,
 		url_prefix: Option<String>,
 		auth_token: &str,
 		part_id: &str,
 		content_key: &impl SymKeyWrapper,
-		verify_key_data: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<(Vec<u8>, SC::SymmetricKey), SdkError>
 	{
 		let url_prefix = match url_prefix {
@@ -37,16 +36,16 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 		let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
 
 		//decrypt the part
-		Self::decrypt_file_part_start(content_key, &res, verify_key_data)
+		Self::decrypt_file_part_start(content_key, &res, policy)
 	}
 
-	pub async fn download_and_decrypt_file_part(
+	pub async fn download_and_decrypt_file_part<'p>(
 		base_url: String,
 		url_prefix: Option<String>,
 		auth_token: &str,
 		part_id: &str,
 		pre_key: &impl SymKey,
-		verify_key_data: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<(Vec<u8>, SC::SymmetricKey), SdkError>
 	{
 		let url_prefix = match url_prefix {
@@ -59,7 +58,7 @@ impl<S: SymKeyGen, SC: SymKeyComposer, SignK: SignKWrapper, VC: VerifyKFromUserK
 		let res = make_req_buffer(HttpMethod::GET, &url, auth_token, None, None, None).await?;
 
 		//decrypt the part
-		Self::decrypt_file_part(pre_key, &res, verify_key_data)
+		Self::decrypt_file_part(pre_key, &res, policy)
 	}
 
 	//______________________________________________________________________________________________
@@ -7,6 +7,8 @@ use alloc::vec::Vec;
 
 #[cfg(feature = "export")]
 pub use group_export::*;
+#[cfg(not(feature = "export"))]
+use sentc_crypto_common::crypto::EncryptedHead;
 use sentc_crypto_common::group::{
 	GroupAcceptJoinReqServerOutput,
 	GroupChildrenList,
@@ -29,6 +31,7 @@ use sentc_crypto_utils::cryptomat::{
 	SearchableKeyComposerWrapper,
 	SignComposerWrapper,
 	SignKeyPairWrapper,
+	SignaturePolicy,
 	SkWrapper,
 	SortableKeyComposerWrapper,
 	StaticKeyComposerWrapper,
@@ -39,8 +42,12 @@ use sentc_crypto_utils::cryptomat::{
 	VerifyKFromUserKeyWrapper,
 };
 use sentc_crypto_utils::http::{make_req, HttpMethod};
+#[cfg(not(feature = "export"))]
+use sentc_crypto_utils::policy::CryptoPolicy;
 use sentc_crypto_utils::{handle_general_server_response, handle_server_response};
 
+#[cfg(not(feature = "export"))]
+use crate::group::GroupKeyResolver;
 use crate::group::{
 	check_create_sub_group,
 	check_delete_user_rank,
@@ -450,6 +457,83 @@ where
 
 		Ok(handle_general_server_response(&res)?)
 	}
+
+	/**
+	Get the key of the encrypted head and fetch it from the server if it is not in the resolver.
+
+	Every algorithm must be allowed by the crypto policy.
+	 */
+	#[cfg(not(feature = "export"))]
+	pub async fn resolve_key_req<'a, 'k>(
+		base_url: String,
+		auth_token: &str,
+		jwt: &str,
+		resolver: &'a mut GroupKeyResolver<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>,
+		head: &EncryptedHead,
+		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'k>>,
+		crypto_policy: &CryptoPolicy,
+		group_as_member: Option<&str>,
+	) -> Result<&'a SC::SymmetricKeyWrapper, SdkError>
+	{
+		let server_output = match resolver.missing_key_id(head) {
+			Some(key_id) => {
+				Some(
+					get_group_key(
+						base_url,
+						auth_token,
+						jwt,
+						resolver.group_id(),
+						key_id,
+						group_as_member,
+					)
+					.await?,
+				)
+			},
+			None => None,
+		};
+
+		Self::resolve_key(resolver, head, private_key, key_policy, crypto_policy, |_, key_id| {
+			server_output.ok_or_else(|| SdkError::GroupKeyNotFound(key_id.to_string()))
+		})
+	}
+
+	/**
+	Decrypt the data with the key of the head and fetch the key from the server if it is not in the resolver.
+	 */
+	#[cfg(not(feature = "export"))]
+	pub async fn decrypt_auto_req<'k, 'p>(
+		base_url: String,
+		auth_token: &str,
+		jwt: &str,
+		resolver: &mut GroupKeyResolver<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper>,
+		encrypted_data_with_head: &[u8],
+		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'k>>,
+		data_policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+		group_as_member: Option<&str>,
+	) -> Result<Vec<u8>, SdkError>
+	{
+		let (head, encrypted_data) = crate::crypto::crypto::split_head_and_encrypted_data::<EncryptedHead>(encrypted_data_with_head)?;
+
+		let key = Self::resolve_key_req(
+			base_url,
+			auth_token,
+			jwt,
+			resolver,
+			&head,
+			private_key,
+			key_policy,
+			crypto_policy,
+			group_as_member,
+		)
+		.await?;
+
+		crypto_policy.check_head(&head)?;
+
+		Ok(key.decrypt_raw(encrypted_data, &head, data_policy)?)
+	}
 }
 
 //__________________________________________________________________________________________________
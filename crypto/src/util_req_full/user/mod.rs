@@ -1,11 +1,11 @@
 #[cfg(feature = "export")]
 mod user_export;
 
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::future::Future;
 
-use sentc_crypto_common::user::{OtpRecoveryKeysOutput, OtpRegister, UserDeviceList, UserInitServerOutput, UserPublicKeyData};
+use sentc_crypto_common::user::{OtpRecoveryKeysOutput, OtpRegister, UserDeviceList, UserInitServerOutput, UserPublicKeyData, UserVerifyKeyData};
 use sentc_crypto_core::cryptomat::{DeriveMasterKeyForAuth, PwHash, SearchableKeyGen, SortableKeyGen};
 use sentc_crypto_utils::cryptomat::{
 	PkFromUserKeyWrapper,
@@ -14,6 +14,7 @@ use sentc_crypto_utils::cryptomat::{
 	SignComposerWrapper,
 	SignKWrapper,
 	SignKeyPairWrapper,
+	SignaturePolicy,
 	SkWrapper,
 	SortableKeyComposerWrapper,
 	StaticKeyComposerWrapper,
@@ -30,11 +31,14 @@ use sentc_crypto_utils::{handle_general_server_response, handle_server_response}
 #[cfg(feature = "export")]
 pub use user_export::*;
 
+use crate::entities::key_transparency::{KeyLogProofServerOutput, KeyLogTreeHead};
 use crate::entities::user::{UserDataInt, UserKeyDataInt};
 use crate::group::Group;
+#[cfg(not(feature = "export"))]
+use crate::key_pin::KeyPinStore;
 use crate::user::User;
 use crate::util_req_full::SessionKind;
-use crate::SdkError;
+use crate::{key_transparency, SdkError};
 
 #[allow(clippy::large_enum_variant)]
 pub enum PreLoginOut<S: SymKeyWrapper, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper, DMK: DeriveMasterKeyForAuth>
@@ -222,19 +226,22 @@ where
 	}
 
 	/// If there are more than 50 user keys, fetch the next 50 keys here and so on.
-	pub async fn fetch_user_key(
+	///
+	/// The key policy is checked against the signature of the user group key, see User::done_key_fetch.
+	pub async fn fetch_user_key<'p>(
 		base_url: String,
 		auth_token: &str,
 		jwt: &str,
 		key_id: &str,
 		private_key: &impl SkWrapper,
+		key_policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<UserKeyDataInt<SC::SymmetricKeyWrapper, StC::SkWrapper, StC::PkWrapper, SignC::SignKWrapper, SignC::VerifyKWrapper>, SdkError>
 	{
 		let url = base_url + "/api/v1/user/user_keys/key/" + key_id;
 
 		let server_out = auth_req(HttpMethod::GET, &url, auth_token, None, jwt).await?;
 
-		let keys = Self::done_key_fetch(private_key, &server_out)?;
+		let keys = Self::done_key_fetch(private_key, &server_out, key_policy)?;
 
 		Ok(keys)
 	}
@@ -372,6 +379,66 @@ where
 		)
 		.await
 	}
+
+	/**
+	Fetch the public key of a user and check it against the pinned public key of this user.
+
+	The verify key that signed the public key is fetched and checked against the pins too.
+	The keys of a new user are pinned on first use.
+	A public key with a new key id must be signed by a pinned verify key, otherwise KeyPinIdentityChanged is returned.
+	 */
+	#[cfg(not(feature = "export"))]
+	pub async fn fetch_user_public_key_pinned<S: KeyPinStore>(base_url: String, auth_token: &str, user_id: &str, store: &mut S) -> UserPublicKeyRes
+	{
+		let public_key = fetch_user_public_key(base_url.clone(), auth_token, user_id).await?;
+
+		let verify_key = match &public_key.public_key_sig_key_id {
+			Some(id) => Some(fetch_user_verify_key_by_id(base_url, auth_token, user_id, id).await?),
+			None => None,
+		};
+
+		Self::check_public_key_pin(store, user_id, &public_key, verify_key.as_ref())?;
+
+		Ok(public_key)
+	}
+
+	/**
+	Fetch a verify key of a user and check it against the pinned verify key of this user.
+
+	signed_by is the id of the previous verify key of the user and the signature of the new verify key (see User::sign_verify_key).
+	A verify key with a new key id must be signed by a pinned verify key, otherwise KeyPinIdentityChanged is returned.
+	 */
+	#[cfg(not(feature = "export"))]
+	pub async fn fetch_user_verify_key_by_id_pinned<S: KeyPinStore>(
+		base_url: String,
+		auth_token: &str,
+		user_id: &str,
+		verify_key_id: &str,
+		signed_by: Option<(&str, &str)>,
+		store: &mut S,
+	) -> UserVerifyKeyRes
+	{
+		let verify_key = fetch_user_verify_key_by_id(base_url.clone(), auth_token, user_id, verify_key_id).await?;
+
+		let signed_by = match signed_by {
+			Some((id, sig)) => {
+				Some((
+					fetch_user_verify_key_by_id(base_url, auth_token, user_id, id).await?,
+					sig,
+				))
+			},
+			None => None,
+		};
+
+		Self::check_verify_key_pin(
+			store,
+			user_id,
+			&verify_key,
+			signed_by.as_ref().map(|(k, sig)| (k, *sig)),
+		)?;
+
+		Ok(verify_key)
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -552,6 +619,281 @@ pub async fn fetch_user_verify_key_by_id(base_url: String, auth_token: &str, use
 	Ok(public_data)
 }
 
+/**
+Fetch the public key of a user and check it against the pinned public key of this user.
+
+The verify key that signed the public key is fetched and checked against the pins too.
+The store is the exported key pin store. Returns the public key, the public key id, the sign key id and the new exported store.
+ */
+#[cfg(feature = "export")]
+pub async fn fetch_user_public_key_pinned(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	store: &str,
+) -> Result<
+	(
+		String,
+		sentc_crypto_common::EncryptionKeyPairId,
+		Option<sentc_crypto_common::SignKeyPairId>,
+		String,
+	),
+	String,
+>
+{
+	let (public_key, public_key_id, sign_key_id) = fetch_user_public_key(base_url.clone(), auth_token, user_id).await?;
+
+	let verify_key = match &sign_key_id {
+		Some(id) => Some(fetch_user_verify_key_by_id(base_url, auth_token, user_id, id).await?),
+		None => None,
+	};
+
+	let store = crate::key_pin::check_public_key_pin(store, user_id, &public_key, verify_key.as_deref())?;
+
+	Ok((public_key, public_key_id, sign_key_id, store))
+}
+
+/**
+Fetch a verify key of a user and check it against the pinned verify key of this user.
+
+signed_by is the id of the previous verify key of the user and the signature of the new verify key.
+The store is the exported key pin store. Returns the verify key and the new exported store.
+ */
+#[cfg(feature = "export")]
+pub async fn fetch_user_verify_key_by_id_pinned(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	verify_key_id: &str,
+	signed_by: Option<(&str, &str)>,
+	store: &str,
+) -> Result<(String, String), String>
+{
+	let verify_key = fetch_user_verify_key_by_id(base_url.clone(), auth_token, user_id, verify_key_id).await?;
+
+	let signed_by = match signed_by {
+		Some((id, sig)) => {
+			Some((
+				fetch_user_verify_key_by_id(base_url, auth_token, user_id, id).await?,
+				sig,
+			))
+		},
+		None => None,
+	};
+
+	let store = crate::key_pin::check_verify_key_pin(
+		store,
+		user_id,
+		&verify_key,
+		signed_by.as_ref().map(|(k, sig)| (k.as_str(), *sig)),
+	)?;
+
+	Ok((verify_key, store))
+}
+
+async fn fetch_key_log_proof<V: VerifyKFromUserKeyWrapper>(
+	url: String,
+	auth_token: &str,
+	leaf_hash: &[u8],
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<KeyLogTreeHead, SdkError>
+{
+	let from = last_tree_head.map(|h| h.tree_size).unwrap_or(0);
+
+	let url = url + "/log_proof?from=" + from.to_string().as_str();
+
+	let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+	let proof: KeyLogProofServerOutput = handle_server_response(&res)?;
+
+	key_transparency::verify_key_log_proof::<V>(leaf_hash, &proof, last_tree_head, log_verify_key)
+}
+
+async fn fetch_user_public_key_verified_int<V: VerifyKFromUserKeyWrapper>(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<(UserPublicKeyData, KeyLogTreeHead), SdkError>
+{
+	let url = base_url.clone() + "/api/v1/user/" + user_id + "/public_key";
+
+	let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+	let public_key = crate::util::public::import_public_key_from_string_into_format(res.as_str())?;
+
+	let leaf = key_transparency::public_key_log_leaf(user_id, &public_key);
+
+	let url = base_url + "/api/v1/user/" + user_id + "/public_key/" + &public_key.public_key_id;
+
+	let tree_head = fetch_key_log_proof::<V>(url, auth_token, &leaf, last_tree_head, log_verify_key).await?;
+
+	Ok((public_key, tree_head))
+}
+
+async fn fetch_user_verify_key_by_id_verified_int<V: VerifyKFromUserKeyWrapper>(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	verify_key_id: &str,
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<(UserVerifyKeyData, KeyLogTreeHead), SdkError>
+{
+	let url = base_url + "/api/v1/user/" + user_id + "/verify_key/" + verify_key_id;
+
+	let res = non_auth_req(HttpMethod::GET, url.as_str(), auth_token, None).await?;
+
+	let verify_key = crate::util::public::import_verify_key_from_string_into_format(res.as_str())?;
+
+	let leaf = key_transparency::verify_key_log_leaf(user_id, &verify_key);
+
+	let tree_head = fetch_key_log_proof::<V>(url, auth_token, &leaf, last_tree_head, log_verify_key).await?;
+
+	Ok((verify_key, tree_head))
+}
+
+/**
+Fetch the public key of a user and check that it is in the key transparency log.
+
+The last tree head is the tree head of the last successful verification. Store the returned tree head for the next fetch.
+Only the first fetch has no last tree head, see the key_transparency mod for the caveat of it.
+The tree head must be signed by the log verify key, which the app must know without asking the server.
+Keys that are missing in the log are refused.
+ */
+#[cfg(not(feature = "export"))]
+pub async fn fetch_user_public_key_verified<V: VerifyKFromUserKeyWrapper>(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<(UserPublicKeyData, KeyLogTreeHead), SdkError>
+{
+	fetch_user_public_key_verified_int::<V>(base_url, auth_token, user_id, last_tree_head, log_verify_key).await
+}
+
+/**
+Fetch the verify key of a user and check that it is in the key transparency log.
+
+The last tree head is the tree head of the last successful verification. Store the returned tree head for the next fetch.
+Only the first fetch has no last tree head, see the key_transparency mod for the caveat of it.
+The tree head must be signed by the log verify key, which the app must know without asking the server.
+Keys that are missing in the log are refused.
+ */
+#[cfg(not(feature = "export"))]
+pub async fn fetch_user_verify_key_by_id_verified<V: VerifyKFromUserKeyWrapper>(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	verify_key_id: &str,
+	last_tree_head: Option<&KeyLogTreeHead>,
+	log_verify_key: &UserVerifyKeyData,
+) -> Result<(UserVerifyKeyData, KeyLogTreeHead), SdkError>
+{
+	fetch_user_verify_key_by_id_verified_int::<V>(
+		base_url,
+		auth_token,
+		user_id,
+		verify_key_id,
+		last_tree_head,
+		log_verify_key,
+	)
+	.await
+}
+
+/**
+Fetch the public key of a user and check that it is in the key transparency log.
+
+The last tree head, the log verify key and the returned tree head are json strings.
+
+Returns the public key, the public key id, the sign key id and the new tree head.
+ */
+#[cfg(feature = "export")]
+pub async fn fetch_user_public_key_verified(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	last_tree_head: Option<&str>,
+	log_verify_key: &str,
+) -> Result<
+	(
+		String,
+		sentc_crypto_common::EncryptionKeyPairId,
+		Option<sentc_crypto_common::SignKeyPairId>,
+		String,
+	),
+	String,
+>
+{
+	let last_tree_head: Option<KeyLogTreeHead> = match last_tree_head {
+		Some(h) => Some(serde_json::from_str(h).map_err(SdkError::JsonParseFailed)?),
+		None => None,
+	};
+
+	let log_verify_key = UserVerifyKeyData::from_string(log_verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	let (public_key, tree_head) = fetch_user_public_key_verified_int::<sentc_crypto_std_keys::util::VerifyKey>(
+		base_url,
+		auth_token,
+		user_id,
+		last_tree_head.as_ref(),
+		&log_verify_key,
+	)
+	.await?;
+
+	Ok((
+		public_key
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)?,
+		public_key.public_key_id,
+		public_key.public_key_sig_key_id,
+		serde_json::to_string(&tree_head).map_err(|_| SdkError::JsonToStringFailed)?,
+	))
+}
+
+/**
+Fetch the verify key of a user and check that it is in the key transparency log.
+
+The last tree head, the log verify key and the returned tree head are json strings.
+ */
+#[cfg(feature = "export")]
+pub async fn fetch_user_verify_key_by_id_verified(
+	base_url: String,
+	auth_token: &str,
+	user_id: &str,
+	verify_key_id: &str,
+	last_tree_head: Option<&str>,
+	log_verify_key: &str,
+) -> Result<(String, String), String>
+{
+	let last_tree_head: Option<KeyLogTreeHead> = match last_tree_head {
+		Some(h) => Some(serde_json::from_str(h).map_err(SdkError::JsonParseFailed)?),
+		None => None,
+	};
+
+	let log_verify_key = UserVerifyKeyData::from_string(log_verify_key).map_err(SdkError::JsonParseFailed)?;
+
+	let (verify_key, tree_head) = fetch_user_verify_key_by_id_verified_int::<sentc_crypto_std_keys::util::VerifyKey>(
+		base_url,
+		auth_token,
+		user_id,
+		verify_key_id,
+		last_tree_head.as_ref(),
+		&log_verify_key,
+	)
+	.await?;
+
+	Ok((
+		verify_key
+			.to_string()
+			.map_err(|_| SdkError::JsonToStringFailed)?,
+		serde_json::to_string(&tree_head).map_err(|_| SdkError::JsonToStringFailed)?,
+	))
+}
+
 //__________________________________________________________________________________________________
 
 pub fn prepare_done_key_rotation<'a>(base_url: String, auth_token: &'a str, jwt: &'a str) -> impl Future<Output = super::group::KeyRotationRes> + 'a
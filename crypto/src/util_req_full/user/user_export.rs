@@ -1,6 +1,7 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use sentc_crypto_common::user::UserVerifyKeyData;
 use sentc_crypto_std_keys::util::{SecretKey, SignKey, SymKeyFormatExport, SymmetricKey};
 use sentc_crypto_utils::cryptomat::{PkWrapper, SignKWrapper, SkWrapper, SymKeyWrapper, VerifyKWrapper};
 use serde_json::from_str;
@@ -153,11 +154,16 @@ pub async fn fetch_user_key(base_url: String, auth_token: &str, jwt: &str, key_i
 {
 	let private_key: SecretKey = private_key.parse()?;
 
-	Ok(
-		StdUser::fetch_user_key(base_url, auth_token, jwt, key_id, &private_key)
-			.await?
-			.try_into()?,
+	Ok(StdUser::fetch_user_key(
+		base_url,
+		auth_token,
+		jwt,
+		key_id,
+		&private_key,
+		None::<&UserVerifyKeyData>,
 	)
+	.await?
+	.try_into()?)
 }
 
 //__________________________________________________________________________________________________
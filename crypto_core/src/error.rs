@@ -31,4 +31,21 @@ pub enum Error
 	OpeRangeError,
 	OpeHdgInvalidInputs,
 	OpeStringToLarge,
+
+	SafetyNumberPayloadInvalid,
+	SafetyNumberVersionMismatch,
+
+	RecoveryKeyInvalidFormat,
+
+	ShamirInvalidThreshold,
+	ShamirNotEnoughShares,
+	ShamirInvalidShare,
+
+	Pkcs11TokenNotFound,
+	Pkcs11KeyNotFound,
+	Pkcs11Failed,
+
+	AgeInvalidFormat,
+	AgeNoIdentityMatched,
+	AgeHeaderMacInvalid,
 }
@@ -23,6 +23,7 @@ extern crate alloc;
 pub mod cryptomat;
 mod error;
 pub mod group;
+pub mod shamir;
 pub mod user;
 
 use rand_core::{CryptoRng, OsRng, RngCore};
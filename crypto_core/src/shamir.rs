@@ -0,0 +1,172 @@
+//! Shamir secret sharing over GF(256).
+//!
+//! Every byte of the secret is split with its own random polynomial of degree threshold - 1.
+//! A share is the threshold, the x coordinate and one y value per byte of the secret.
+//! Any threshold shares can rebuild the secret, fewer shares reveal nothing about it.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+
+use crate::error::Error;
+use crate::get_rand;
+
+//the threshold and the x coordinate
+const SHARE_PREFIX_LEN: usize = 2;
+
+//multiplication in GF(2^8) with the aes polynomial, without branches on the data
+fn gf_mul(mut a: u8, mut b: u8) -> u8
+{
+	let mut out = 0u8;
+
+	for _ in 0..8 {
+		out ^= a & 0u8.wrapping_sub(b & 1);
+
+		let carry = 0u8.wrapping_sub(a >> 7);
+		a = (a << 1) ^ (0x1b & carry);
+		b >>= 1;
+	}
+
+	out
+}
+
+//a^254 = a^-1
+fn gf_inv(a: u8) -> u8
+{
+	let mut out = 1u8;
+	let mut base = a;
+	let mut exp = 254u8;
+
+	while exp > 0 {
+		if exp & 1 == 1 {
+			out = gf_mul(out, base);
+		}
+
+		base = gf_mul(base, base);
+		exp >>= 1;
+	}
+
+	out
+}
+
+/**
+Split the secret into share_count shares. Any threshold shares can rebuild the secret.
+
+The threshold must be at least 1 and not bigger than the share count.
+ */
+pub fn split_secret(secret: &[u8], threshold: u8, share_count: u8) -> Result<Vec<Vec<u8>>, Error>
+{
+	if threshold == 0 || threshold > share_count || secret.is_empty() {
+		return Err(Error::ShamirInvalidThreshold);
+	}
+
+	let mut rng = get_rand();
+
+	let mut shares: Vec<Vec<u8>> = (1..=share_count)
+		.map(|x| {
+			let mut share = Vec::with_capacity(SHARE_PREFIX_LEN + secret.len());
+			share.push(threshold);
+			share.push(x);
+			share
+		})
+		.collect();
+
+	let mut coefficients = vec![0u8; threshold as usize];
+
+	for byte in secret {
+		coefficients[0] = *byte;
+
+		rng.try_fill_bytes(&mut coefficients[1..])
+			.map_err(|_| Error::KeyCreationFailed)?;
+
+		for share in shares.iter_mut() {
+			let x = share[1];
+
+			//horner
+			let mut y = 0u8;
+
+			for c in coefficients.iter().rev() {
+				y = gf_mul(y, x) ^ c;
+			}
+
+			share.push(y);
+		}
+	}
+
+	Ok(shares)
+}
+
+/**
+Rebuild the secret from the shares.
+
+Fails if there are fewer shares than the threshold of the shares or if the shares don't belong together.
+ */
+pub fn combine_shares<S: AsRef<[u8]>>(shares: &[S]) -> Result<Vec<u8>, Error>
+{
+	let first = shares.first().ok_or(Error::ShamirNotEnoughShares)?.as_ref();
+
+	if first.len() <= SHARE_PREFIX_LEN {
+		return Err(Error::ShamirInvalidShare);
+	}
+
+	let threshold = first[0] as usize;
+	let len = first.len();
+
+	if threshold == 0 {
+		return Err(Error::ShamirInvalidShare);
+	}
+
+	if shares.len() < threshold {
+		return Err(Error::ShamirNotEnoughShares);
+	}
+
+	//only the first threshold shares are needed
+	let shares: Vec<&[u8]> = shares[..threshold].iter().map(|s| s.as_ref()).collect();
+
+	for (i, share) in shares.iter().enumerate() {
+		if share.len() != len || share[0] as usize != threshold || share[1] == 0 {
+			return Err(Error::ShamirInvalidShare);
+		}
+
+		if shares[..i].iter().any(|s| s[1] == share[1]) {
+			return Err(Error::ShamirInvalidShare);
+		}
+	}
+
+	//the lagrange basis at x = 0
+	let basis: Vec<u8> = shares
+		.iter()
+		.map(|share| {
+			let x_j = share[1];
+
+			let mut num = 1u8;
+			let mut den = 1u8;
+
+			for other in shares.iter() {
+				let x_m = other[1];
+
+				if x_m != x_j {
+					num = gf_mul(num, x_m);
+					den = gf_mul(den, x_m ^ x_j);
+				}
+			}
+
+			gf_mul(num, gf_inv(den))
+		})
+		.collect();
+
+	let mut secret = Vec::with_capacity(len - SHARE_PREFIX_LEN);
+
+	for i in SHARE_PREFIX_LEN..len {
+		let mut byte = 0u8;
+
+		for (share, b) in shares.iter().zip(basis.iter()) {
+			byte ^= gf_mul(share[i], *b);
+		}
+
+		secret.push(byte);
+	}
+
+	Ok(secret)
+}
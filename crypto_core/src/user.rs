@@ -1,6 +1,9 @@
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
 
-use sha2::{Digest, Sha256};
+use rand_core::RngCore;
+use sha2::{Digest, Sha256, Sha512};
 
 use crate::cryptomat::{
 	ClientRandomValue,
@@ -16,10 +19,12 @@ use crate::cryptomat::{
 	Sk,
 	SkComposer,
 	StaticKeyPair,
+	SymKey,
 	SymKeyGen,
 	VerifyK,
 };
 use crate::error::Error;
+use crate::get_rand;
 
 pub struct RegisterOutPut<P: Pk, V: VerifyK, CRV: ClientRandomValue, HAK: HashedAuthenticationKey>
 {
@@ -70,6 +75,19 @@ pub struct ResetPasswordOutput<CRV: ClientRandomValue, HAK: HashedAuthentication
 	pub encrypted_sign_key: Vec<u8>,
 }
 
+pub struct RecoveryKeyOutput<CRV: ClientRandomValue, HAK: HashedAuthenticationKey>
+{
+	//the key for the user, only show it once and never send it to the server
+	pub recovery_key: String,
+
+	//like the password derived data but from the recovery key
+	pub client_random_value: CRV,
+	pub hashed_authentication_key_bytes: HAK,
+	pub encrypted_master_key: Vec<u8>,
+	pub encrypted_master_key_alg: &'static str,
+	pub derived_alg: &'static str,
+}
+
 pub struct PrepareLoginOutput<DMK: DeriveMasterKeyForAuth, DAK: DeriveAuthKeyForAuth>
 {
 	pub master_key_encryption_key: DMK,
@@ -100,6 +118,46 @@ Then login again and encrypt everything what was encrypted by the old keys with
 pub fn register<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
 	password: &str,
 ) -> Result<RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>, Error>
+{
+	let (out, _) = register_internally::<S, St, Sign, H>(password)?;
+
+	Ok(out)
+}
+
+/**
+# Register a new user with a recovery key
+
+Like register but creates a recovery key for the master key too.
+With the recovery key the user can set a new password without losing the key pairs (see recover_with_recovery_key).
+*/
+#[allow(clippy::type_complexity)]
+pub fn register_with_recovery_key<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
+	password: &str,
+) -> Result<
+	(
+		RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>,
+		RecoveryKeyOutput<H::CRV, H::HAK>,
+	),
+	Error,
+>
+{
+	let (out, master_key) = register_internally::<S, St, Sign, H>(password)?;
+
+	let recovery = recovery_key_for_master_key::<H>(&master_key)?;
+
+	Ok((out, recovery))
+}
+
+#[allow(clippy::type_complexity)]
+fn register_internally<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
+	password: &str,
+) -> Result<
+	(
+		RegisterOutPut<St::PublicKey, Sign::VerifyKey, H::CRV, H::HAK>,
+		S::SymmetricKey,
+	),
+	Error,
+>
 {
 	//1. create master key
 	let master_key = S::generate()?;
@@ -116,20 +174,23 @@ pub fn register<S: SymKeyGen, St: StaticKeyPair, Sign: SignKeyPair, H: PwHash>(
 	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
 		H::derived_keys_from_password(password.as_bytes(), &master_key, None)?;
 
-	Ok(RegisterOutPut {
-		master_key_alg: master_key.get_alg_str(),
-		derived_alg: client_random_value.get_alg_str(),
-		client_random_value,
-		hashed_authentication_key_bytes,
-		encrypted_master_key,
-		encrypted_master_key_alg,
-		encrypted_sign_key,
-		verify_key,
-		keypair_sign_alg: sign_k.get_alg_str(),
-		encrypted_private_key,
-		public_key,
-		keypair_encrypt_alg: sk.get_alg_str(),
-	})
+	Ok((
+		RegisterOutPut {
+			master_key_alg: master_key.get_alg_str(),
+			derived_alg: client_random_value.get_alg_str(),
+			client_random_value,
+			hashed_authentication_key_bytes,
+			encrypted_master_key,
+			encrypted_master_key_alg,
+			encrypted_sign_key,
+			verify_key,
+			keypair_sign_alg: sign_k.get_alg_str(),
+			encrypted_private_key,
+			public_key,
+			keypair_encrypt_alg: sk.get_alg_str(),
+		},
+		master_key,
+	))
 }
 
 /**
@@ -266,6 +327,182 @@ pub fn password_reset<S: SymKeyGen, H: PwHash>(
 	})
 }
 
+const RECOVERY_KEY_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+//160 bits, encoded as 32 chars in 8 groups
+const RECOVERY_KEY_BYTES: usize = 20;
+const RECOVERY_KEY_CHARS: usize = 32;
+const RECOVERY_KEY_GROUP: usize = 4;
+
+/**
+Generates a new recovery key.
+
+The key is encoded in crockford base32 with groups of 4 chars, e.g. `K3QF-9T0A-...`, so it can be written down and typed in.
+ */
+pub fn generate_recovery_key() -> Result<String, Error>
+{
+	let mut bytes = [0u8; RECOVERY_KEY_BYTES];
+
+	get_rand()
+		.try_fill_bytes(&mut bytes)
+		.map_err(|_| Error::KeyCreationFailed)?;
+
+	recovery_key_from_bytes(&bytes)
+}
+
+/**
+Encodes the bytes of a recovery key (e.g. after rebuilding it from shares) back to the recovery key.
+ */
+pub fn recovery_key_from_bytes(bytes: &[u8]) -> Result<String, Error>
+{
+	if bytes.len() != RECOVERY_KEY_BYTES {
+		return Err(Error::RecoveryKeyInvalidFormat);
+	}
+
+	let mut out = String::with_capacity(RECOVERY_KEY_CHARS + RECOVERY_KEY_CHARS / RECOVERY_KEY_GROUP);
+
+	let mut buffer = 0u32;
+	let mut bits = 0;
+	let mut chars = 0;
+
+	for b in bytes {
+		buffer = (buffer << 8) | *b as u32;
+		bits += 8;
+
+		while bits >= 5 {
+			bits -= 5;
+
+			if chars > 0 && chars % RECOVERY_KEY_GROUP == 0 {
+				out.push('-');
+			}
+
+			out.push(RECOVERY_KEY_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+			chars += 1;
+		}
+	}
+
+	Ok(out)
+}
+
+/**
+Decodes a recovery key which was typed in by the user.
+
+The key is case-insensitive, dashes and spaces are ignored and the chars O, I and L are read as 0 and 1.
+ */
+pub fn recovery_key_to_bytes(recovery_key: &str) -> Result<Vec<u8>, Error>
+{
+	let mut out = Vec::with_capacity(RECOVERY_KEY_BYTES);
+
+	let mut buffer = 0u32;
+	let mut bits = 0;
+	let mut chars = 0;
+
+	for c in recovery_key.chars() {
+		let c = match c.to_ascii_uppercase() {
+			'-' | ' ' => continue,
+			'O' => '0',
+			'I' | 'L' => '1',
+			c => c,
+		};
+
+		let value = RECOVERY_KEY_ALPHABET
+			.iter()
+			.position(|a| *a as char == c)
+			.ok_or(Error::RecoveryKeyInvalidFormat)?;
+
+		buffer = (buffer << 5) | value as u32;
+		bits += 5;
+		chars += 1;
+
+		if bits >= 8 {
+			bits -= 8;
+			out.push((buffer >> bits) as u8);
+		}
+	}
+
+	if chars != RECOVERY_KEY_CHARS {
+		return Err(Error::RecoveryKeyInvalidFormat);
+	}
+
+	Ok(out)
+}
+
+fn recovery_key_for_master_key<H: PwHash>(master_key: &impl SymKey) -> Result<RecoveryKeyOutput<H::CRV, H::HAK>, Error>
+{
+	let recovery_key = generate_recovery_key()?;
+	let recovery_key_bytes = recovery_key_to_bytes(&recovery_key)?;
+
+	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
+		H::derived_keys_from_password(&recovery_key_bytes, master_key, None)?;
+
+	Ok(RecoveryKeyOutput {
+		recovery_key,
+		derived_alg: client_random_value.get_alg_str(),
+		client_random_value,
+		hashed_authentication_key_bytes,
+		encrypted_master_key,
+		encrypted_master_key_alg,
+	})
+}
+
+/**
+# Create a recovery key for an existing user
+
+The master key is decrypted with the password like in change_password.
+A new recovery key replaces the old one.
+*/
+pub fn create_recovery_key<H: PwHash>(
+	password: &str,
+	salt: &[u8],
+	encrypted_master_key: &[u8],
+	derived_encryption_key_alg: &str,
+) -> Result<RecoveryKeyOutput<H::CRV, H::HAK>, Error>
+{
+	let prepare_login_output = prepare_login::<H>(password, salt, derived_encryption_key_alg)?;
+
+	let master_key = prepare_login_output
+		.master_key_encryption_key
+		.get_master_key(encrypted_master_key)?;
+
+	recovery_key_for_master_key::<H>(&master_key)
+}
+
+/**
+# Set a new password with the recovery key
+
+Unlike password_reset the master key stays the same, so the private and the sign key are still valid.
+
+The salt, the encrypted master key and the alg are from the recovery key data (like the data from the prepare login for the password).
+The old auth key of the output is derived from the recovery key, so the server can check it against the recovery data.
+*/
+#[allow(clippy::type_complexity)]
+pub fn recover_with_recovery_key<H: PwHash>(
+	recovery_key: &str,
+	new_pw: &str,
+	recovery_salt: &[u8],
+	encrypted_master_key: &[u8],
+	derived_encryption_key_alg: &str,
+) -> Result<ChangePasswordOutput<H::CRV, H::HAK, H::DAK>, Error>
+{
+	let recovery_key_bytes = recovery_key_to_bytes(recovery_key)?;
+
+	let (master_key_encryption_key, auth_key) = H::derive_keys_for_auth(&recovery_key_bytes, recovery_salt, derived_encryption_key_alg)?;
+
+	let master_key = master_key_encryption_key.get_master_key(encrypted_master_key)?;
+
+	let (client_random_value, hashed_authentication_key_bytes, encrypted_master_key, encrypted_master_key_alg) =
+		H::derived_keys_from_password(new_pw.as_bytes(), &master_key, None)?;
+
+	Ok(ChangePasswordOutput {
+		derived_alg: client_random_value.get_alg_str(),
+		client_random_value,
+		hashed_authentication_key_bytes,
+		encrypted_master_key,
+		encrypted_master_key_alg,
+		old_auth_key: auth_key,
+	})
+}
+
 /**
 Creates a safety number in byte of a given verify key and additional user information like the user id or username.
 
@@ -297,3 +534,124 @@ pub fn safety_number<Vk: VerifyK>(
 
 	number_bytes.to_vec()
 }
+
+/**
+The version of the numeric and the qr safety number.
+
+This version is part of every fingerprint and qr payload, so numbers of different versions will never match.
+ */
+pub const SAFETY_NUMBER_VERSION: u8 = 0;
+
+const SAFETY_NUMBER_ITERATIONS: u32 = 5200;
+
+//30 bytes for 6 blocks of 5 bytes, every block is rendered as 5 digits
+const SAFETY_NUMBER_FINGERPRINT_LEN: usize = 30;
+
+const SAFETY_NUMBER_QR_LEN: usize = 1 + SAFETY_NUMBER_FINGERPRINT_LEN * 2;
+
+/**
+Creates the fingerprint of one identity for the numeric and the qr safety number.
+
+The verify key and the user info are hashed iteratively with sha512 and the output is truncated to 30 bytes.
+ */
+pub fn safety_number_fingerprint<Vk: VerifyK>(verify_key: &Vk, user_info: &str) -> Vec<u8>
+{
+	let mut hasher = Sha512::new();
+
+	hasher.update([SAFETY_NUMBER_VERSION]);
+	verify_key.create_hash(&mut hasher);
+	hasher.update(user_info.as_bytes());
+
+	let mut hash = hasher.finalize();
+
+	for _ in 0..SAFETY_NUMBER_ITERATIONS {
+		let mut hasher = Sha512::new();
+
+		hasher.update(hash);
+		verify_key.create_hash(&mut hasher);
+
+		hash = hasher.finalize();
+	}
+
+	hash[..SAFETY_NUMBER_FINGERPRINT_LEN].to_vec()
+}
+
+fn safety_number_digits(fingerprint: &[u8]) -> String
+{
+	let mut out = String::with_capacity(SAFETY_NUMBER_FINGERPRINT_LEN);
+
+	for chunk in fingerprint.chunks_exact(5) {
+		let mut value = 0u64;
+
+		for b in chunk {
+			value = (value << 8) | *b as u64;
+		}
+
+		//write to a string can't fail
+		let _ = write!(out, "{:05}", value % 100000);
+	}
+
+	out
+}
+
+/**
+Creates a numeric safety number with 60 digits of two identities.
+
+Every identity creates 30 digits. The digits are sorted before they are combined,
+so both users get the same number regardless of the order of the identities.
+ */
+pub fn numeric_safety_number<Vk: VerifyK>(user_1_verify_key: &Vk, user_1_user_info: &str, user_2_verify_key: &Vk, user_2_user_info: &str) -> String
+{
+	let user_1 = safety_number_digits(&safety_number_fingerprint(user_1_verify_key, user_1_user_info));
+	let user_2 = safety_number_digits(&safety_number_fingerprint(user_2_verify_key, user_2_user_info));
+
+	if user_1 <= user_2 {
+		user_1 + &user_2
+	} else {
+		user_2 + &user_1
+	}
+}
+
+/**
+Creates the bytes for a qr code of the safety number.
+
+The payload contains the version, the fingerprint of the own identity and the fingerprint of the other identity.
+The other user scans the code and checks it with verify_safety_number_qr_payload.
+ */
+pub fn safety_number_qr_payload<Vk: VerifyK>(own_verify_key: &Vk, own_user_info: &str, other_verify_key: &Vk, other_user_info: &str) -> Vec<u8>
+{
+	let mut out = Vec::with_capacity(SAFETY_NUMBER_QR_LEN);
+
+	out.push(SAFETY_NUMBER_VERSION);
+	out.extend(safety_number_fingerprint(own_verify_key, own_user_info));
+	out.extend(safety_number_fingerprint(other_verify_key, other_user_info));
+
+	out
+}
+
+/**
+Checks a scanned qr payload of the other user.
+
+The scanned payload was created from the view of the other user,
+so the first fingerprint must match the other identity and the second the own identity.
+ */
+pub fn verify_safety_number_qr_payload<Vk: VerifyK>(
+	scanned_payload: &[u8],
+	own_verify_key: &Vk,
+	own_user_info: &str,
+	other_verify_key: &Vk,
+	other_user_info: &str,
+) -> Result<bool, Error>
+{
+	if scanned_payload.len() != SAFETY_NUMBER_QR_LEN {
+		return Err(Error::SafetyNumberPayloadInvalid);
+	}
+
+	if scanned_payload[0] != SAFETY_NUMBER_VERSION {
+		return Err(Error::SafetyNumberVersionMismatch);
+	}
+
+	let expected = safety_number_qr_payload(other_verify_key, other_user_info, own_verify_key, own_user_info);
+
+	Ok(expected == scanned_payload)
+}
@@ -0,0 +1,482 @@
+//! Derive macro for field-level encryption of structs with the sentc sdk.
+//!
+//! Use it via the derive feature of sentc-crypto:
+//!
+//! ```toml
+//! sentc-crypto = { version = "<the actual version number>", features = ["std_keys", "derive"] }
+//! ```
+//!
+//! The macro generates an encrypted counterpart type (Encrypted + the struct name)
+//! and an encrypt_fields fn for the struct and a decrypt_fields fn for the encrypted type.
+//!
+//! # Field attributes
+//!
+//! * `#[sentc(encrypt)]` encrypts the field with the symmetric key (e.g. a group key)
+//! * `#[sentc(sign)]` encrypts the field and signs the encrypted data with the sign key.
+//!   decrypt_fields takes a SignaturePolicy (or an `Option<&UserVerifyKeyData>`) for these fields
+//! * `#[sentc(searchable)]` encrypts the field and creates the searchable hashes in the field_searchable field
+//! * `#[sentc(searchable(full))]` the same as searchable but only for exact matches
+//! * `#[sentc(searchable(limit = 10))]` the same as searchable but only the first bytes are hashed
+//! * `#[sentc(sortable)]` encrypts the field and creates the sortable number in the field_sortable field (only for String and unsigned integer fields)
+//!
+//! Fields without attributes are cloned to the encrypted type.
+//!
+//! String fields are encrypted to a String and `Vec<u8>` fields to a `Vec<u8>`.
+//! Every other type is encrypted as String and must implement Display and FromStr.
+//!
+//! # Struct attributes
+//!
+//! * `#[sentc(derive(Serialize, Deserialize))]` adds the derives to the encrypted type
+//! * `#[sentc(name = "PersonEnc")]` to change the name of the encrypted type
+//!
+//! # Example
+//!
+//! ```ignore
+//! use sentc_crypto::SentcEncrypt;
+//!
+//! #[derive(SentcEncrypt)]
+//! struct Person
+//! {
+//! 	id: String,
+//! 	#[sentc(encrypt)]
+//! 	name: String,
+//! 	#[sentc(searchable(full))]
+//! 	email: String,
+//! 	#[sentc(sortable)]
+//! 	age: u64,
+//! }
+//!
+//! let encrypted = person.encrypt_fields(&group_key, &hmac_key, &sortable_key)?;
+//! let person = encrypted.decrypt_fields(&group_key)?;
+//! ```
+
+#![allow(clippy::tabs_in_doc_comments)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitInt, LitStr, Path, Token, Type, Visibility};
+
+#[proc_macro_derive(SentcEncrypt, attributes(sentc))]
+pub fn derive_sentc_encrypt(input: TokenStream) -> TokenStream
+{
+	let input = parse_macro_input!(input as DeriveInput);
+
+	match expand(input) {
+		Ok(out) => out.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+enum FieldKind
+{
+	Str,
+	Bytes,
+	Unsigned,
+	Other,
+}
+
+struct Searchable
+{
+	full: bool,
+	limit: Option<LitInt>,
+}
+
+#[derive(Default)]
+struct FieldOptions
+{
+	encrypt: bool,
+	sign: bool,
+	searchable: Option<Searchable>,
+	sortable: bool,
+}
+
+impl FieldOptions
+{
+	fn is_encrypted(&self) -> bool
+	{
+		self.encrypt || self.sign || self.searchable.is_some() || self.sortable
+	}
+}
+
+#[derive(Default)]
+struct StructOptions
+{
+	derives: Vec<Path>,
+	name: Option<Ident>,
+}
+
+struct FieldDef
+{
+	name: Ident,
+	vis: Visibility,
+	ty: Type,
+	kind: FieldKind,
+	options: FieldOptions,
+}
+
+fn get_field_kind(ty: &Type) -> FieldKind
+{
+	let path = match ty {
+		Type::Path(p) if p.qself.is_none() => &p.path,
+		_ => return FieldKind::Other,
+	};
+
+	let last = match path.segments.last() {
+		Some(s) => s,
+		None => return FieldKind::Other,
+	};
+
+	match last.ident.to_string().as_str() {
+		"String" => FieldKind::Str,
+		"u8" | "u16" | "u32" | "u64" => FieldKind::Unsigned,
+		"Vec" => {
+			if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+				if let Some(syn::GenericArgument::Type(Type::Path(inner))) = args.args.first() {
+					if inner.path.is_ident("u8") {
+						return FieldKind::Bytes;
+					}
+				}
+			}
+
+			FieldKind::Other
+		},
+		_ => FieldKind::Other,
+	}
+}
+
+fn parse_struct_options(attrs: &[Attribute]) -> syn::Result<StructOptions>
+{
+	let mut options = StructOptions::default();
+
+	for attr in attrs {
+		if !attr.path().is_ident("sentc") {
+			continue;
+		}
+
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("derive") {
+				let content;
+				syn::parenthesized!(content in meta.input);
+
+				let paths = content.parse_terminated(Path::parse_mod_style, Token![,])?;
+				options.derives.extend(paths);
+
+				Ok(())
+			} else if meta.path.is_ident("name") {
+				let name: LitStr = meta.value()?.parse()?;
+				options.name = Some(name.parse()?);
+
+				Ok(())
+			} else {
+				Err(meta.error("unknown sentc struct attribute. Use derive(..) or name = \"..\""))
+			}
+		})?;
+	}
+
+	Ok(options)
+}
+
+fn parse_field_options(attrs: &[Attribute]) -> syn::Result<FieldOptions>
+{
+	let mut options = FieldOptions::default();
+
+	for attr in attrs {
+		if !attr.path().is_ident("sentc") {
+			continue;
+		}
+
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("encrypt") {
+				options.encrypt = true;
+			} else if meta.path.is_ident("sign") {
+				options.sign = true;
+			} else if meta.path.is_ident("sortable") {
+				options.sortable = true;
+			} else if meta.path.is_ident("searchable") {
+				let mut searchable = Searchable {
+					full: false,
+					limit: None,
+				};
+
+				if meta.input.peek(syn::token::Paren) {
+					meta.parse_nested_meta(|inner| {
+						if inner.path.is_ident("full") {
+							searchable.full = true;
+
+							Ok(())
+						} else if inner.path.is_ident("limit") {
+							searchable.limit = Some(inner.value()?.parse()?);
+
+							Ok(())
+						} else {
+							Err(inner.error("unknown searchable option. Use full or limit = .."))
+						}
+					})?;
+				}
+
+				options.searchable = Some(searchable);
+			} else {
+				return Err(meta.error("unknown sentc field attribute. Use encrypt, sign, searchable or sortable"));
+			}
+
+			Ok(())
+		})?;
+	}
+
+	Ok(options)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2>
+{
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(
+			&input.generics,
+			"SentcEncrypt does not support generic structs",
+		));
+	}
+
+	let named = match &input.data {
+		Data::Struct(s) => {
+			match &s.fields {
+				Fields::Named(f) => &f.named,
+				_ => {
+					return Err(Error::new_spanned(
+						&input.ident,
+						"SentcEncrypt is only supported for structs with named fields",
+					))
+				},
+			}
+		},
+		_ => {
+			return Err(Error::new_spanned(
+				&input.ident,
+				"SentcEncrypt is only supported for structs",
+			))
+		},
+	};
+
+	let struct_options = parse_struct_options(&input.attrs)?;
+
+	let mut fields = Vec::with_capacity(named.len());
+
+	for field in named {
+		let options = parse_field_options(&field.attrs)?;
+		let kind = get_field_kind(&field.ty);
+
+		if options.searchable.is_some() && !matches!(kind, FieldKind::Str) {
+			return Err(Error::new_spanned(
+				&field.ty,
+				"searchable is only supported for String fields",
+			));
+		}
+
+		if options.sortable && !matches!(kind, FieldKind::Str | FieldKind::Unsigned) {
+			return Err(Error::new_spanned(
+				&field.ty,
+				"sortable is only supported for String and unsigned integer fields",
+			));
+		}
+
+		fields.push(FieldDef {
+			//named fields always got an ident
+			name: field.ident.clone().unwrap(),
+			vis: field.vis.clone(),
+			ty: field.ty.clone(),
+			kind,
+			options,
+		});
+	}
+
+	let name = &input.ident;
+	let vis = &input.vis;
+	let enc_name = struct_options
+		.name
+		.unwrap_or_else(|| format_ident!("Encrypted{}", name));
+
+	let use_searchable = fields.iter().any(|f| f.options.searchable.is_some());
+	let use_sortable = fields.iter().any(|f| f.options.sortable);
+	let use_sign = fields.iter().any(|f| f.options.sign);
+
+	let utils = quote!(::sentc_crypto::sdk_utils);
+	let common = quote!(::sentc_crypto::sdk_common);
+	//sentc-crypto is no_std, so use the alloc types of the re-export
+	let alloc = quote!(::sentc_crypto::derive_alloc);
+
+	let mut enc_fields = Vec::new();
+	let mut encrypt_values = Vec::new();
+	let mut decrypt_values = Vec::new();
+
+	for f in &fields {
+		let FieldDef {
+			name: f_name,
+			vis: f_vis,
+			ty,
+			..
+		} = f;
+
+		if !f.options.is_encrypted() {
+			enc_fields.push(quote!(#f_vis #f_name: #ty));
+			encrypt_values.push(quote!(#f_name: ::core::clone::Clone::clone(&self.#f_name)));
+			decrypt_values.push(quote!(#f_name: ::core::clone::Clone::clone(&self.#f_name)));
+
+			continue;
+		}
+
+		let verify_key = if f.options.sign {
+			quote!(policy)
+		} else {
+			quote!(::core::option::Option::None)
+		};
+
+		let (enc_ty, encrypt, decrypt) = match f.kind {
+			FieldKind::Bytes => {
+				let encrypt = if f.options.sign {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt_with_sign(key, &self.#f_name, sign_key)?)
+				} else {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt(key, &self.#f_name)?)
+				};
+
+				(
+					quote!(#alloc::Vec<u8>),
+					encrypt,
+					quote!(#utils::cryptomat::SymKeyCrypto::decrypt(key, &self.#f_name, #verify_key)?),
+				)
+			},
+			FieldKind::Str => {
+				let encrypt = if f.options.sign {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt_string_with_sign(key, &self.#f_name, sign_key)?)
+				} else {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt_string(key, &self.#f_name)?)
+				};
+
+				(
+					quote!(#alloc::String),
+					encrypt,
+					quote!(#utils::cryptomat::SymKeyCrypto::decrypt_string(key, &self.#f_name, #verify_key)?),
+				)
+			},
+			FieldKind::Unsigned | FieldKind::Other => {
+				let value = quote!(&#alloc::ToString::to_string(&self.#f_name));
+
+				let encrypt = if f.options.sign {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt_string_with_sign(key, #value, sign_key)?)
+				} else {
+					quote!(#utils::cryptomat::SymKeyCrypto::encrypt_string(key, #value)?)
+				};
+
+				(
+					quote!(#alloc::String),
+					encrypt,
+					quote!(
+						#utils::cryptomat::SymKeyCrypto::decrypt_string(key, &self.#f_name, #verify_key)?
+							.parse::<#ty>()
+							.map_err(|_| #utils::error::SdkUtilError::DecodeEncryptedDataFailed)?
+					),
+				)
+			},
+		};
+
+		enc_fields.push(quote!(#f_vis #f_name: #enc_ty));
+		encrypt_values.push(quote!(#f_name: #encrypt));
+		decrypt_values.push(quote!(#f_name: #decrypt));
+
+		if let Some(searchable) = &f.options.searchable {
+			let search_name = format_ident!("{}_searchable", f_name);
+			let full = searchable.full;
+			let limit = match &searchable.limit {
+				Some(l) => quote!(::core::option::Option::Some(#l)),
+				None => quote!(::core::option::Option::None),
+			};
+
+			enc_fields.push(quote!(#f_vis #search_name: #common::content_searchable::SearchableCreateOutput));
+			encrypt_values.push(quote!(
+				#search_name: #utils::cryptomat::SearchableKeyWrapper::create_searchable(searchable_key, &self.#f_name, #full, #limit)?
+			));
+		}
+
+		if f.options.sortable {
+			let sort_name = format_ident!("{}_sortable", f_name);
+
+			let sortable = match f.kind {
+				FieldKind::Str => {
+					quote!(#utils::cryptomat::SortableKeyWrapper::encrypt_string(sortable_key, &self.#f_name, ::core::option::Option::None)?)
+				},
+				_ => quote!(#utils::cryptomat::SortableKeyWrapper::encrypt_number(sortable_key, self.#f_name as u64)?),
+			};
+
+			enc_fields.push(quote!(#f_vis #sort_name: #common::content_sortable::SortableEncryptOutput));
+			encrypt_values.push(quote!(#sort_name: #sortable));
+		}
+	}
+
+	let mut encrypt_args = vec![quote!(key: &impl #utils::cryptomat::SymKeyWrapper)];
+
+	if use_searchable {
+		encrypt_args.push(quote!(searchable_key: &impl #utils::cryptomat::SearchableKeyWrapper));
+	}
+
+	if use_sortable {
+		encrypt_args.push(quote!(sortable_key: &impl #utils::cryptomat::SortableKeyWrapper));
+	}
+
+	if use_sign {
+		encrypt_args.push(quote!(sign_key: &impl #utils::cryptomat::SignKWrapper));
+	}
+
+	let mut decrypt_args = vec![quote!(key: &impl #utils::cryptomat::SymKeyWrapper)];
+
+	if use_sign {
+		decrypt_args.push(quote!(policy: impl ::core::convert::Into<#utils::cryptomat::SignaturePolicy<'p>>));
+	}
+
+	//the policy is copy, convert it once for all signed fields
+	let (decrypt_lifetime, decrypt_policy) = if use_sign {
+		(
+			quote!(<'p>),
+			quote!(let policy: #utils::cryptomat::SignaturePolicy = ::core::convert::Into::into(policy);),
+		)
+	} else {
+		(quote!(), quote!())
+	};
+
+	let derives = &struct_options.derives;
+	let derive_attr = if derives.is_empty() {
+		quote!()
+	} else {
+		quote!(#[derive(#(#derives),*)])
+	};
+
+	let enc_doc = format!("The encrypted version of [`{}`].", name);
+
+	Ok(quote! {
+		#[doc = #enc_doc]
+		#derive_attr
+		#vis struct #enc_name
+		{
+			#(#enc_fields,)*
+		}
+
+		impl #name
+		{
+			pub fn encrypt_fields(&self, #(#encrypt_args),*) -> ::core::result::Result<#enc_name, #utils::error::SdkUtilError>
+			{
+				::core::result::Result::Ok(#enc_name {
+					#(#encrypt_values,)*
+				})
+			}
+		}
+
+		impl #enc_name
+		{
+			pub fn decrypt_fields #decrypt_lifetime (&self, #(#decrypt_args),*) -> ::core::result::Result<#name, #utils::error::SdkUtilError>
+			{
+				#decrypt_policy
+
+				::core::result::Result::Ok(#name {
+					#(#decrypt_values,)*
+				})
+			}
+		}
+	})
+}
@@ -0,0 +1,179 @@
+use sentc_crypto::sdk_common::user::UserVerifyKeyData;
+use sentc_crypto::sdk_core::cryptomat::{CryptoAlg, SearchableKeyGen, SignKeyPair, SortableKeyGen, SymKeyGen};
+use sentc_crypto::sdk_utils::cryptomat::{SearchableKeyWrapper, SignaturePolicy};
+use sentc_crypto::std_keys::core::{HmacKey as CoreHmacKey, SignKey as CoreSignKey, SortKeys, SymmetricKey as CoreSymmetricKey};
+use sentc_crypto::std_keys::util::export::export_raw_verify_key_to_pem;
+use sentc_crypto::std_keys::util::{HmacKey, SignKey, SortableKey, SymmetricKey};
+use sentc_crypto::SentcEncrypt;
+
+#[derive(SentcEncrypt, Debug, PartialEq)]
+struct Person
+{
+	id: String,
+	#[sentc(encrypt)]
+	name: String,
+	#[sentc(searchable(full))]
+	email: String,
+	#[sentc(searchable(limit = 3))]
+	city: String,
+	#[sentc(sortable)]
+	age: u64,
+	#[sentc(encrypt)]
+	score: f64,
+	#[sentc(sign)]
+	contract: Vec<u8>,
+}
+
+#[derive(SentcEncrypt, Debug, PartialEq)]
+#[sentc(name = "NoteEnc")]
+struct Note
+{
+	#[sentc(encrypt)]
+	text: String,
+}
+
+fn create_keys() -> (SymmetricKey, HmacKey, SortableKey, SignKey, UserVerifyKeyData)
+{
+	let key = SymmetricKey {
+		key: CoreSymmetricKey::generate().unwrap(),
+		key_id: "sym_key".to_string(),
+	};
+
+	let hmac_key = HmacKey {
+		key: CoreHmacKey::generate().unwrap(),
+		key_id: "hmac_key".to_string(),
+	};
+
+	let sortable_key = SortableKey {
+		key: SortKeys::generate().unwrap(),
+		key_id: "sortable_key".to_string(),
+	};
+
+	let (sk, vk) = CoreSignKey::generate_key_pair().unwrap();
+
+	let verify_key = UserVerifyKeyData {
+		verify_key_pem: export_raw_verify_key_to_pem(&vk).unwrap(),
+		verify_key_alg: vk.get_alg_str().to_string(),
+		verify_key_id: "sign_key".to_string(),
+	};
+
+	let sign_key = SignKey {
+		key: sk,
+		key_id: "sign_key".to_string(),
+	};
+
+	(key, hmac_key, sortable_key, sign_key, verify_key)
+}
+
+#[test]
+fn test_encrypt_and_decrypt_fields()
+{
+	let (key, hmac_key, sortable_key, sign_key, verify_key) = create_keys();
+
+	let person = Person {
+		id: "123".to_string(),
+		name: "Max".to_string(),
+		email: "max@example.com".to_string(),
+		city: "Berlin".to_string(),
+		age: 30,
+		score: 1.5,
+		contract: vec![1, 2, 3],
+	};
+
+	let encrypted = person
+		.encrypt_fields(&key, &hmac_key, &sortable_key, &sign_key)
+		.unwrap();
+
+	assert_eq!(encrypted.id, person.id);
+	assert_ne!(encrypted.name, person.name);
+	assert_ne!(encrypted.contract, person.contract);
+
+	//full search creates only one hash
+	assert_eq!(encrypted.email_searchable.hashes.len(), 1);
+	assert_eq!(
+		encrypted.email_searchable.hashes[0],
+		hmac_key.search("max@example.com").unwrap()
+	);
+	assert!(encrypted
+		.city_searchable
+		.hashes
+		.contains(&hmac_key.search("Ber").unwrap()));
+
+	assert_eq!(encrypted.age_sortable.key_id, "sortable_key");
+
+	let decrypted = encrypted.decrypt_fields(&key, Some(&verify_key)).unwrap();
+
+	assert_eq!(decrypted, person);
+}
+
+#[test]
+fn test_decrypt_without_verify()
+{
+	let (key, hmac_key, sortable_key, sign_key, _) = create_keys();
+
+	let person = Person {
+		id: "123".to_string(),
+		name: "Max".to_string(),
+		email: "max@example.com".to_string(),
+		city: "Berlin".to_string(),
+		age: 30,
+		score: 1.5,
+		contract: vec![1, 2, 3],
+	};
+
+	let encrypted = person
+		.encrypt_fields(&key, &hmac_key, &sortable_key, &sign_key)
+		.unwrap();
+
+	let decrypted = encrypted.decrypt_fields(&key, None).unwrap();
+
+	assert_eq!(decrypted, person);
+}
+
+#[test]
+fn test_decrypt_with_signature_policy()
+{
+	let (key, hmac_key, sortable_key, sign_key, verify_key) = create_keys();
+	let (.., other_verify_key) = create_keys();
+
+	let person = Person {
+		id: "123".to_string(),
+		name: "Max".to_string(),
+		email: "max@example.com".to_string(),
+		city: "Berlin".to_string(),
+		age: 30,
+		score: 1.5,
+		contract: vec![1, 2, 3],
+	};
+
+	let encrypted = person
+		.encrypt_fields(&key, &hmac_key, &sortable_key, &sign_key)
+		.unwrap();
+
+	let decrypted = encrypted
+		.decrypt_fields(&key, SignaturePolicy::Require(&verify_key))
+		.unwrap();
+
+	assert_eq!(decrypted, person);
+
+	assert!(encrypted
+		.decrypt_fields(&key, SignaturePolicy::Require(&other_verify_key))
+		.is_err());
+	assert!(encrypted
+		.decrypt_fields(&key, SignaturePolicy::VerifyIfSigned(None))
+		.is_err());
+}
+
+#[test]
+fn test_encrypted_type_name()
+{
+	let (key, ..) = create_keys();
+
+	let note = Note {
+		text: "hello".to_string(),
+	};
+
+	let encrypted: NoteEnc = note.encrypt_fields(&key).unwrap();
+
+	assert_eq!(encrypted.decrypt_fields(&key).unwrap(), note);
+}
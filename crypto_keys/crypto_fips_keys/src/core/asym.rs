@@ -1,8 +1,14 @@
-use openssl::pkey::{HasPrivate, HasPublic, Private, Public};
+#[cfg(feature = "pkcs11")]
+use cryptoki::object::KeyType;
+#[cfg(feature = "pkcs11")]
+use openssl::bn::BigNum;
+use openssl::pkey::{HasPublic, Private, Public};
 use openssl::rsa::{Padding, Rsa};
 use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, SkComposer, StaticKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{crypto_alg_str_impl, Error};
 
+#[cfg(feature = "pkcs11")]
+use crate::core::pkcs11::{self, Pkcs11Key, PKCS11_URI_PREFIX};
 use crate::core::sym;
 use crate::import_export_openssl;
 
@@ -29,9 +35,25 @@ impl Pk for RsaPk
 	}
 
 	fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.encrypt_internally(data, None)
+	}
+}
+
+impl RsaPk
+{
+	/**
+	Encrypt with aad for the aes encryption. Used for jwe where the protected header is the aad.
+	 */
+	pub fn encrypt_with_aad(&self, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.encrypt_internally(data, Some(aad))
+	}
+
+	fn encrypt_internally(&self, data: &[u8], aad: Option<&[u8]>) -> Result<Vec<u8>, Error>
 	{
 		let aes_key = sym::raw_generate()?;
-		let encrypted = sym::raw_encrypt(&aes_key, data)?;
+		let encrypted = sym::raw_encrypt_with_aad(&aes_key, data, aad.unwrap_or_default())?;
 
 		//the module size of rsa is the size of the encrypted output
 		let encrypted_aes_key_len = self.0.size() as usize;
@@ -51,22 +73,81 @@ impl Pk for RsaPk
 	}
 }
 
-pub struct RsaSk(Rsa<Private>);
+enum RsaSkInner
+{
+	Openssl(Rsa<Private>),
+	#[cfg(feature = "pkcs11")]
+	Pkcs11(Pkcs11Key),
+}
+
+pub struct RsaSk(RsaSkInner);
 
 import_export_openssl!(RsaSk, import_sk, export_sk);
 crypto_alg_str_impl!(RsaSk, FIPS_OPENSSL_RSA_OAEP_WRAP);
 
+#[cfg(feature = "pkcs11")]
+impl RsaSk
+{
+	/**
+	Generate a new key pair in the pkcs11 token. The private key can't be exported from the token.
+	 */
+	pub fn generate_in_token(token_label: &str, key_label: &str) -> Result<(Self, RsaPk), Error>
+	{
+		let (key, modulus, exponent) = pkcs11::generate_rsa_key_pair(token_label, key_label, RSA_LENGTH)?;
+
+		let n = BigNum::from_slice(&modulus).map_err(|_| Error::KeyCreationFailed)?;
+		let e = BigNum::from_slice(&exponent).map_err(|_| Error::KeyCreationFailed)?;
+
+		let pk = Rsa::from_public_components(n, e).map_err(|_| Error::KeyCreationFailed)?;
+
+		Ok((Self(RsaSkInner::Pkcs11(key)), RsaPk(pk)))
+	}
+
+	/**
+	Load an existing private key from the pkcs11 token by its label.
+	 */
+	pub fn from_token(token_label: &str, key_label: &str) -> Result<Self, Error>
+	{
+		Ok(Self(RsaSkInner::Pkcs11(Pkcs11Key::find(
+			token_label,
+			key_label,
+			KeyType::RSA,
+		)?)))
+	}
+}
+
 impl Sk for RsaSk
 {
 	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
 	{
+		//for keys in a token only the reference to the key is encrypted
 		master_key.encrypt(&export_sk(&self.0)?)
 	}
 
 	fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.decrypt_internally(ciphertext, None)
+	}
+}
+
+impl RsaSk
+{
+	/**
+	Decrypt with aad for the aes decryption. Used for jwe where the protected header is the aad.
+	 */
+	pub fn decrypt_with_aad(&self, ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.decrypt_internally(ciphertext, Some(aad))
+	}
+
+	fn decrypt_internally(&self, ciphertext: &[u8], aad: Option<&[u8]>) -> Result<Vec<u8>, Error>
 	{
 		//the module size of rsa is the size of the encrypted output
-		let encrypted_aes_key_len = self.0.size() as usize;
+		let encrypted_aes_key_len = match &self.0 {
+			RsaSkInner::Openssl(k) => k.size() as usize,
+			#[cfg(feature = "pkcs11")]
+			RsaSkInner::Pkcs11(k) => k.rsa_size()?,
+		};
 
 		if ciphertext.len() <= encrypted_aes_key_len {
 			return Err(Error::DecryptionFailedCiphertextShort);
@@ -75,14 +156,25 @@ impl Sk for RsaSk
 		let encrypted_aes_key = &ciphertext[..encrypted_aes_key_len];
 		let en = &ciphertext[encrypted_aes_key_len..];
 
-		let mut aes_key = vec![0u8; encrypted_aes_key_len];
+		let aes_key = match &self.0 {
+			RsaSkInner::Openssl(k) => {
+				let mut aes_key = vec![0u8; encrypted_aes_key_len];
 
-		self.0
-			.private_decrypt(encrypted_aes_key, &mut aes_key, Padding::PKCS1_OAEP)
-			.map_err(|_| Error::DecryptionFailed)?;
+				k.private_decrypt(encrypted_aes_key, &mut aes_key, Padding::PKCS1_OAEP)
+					.map_err(|_| Error::DecryptionFailed)?;
+
+				aes_key
+			},
+			#[cfg(feature = "pkcs11")]
+			RsaSkInner::Pkcs11(k) => k.decrypt_rsa_oaep(encrypted_aes_key)?,
+		};
+
+		if aes_key.len() < 32 {
+			return Err(Error::DecryptionFailed);
+		}
 
 		//use only the bytes for the aes key. the rest is zero
-		sym::raw_decrypt(&aes_key[..32], en)
+		sym::raw_decrypt_with_aad(&aes_key[..32], en, aad.unwrap_or_default())
 	}
 }
 
@@ -113,21 +205,34 @@ impl StaticKeyPair for RsaSk
 
 		let pub_k = RsaPk(import_pk(&export_pk(&rsa_private)?)?);
 
-		Ok((Self(rsa_private), pub_k))
+		Ok((Self(RsaSkInner::Openssl(rsa_private)), pub_k))
 	}
 }
 
 //__________________________________________________________________________________________________
 
-fn export_sk<T: HasPrivate>(key: &Rsa<T>) -> Result<Vec<u8>, Error>
+fn export_sk(key: &RsaSkInner) -> Result<Vec<u8>, Error>
 {
-	key.private_key_to_pem()
-		.map_err(|_e| Error::KeyCreationFailed)
+	match key {
+		RsaSkInner::Openssl(k) => {
+			k.private_key_to_pem()
+				.map_err(|_e| Error::KeyCreationFailed)
+		},
+		#[cfg(feature = "pkcs11")]
+		RsaSkInner::Pkcs11(k) => Ok(k.uri()),
+	}
 }
 
-fn import_sk(key: &[u8]) -> Result<Rsa<Private>, Error>
+fn import_sk(key: &[u8]) -> Result<RsaSkInner, Error>
 {
-	Rsa::<Private>::private_key_from_pem(key).map_err(|_e| Error::KeyCreationFailed)
+	#[cfg(feature = "pkcs11")]
+	if key.starts_with(PKCS11_URI_PREFIX) {
+		return Ok(RsaSkInner::Pkcs11(Pkcs11Key::from_uri(key, KeyType::RSA)?));
+	}
+
+	Ok(RsaSkInner::Openssl(
+		Rsa::<Private>::private_key_from_pem(key).map_err(|_e| Error::KeyCreationFailed)?,
+	))
 }
 
 fn export_pk<T: HasPublic>(key: &Rsa<T>) -> Result<Vec<u8>, Error>
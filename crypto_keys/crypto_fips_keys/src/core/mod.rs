@@ -3,6 +3,8 @@ use sentc_crypto_core::Error;
 
 pub mod asym;
 pub mod hmac;
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
 pub mod pw_hash;
 pub mod sign;
 pub mod sortable;
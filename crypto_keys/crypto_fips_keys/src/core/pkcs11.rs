@@ -0,0 +1,366 @@
+//! Private keys in a PKCS#11 token (HSM, smartcard or SoftHSM for local tests).
+//!
+//! The private keys never leave the token. Decrypt and sign are done by the token.
+//! Instead of the key bytes, the export of the key is a reference to the key in the token,
+//! a pkcs11 uri like pkcs11:token=sentc;object=device_key (RFC 7512).
+//! Encrypting the key with a master key encrypts only this reference.
+//!
+//! Before using or importing a key, the module must be loaded and the token must be opened:
+//!
+//! ```ignore
+//! let module = Pkcs11Module::load("/usr/lib/softhsm/libsofthsm2.so")?;
+//! module.open_token("sentc", "1234")?;
+//!
+//! let (sk, pk) = RsaSk::generate_in_token("sentc", "device_key")?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::rsa::{PkcsMgfType, PkcsOaepParams, PkcsOaepSource};
+use cryptoki::mechanism::{Mechanism, MechanismType};
+use cryptoki::object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::types::AuthPin;
+use sentc_crypto_core::Error;
+
+pub(crate) const PKCS11_URI_PREFIX: &[u8] = b"pkcs11:";
+
+//der encoded oid of ed25519 (1.3.101.112)
+const ED25519_PARAMS: [u8; 5] = [0x06, 0x03, 0x2b, 0x65, 0x70];
+
+static TOKENS: Mutex<BTreeMap<String, Arc<Pkcs11Token>>> = Mutex::new(BTreeMap::new());
+
+/**
+A loaded pkcs11 library.
+
+A library should only be loaded once per process. Open all tokens of this library with the same module.
+ */
+pub struct Pkcs11Module(Pkcs11);
+
+impl Pkcs11Module
+{
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error>
+	{
+		let ctx = Pkcs11::new(path).map_err(|_| Error::Pkcs11Failed)?;
+
+		ctx.initialize(CInitializeArgs::OsThreads)
+			.map_err(|_| Error::Pkcs11Failed)?;
+
+		Ok(Self(ctx))
+	}
+
+	/**
+	Open a session to the token with this label and log in as user.
+
+	After this, keys of this token can be generated, loaded and imported from their reference.
+	 */
+	pub fn open_token(&self, token_label: &str, pin: &str) -> Result<(), Error>
+	{
+		let slot = self
+			.0
+			.get_slots_with_token()
+			.map_err(|_| Error::Pkcs11Failed)?
+			.into_iter()
+			.find(|slot| {
+				self.0
+					.get_token_info(*slot)
+					.map(|info| info.label() == token_label)
+					.unwrap_or(false)
+			})
+			.ok_or(Error::Pkcs11TokenNotFound)?;
+
+		let session = self
+			.0
+			.open_rw_session(slot)
+			.map_err(|_| Error::Pkcs11Failed)?;
+
+		session
+			.login(UserType::User, Some(&AuthPin::new(pin.into())))
+			.map_err(|_| Error::Pkcs11Failed)?;
+
+		TOKENS.lock().map_err(|_| Error::Pkcs11Failed)?.insert(
+			token_label.to_string(),
+			Arc::new(Pkcs11Token {
+				label: token_label.to_string(),
+				session: Mutex::new(session),
+			}),
+		);
+
+		Ok(())
+	}
+}
+
+/**
+Close the session to the token.
+
+Keys of this token which are still in use keep the session open until they are dropped.
+ */
+pub fn close_token(token_label: &str) -> Result<(), Error>
+{
+	TOKENS
+		.lock()
+		.map_err(|_| Error::Pkcs11Failed)?
+		.remove(token_label);
+
+	Ok(())
+}
+
+struct Pkcs11Token
+{
+	label: String,
+	session: Mutex<Session>,
+}
+
+impl Pkcs11Token
+{
+	fn get(token_label: &str) -> Result<Arc<Self>, Error>
+	{
+		TOKENS
+			.lock()
+			.map_err(|_| Error::Pkcs11Failed)?
+			.get(token_label)
+			.cloned()
+			.ok_or(Error::Pkcs11TokenNotFound)
+	}
+
+	fn with_session<T>(&self, f: impl FnOnce(&Session) -> cryptoki::error::Result<T>) -> Result<T, Error>
+	{
+		let session = self.session.lock().map_err(|_| Error::Pkcs11Failed)?;
+
+		f(&session).map_err(|_| Error::Pkcs11Failed)
+	}
+}
+
+/**
+A private key in a token.
+ */
+pub(crate) struct Pkcs11Key
+{
+	token: Arc<Pkcs11Token>,
+	label: String,
+	handle: ObjectHandle,
+}
+
+impl Pkcs11Key
+{
+	pub(crate) fn find(token_label: &str, key_label: &str, key_type: KeyType) -> Result<Self, Error>
+	{
+		let token = Pkcs11Token::get(token_label)?;
+
+		let handle = token
+			.with_session(|session| {
+				session.find_objects(&[
+					Attribute::Class(ObjectClass::PRIVATE_KEY),
+					Attribute::KeyType(key_type),
+					Attribute::Label(key_label.as_bytes().to_vec()),
+				])
+			})?
+			.into_iter()
+			.next()
+			.ok_or(Error::Pkcs11KeyNotFound)?;
+
+		Ok(Self {
+			token,
+			label: key_label.to_string(),
+			handle,
+		})
+	}
+
+	pub(crate) fn from_uri(uri: &[u8], key_type: KeyType) -> Result<Self, Error>
+	{
+		let uri = std::str::from_utf8(&uri[PKCS11_URI_PREFIX.len()..]).map_err(|_| Error::Pkcs11KeyNotFound)?;
+
+		let mut token_label = None;
+		let mut key_label = None;
+
+		for part in uri.split(';') {
+			match part.split_once('=') {
+				Some(("token", v)) => token_label = Some(percent_decode(v)?),
+				Some(("object", v)) => key_label = Some(percent_decode(v)?),
+				_ => {},
+			}
+		}
+
+		match (token_label, key_label) {
+			(Some(token_label), Some(key_label)) => Self::find(&token_label, &key_label, key_type),
+			_ => Err(Error::Pkcs11KeyNotFound),
+		}
+	}
+
+	pub(crate) fn uri(&self) -> Vec<u8>
+	{
+		let mut uri = PKCS11_URI_PREFIX.to_vec();
+
+		uri.extend_from_slice(b"token=");
+		uri.extend_from_slice(percent_encode(&self.token.label).as_bytes());
+		uri.extend_from_slice(b";object=");
+		uri.extend_from_slice(percent_encode(&self.label).as_bytes());
+		uri.extend_from_slice(b";type=private");
+
+		uri
+	}
+
+	pub(crate) fn rsa_size(&self) -> Result<usize, Error>
+	{
+		let attributes = self
+			.token
+			.with_session(|session| session.get_attributes(self.handle, &[AttributeType::Modulus]))?;
+
+		match attributes.first() {
+			Some(Attribute::Modulus(m)) => Ok(m.len()),
+			_ => Err(Error::Pkcs11Failed),
+		}
+	}
+
+	/**
+	Rsa oaep with sha1 and mgf1 sha1 like the openssl default padding.
+	 */
+	pub(crate) fn decrypt_rsa_oaep(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let params = PkcsOaepParams::new(MechanismType::SHA1, PkcsMgfType::MGF1_SHA1, PkcsOaepSource::empty());
+
+		self.token
+			.with_session(|session| session.decrypt(&Mechanism::RsaPkcsOaep(params), self.handle, data))
+			.map_err(|_| Error::DecryptionFailed)
+	}
+
+	pub(crate) fn sign_eddsa(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.token
+			.with_session(|session| session.sign(&Mechanism::Eddsa, self.handle, data))
+			.map_err(|_| Error::InitSignFailed)
+	}
+}
+
+fn private_key_template(key_label: &str) -> Vec<Attribute>
+{
+	vec![
+		Attribute::Token(true),
+		Attribute::Private(true),
+		Attribute::Sensitive(true),
+		Attribute::Extractable(false),
+		Attribute::Label(key_label.as_bytes().to_vec()),
+	]
+}
+
+/**
+Generate a rsa key pair in the token.
+
+Returns the private key and the modulus and the public exponent of the public key.
+ */
+pub(crate) fn generate_rsa_key_pair(token_label: &str, key_label: &str, bits: u32) -> Result<(Pkcs11Key, Vec<u8>, Vec<u8>), Error>
+{
+	let token = Pkcs11Token::get(token_label)?;
+
+	let public_template = [
+		Attribute::Token(true),
+		Attribute::Encrypt(true),
+		Attribute::ModulusBits(u64::from(bits).into()),
+		Attribute::PublicExponent(vec![0x01, 0x00, 0x01]),
+		Attribute::Label(key_label.as_bytes().to_vec()),
+	];
+
+	let mut private_template = private_key_template(key_label);
+	private_template.push(Attribute::Decrypt(true));
+
+	let (public_key, private_key) = token
+		.with_session(|session| session.generate_key_pair(&Mechanism::RsaPkcsKeyPairGen, &public_template, &private_template))
+		.map_err(|_| Error::KeyCreationFailed)?;
+
+	let attributes = token.with_session(|session| session.get_attributes(public_key, &[AttributeType::Modulus, AttributeType::PublicExponent]))?;
+
+	let (modulus, exponent) = match attributes.as_slice() {
+		[Attribute::Modulus(m), Attribute::PublicExponent(e)] => (m.clone(), e.clone()),
+		_ => return Err(Error::KeyCreationFailed),
+	};
+
+	Ok((
+		Pkcs11Key {
+			token,
+			label: key_label.to_string(),
+			handle: private_key,
+		},
+		modulus,
+		exponent,
+	))
+}
+
+/**
+Generate an ed25519 key pair in the token. The token must support pkcs11 3.0 eddsa.
+
+Returns the private key and the raw public key.
+ */
+pub(crate) fn generate_ed25519_key_pair(token_label: &str, key_label: &str) -> Result<(Pkcs11Key, Vec<u8>), Error>
+{
+	let token = Pkcs11Token::get(token_label)?;
+
+	let public_template = [
+		Attribute::Token(true),
+		Attribute::Verify(true),
+		Attribute::EcParams(ED25519_PARAMS.to_vec()),
+		Attribute::Label(key_label.as_bytes().to_vec()),
+	];
+
+	let mut private_template = private_key_template(key_label);
+	private_template.push(Attribute::Sign(true));
+
+	let (public_key, private_key) = token
+		.with_session(|session| session.generate_key_pair(&Mechanism::EccEdwardsKeyPairGen, &public_template, &private_template))
+		.map_err(|_| Error::SignKeyCreateFailed)?;
+
+	let attributes = token.with_session(|session| session.get_attributes(public_key, &[AttributeType::EcPoint]))?;
+
+	let point = match attributes.first() {
+		//the point is a der encoded octet string
+		Some(Attribute::EcPoint(p)) if p.len() == 34 && p[0] == 0x04 && p[1] == 32 => p[2..].to_vec(),
+		Some(Attribute::EcPoint(p)) if p.len() == 32 => p.clone(),
+		_ => return Err(Error::SignKeyCreateFailed),
+	};
+
+	Ok((
+		Pkcs11Key {
+			token,
+			label: key_label.to_string(),
+			handle: private_key,
+		},
+		point,
+	))
+}
+
+fn percent_encode(value: &str) -> String
+{
+	let mut out = String::with_capacity(value.len());
+
+	for b in value.bytes() {
+		if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+			out.push(b as char);
+		} else {
+			out.push_str(&format!("%{:02X}", b));
+		}
+	}
+
+	out
+}
+
+fn percent_decode(value: &str) -> Result<String, Error>
+{
+	let bytes = value.as_bytes();
+	let mut out = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		if bytes[i] == b'%' {
+			let hex = value.get(i + 1..i + 3).ok_or(Error::Pkcs11KeyNotFound)?;
+			out.push(u8::from_str_radix(hex, 16).map_err(|_| Error::Pkcs11KeyNotFound)?);
+			i += 3;
+		} else {
+			out.push(bytes[i]);
+			i += 1;
+		}
+	}
+
+	String::from_utf8(out).map_err(|_| Error::Pkcs11KeyNotFound)
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "pkcs11")]
+use cryptoki::object::KeyType;
 use digest::Digest;
 use openssl::pkey::{HasPrivate, HasPublic, Id, PKey, Private, Public};
 use openssl::sign::{Signer, Verifier};
@@ -5,6 +7,8 @@ use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyComposer, SignKeyPair, Sym
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_single_value, Error};
 
 use crate::core::export_sk;
+#[cfg(feature = "pkcs11")]
+use crate::core::pkcs11::{self, Pkcs11Key, PKCS11_URI_PREFIX};
 use crate::import_export_openssl;
 
 pub const FIPS_OPENSSL_ED25519: &str = "fips_openssl_ED25519";
@@ -60,17 +64,63 @@ impl VerifyK for Ed25519FIPSVerifyK
 	}
 }
 
-pub struct Ed25519FIPSSignK(PKey<Private>);
+enum SignKInner
+{
+	Openssl(PKey<Private>),
+	#[cfg(feature = "pkcs11")]
+	Pkcs11(Pkcs11Key),
+}
+
+impl SignKInner
+{
+	fn sign(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		match self {
+			Self::Openssl(k) => sign_internally(k, data),
+			#[cfg(feature = "pkcs11")]
+			Self::Pkcs11(k) => k.sign_eddsa(data),
+		}
+	}
+}
+
+pub struct Ed25519FIPSSignK(SignKInner);
 
 impl Ed25519FIPSSignK
 {
 	pub fn import(bytes: &[u8]) -> Result<Self, Error>
 	{
-		Ok(Self(import_sk(bytes)?))
+		Ok(Self(import_sign_key(bytes)?))
+	}
+
+	/**
+	Generate a new key pair in the pkcs11 token. The sign key can't be exported from the token.
+	 */
+	#[cfg(feature = "pkcs11")]
+	pub fn generate_in_token(token_label: &str, key_label: &str) -> Result<(Self, Ed25519FIPSVerifyK), Error>
+	{
+		let (key, verify_key) = pkcs11::generate_ed25519_key_pair(token_label, key_label)?;
+
+		Ok((
+			Self(SignKInner::Pkcs11(key)),
+			Ed25519FIPSVerifyK(import_pk(&verify_key)?),
+		))
+	}
+
+	/**
+	Load an existing sign key from the pkcs11 token by its label.
+	 */
+	#[cfg(feature = "pkcs11")]
+	pub fn from_token(token_label: &str, key_label: &str) -> Result<Self, Error>
+	{
+		Ok(Self(SignKInner::Pkcs11(Pkcs11Key::find(
+			token_label,
+			key_label,
+			KeyType::EC_EDWARDS,
+		)?)))
 	}
 }
 
-import_export_openssl!(Ed25519FIPSSignK, import_sk, export_sk);
+import_export_openssl!(Ed25519FIPSSignK, import_sign_key, export_sign_key);
 crypto_alg_str_impl!(Ed25519FIPSSignK, FIPS_OPENSSL_ED25519);
 
 impl SignK for Ed25519FIPSSignK
@@ -79,12 +129,13 @@ impl SignK for Ed25519FIPSSignK
 
 	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
 	{
-		master_key.encrypt(&export_sk(&self.0)?)
+		//for keys in a token only the reference to the key is encrypted
+		master_key.encrypt(&export_sign_key(&self.0)?)
 	}
 
 	fn sign(&self, data: &[u8]) -> Result<Vec<u8>, Error>
 	{
-		let sig = sign_internally(&self.0, data)?;
+		let sig = self.0.sign(data)?;
 
 		let mut output = Vec::with_capacity(sig.len() + data.len());
 		output.extend_from_slice(&sig);
@@ -95,7 +146,7 @@ impl SignK for Ed25519FIPSSignK
 
 	fn sign_only<D: AsRef<[u8]>>(&self, data: D) -> Result<Self::Signature, Error>
 	{
-		let sig = sign_internally(&self.0, data.as_ref())?;
+		let sig = self.0.sign(data.as_ref())?;
 
 		Ok(Ed25519FIPSSig(sig))
 	}
@@ -110,7 +161,7 @@ impl SignKeyPair for Ed25519FIPSSignK
 	{
 		let (vk, sk) = generate_key_pair()?;
 
-		Ok((Self(sk), Ed25519FIPSVerifyK(vk)))
+		Ok((Self(SignKInner::Openssl(sk)), Ed25519FIPSVerifyK(vk)))
 	}
 }
 
@@ -142,6 +193,25 @@ pub fn import_sk(key: &[u8]) -> Result<PKey<Private>, Error>
 	PKey::private_key_from_raw_bytes(key, Id::ED25519).map_err(|_e| Error::KeyCreationFailed)
 }
 
+fn import_sign_key(key: &[u8]) -> Result<SignKInner, Error>
+{
+	#[cfg(feature = "pkcs11")]
+	if key.starts_with(PKCS11_URI_PREFIX) {
+		return Ok(SignKInner::Pkcs11(Pkcs11Key::from_uri(key, KeyType::EC_EDWARDS)?));
+	}
+
+	Ok(SignKInner::Openssl(import_sk(key)?))
+}
+
+fn export_sign_key(key: &SignKInner) -> Result<Vec<u8>, Error>
+{
+	match key {
+		SignKInner::Openssl(k) => export_sk(k),
+		#[cfg(feature = "pkcs11")]
+		SignKInner::Pkcs11(k) => Ok(k.uri()),
+	}
+}
+
 fn export_pk<T: HasPublic>(verify_key: &PKey<T>) -> Result<Vec<u8>, Error>
 {
 	verify_key
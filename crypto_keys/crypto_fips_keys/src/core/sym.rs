@@ -86,6 +86,16 @@ pub fn raw_decrypt(key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error>
 	decrypt_internally(key, ciphertext, None)
 }
 
+pub fn raw_encrypt_with_aad(key: &[u8], data: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+{
+	encrypt_internally(key, data, Some(aad))
+}
+
+pub fn raw_decrypt_with_aad(key: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+{
+	decrypt_internally(key, ciphertext, Some(aad))
+}
+
 pub fn raw_generate() -> Result<AesKey, Error>
 {
 	let mut key = [0u8; 32]; //aes 256
@@ -122,8 +132,12 @@ fn encrypt_internally(key: &[u8], data: &[u8], aad: Option<&[u8]>) -> Result<Vec
 
 fn decrypt_internally(key: &[u8], ciphertext: &[u8], aad: Option<&[u8]>) -> Result<Vec<u8>, Error>
 {
+	if ciphertext.len() < AES_IV_LENGTH + AES_MAC_LENGTH {
+		return Err(Error::DecryptionFailed);
+	}
+
 	let nonce = &ciphertext[..AES_IV_LENGTH];
-	let tag = &ciphertext[AES_IV_LENGTH..AES_MAC_LENGTH];
+	let tag = &ciphertext[AES_IV_LENGTH..(AES_IV_LENGTH + AES_MAC_LENGTH)];
 	let encrypted = &ciphertext[(AES_IV_LENGTH + AES_MAC_LENGTH)..];
 
 	decrypt_aead(
@@ -191,6 +205,32 @@ mod test
 		assert!(matches!(decrypt_result, Err(DecryptionFailed)));
 	}
 
+	#[test]
+	fn test_not_decrypt_with_changed_tag()
+	{
+		openssl::provider::Provider::load(None, "fips").unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let output = Aes256GcmKey::generate().unwrap();
+
+		let encrypted = output.encrypt(text.as_bytes()).unwrap();
+
+		//every byte of the tag must be checked, not only the first ones
+		for i in (AES_IV_LENGTH + 4)..(AES_IV_LENGTH + AES_MAC_LENGTH) {
+			let mut changed = encrypted.clone();
+			changed[i] ^= 1;
+
+			assert!(matches!(output.decrypt(&changed), Err(DecryptionFailed)));
+		}
+
+		//too short for the nonce and the tag
+		assert!(matches!(
+			output.decrypt(&encrypted[..AES_IV_LENGTH + AES_MAC_LENGTH - 1]),
+			Err(DecryptionFailed)
+		));
+	}
+
 	#[test]
 	fn test_encrypt_decrypt_with_payload()
 	{
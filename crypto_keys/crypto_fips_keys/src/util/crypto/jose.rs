@@ -0,0 +1,155 @@
+use sentc_crypto_common::crypto::EncryptedHead;
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_utils::cryptomat::PkFromUserKeyWrapper;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::jose::{JosePk, JoseSignKey, JoseSk, JoseSymKey, JoseVerifyKey, Jwe, JweHeader, JWE_ALG_RSA_OAEP, JWS_ALG_EDDSA};
+
+use crate::core::asym::RSA_LENGTH;
+use crate::core::sign::FIPS_OPENSSL_ED25519;
+use crate::util::{PublicKey, SecretKey, SignKey, SymmetricKey, VerifyKey};
+
+const AES_IV_LENGTH: usize = 12;
+const AES_TAG_LENGTH: usize = 16;
+
+//the rsa oaep wrapped aes key is in front of the aes output
+const RSA_WRAPPED_KEY_LENGTH: usize = (RSA_LENGTH / 8) as usize;
+
+//the openssl aes gcm output is: iv || tag || ciphertext
+fn split_aead(encrypted: &[u8]) -> Result<(&[u8], &[u8], &[u8]), SdkUtilError>
+{
+	if encrypted.len() < AES_IV_LENGTH + AES_TAG_LENGTH {
+		return Err(SdkUtilError::DecodeEncryptedDataFailed);
+	}
+
+	let (iv, rest) = encrypted.split_at(AES_IV_LENGTH);
+	let (tag, ciphertext) = rest.split_at(AES_TAG_LENGTH);
+
+	Ok((iv, ciphertext, tag))
+}
+
+fn join_aead(iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>
+{
+	[iv, tag, ciphertext].concat()
+}
+
+fn set_rsa_ciphertext(jwe: &mut Jwe, encrypted: &[u8]) -> Result<(), SdkUtilError>
+{
+	if encrypted.len() < RSA_WRAPPED_KEY_LENGTH {
+		return Err(SdkUtilError::DecodeEncryptedDataFailed);
+	}
+
+	let (encrypted_key, encrypted) = encrypted.split_at(RSA_WRAPPED_KEY_LENGTH);
+	let (iv, ciphertext, tag) = split_aead(encrypted)?;
+
+	jwe.encrypted_key = encrypted_key.to_vec();
+	jwe.iv = iv.to_vec();
+	jwe.ciphertext = ciphertext.to_vec();
+	jwe.tag = tag.to_vec();
+
+	Ok(())
+}
+
+fn rsa_ciphertext(jwe: &Jwe) -> Vec<u8>
+{
+	[jwe.encrypted_key.as_slice(), &join_aead(&jwe.iv, &jwe.ciphertext, &jwe.tag)].concat()
+}
+
+impl JoseSymKey for SymmetricKey
+{
+	fn split_aead<'a>(&self, encrypted: &'a [u8]) -> Result<(&'a [u8], &'a [u8], &'a [u8]), SdkUtilError>
+	{
+		split_aead(encrypted)
+	}
+
+	fn join_aead(&self, iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>
+	{
+		join_aead(iv, ciphertext, tag)
+	}
+}
+
+impl PublicKey
+{
+	/**
+	Export data encrypted by a rsa public key to a jwe in flattened json serialization.
+
+	The rsa oaep wrapped aes key is the jwe encrypted key, so no public key is needed.
+	 */
+	pub fn export_jwe(head: &EncryptedHead, encrypted: &[u8]) -> Result<String, SdkUtilError>
+	{
+		if head.sign.is_some() {
+			return Err(SdkUtilError::JoseSignedDataNotSupported);
+		}
+
+		let mut jwe = Jwe::unprotected(JweHeader::new(JWE_ALG_RSA_OAEP, &head.id));
+		set_rsa_ciphertext(&mut jwe, encrypted)?;
+
+		jwe.to_json()
+	}
+
+	/**
+	Import a jwe without protected header back to the sentc head and ciphertext.
+	 */
+	pub fn import_jwe(jwe: &str) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
+	{
+		let jwe = Jwe::parse(jwe)?;
+		jwe.header.check(JWE_ALG_RSA_OAEP)?;
+
+		if !jwe.aad().is_empty() {
+			//sentc ciphertexts are encrypted without aad
+			return Err(SdkUtilError::JoseInvalidFormat);
+		}
+
+		Ok((jwe.encrypted_head()?, rsa_ciphertext(&jwe)))
+	}
+}
+
+impl JosePk for PublicKey
+{
+	fn encrypt_jwe_with_user_key(reply_public_key: &UserPublicKeyData, data: &[u8]) -> Result<String, SdkUtilError>
+	{
+		let public_key = Self::from_user_key(reply_public_key)?;
+
+		let mut jwe = Jwe::protected(JweHeader::new(JWE_ALG_RSA_OAEP, &reply_public_key.public_key_id))?;
+
+		let encrypted = public_key.encrypt_with_aad(data, jwe.aad())?;
+		set_rsa_ciphertext(&mut jwe, &encrypted)?;
+
+		jwe.to_compact()
+	}
+}
+
+impl JoseSk for SecretKey
+{
+	fn decrypt_jwe(&self, jwe: &str) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let jwe = Jwe::parse(jwe)?;
+		jwe.header.check(JWE_ALG_RSA_OAEP)?;
+
+		if jwe.encrypted_key.len() != RSA_WRAPPED_KEY_LENGTH {
+			return Err(SdkUtilError::JoseInvalidFormat);
+		}
+
+		Ok(self
+			.key
+			.decrypt_with_aad(&rsa_ciphertext(&jwe), jwe.aad())?)
+	}
+}
+
+impl JoseSignKey for SignKey
+{
+	fn jws_alg(&self) -> Result<&'static str, SdkUtilError>
+	{
+		Ok(JWS_ALG_EDDSA)
+	}
+}
+
+impl JoseVerifyKey for VerifyKey
+{
+	fn jws_alg(alg: &str) -> Result<&'static str, SdkUtilError>
+	{
+		match alg {
+			FIPS_OPENSSL_ED25519 => Ok(JWS_ALG_EDDSA),
+			_ => Err(SdkUtilError::JoseAlgNotSupported(alg.to_string())),
+		}
+	}
+}
@@ -13,6 +13,7 @@ use crate::core::hmac::HmacKey as CoreHmacKey;
 use crate::core::sortable::NonSortableKeys;
 
 mod asym;
+mod jose;
 mod searchable;
 mod sign;
 mod symmetric_key;
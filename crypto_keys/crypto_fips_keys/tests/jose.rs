@@ -0,0 +1,33 @@
+#![cfg(feature = "full")]
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use sentc_crypto_common::user::UserVerifyKeyData;
+use sentc_crypto_fips_keys::core::sign::FIPS_OPENSSL_ED25519;
+use sentc_crypto_fips_keys::util::VerifyKey;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::export_key_to_pem;
+use sentc_crypto_utils::jose::JoseVerifyKey;
+
+//rfc 8037 a.4 and a.5, the payload is detached
+const RFC_8037_ED25519_X: &str = "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo";
+const RFC_8037_JWS: &str = "eyJhbGciOiJFZERTQSJ9..hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+const RFC_8037_PAYLOAD: &[u8] = b"Example of Ed25519 signing";
+
+#[test]
+fn test_rfc_8037_jws()
+{
+	openssl::provider::Provider::load(None, "fips").unwrap();
+
+	let verify_key = UserVerifyKeyData {
+		verify_key_pem: export_key_to_pem(&Base64UrlUnpadded::decode_vec(RFC_8037_ED25519_X).unwrap()).unwrap(),
+		verify_key_alg: FIPS_OPENSSL_ED25519.to_string(),
+		verify_key_id: "rfc_8037".to_string(),
+	};
+
+	VerifyKey::verify_jws_detached(RFC_8037_JWS, RFC_8037_PAYLOAD, &verify_key).unwrap();
+
+	assert!(matches!(
+		VerifyKey::verify_jws_detached(RFC_8037_JWS, b"Example of Ed25519 signing.", &verify_key),
+		Err(SdkUtilError::VerifyFailed)
+	));
+}
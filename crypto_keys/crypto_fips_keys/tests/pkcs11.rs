@@ -0,0 +1,134 @@
+//! Tests with a pkcs11 token. The tests are skipped if SENTC_PKCS11_MODULE is not set.
+//!
+//! With SoftHSM:
+//! softhsm2-util --init-token --free --label sentc --pin 1234 --so-pin 1234
+//! SENTC_PKCS11_MODULE=/usr/lib/softhsm/libsofthsm2.so cargo test --package sentc-crypto-fips-keys --features pkcs11 --test pkcs11
+
+#![cfg(feature = "pkcs11")]
+
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sentc_crypto_core::cryptomat::{Pk, SignK, SignKeyComposer, Sk, SkComposer, SymKeyGen, VerifyK};
+use sentc_crypto_fips_keys::core::asym::RsaSk;
+use sentc_crypto_fips_keys::core::pkcs11::Pkcs11Module;
+use sentc_crypto_fips_keys::core::sign::Ed25519FIPSSignK;
+use sentc_crypto_fips_keys::core::sym::Aes256GcmKey;
+
+static MODULE: OnceLock<Option<Pkcs11Module>> = OnceLock::new();
+
+fn token() -> Option<String>
+{
+	let token = std::env::var("SENTC_PKCS11_TOKEN").unwrap_or_else(|_| "sentc".to_string());
+
+	let module = MODULE.get_or_init(|| {
+		let path = std::env::var("SENTC_PKCS11_MODULE").ok()?;
+		let pin = std::env::var("SENTC_PKCS11_PIN").unwrap_or_else(|_| "1234".to_string());
+
+		let module = Pkcs11Module::load(path).unwrap();
+		module.open_token(&token, &pin).unwrap();
+
+		Some(module)
+	});
+
+	module.as_ref().map(|_| token)
+}
+
+fn key_label(name: &str) -> String
+{
+	let time = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.unwrap()
+		.as_nanos();
+
+	format!("{}_{}", name, time)
+}
+
+#[test]
+fn test_decrypt_in_token()
+{
+	let Some(token) = token() else {
+		return;
+	};
+
+	let (sk, pk) = RsaSk::generate_in_token(&token, &key_label("decrypt")).unwrap();
+
+	let encrypted = pk.encrypt(b"hello world").unwrap();
+	let decrypted = sk.decrypt(&encrypted).unwrap();
+
+	assert_eq!(decrypted, b"hello world");
+}
+
+#[test]
+fn test_sign_in_token()
+{
+	let Some(token) = token() else {
+		return;
+	};
+
+	let (sk, vk) = Ed25519FIPSSignK::generate_in_token(&token, &key_label("sign")).unwrap();
+
+	let signed = sk.sign(b"hello world").unwrap();
+	let (data, verified) = vk.verify(&signed).unwrap();
+
+	assert!(verified);
+	assert_eq!(data, b"hello world");
+}
+
+#[test]
+fn test_export_only_the_reference()
+{
+	let Some(token) = token() else {
+		return;
+	};
+
+	let label = key_label("export");
+
+	let (sk, pk) = RsaSk::generate_in_token(&token, &label).unwrap();
+
+	let exported = sk.export().unwrap();
+
+	assert!(exported.starts_with(b"pkcs11:"));
+	assert!(String::from_utf8(exported.clone())
+		.unwrap()
+		.contains(&label));
+
+	let imported = RsaSk::try_from(exported).unwrap();
+
+	let encrypted = pk.encrypt(b"hello world").unwrap();
+	assert_eq!(imported.decrypt(&encrypted).unwrap(), b"hello world");
+
+	//the encrypted reference from the server
+	let master_key = Aes256GcmKey::generate().unwrap();
+	let encrypted_key = sk.encrypt_by_master_key(&master_key).unwrap();
+
+	let decrypted_key = RsaSk::decrypt_by_master_key(&master_key, &encrypted_key, "fips_openssl_rsa_oaep_wrap").unwrap();
+	assert_eq!(decrypted_key.decrypt(&encrypted).unwrap(), b"hello world");
+
+	//same for the sign key
+	let (sign_key, vk) = Ed25519FIPSSignK::generate_in_token(&token, &label).unwrap();
+	let encrypted_key = sign_key.encrypt_by_master_key(&master_key).unwrap();
+
+	let decrypted_key = Ed25519FIPSSignK::decrypt_by_master_key(&master_key, &encrypted_key, "fips_openssl_ED25519").unwrap();
+	let (_, verified) = vk.verify(&decrypted_key.sign(b"hello").unwrap()).unwrap();
+	assert!(verified);
+}
+
+#[test]
+fn test_load_key_by_label()
+{
+	let Some(token) = token() else {
+		return;
+	};
+
+	let label = key_label("load");
+
+	let (_, pk) = RsaSk::generate_in_token(&token, &label).unwrap();
+
+	let sk = RsaSk::from_token(&token, &label).unwrap();
+
+	let encrypted = pk.encrypt(b"hello world").unwrap();
+	assert_eq!(sk.decrypt(&encrypted).unwrap(), b"hello world");
+
+	assert!(RsaSk::from_token(&token, "not_existing_key").is_err());
+}
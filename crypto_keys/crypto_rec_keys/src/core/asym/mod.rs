@@ -7,10 +7,13 @@ pub use crate::core::asym::ecies_ml_kem_hybrid::ECIES_ML_KEM_REC_HYBRID_OUTPUT;
 use crate::core::asym::ecies_ml_kem_hybrid::{EciesMlKemHybridPk, EciesMlKemHybridSk};
 pub use crate::core::asym::pqc_ml_kem::ML_KEM_REC_OUTPUT;
 use crate::core::asym::pqc_ml_kem::{MlKemPk, MlKemSk};
+pub use crate::core::asym::x_wing::X_WING_REC_OUTPUT;
+use crate::core::asym::x_wing::{XWingPk, XWingSk};
 
 mod ecies;
 mod ecies_ml_kem_hybrid;
 mod pqc_ml_kem;
+mod x_wing;
 
 macro_rules! deref_macro {
     ($self:expr, $method:ident $(, $args:expr)*) => {
@@ -18,6 +21,7 @@ macro_rules! deref_macro {
             Self::Ecies(inner) => inner.$method($($args),*),
             Self::MlKem(inner) => inner.$method($($args),*),
 			Self::EciesMlKemHybrid(inner) => inner.$method($($args),*),
+			Self::XWing(inner) => inner.$method($($args),*),
         }
     };
 }
@@ -35,7 +39,7 @@ macro_rules! crypto_alg_impl {
 }
 
 macro_rules! get_inner_key {
-	($st:ty,$t:ident) => {
+	($st:ty,$t:ident,$w:ident) => {
 		impl $st
 		{
 			pub fn ecies_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
@@ -52,6 +56,11 @@ macro_rules! get_inner_key {
 			{
 				Ok(Self::EciesMlKemHybrid($t::from_bytes(bytes_x, bytes_k)?))
 			}
+
+			pub fn x_wing_from_bytes_owned(bytes_x: Vec<u8>, bytes_k: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::XWing($w::from_bytes(bytes_x, bytes_k)?))
+			}
 		}
 	};
 }
@@ -62,10 +71,11 @@ pub enum PublicKey
 	Ecies(EciesPk),
 	MlKem(MlKemPk),
 	EciesMlKemHybrid(EciesMlKemHybridPk),
+	XWing(XWingPk),
 }
 
 crypto_alg_impl!(PublicKey);
-get_inner_key!(PublicKey, EciesMlKemHybridPk);
+get_inner_key!(PublicKey, EciesMlKemHybridPk, XWingPk);
 
 impl Pk for PublicKey
 {
@@ -90,10 +100,11 @@ pub enum SecretKey
 	Ecies(EciesSk),
 	MlKem(MlKemSk),
 	EciesMlKemHybrid(EciesMlKemHybridSk),
+	XWing(XWingSk),
 }
 
 crypto_alg_impl!(SecretKey);
-get_inner_key!(SecretKey, EciesMlKemHybridSk);
+get_inner_key!(SecretKey, EciesMlKemHybridSk, XWingSk);
 
 impl Sk for SecretKey
 {
@@ -120,6 +131,7 @@ impl SkComposer for SecretKey
 			ECIES_REC_OUTPUT => Self::Ecies(bytes.try_into()?),
 			ML_KEM_REC_OUTPUT => Self::MlKem(bytes.into()),
 			ECIES_ML_KEM_REC_HYBRID_OUTPUT => Self::EciesMlKemHybrid(bytes.try_into()?),
+			X_WING_REC_OUTPUT => Self::XWing(bytes.try_into()?),
 			_ => return Err(Error::AlgNotFound),
 		};
 
@@ -140,6 +152,9 @@ impl StaticKeyPair for SecretKey
 		#[cfg(feature = "ecies")]
 		let (sk, pk) = EciesSk::generate_static_keypair()?;
 
+		#[cfg(feature = "x_wing")]
+		let (sk, pk) = XWingSk::generate_static_keypair()?;
+
 		Ok((sk.into(), pk.into()))
 	}
 }
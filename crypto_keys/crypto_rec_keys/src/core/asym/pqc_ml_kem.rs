@@ -1,5 +1,5 @@
 use safe_oqs::kem;
-use safe_oqs::kem::{Kem, PublicKey, SecretKey};
+use safe_oqs::kem::{Ciphertext, Kem, PublicKey, SecretKey, SharedSecret};
 use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, from_bytes_owned_single_value, into_bytes_from_bytes_inner, Error};
 use sentc_crypto_fips_keys::core::sym::raw_decrypt;
@@ -8,7 +8,7 @@ use crate::core::sym::raw_encrypt;
 
 pub const ML_KEM_REC_OUTPUT: &str = "ML_KEM_REC_768";
 
-const CT_LEN: usize = 1088; //from oqs::ffi::kem::OQS_KEM_ml_kem_768_length_ciphertext
+pub(super) const CT_LEN: usize = 1088; //from oqs::ffi::kem::OQS_KEM_ml_kem_768_length_ciphertext
 
 #[derive(Clone)]
 pub struct MlKemPk(Vec<u8>);
@@ -94,16 +94,36 @@ pub(super) fn generate_keypair() -> Result<(SecretKey, PublicKey), Error>
 	Ok((sk, pk))
 }
 
-pub(super) fn encrypt_internally(pk: &[u8], data: &[u8]) -> Result<Vec<u8>, Error>
+pub(super) fn encapsulate(pk: &[u8]) -> Result<(Ciphertext, SharedSecret), Error>
 {
 	let kem_alg = get_oqs()?;
 	let pk = kem_alg
 		.public_key_from_bytes(pk)
 		.ok_or(Error::KeyDecryptFailed)?;
 
-	let (ciphertext, shared_secret) = kem_alg
-		.encapsulate(pk)
-		.map_err(|_| Error::EncryptionFailed)?;
+	kem_alg.encapsulate(pk).map_err(|_| Error::EncryptionFailed)
+}
+
+pub(super) fn decapsulate(sk: &[u8], ciphertext: &[u8]) -> Result<SharedSecret, Error>
+{
+	let kem_alg = get_oqs()?;
+
+	let sk = kem_alg
+		.secret_key_from_bytes(sk)
+		.ok_or(Error::KeyDecryptFailed)?;
+
+	let ct = kem_alg
+		.ciphertext_from_bytes(ciphertext)
+		.ok_or(Error::DecryptionFailedCiphertextShort)?;
+
+	kem_alg
+		.decapsulate(sk, ct)
+		.map_err(|_| Error::DecryptionFailed)
+}
+
+pub(super) fn encrypt_internally(pk: &[u8], data: &[u8]) -> Result<Vec<u8>, Error>
+{
+	let (ciphertext, shared_secret) = encapsulate(pk)?;
 
 	let encrypted = raw_encrypt(shared_secret.as_ref(), data)?;
 
@@ -120,19 +140,7 @@ pub(super) fn decrypt_internally(sk: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>
 		return Err(Error::DecryptionFailedCiphertextShort);
 	}
 
-	let kem_alg = get_oqs()?;
-
-	let sk = kem_alg
-		.secret_key_from_bytes(sk)
-		.ok_or(Error::KeyDecryptFailed)?;
-
-	let ct = kem_alg
-		.ciphertext_from_bytes(&ciphertext[..CT_LEN])
-		.ok_or(Error::DecryptionFailedCiphertextShort)?;
-
-	let shared_secret = kem_alg
-		.decapsulate(sk, ct)
-		.map_err(|_| Error::DecryptionFailed)?;
+	let shared_secret = decapsulate(sk, &ciphertext[..CT_LEN])?;
 
 	let encrypted = &ciphertext[CT_LEN..];
 
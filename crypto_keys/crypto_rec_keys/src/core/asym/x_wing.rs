@@ -0,0 +1,378 @@
+//! X-Wing hybrid kem (X25519 + ML-KEM-768) from draft-connolly-cfrg-xwing-kem.
+//!
+//! The public key is the ml-kem encapsulation key followed by the X25519 public key and the kem ciphertext is
+//! the ml-kem ciphertext followed by the X25519 ephemeral public key. Both are the same as in the draft.
+//! The shared secret of the combiner is used as aes key, the aes output is appended to the kem ciphertext.
+//!
+//! oqs can't derive the ml-kem key pair from a seed, so the secret key is stored expanded
+//! (X25519 secret key and ml-kem decapsulation key) and not as the 32 bytes seed of the draft.
+
+use openssl::derive::Deriver;
+use openssl::hash::{hash, MessageDigest};
+use openssl::pkey::{HasPrivate, HasPublic, PKey, Private, Public};
+use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
+use sentc_crypto_core::{crypto_alg_str_impl, Error};
+use sentc_crypto_fips_keys::core::sym::{raw_decrypt, raw_encrypt};
+
+use crate::core::asym::ecies::{generate_key_pair, import_pk, import_sk};
+use crate::core::asym::pqc_ml_kem::CT_LEN as ML_KEM_CT_LEN;
+use crate::core::{export_pk, export_sk};
+use crate::{hybrid_import_export, hybrid_sk_from_bytes};
+
+pub const X_WING_REC_OUTPUT: &str = "X-Wing_REC";
+
+const X25519_LENGTH: usize = 32;
+const CT_LEN: usize = ML_KEM_CT_LEN + X25519_LENGTH;
+
+//the ascii of \.//^\
+const X_WING_LABEL: &[u8; 6] = b"\\.//^\\";
+
+#[derive(Clone)]
+pub struct XWingPk
+{
+	x: PKey<Public>,
+	k: Vec<u8>,
+}
+
+crypto_alg_str_impl!(XWingPk, X_WING_REC_OUTPUT);
+hybrid_import_export!(XWingPk, import_pk, export_pk);
+
+impl Into<super::PublicKey> for XWingPk
+{
+	fn into(self) -> super::PublicKey
+	{
+		super::PublicKey::XWing(self)
+	}
+}
+
+impl Pk for XWingPk
+{
+	fn sign_public_key<S: SignK>(&self, sign_key: &S) -> Result<S::Signature, Error>
+	{
+		sign_key.sign_only(self.encoded()?)
+	}
+
+	fn verify_public_key<V: VerifyK>(&self, verify_key: &V, sig: &V::Signature) -> Result<bool, Error>
+	{
+		verify_key.verify_only(sig, &self.encoded()?)
+	}
+
+	fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let (ct, ss) = encapsulate(&self.k, &self.x)?;
+
+		let encrypted = raw_encrypt(&ss, data)?;
+
+		let mut cipher_text = Vec::with_capacity(CT_LEN + encrypted.len());
+		cipher_text.extend_from_slice(&ct);
+		cipher_text.extend_from_slice(&encrypted);
+
+		Ok(cipher_text)
+	}
+}
+
+impl XWingPk
+{
+	/**
+	The X-Wing encoded public key: ml-kem encapsulation key || X25519 public key
+	 */
+	pub fn encoded(&self) -> Result<Vec<u8>, Error>
+	{
+		Ok([self.k.as_slice(), &export_pk(&self.x)?].concat())
+	}
+}
+
+pub struct XWingSk
+{
+	x: PKey<Private>,
+	k: Vec<u8>,
+}
+
+crypto_alg_str_impl!(XWingSk, X_WING_REC_OUTPUT);
+hybrid_import_export!(XWingSk, import_sk, export_sk);
+hybrid_sk_from_bytes!(XWingSk, import_sk);
+
+impl Into<super::SecretKey> for XWingSk
+{
+	fn into(self) -> super::SecretKey
+	{
+		super::SecretKey::XWing(self)
+	}
+}
+
+impl Sk for XWingSk
+{
+	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		let k = [&export_sk(&self.x)?, self.k.as_slice()].concat();
+
+		master_key.encrypt(&k)
+	}
+
+	fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		if ciphertext.len() <= CT_LEN {
+			return Err(Error::DecryptionFailedCiphertextShort);
+		}
+
+		let ss = decapsulate(&self.k, &self.x, &ciphertext[..CT_LEN])?;
+
+		raw_decrypt(&ss, &ciphertext[CT_LEN..])
+	}
+}
+
+impl StaticKeyPair for XWingSk
+{
+	type SecretKey = Self;
+	type PublicKey = XWingPk;
+
+	fn generate_static_keypair() -> Result<(Self::SecretKey, Self::PublicKey), Error>
+	{
+		let (x_pk, x_sk) = generate_key_pair()?;
+		let (k_sk, k_pk) = super::pqc_ml_kem::generate_keypair()?;
+
+		Ok((
+			Self {
+				x: x_sk,
+				k: k_sk.into_vec(),
+			},
+			XWingPk {
+				x: x_pk,
+				k: k_pk.into_vec(),
+			},
+		))
+	}
+}
+
+//__________________________________________________________________________________________________
+
+fn encapsulate<T: HasPublic>(pk_m: &[u8], pk_x: &PKey<T>) -> Result<(Vec<u8>, [u8; 32]), Error>
+{
+	let (ct_m, ss_m) = super::pqc_ml_kem::encapsulate(pk_m)?;
+
+	let (ep_pk, ep_sk) = generate_key_pair()?;
+	let ct_x = export_pk(&ep_pk)?;
+	let ss_x = diffie_hellman(&ep_sk, pk_x).map_err(|_| Error::EncryptionFailed)?;
+
+	let ss = combiner(ss_m.as_ref(), &ss_x, &ct_x, &export_pk(pk_x)?)?;
+
+	Ok(([ct_m.as_ref(), &ct_x].concat(), ss))
+}
+
+fn decapsulate<T: HasPrivate>(sk_m: &[u8], sk_x: &PKey<T>, ciphertext: &[u8]) -> Result<[u8; 32], Error>
+{
+	let (ct_m, ct_x) = ciphertext.split_at(ML_KEM_CT_LEN);
+
+	let ss_m = super::pqc_ml_kem::decapsulate(sk_m, ct_m)?;
+
+	let ep_pk = import_pk(ct_x).map_err(|_| Error::DecryptionFailed)?;
+	let ss_x = diffie_hellman(sk_x, &ep_pk).map_err(|_| Error::DecryptionFailed)?;
+
+	//the public key is derived from the secret key
+	combiner(ss_m.as_ref(), &ss_x, ct_x, &export_pk(sk_x)?)
+}
+
+fn diffie_hellman<T: HasPrivate, P: HasPublic>(sk: &PKey<T>, pk: &PKey<P>) -> Result<Vec<u8>, openssl::error::ErrorStack>
+{
+	let mut deriver = Deriver::new(sk)?;
+	deriver.set_peer(pk)?;
+
+	deriver.derive_to_vec()
+}
+
+fn combiner(ss_m: &[u8], ss_x: &[u8], ct_x: &[u8], pk_x: &[u8]) -> Result<[u8; 32], Error>
+{
+	let input = [ss_m, ss_x, ct_x, pk_x, X_WING_LABEL].concat();
+
+	let digest = hash(MessageDigest::sha3_256(), &input).map_err(|_| Error::KeyCreationFailed)?;
+
+	let mut ss = [0u8; 32];
+	ss.copy_from_slice(&digest);
+
+	Ok(ss)
+}
+
+#[cfg(test)]
+mod test
+{
+	use core::str::from_utf8;
+
+	use sentc_crypto_core::Error::{DecryptionFailed, DecryptionFailedCiphertextShort};
+
+	use super::*;
+
+	fn from_hex(hex: &str) -> Vec<u8>
+	{
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_key_gen()
+	{
+		let (_, pk) = XWingSk::generate_static_keypair().unwrap();
+
+		assert_eq!(pk.encoded().unwrap().len(), 1216);
+	}
+
+	#[test]
+	fn test_encrypt_and_decrypt()
+	{
+		let (sk, pk) = XWingSk::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		let decrypted = sk.decrypt(&encrypted).unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		let decrypted_text = from_utf8(&decrypted).unwrap();
+
+		assert_eq!(text, decrypted_text);
+	}
+
+	#[test]
+	fn test_kem()
+	{
+		let (sk, pk) = XWingSk::generate_static_keypair().unwrap();
+
+		let (ct, ss) = encapsulate(&pk.k, &pk.x).unwrap();
+
+		assert_eq!(ct.len(), CT_LEN);
+		assert_eq!(decapsulate(&sk.k, &sk.x, &ct).unwrap(), ss);
+	}
+
+	#[test]
+	fn test_decapsulate_from_other_implementation()
+	{
+		//the first test vector of the draft: seed 7f9c2ba4..ef26 (SHAKE128 of the empty string) expanded
+		//into the X25519 secret key and the ml-kem decapsulation key, ct and ss are the published values.
+		let sk = XWingSk::from_bytes(from_hex(SK_X), from_hex(SK_M)).unwrap();
+
+		//the ml-kem encapsulation key is stored in the decapsulation key
+		let pk = [&sk.k[1152..2336], export_pk(&sk.x).unwrap().as_slice()].concat();
+
+		assert_eq!(pk, from_hex(PK));
+
+		let ss = decapsulate(&sk.k, &sk.x, &from_hex(CT)).unwrap();
+
+		assert_eq!(ss.as_slice(), from_hex(SS));
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_key()
+	{
+		let (_sk, pk) = XWingSk::generate_static_keypair().unwrap();
+
+		let (sk, _pk) = XWingSk::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		let decrypted_result = sk.decrypt(&encrypted);
+
+		assert!(matches!(decrypted_result, Err(DecryptionFailed)));
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_ciphertext()
+	{
+		let (sk, pk) = XWingSk::generate_static_keypair().unwrap();
+
+		let encrypted = pk.encrypt(b"Hello world").unwrap();
+
+		let decrypted_result = sk.decrypt(&encrypted[..CT_LEN]);
+
+		assert!(matches!(decrypted_result, Err(DecryptionFailedCiphertextShort)));
+	}
+
+	const SK_X: &str = "cd3f2cce66ed130e5e73a0ebd454e15488885a2a1544252a20e0f58b6e8fc27b";
+
+	const SK_M: &str = "\
+2038c73d3a323011778e8051ee3a32c601cd65dac0a8f9a9408123f064488beb618a1143d45a9cf816cc959b839cbb0f1f9cbb0c95aa5c7b2a85035e88c4644f\
+55598d517ba6c3b5db0a323422184871507bc3bf06ca4b7de396fcc147e2d38d85e32aa0828c507bc2dbc9c4938439ed872207bcbffe23687afc6b7611b8fd7a\
+c27ad35a8abb26d91074010b6c7355a1514ca5ba81316b0b5cddb9a6d819a21a8700d8f99b0788413d5c3c7d7ca19b8002089a8a8ca27b05905e94bc61e3d7cf\
+2db697c5962b0b4b393bb09bbffa2e8c6c6e93c2bc34ea4817165549da2d6ba8a44037b810b77c0e1934a69537163134261b2342c868346b41d8090381c0c00c\
+331be38822bfe296bfca35921820e7643be8a82bce59cc65d527fae8c703594ad857184fe479daecab24979cf1628256d48f25da7e6a306b39a0a852540cad18\
+0c50c0b4c677a02e8b9b796401c5dc14a52825a088c31b447155044f0b081873359f0a52c468e68afd394e8948ac79c2937840a4dfc81faf5214bdda1c02d4ce\
+eb692560b6b867d7ca3bfbaf5f1c331e36a9eae696b0925e67f19432b097f877a599d8aedc302b91381097d90d2188246a970ec3580aab15430ad747727b42a7\
+f7c3cb7b120ca68cd6664776d99d65f3086ca5bcb1988f1dbc99aac26d67010bd308c0ce97959e8b8648212f3d262e4df5b74e10cbb0ab3ea05c48df64b812f8\
+10354776bc577692e52199cb7a6034cb03d861f95b3b8e5114623b71d5f0142223ba17db51d4a591b24aa463ca96ad6a4960183ad7249d294abf90712f85c89e\
+56a30670877c0c5a4850386c0a822398042b69b672bd359bf6774a69ba1a6b990e14d1ab128035887a2f9ce660c614907ff2175a770864e27407e31221827f38\
+1814d8eac6be6abfdbca71b472b58929c13797362572bfaa165d864a9dcc71610db3b233b40e3b256726e3173b7c7e7d75cf94282ba55622bce6799617c912e5\
+1030f826d1a6c278749cc86452c36297917a60ee271bfe05ac4d279db44a467133227b806a799694651625c50a0679109e40f66f5c84bb6a394f981c607b54b9\
+0c3082c3843d814802017a3a0cd435d000465d399b6ba924530173fb1444635b1aa5cca4b9d38d27b7a8f0d5247ab13423fa46504acb66b06e961111bac0693e\
+e07d14829735d63a8447a2f4ec607f02967c631e52bb685033abbfd86f5620727075cd3f452ba0d7935366aedf2712c2d92ab9534762d1bde033a2f3f06ee7db\
+9e5f0131e1364dad73725bd370927605ed38194019b9e4f547bab62dab50b2e16ab6af94cb6c551ee4379aac0772299cbbe3a58b08e9b6f70ca9ec746f22c4a9\
+4ea97260095ae4fca6c90a2fd25a9b25ec5df5b0275d484e07226a1d7334a28bbe248a5c56b25240cb84dfdb2847a8851e107d89c554c6338a8e55abd737aedf\
+4104cc08550f724a93d80dac899663e509917b96bdf15ca3013f1262030dc91ea87aabc7c8950ec174834a4b35881323aa24a0730b71c047a25ba2f5a6c404f7\
+2cc294910ee0ce90351bb844a4480aa25c6452f4d0a551390a2d3a0cc81c89b2184e1c484e35a36bc65031778a6c93e68db1c17ce2c01f00106377f47ce20523\
+e2236b35a8c24b39b10aa1323a96a919a2ced88400633a7b07131713fc14b2b5b19cfc3da5fa1a92c49f25513e0fd30d6b1611c9ab9635d7086727a4b7d21d34\
+244e66969cf15b3b2a785329f61b096b277ea037383479a6b556de7231fe4b7fa9c9ac24c0699a0018a5253401bacfa905ca816573e56a2d2e067e9b7287533b\
+a13a937dedb31fa44baced40769923610034ae31e619a170245199b3c5c39864859fe1b4c9717a07c30495bdfb98a0a002ccf56c1286cef5041dede3c44cf16b\
+f562c7448518026b3d8b9940680abd38a1575fd27b58da063bfac32c39c30869374c05c1aeb1898b6b303cc68be455346ee0af699636224a148ca2aea1046311\
+1c709f69b69c70ce8538746698c4c60a9aef0030c7924ceec42a5d36816f545eae13293460b3acb37ea0e13d70e4aa78686da398a8397c08eaf96882113fe4f7\
+bad4da40b0501e1c753efe73053c87014e8661c33099afe8bede414a5b1aa27d8392b3e131e9a70c1055878240cad0f40d5fe3cdf85236ead97e2a97448363b2\
+808caafd516cd25052c5c362543c2517e4acd0e60ec07163009b6425fc32277acee71c24bab53ed9f29e74c66a0a3564955998d76b96a9a8b50d1635a4d7a67e\
+b42df5644d330457293a8042f53cc7a69288f17ed55827e82b28e82665a86a14fbd96645eca8172c044f83bc0d8c0b4c8626985631ca87af829068f1358963cb\
+333664ca482763ba3b3bb208577f9ba6ac62c25f76592743b64be519317714cb4102cb7b2f9a25b2b4f0615de31decd9ca55026d6da0b65111b16fe52feed8a4\
+87e144462a6dba93728f500b6ffc49e515569ef25fed17aff520507368253525860f58be3be61c964604a6ac814e6935596402a520a4670b3d284318866593d1\
+5a4bb01c35e3e587ee0c67d2880d6f2407fb7a70712b838deb96c5d7bf2b44bcf6038ccbe33fbcf51a54a584fe90083c91c7a6d43d4fb15f48c60c2fd66e0a8a\
+ad4ad64e5c42bb8877c0ebec2b5e387c8a988fdc23beb9e16c8757781e0a1499c61e138c21f216c29d076979871caa6942bafc090544bee99b54b16cb9a9a364\
+d6246d9f42cce53c66b59c45c8f9ae9299a75d15180c3c952151a91b7a10772429dc4cbae6fcc622fa8018c63439f890630b9928db6bb7f9438ae4065ed34d73\
+d486f3f52f90f0807dc88dfdd8c728e954f1ac35c06c000ce41a0582580e3bb57b672972890ac5e7988e7850657116f1b57d0809aaedec0bede1ae148148311c\
+6f7e317346e5189fb8cd635b986f8c0bdd27641c584b778b3a911a80be1c9692ab8e1bbb12839573cce19df183b45835bbb55052f9fc66a1678ef2a36dea7841\
+1e6c8d60501b4e60592d13698a943b509185db912e2ea10be06171236b327c71716094c964a68b03377f513a05bcd99c1f346583bb052977a10a12adfc758034\
+e5617da4c1276585e5774e1f3b9978b09d0e9c44d3bc86151c43aad185712717340223ac381d21150a04294e97bb13bbda21b5a182b6da969e19a7fd072737fa\
+8e880a53c2428e3d049b7d2197405296ddb361912a7bcf4827ced611d0c7a7da104dde4322095339f64a61d5bb108ff0bf4d780cae509fb22c256914193ff734\
+9042581237d522828824ee3bdfd07fb03f1f942d2ea179fe722f06cc03de5b69cbd7ed3b8a90f2b40603d89f10cdf585897189f91fc51fb8cdd25c6c7dfee0c3\
+f0fc6fa4e4827531168087ef223e9b070c5a78a789fd46d4c604d69b1139d4da";
+
+	const PK: &str = "\
+e2236b35a8c24b39b10aa1323a96a919a2ced88400633a7b07131713fc14b2b5b19cfc3da5fa1a92c49f25513e0fd30d6b1611c9ab9635d7086727a4b7d21d34\
+244e66969cf15b3b2a785329f61b096b277ea037383479a6b556de7231fe4b7fa9c9ac24c0699a0018a5253401bacfa905ca816573e56a2d2e067e9b7287533b\
+a13a937dedb31fa44baced40769923610034ae31e619a170245199b3c5c39864859fe1b4c9717a07c30495bdfb98a0a002ccf56c1286cef5041dede3c44cf16b\
+f562c7448518026b3d8b9940680abd38a1575fd27b58da063bfac32c39c30869374c05c1aeb1898b6b303cc68be455346ee0af699636224a148ca2aea1046311\
+1c709f69b69c70ce8538746698c4c60a9aef0030c7924ceec42a5d36816f545eae13293460b3acb37ea0e13d70e4aa78686da398a8397c08eaf96882113fe4f7\
+bad4da40b0501e1c753efe73053c87014e8661c33099afe8bede414a5b1aa27d8392b3e131e9a70c1055878240cad0f40d5fe3cdf85236ead97e2a97448363b2\
+808caafd516cd25052c5c362543c2517e4acd0e60ec07163009b6425fc32277acee71c24bab53ed9f29e74c66a0a3564955998d76b96a9a8b50d1635a4d7a67e\
+b42df5644d330457293a8042f53cc7a69288f17ed55827e82b28e82665a86a14fbd96645eca8172c044f83bc0d8c0b4c8626985631ca87af829068f1358963cb\
+333664ca482763ba3b3bb208577f9ba6ac62c25f76592743b64be519317714cb4102cb7b2f9a25b2b4f0615de31decd9ca55026d6da0b65111b16fe52feed8a4\
+87e144462a6dba93728f500b6ffc49e515569ef25fed17aff520507368253525860f58be3be61c964604a6ac814e6935596402a520a4670b3d284318866593d1\
+5a4bb01c35e3e587ee0c67d2880d6f2407fb7a70712b838deb96c5d7bf2b44bcf6038ccbe33fbcf51a54a584fe90083c91c7a6d43d4fb15f48c60c2fd66e0a8a\
+ad4ad64e5c42bb8877c0ebec2b5e387c8a988fdc23beb9e16c8757781e0a1499c61e138c21f216c29d076979871caa6942bafc090544bee99b54b16cb9a9a364\
+d6246d9f42cce53c66b59c45c8f9ae9299a75d15180c3c952151a91b7a10772429dc4cbae6fcc622fa8018c63439f890630b9928db6bb7f9438ae4065ed34d73\
+d486f3f52f90f0807dc88dfdd8c728e954f1ac35c06c000ce41a0582580e3bb57b672972890ac5e7988e7850657116f1b57d0809aaedec0bede1ae148148311c\
+6f7e317346e5189fb8cd635b986f8c0bdd27641c584b778b3a911a80be1c9692ab8e1bbb12839573cce19df183b45835bbb55052f9fc66a1678ef2a36dea7841\
+1e6c8d60501b4e60592d13698a943b509185db912e2ea10be06171236b327c71716094c964a68b03377f513a05bcd99c1f346583bb052977a10a12adfc758034\
+e5617da4c1276585e5774e1f3b9978b09d0e9c44d3bc86151c43aad185712717340223ac381d21150a04294e97bb13bbda21b5a182b6da969e19a7fd072737fa\
+8e880a53c2428e3d049b7d2197405296ddb361912a7bcf4827ced611d0c7a7da104dde4322095339f64a61d5bb108ff0bf4d780cae509fb22c256914193ff734\
+9042581237d522828824ee3bdfd07fb03f1f942d2ea179fe722f06cc03de5b69859edb06eff389b27dce59844570216223593d4ba32d9abac8cd049040ef6534";
+
+	const CT: &str = "\
+b83aa828d4d62b9a83ceffe1d3d3bb1ef31264643c070c5798927e41fb07914a273f8f96e7826cd5375a283d7da885304c5de0516a0f0654243dc5b97f8bfeb8\
+31f68251219aabdd723bc6512041acbaef8af44265524942b902e68ffd23221cda70b1b55d776a92d1143ea3a0c475f63ee6890157c7116dae3f62bf72f60acd\
+2bb8cc31ce2ba0de364f52b8ed38c79d719715963a5dd3842d8e8b43ab704e4759b5327bf027c63c8fa857c4908d5a8a7b88ac7f2be394d93c3706ddd4e698cc\
+6ce370101f4d0213254238b4a2e8821b6e414a1cf20f6c1244b699046f5a01caa0a1a55516300b40d2048c77cc73afba79afeea9d2c0118bdf2adb8870dc328c\
+5516cc45b1a2058141039e2c90a110a9e16b318dfb53bd49a126d6b73f215787517b8917cc01cabd107d06859854ee8b4f9861c226d3764c87339ab16c3667d2\
+f49384e55456dd40414b70a6af841585f4c90c68725d57704ee8ee7ce6e2f9be582dbee985e038ffc346ebfb4e22158b6c84374a9ab4a44e1f91de5aac5197f8\
+9bc5e5442f51f9a5937b102ba3beaebf6e1c58380a4a5fedce4a4e5026f88f528f59ffd2db41752b3a3d90efabe463899b7d40870c530c8841e8712b733668ed\
+033adbfafb2d49d37a44d4064e5863eb0af0a08d47b3cc888373bc05f7a33b841bc2587c57eb69554e8a3767b7506917b6b70498727f16eac1a36ec8d8cfaf75\
+1549f2277db277e8a55a9a5106b23a0206b4721fa9b3048552c5bd5b594d6e247f38c18c591aea7f56249c72ce7b117afcc3a8621582f9cf71787e183dee0936\
+7976e98409ad9217a497df888042384d7707a6b78f5f7fb8409e3b535175373461b776002d799cbad62860be70573ecbe13b246e0da7e93a52168e0fb6a9756b\
+895ef7f0147a0dc81bfa644b088a9228160c0f9acf1379a2941cd28c06ebc80e44e17aa2f8177010afd78a97ce0868d1629ebb294c5151812c583daeb8868522\
+0f4da9118112e07041fcc24d5564a99fdbde28869fe0722387d7a9a4d16e1cc8555917e09944aa5ebaaaec2cf62693afad42a3f518fce67d273cc6c9fb5472b3\
+80e8573ec7de06a3ba2fd5f931d725b493026cb0acbd3fe62d00e4c790d965d7a03a3c0b4222ba8c2a9a16e2ac658f572ae0e746eafc4feba023576f08942278\
+a041fb82a70a595d5bacbf297ce2029898a71e5c3b0d1c6228b485b1ade509b35fbca7eca97b2132e7cb6bc465375146b7dceac969308ac0c2ac89e7863eb894\
+3015b24314cafb9c7c0e85fe543d56658c213632599efabfc1ec49dd8c88547bb2cc40c9d38cbd3099b4547840560531d0188cd1e9c23a0ebee0a03d5577d66b\
+1d2bcb4baaf21cc7fef1e03806ca96299df0dfbc56e1b2b43e4fc20c37f834c4af62127e7dae86c3c25a2f696ac8b589dec71d595bfbe94b5ed4bc07d800b330\
+796fda89edb77be0294136139354eb8cd37591578f9c600dd9be8ec6219fdd507adf3397ed4d68707b8d13b24ce4cd8fb22851bfe9d632407f31ed6f7cb1600d\
+e56f17576740ce2a32fc5145030145cfb97e63e0e41d354274a079d3e6fb2e15";
+
+	const SS: &str = "d2df0522128f09dd8e2c92b1e905c793d8f57a54c3da25861f10bf4ca613e384";
+}
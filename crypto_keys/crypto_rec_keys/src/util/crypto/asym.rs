@@ -1,8 +1,8 @@
 use openssl::base64::{decode_block, encode_block};
 use sentc_crypto_common::crypto::EncryptedHead;
-use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_common::user::UserPublicKeyData;
 use sentc_crypto_core::cryptomat::Sk;
-use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SignKWrapper, SkCryptoWrapper, VerifyKFromUserKeyWrapper};
+use sentc_crypto_utils::cryptomat::{verify_with_policy, PkFromUserKeyWrapper, SignKWrapper, SignaturePolicy, SkCryptoWrapper};
 use sentc_crypto_utils::error::SdkUtilError;
 
 use crate::core::asym::PublicKey as CorePk;
@@ -11,30 +11,18 @@ use crate::util::{PublicKey, SecretKey, VerifyKey};
 
 impl SkCryptoWrapper for SecretKey
 {
-	fn decrypt_raw(&self, encrypted_data: &[u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	fn decrypt_raw<'p>(&self, encrypted_data: &[u8], head: &EncryptedHead, policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>
 	{
-		match &head.sign {
-			None => Ok(self.key.decrypt(encrypted_data)?),
-			Some(h) => {
-				match verify_key {
-					None => {
-						let (_, encrypted_data_without_sig) = VerifyKey::split_sig_and_data(&h.alg, encrypted_data)?;
-						Ok(self.key.decrypt(encrypted_data_without_sig)?)
-					},
-					Some(vk) => {
-						let encrypted_data_without_sig = VerifyKey::verify_with_user_key(vk, encrypted_data, h)?;
-						Ok(self.key.decrypt(encrypted_data_without_sig)?)
-					},
-				}
-			},
-		}
+		let encrypted_data = verify_with_policy::<VerifyKey>(encrypted_data, head.sign.as_ref(), &self.key_id, policy.into())?;
+
+		Ok(self.key.decrypt(encrypted_data)?)
 	}
 
-	fn decrypt_string(&self, encrypted_data_with_head: &str, verify_key: Option<&UserVerifyKeyData>) -> Result<String, SdkUtilError>
+	fn decrypt_string<'p>(&self, encrypted_data_with_head: &str, policy: impl Into<SignaturePolicy<'p>>) -> Result<String, SdkUtilError>
 	{
 		let encrypted = decode_block(encrypted_data_with_head).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)?;
 
-		let decrypted = self.decrypt(&encrypted, verify_key)?;
+		let decrypted = self.decrypt(&encrypted, policy)?;
 
 		String::from_utf8(decrypted).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)
 	}
@@ -0,0 +1,73 @@
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_core::cryptomat::CryptoAlg;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::jose::{JosePk, JoseSignKey, JoseSk, JoseSymKey, JoseVerifyKey, JWS_ALG_EDDSA};
+
+use crate::core::sign::{SignKey as CoreSign, FIPS_OPENSSL_ED25519};
+use crate::util::{PublicKey, SecretKey, SignKey, SymmetricKey, VerifyKey};
+
+const AES_IV_LENGTH: usize = 12;
+const AES_TAG_LENGTH: usize = 16;
+
+impl JoseSymKey for SymmetricKey
+{
+	//the openssl aes gcm output is: iv || tag || ciphertext
+	fn split_aead<'a>(&self, encrypted: &'a [u8]) -> Result<(&'a [u8], &'a [u8], &'a [u8]), SdkUtilError>
+	{
+		if encrypted.len() < AES_IV_LENGTH + AES_TAG_LENGTH {
+			return Err(SdkUtilError::DecodeEncryptedDataFailed);
+		}
+
+		let (iv, rest) = encrypted.split_at(AES_IV_LENGTH);
+		let (tag, ciphertext) = rest.split_at(AES_TAG_LENGTH);
+
+		Ok((iv, ciphertext, tag))
+	}
+
+	fn join_aead(&self, iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>
+	{
+		[iv, tag, ciphertext].concat()
+	}
+}
+
+//the rec ecies uses its own kdf and ml-kem has no jose registration
+
+impl JosePk for PublicKey
+{
+	fn encrypt_jwe_with_user_key(reply_public_key: &UserPublicKeyData, _data: &[u8]) -> Result<String, SdkUtilError>
+	{
+		Err(SdkUtilError::JoseAlgNotSupported(
+			reply_public_key.public_key_alg.clone(),
+		))
+	}
+}
+
+impl JoseSk for SecretKey
+{
+	fn decrypt_jwe(&self, _jwe: &str) -> Result<Vec<u8>, SdkUtilError>
+	{
+		Err(SdkUtilError::JoseAlgNotSupported(self.key.get_alg_str().to_string()))
+	}
+}
+
+impl JoseSignKey for SignKey
+{
+	fn jws_alg(&self) -> Result<&'static str, SdkUtilError>
+	{
+		match self.key {
+			CoreSign::Ed25519(_) => Ok(JWS_ALG_EDDSA),
+			_ => Err(SdkUtilError::JoseAlgNotSupported(self.key.get_alg_str().to_string())),
+		}
+	}
+}
+
+impl JoseVerifyKey for VerifyKey
+{
+	fn jws_alg(alg: &str) -> Result<&'static str, SdkUtilError>
+	{
+		match alg {
+			FIPS_OPENSSL_ED25519 => Ok(JWS_ALG_EDDSA),
+			_ => Err(SdkUtilError::JoseAlgNotSupported(alg.to_string())),
+		}
+	}
+}
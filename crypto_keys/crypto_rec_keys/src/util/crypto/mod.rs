@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::sortable::OpeSortableKey;
 
 mod asym;
+mod jose;
 mod sign;
 mod sortable;
 mod symmetric_key;
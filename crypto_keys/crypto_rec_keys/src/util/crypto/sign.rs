@@ -5,7 +5,7 @@ use sentc_crypto_utils::cryptomat::{SignKCryptoWrapper, VerifyKFromUserKeyWrappe
 use sentc_crypto_utils::error::SdkUtilError;
 
 use crate::core::sign::{Signature, VerifyKey as CoreVk};
-use crate::util::export::import_verify_key_from_pem_with_alg;
+use crate::util::export::{import_sig_from_string, import_verify_key_from_pem_with_alg, sig_to_string};
 use crate::util::{SignKey, VerifyKey};
 
 impl SignKCryptoWrapper for SignKey
@@ -22,6 +22,19 @@ impl SignKCryptoWrapper for SignKey
 			sig,
 		))
 	}
+
+	fn sign_detached(&self, data: &[u8]) -> Result<(SignHead, String), SdkUtilError>
+	{
+		let sig = self.key.sign_only(data)?;
+
+		Ok((
+			SignHead {
+				id: self.key_id.clone(),
+				alg: self.key.get_alg_str().to_string(),
+			},
+			sig_to_string(&sig),
+		))
+	}
 }
 
 impl VerifyKFromUserKeyWrapper for VerifyKey
@@ -37,4 +50,9 @@ impl VerifyKFromUserKeyWrapper for VerifyKey
 	{
 		import_verify_key_from_pem_with_alg(&verify_key.verify_key_pem, &verify_key.verify_key_alg)
 	}
+
+	fn sig_from_string(sig: &str, alg: &str) -> Result<Signature, SdkUtilError>
+	{
+		import_sig_from_string(sig, alg)
+	}
 }
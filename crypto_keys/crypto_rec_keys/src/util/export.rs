@@ -2,7 +2,7 @@ use openssl::base64::{decode_block, encode_block};
 use sentc_crypto_utils::error::SdkUtilError;
 use sentc_crypto_utils::{export_key_to_pem, import_key_from_pem};
 
-use crate::core::asym::{PublicKey, ECIES_ML_KEM_REC_HYBRID_OUTPUT, ECIES_REC_OUTPUT, ML_KEM_REC_OUTPUT};
+use crate::core::asym::{PublicKey, ECIES_ML_KEM_REC_HYBRID_OUTPUT, ECIES_REC_OUTPUT, ML_KEM_REC_OUTPUT, X_WING_REC_OUTPUT};
 use crate::core::sign::{Signature, VerifyKey, ED25519_ML_DSA_HYBRID_REC_OUTPUT, FIPS_OPENSSL_ED25519, ML_DSA_REC_OUTPUT};
 use crate::util::HybridPublicKeyExportFormat;
 
@@ -25,6 +25,14 @@ pub fn import_public_key_from_pem_with_alg(public_key: &str, alg: &str) -> Resul
 
 			Ok(PublicKey::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?)
 		},
+		X_WING_REC_OUTPUT => {
+			let key: HybridPublicKeyExportFormat = serde_json::from_str(public_key).map_err(SdkUtilError::JsonParseFailed)?;
+
+			let bytes_x = import_key_from_pem(&key.x)?;
+			let bytes_k = import_key_from_pem(&key.k)?;
+
+			Ok(PublicKey::x_wing_from_bytes_owned(bytes_x, bytes_k)?)
+		},
 		_ => Err(SdkUtilError::AlgNotFound),
 	}
 }
@@ -106,6 +114,18 @@ pub fn export_raw_public_key_to_pem(key: &PublicKey) -> Result<String, SdkUtilEr
 			let x = export_key_to_pem(&x)?;
 			let k = export_key_to_pem(k)?;
 
+			serde_json::to_string(&HybridPublicKeyExportFormat {
+				x,
+				k,
+			})
+			.map_err(|_| SdkUtilError::JsonToStringFailed)
+		},
+		PublicKey::XWing(key) => {
+			let (x, k) = key.prepare_export()?;
+
+			let x = export_key_to_pem(&x)?;
+			let k = export_key_to_pem(k)?;
+
 			serde_json::to_string(&HybridPublicKeyExportFormat {
 				x,
 				k,
@@ -27,8 +27,8 @@ use sentc_crypto_utils::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::core::asym::{PublicKey as CorePk, SecretKey as CoreSk};
-use crate::core::sign::{SignKey as CoreSign, VerifyKey as CoreVk};
+use crate::core::asym::{PublicKey as CorePk, SecretKey as CoreSk, ECIES_ML_KEM_REC_HYBRID_OUTPUT, ML_KEM_REC_OUTPUT, X_WING_REC_OUTPUT};
+use crate::core::sign::{SignKey as CoreSign, VerifyKey as CoreVk, ED25519_ML_DSA_HYBRID_REC_OUTPUT, ML_DSA_REC_OUTPUT};
 use crate::core::sym::Aes256GcmKey;
 #[cfg(feature = "full")]
 pub use crate::util::crypto::SortableKey;
@@ -41,6 +41,19 @@ use crate::util::export::{
 	sig_to_string,
 };
 
+/**
+The post-quantum (pure and hybrid) asymmetric and sign algorithms of this crate.
+
+Use it as post_quantum list of the crypto policy.
+ */
+pub const POST_QUANTUM_ALGS: &[&str] = &[
+	ML_KEM_REC_OUTPUT,
+	ECIES_ML_KEM_REC_HYBRID_OUTPUT,
+	X_WING_REC_OUTPUT,
+	ML_DSA_REC_OUTPUT,
+	ED25519_ML_DSA_HYBRID_REC_OUTPUT,
+];
+
 pub struct SymmetricKey
 {
 	pub key: Aes256GcmKey,
@@ -151,6 +164,10 @@ pub enum SecretKeyFormatExport
 	{
 		x: String, k: String, key_id: EncryptionKeyPairId
 	},
+	XWing
+	{
+		x: String, k: String, key_id: EncryptionKeyPairId
+	},
 }
 
 impl TryFrom<SecretKey> for SecretKeyFormatExport
@@ -188,6 +205,18 @@ impl TryFrom<SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id.clone(),
 				})
 			},
+			CoreSk::XWing(k) => {
+				let (x, k) = k.prepare_export()?;
+
+				let x = encode_block(&x);
+				let k = encode_block(k);
+
+				Ok(Self::XWing {
+					k,
+					x,
+					key_id: value.key_id.clone(),
+				})
+			},
 		}
 	}
 }
@@ -227,6 +256,18 @@ impl<'a> TryFrom<&'a SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id.clone(),
 				})
 			},
+			CoreSk::XWing(k) => {
+				let (x, k) = k.prepare_export()?;
+
+				let x = encode_block(&x);
+				let k = encode_block(k);
+
+				Ok(Self::XWing {
+					k,
+					x,
+					key_id: value.key_id.clone(),
+				})
+			},
 		}
 	}
 }
@@ -273,6 +314,19 @@ impl TryInto<SecretKey> for SecretKeyFormatExport
 					key: CoreSk::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::XWing {
+				x,
+				k,
+				key_id,
+			} => {
+				let bytes_x = decode_block(&x).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+				let bytes_k = decode_block(&k).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(SecretKey {
+					key_id,
+					key: CoreSk::x_wing_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
@@ -315,6 +369,10 @@ pub enum PublicKeyFormatExport
 	{
 		x: String, k: String, key_id: EncryptionKeyPairId
 	},
+	XWing
+	{
+		x: String, k: String, key_id: EncryptionKeyPairId
+	},
 }
 
 impl TryFrom<PublicKey> for PublicKeyFormatExport
@@ -352,6 +410,18 @@ impl TryFrom<PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id,
 				})
 			},
+			CorePk::XWing(k) => {
+				let (x, k) = k.prepare_export()?;
+
+				let x = encode_block(&x);
+				let k = encode_block(k);
+
+				Ok(Self::XWing {
+					x,
+					k,
+					key_id: value.key_id,
+				})
+			},
 		}
 	}
 }
@@ -391,6 +461,18 @@ impl<'a> TryFrom<&'a PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id.clone(),
 				})
 			},
+			CorePk::XWing(k) => {
+				let (x, k) = k.prepare_export()?;
+
+				let x = encode_block(&x);
+				let k = encode_block(k);
+
+				Ok(Self::XWing {
+					x,
+					k,
+					key_id: value.key_id.clone(),
+				})
+			},
 		}
 	}
 }
@@ -437,6 +519,19 @@ impl TryInto<PublicKey> for PublicKeyFormatExport
 					key: CorePk::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::XWing {
+				key_id,
+				x,
+				k,
+			} => {
+				let bytes_x = decode_block(&x).map_err(|_| SdkUtilError::ImportPublicKeyFailed)?;
+				let bytes_k = decode_block(&k).map_err(|_| SdkUtilError::ImportPublicKeyFailed)?;
+
+				Ok(PublicKey {
+					key_id,
+					key: CorePk::x_wing_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
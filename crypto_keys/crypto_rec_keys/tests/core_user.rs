@@ -162,6 +162,13 @@ fn test_password_reset()
 			assert_eq!(x, x1);
 			assert_eq!(k, k1);
 		},
+		(SecretKey::XWing(sk), SecretKey::XWing(sk1)) => {
+			let (x, k) = sk.prepare_export().unwrap();
+			let (x1, k1) = sk1.prepare_export().unwrap();
+
+			assert_eq!(x, x1);
+			assert_eq!(k, k1);
+		},
 		_ => panic!("Keys not the same format"),
 	}
 }
@@ -0,0 +1,904 @@
+//! Encoder and decoder for the [age v1](https://age-encryption.org/v1) file format.
+//!
+//! The X25519 part of the ecies keys (and of the ecies kyber hybrid keys) is used as age recipient and identity.
+//! A file created for a sentc user key can be opened with every age implementation by the exported identity string
+//! and the other way around. Because age only knows X25519, a file for a hybrid key has no post-quantum protection.
+//!
+//! Passphrases are supported with the scrypt recipient. An scrypt stanza must be the only stanza in the header.
+//!
+//! The payload is encrypted with the age STREAM construction in chunks of 64 KiB.
+//! Use [`AgeEncryptor`] and [`AgeDecryptor`] to process large files chunk by chunk
+//! or [`encrypt`] and [`decrypt`] for data that is already in memory.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::from_utf8;
+
+use base64ct::{Base64Unpadded, Encoding};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use rand_core::RngCore;
+use sentc_crypto_core::Error;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+
+use crate::core::asym::{PublicKey, SecretKey};
+use crate::get_rand;
+
+const AGE_VERSION_LINE: &[u8] = b"age-encryption.org/v1";
+const X25519_LABEL: &[u8] = b"age-encryption.org/v1/X25519";
+const SCRYPT_LABEL: &[u8] = b"age-encryption.org/v1/scrypt";
+
+const RECIPIENT_HRP: &str = "age";
+const IDENTITY_HRP: &str = "age-secret-key-";
+
+const FILE_KEY_LEN: usize = 16;
+const PAYLOAD_NONCE_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+const CHUNK_SIZE: usize = 64 * 1024;
+const ENCRYPTED_CHUNK_SIZE: usize = CHUNK_SIZE + TAG_LEN;
+const STANZA_COLUMNS: usize = 64;
+
+const SCRYPT_SALT_LEN: usize = 16;
+
+/**
+Work factor (log2 of N) used by the age reference implementation for new files.
+ */
+pub const SCRYPT_DEFAULT_WORK_FACTOR: u8 = 18;
+
+/**
+Highest work factor that is accepted while decrypting, to not hang on crafted files.
+ */
+pub const SCRYPT_MAX_WORK_FACTOR: u8 = 22;
+
+//__________________________________________________________________________________________________
+//keys
+
+/**
+An age X25519 recipient. Encoded as `age1...` string.
+ */
+#[derive(Clone)]
+pub struct AgeX25519Recipient([u8; 32]);
+
+impl AgeX25519Recipient
+{
+	/**
+	Use the X25519 part of an ecies, ecies kyber hybrid, ecies ml-kem hybrid or hpke public key.
+
+	Kyber and ml-kem only keys can't be used with age.
+	 */
+	pub fn from_public_key(key: &PublicKey) -> Result<Self, Error>
+	{
+		let x = match key {
+			PublicKey::Ecies(k) => k.as_ref(),
+			PublicKey::EciesKyberHybrid(k) => k.get_raw_keys().0,
+			PublicKey::Hpke(k) => k.as_ref(),
+			PublicKey::EciesMlKemHybrid(k) => k.get_raw_keys().0,
+			PublicKey::Kyber(_) | PublicKey::MlKem(_) => return Err(Error::AlgNotFound),
+		};
+
+		Ok(Self(x.try_into().map_err(|_| Error::KeyDecryptFailed)?))
+	}
+
+	pub fn from_bech32(recipient: &str) -> Result<Self, Error>
+	{
+		if recipient.bytes().any(|b| b.is_ascii_uppercase()) {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let (hrp, data) = bech32_decode(recipient)?;
+
+		if hrp != RECIPIENT_HRP {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		Ok(Self(data.try_into().map_err(|_| Error::AgeInvalidFormat)?))
+	}
+
+	pub fn to_bech32(&self) -> String
+	{
+		bech32_encode(RECIPIENT_HRP, &self.0)
+	}
+}
+
+/**
+An age X25519 identity. Encoded as `AGE-SECRET-KEY-1...` string.
+ */
+pub struct AgeX25519Identity(StaticSecret);
+
+impl AgeX25519Identity
+{
+	/**
+	Use the X25519 part of an ecies, ecies kyber hybrid, ecies ml-kem hybrid or hpke secret key.
+
+	Kyber and ml-kem only keys can't be used with age.
+	 */
+	pub fn from_secret_key(key: &SecretKey) -> Result<Self, Error>
+	{
+		let x = match key {
+			SecretKey::Ecies(k) => k.as_ref(),
+			SecretKey::EciesKyberHybrid(k) => k.get_raw_keys().0,
+			SecretKey::Hpke(k) => k.as_ref(),
+			SecretKey::EciesMlKemHybrid(k) => k.get_raw_keys().0,
+			SecretKey::Kyber(_) | SecretKey::MlKem(_) => return Err(Error::AlgNotFound),
+		};
+
+		let x: [u8; 32] = x.try_into().map_err(|_| Error::KeyDecryptFailed)?;
+
+		Ok(Self(x.into()))
+	}
+
+	pub fn from_bech32(identity: &str) -> Result<Self, Error>
+	{
+		if identity.bytes().any(|b| b.is_ascii_lowercase()) {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let (hrp, data) = bech32_decode(identity)?;
+
+		if hrp != IDENTITY_HRP {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let x: [u8; 32] = data.try_into().map_err(|_| Error::AgeInvalidFormat)?;
+
+		Ok(Self(x.into()))
+	}
+
+	pub fn to_bech32(&self) -> String
+	{
+		bech32_encode(IDENTITY_HRP, &self.0.to_bytes()).to_ascii_uppercase()
+	}
+
+	pub fn to_recipient(&self) -> AgeX25519Recipient
+	{
+		AgeX25519Recipient(X25519PublicKey::from(&self.0).to_bytes())
+	}
+}
+
+pub enum AgeRecipient<'a>
+{
+	X25519(AgeX25519Recipient),
+	Scrypt
+	{
+		passphrase: &'a [u8],
+		work_factor: u8,
+	},
+}
+
+pub enum AgeIdentity<'a>
+{
+	X25519(AgeX25519Identity),
+	Scrypt
+	{
+		passphrase: &'a [u8],
+		max_work_factor: u8,
+	},
+}
+
+//__________________________________________________________________________________________________
+//encrypt
+
+/**
+Encrypts the payload with the age STREAM construction.
+
+Call [`AgeEncryptor::update`] with the plaintext chunks and write the output after the header.
+The last chunk is returned by [`AgeEncryptor::finish`].
+ */
+pub struct AgeEncryptor
+{
+	cipher: ChaCha20Poly1305,
+	counter: u64,
+	buffer: Vec<u8>,
+}
+
+impl AgeEncryptor
+{
+	/**
+	Creates a new file key and wraps it for every recipient.
+
+	Returns the encryptor and the header including the payload nonce. The header must be written first.
+	 */
+	pub fn new(recipients: &[AgeRecipient]) -> Result<(Self, Vec<u8>), Error>
+	{
+		if recipients.is_empty() {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let has_scrypt = recipients
+			.iter()
+			.any(|r| matches!(r, AgeRecipient::Scrypt { .. }));
+
+		if has_scrypt && recipients.len() > 1 {
+			//age doesn't allow a scrypt stanza with other stanzas
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let mut rng = get_rand();
+
+		let mut file_key = [0u8; FILE_KEY_LEN];
+		rng.fill_bytes(&mut file_key);
+
+		let mut header = Vec::new();
+		header.extend_from_slice(AGE_VERSION_LINE);
+		header.push(b'\n');
+
+		for recipient in recipients {
+			let (args, body) = match recipient {
+				AgeRecipient::X25519(r) => wrap_x25519(r, &file_key)?,
+				AgeRecipient::Scrypt {
+					passphrase,
+					work_factor,
+				} => wrap_scrypt(passphrase, *work_factor, &file_key)?,
+			};
+
+			write_stanza(&mut header, &args, &body);
+		}
+
+		header.extend_from_slice(b"---");
+
+		let mac = header_mac(&file_key, &header)?;
+
+		header.push(b' ');
+		header.extend_from_slice(Base64Unpadded::encode_string(&mac).as_bytes());
+		header.push(b'\n');
+
+		let mut nonce = [0u8; PAYLOAD_NONCE_LEN];
+		rng.fill_bytes(&mut nonce);
+
+		header.extend_from_slice(&nonce);
+
+		Ok((
+			Self {
+				cipher: payload_cipher(&file_key, &nonce),
+				counter: 0,
+				buffer: Vec::new(),
+			},
+			header,
+		))
+	}
+
+	/**
+	Encrypts all full chunks. The last chunk is kept until finish because it must be marked as final.
+	 */
+	pub fn update(&mut self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.buffer.extend_from_slice(data);
+
+		let mut out = Vec::new();
+		let mut start = 0;
+
+		while self.buffer.len() - start > CHUNK_SIZE {
+			let chunk = stream_encrypt(
+				&self.cipher,
+				self.counter,
+				false,
+				&self.buffer[start..start + CHUNK_SIZE],
+			)?;
+			out.extend_from_slice(&chunk);
+
+			self.counter = next_counter(self.counter)?;
+			start += CHUNK_SIZE;
+		}
+
+		self.buffer.drain(..start);
+
+		Ok(out)
+	}
+
+	pub fn finish(self) -> Result<Vec<u8>, Error>
+	{
+		stream_encrypt(&self.cipher, self.counter, true, &self.buffer)
+	}
+}
+
+/**
+Encrypts the data for the recipients and returns the whole age file.
+ */
+pub fn encrypt(data: &[u8], recipients: &[AgeRecipient]) -> Result<Vec<u8>, Error>
+{
+	let (mut encryptor, mut out) = AgeEncryptor::new(recipients)?;
+
+	out.extend_from_slice(&encryptor.update(data)?);
+	out.extend_from_slice(&encryptor.finish()?);
+
+	Ok(out)
+}
+
+//__________________________________________________________________________________________________
+//decrypt
+
+/**
+Decrypts the payload of an age file.
+
+Call [`AgeDecryptor::update`] with the rest of the file after the header.
+The last chunk is returned by [`AgeDecryptor::finish`].
+ */
+pub struct AgeDecryptor
+{
+	cipher: ChaCha20Poly1305,
+	counter: u64,
+	buffer: Vec<u8>,
+}
+
+impl AgeDecryptor
+{
+	/**
+	Reads the header from the start of the file and unwraps the file key with the first matching identity.
+
+	The input must contain the whole header and the payload nonce.
+	Returns the decryptor and the number of bytes that belong to the header.
+	 */
+	pub fn new(data: &[u8], identities: &[AgeIdentity]) -> Result<(Self, usize), Error>
+	{
+		let header = parse_header(data)?;
+
+		let file_key = unwrap_file_key(&header.stanzas, identities)?;
+
+		//check the header mac after the file key was found
+		let mac_key = hkdf_sha256(&file_key, &[], b"header");
+		let mut mac = Hmac::<Sha256>::new_from_slice(&mac_key).map_err(|_| Error::AgeHeaderMacInvalid)?;
+		mac.update(&data[..header.mac_input_len]);
+		mac.verify_slice(&header.mac)
+			.map_err(|_| Error::AgeHeaderMacInvalid)?;
+
+		let nonce_end = header.len + PAYLOAD_NONCE_LEN;
+
+		if data.len() < nonce_end {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		let nonce = &data[header.len..nonce_end];
+
+		Ok((
+			Self {
+				cipher: payload_cipher(&file_key, nonce),
+				counter: 0,
+				buffer: Vec::new(),
+			},
+			nonce_end,
+		))
+	}
+
+	/**
+	Decrypts all full chunks. The last chunk is kept until finish because it must be marked as final.
+	 */
+	pub fn update(&mut self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.buffer.extend_from_slice(data);
+
+		let mut out = Vec::new();
+		let mut start = 0;
+
+		while self.buffer.len() - start > ENCRYPTED_CHUNK_SIZE {
+			let chunk = stream_decrypt(
+				&self.cipher,
+				self.counter,
+				false,
+				&self.buffer[start..start + ENCRYPTED_CHUNK_SIZE],
+			)?;
+			out.extend_from_slice(&chunk);
+
+			self.counter = next_counter(self.counter)?;
+			start += ENCRYPTED_CHUNK_SIZE;
+		}
+
+		self.buffer.drain(..start);
+
+		Ok(out)
+	}
+
+	pub fn finish(self) -> Result<Vec<u8>, Error>
+	{
+		//only an empty file is allowed to have an empty last chunk
+		if self.buffer.len() < TAG_LEN || (self.buffer.len() == TAG_LEN && self.counter > 0) {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		stream_decrypt(&self.cipher, self.counter, true, &self.buffer)
+	}
+}
+
+/**
+Decrypts a whole age file with the first matching identity.
+ */
+pub fn decrypt(data: &[u8], identities: &[AgeIdentity]) -> Result<Vec<u8>, Error>
+{
+	let (mut decryptor, header_len) = AgeDecryptor::new(data, identities)?;
+
+	let mut out = decryptor.update(&data[header_len..])?;
+	out.extend_from_slice(&decryptor.finish()?);
+
+	Ok(out)
+}
+
+//__________________________________________________________________________________________________
+//stanzas
+
+fn wrap_x25519(recipient: &AgeX25519Recipient, file_key: &[u8]) -> Result<(Vec<String>, Vec<u8>), Error>
+{
+	let ephemeral = StaticSecret::random_from_rng(get_rand());
+	let share = X25519PublicKey::from(&ephemeral);
+	let recipient_pk = X25519PublicKey::from(recipient.0);
+
+	let shared = ephemeral.diffie_hellman(&recipient_pk);
+
+	if !shared.was_contributory() {
+		return Err(Error::KeyCreationFailed);
+	}
+
+	let mut salt = [0u8; 64];
+	salt[..32].copy_from_slice(share.as_bytes());
+	salt[32..].copy_from_slice(&recipient.0);
+
+	let wrap_key = hkdf_sha256(shared.as_bytes(), &salt, X25519_LABEL);
+
+	Ok((
+		alloc::vec![String::from("X25519"), Base64Unpadded::encode_string(share.as_bytes())],
+		aead_wrap(&wrap_key, file_key)?,
+	))
+}
+
+fn unwrap_x25519(identity: &AgeX25519Identity, stanza: &Stanza) -> Result<Option<[u8; FILE_KEY_LEN]>, Error>
+{
+	if stanza.args.len() != 2 {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let share: [u8; 32] = decode_b64(&stanza.args[1])?
+		.try_into()
+		.map_err(|_| Error::AgeInvalidFormat)?;
+
+	if stanza.body.len() != FILE_KEY_LEN + TAG_LEN {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let share_pk = X25519PublicKey::from(share);
+	let shared = identity.0.diffie_hellman(&share_pk);
+
+	if !shared.was_contributory() {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let recipient = X25519PublicKey::from(&identity.0);
+
+	let mut salt = [0u8; 64];
+	salt[..32].copy_from_slice(&share);
+	salt[32..].copy_from_slice(recipient.as_bytes());
+
+	let wrap_key = hkdf_sha256(shared.as_bytes(), &salt, X25519_LABEL);
+
+	//a wrong key is not an error, the file might be for another recipient
+	Ok(aead_unwrap(&wrap_key, &stanza.body))
+}
+
+fn wrap_scrypt(passphrase: &[u8], work_factor: u8, file_key: &[u8]) -> Result<(Vec<String>, Vec<u8>), Error>
+{
+	if work_factor == 0 || work_factor > SCRYPT_MAX_WORK_FACTOR {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let mut salt = [0u8; SCRYPT_SALT_LEN];
+	get_rand().fill_bytes(&mut salt);
+
+	let wrap_key = scrypt_key(passphrase, &salt, work_factor)?;
+
+	Ok((
+		alloc::vec![String::from("scrypt"), Base64Unpadded::encode_string(&salt), format!("{}", work_factor),],
+		aead_wrap(&wrap_key, file_key)?,
+	))
+}
+
+fn unwrap_scrypt(passphrase: &[u8], max_work_factor: u8, stanza: &Stanza) -> Result<Option<[u8; FILE_KEY_LEN]>, Error>
+{
+	if stanza.args.len() != 3 {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let salt = decode_b64(&stanza.args[1])?;
+
+	if salt.len() != SCRYPT_SALT_LEN {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let work_factor = &stanza.args[2];
+
+	if work_factor.is_empty() || work_factor.starts_with('0') || !work_factor.bytes().all(|b| b.is_ascii_digit()) {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let work_factor: u8 = work_factor.parse().map_err(|_| Error::AgeInvalidFormat)?;
+
+	if work_factor > max_work_factor.min(SCRYPT_MAX_WORK_FACTOR) {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	if stanza.body.len() != FILE_KEY_LEN + TAG_LEN {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let wrap_key = scrypt_key(passphrase, &salt, work_factor)?;
+
+	Ok(aead_unwrap(&wrap_key, &stanza.body))
+}
+
+fn unwrap_file_key(stanzas: &[Stanza], identities: &[AgeIdentity]) -> Result<[u8; FILE_KEY_LEN], Error>
+{
+	let has_scrypt = stanzas.iter().any(|s| s.args[0] == "scrypt");
+
+	if has_scrypt && stanzas.len() > 1 {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	for stanza in stanzas {
+		for identity in identities {
+			let file_key = match (stanza.args[0].as_str(), identity) {
+				("X25519", AgeIdentity::X25519(i)) => unwrap_x25519(i, stanza)?,
+				(
+					"scrypt",
+					AgeIdentity::Scrypt {
+						passphrase,
+						max_work_factor,
+					},
+				) => unwrap_scrypt(passphrase, *max_work_factor, stanza)?,
+				//unknown stanza types are skipped
+				_ => None,
+			};
+
+			if let Some(k) = file_key {
+				return Ok(k);
+			}
+		}
+	}
+
+	Err(Error::AgeNoIdentityMatched)
+}
+
+fn scrypt_key(passphrase: &[u8], salt: &[u8], work_factor: u8) -> Result<[u8; 32], Error>
+{
+	let mut full_salt = Vec::with_capacity(SCRYPT_LABEL.len() + salt.len());
+	full_salt.extend_from_slice(SCRYPT_LABEL);
+	full_salt.extend_from_slice(salt);
+
+	let params = scrypt::Params::new(work_factor, 8, 1, 32).map_err(|_| Error::AgeInvalidFormat)?;
+
+	let mut out = [0u8; 32];
+	scrypt::scrypt(passphrase, &full_salt, &params, &mut out).map_err(|_| Error::KeyCreationFailed)?;
+
+	Ok(out)
+}
+
+//__________________________________________________________________________________________________
+//header
+
+struct Stanza
+{
+	args: Vec<String>,
+	body: Vec<u8>,
+}
+
+struct Header
+{
+	stanzas: Vec<Stanza>,
+	mac: Vec<u8>,
+	//the bytes until and including the ---
+	mac_input_len: usize,
+	//the bytes until and including the new line after the mac
+	len: usize,
+}
+
+fn write_stanza(out: &mut Vec<u8>, args: &[String], body: &[u8])
+{
+	out.extend_from_slice(b"->");
+
+	for arg in args {
+		out.push(b' ');
+		out.extend_from_slice(arg.as_bytes());
+	}
+
+	out.push(b'\n');
+
+	let body = Base64Unpadded::encode_string(body);
+	let body = body.as_bytes();
+
+	for line in body.chunks(STANZA_COLUMNS) {
+		out.extend_from_slice(line);
+		out.push(b'\n');
+	}
+
+	//a full last line must be followed by an empty line
+	if body.len() % STANZA_COLUMNS == 0 {
+		out.push(b'\n');
+	}
+}
+
+fn parse_header(data: &[u8]) -> Result<Header, Error>
+{
+	let mut pos = 0;
+
+	let version = read_line(data, &mut pos)?;
+
+	if version != AGE_VERSION_LINE {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let mut stanzas = Vec::new();
+
+	loop {
+		let line_start = pos;
+		let line = read_line(data, &mut pos)?;
+
+		if let Some(mac) = line.strip_prefix(b"--- ") {
+			let mac = decode_b64(from_utf8(mac).map_err(|_| Error::AgeInvalidFormat)?)?;
+
+			if mac.len() != 32 || stanzas.is_empty() {
+				return Err(Error::AgeInvalidFormat);
+			}
+
+			return Ok(Header {
+				stanzas,
+				mac,
+				mac_input_len: line_start + 3,
+				len: pos,
+			});
+		}
+
+		let args = line.strip_prefix(b"-> ").ok_or(Error::AgeInvalidFormat)?;
+		let args = from_utf8(args).map_err(|_| Error::AgeInvalidFormat)?;
+
+		let args = args
+			.split(' ')
+			.map(|a| {
+				if a.is_empty() || !a.bytes().all(|b| (33..=126).contains(&b)) {
+					Err(Error::AgeInvalidFormat)
+				} else {
+					Ok(String::from(a))
+				}
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut body = String::new();
+
+		loop {
+			let line = read_line(data, &mut pos)?;
+
+			if line.len() > STANZA_COLUMNS {
+				return Err(Error::AgeInvalidFormat);
+			}
+
+			body.push_str(from_utf8(line).map_err(|_| Error::AgeInvalidFormat)?);
+
+			if line.len() < STANZA_COLUMNS {
+				break;
+			}
+		}
+
+		stanzas.push(Stanza {
+			args,
+			body: decode_b64(&body)?,
+		});
+	}
+}
+
+fn read_line<'a>(data: &'a [u8], pos: &mut usize) -> Result<&'a [u8], Error>
+{
+	let rest = &data[*pos..];
+	let end = rest
+		.iter()
+		.position(|b| *b == b'\n')
+		.ok_or(Error::AgeInvalidFormat)?;
+
+	*pos += end + 1;
+
+	Ok(&rest[..end])
+}
+
+fn header_mac(file_key: &[u8], header: &[u8]) -> Result<Vec<u8>, Error>
+{
+	let mac_key = hkdf_sha256(file_key, &[], b"header");
+
+	let mut mac = Hmac::<Sha256>::new_from_slice(&mac_key).map_err(|_| Error::KeyCreationFailed)?;
+	mac.update(header);
+
+	Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn decode_b64(data: &str) -> Result<Vec<u8>, Error>
+{
+	Base64Unpadded::decode_vec(data).map_err(|_| Error::AgeInvalidFormat)
+}
+
+//__________________________________________________________________________________________________
+//crypto
+
+fn hkdf_sha256(ikm: &[u8], salt: &[u8], info: &[u8]) -> [u8; 32]
+{
+	let h = Hkdf::<Sha256>::new(Some(salt), ikm);
+	let mut out = [0u8; 32];
+
+	//32 bytes is always a valid length for sha256
+	h.expand(info, &mut out).unwrap();
+
+	out
+}
+
+fn aead_wrap(key: &[u8; 32], file_key: &[u8]) -> Result<Vec<u8>, Error>
+{
+	ChaCha20Poly1305::new(Key::from_slice(key))
+		.encrypt(&Nonce::default(), file_key)
+		.map_err(|_| Error::EncryptionFailed)
+}
+
+fn aead_unwrap(key: &[u8; 32], body: &[u8]) -> Option<[u8; FILE_KEY_LEN]>
+{
+	ChaCha20Poly1305::new(Key::from_slice(key))
+		.decrypt(&Nonce::default(), body)
+		.ok()
+		.and_then(|k| k.try_into().ok())
+}
+
+fn payload_cipher(file_key: &[u8], nonce: &[u8]) -> ChaCha20Poly1305
+{
+	let key = hkdf_sha256(file_key, nonce, b"payload");
+
+	ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+fn stream_nonce(counter: u64, last: bool) -> Nonce
+{
+	//11 bytes big endian counter and the last chunk flag
+	let mut nonce = [0u8; 12];
+	nonce[3..11].copy_from_slice(&counter.to_be_bytes());
+	nonce[11] = last as u8;
+
+	nonce.into()
+}
+
+fn next_counter(counter: u64) -> Result<u64, Error>
+{
+	counter.checked_add(1).ok_or(Error::AgeInvalidFormat)
+}
+
+fn stream_encrypt(cipher: &ChaCha20Poly1305, counter: u64, last: bool, chunk: &[u8]) -> Result<Vec<u8>, Error>
+{
+	cipher
+		.encrypt(&stream_nonce(counter, last), chunk)
+		.map_err(|_| Error::EncryptionFailed)
+}
+
+fn stream_decrypt(cipher: &ChaCha20Poly1305, counter: u64, last: bool, chunk: &[u8]) -> Result<Vec<u8>, Error>
+{
+	cipher
+		.decrypt(&stream_nonce(counter, last), chunk)
+		.map_err(|_| Error::DecryptionFailed)
+}
+
+//__________________________________________________________________________________________________
+//bech32
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+fn bech32_polymod(values: &[u8]) -> u32
+{
+	let mut chk = 1u32;
+
+	for v in values {
+		let b = chk >> 25;
+		chk = ((chk & 0x1ffffff) << 5) ^ (*v as u32);
+
+		for (i, g) in BECH32_GEN.iter().enumerate() {
+			if (b >> i) & 1 == 1 {
+				chk ^= g;
+			}
+		}
+	}
+
+	chk
+}
+
+fn bech32_hrp_expand(hrp: &str) -> Vec<u8>
+{
+	let mut out: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+	out.push(0);
+	out.extend(hrp.bytes().map(|b| b & 31));
+
+	out
+}
+
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Error>
+{
+	let mut acc = 0u32;
+	let mut bits = 0u32;
+	let max = (1u32 << to) - 1;
+	let mut out = Vec::new();
+
+	for v in data {
+		let v = *v as u32;
+
+		if v >> from != 0 {
+			return Err(Error::AgeInvalidFormat);
+		}
+
+		acc = (acc << from) | v;
+		bits += from;
+
+		while bits >= to {
+			bits -= to;
+			out.push(((acc >> bits) & max) as u8);
+		}
+	}
+
+	if pad {
+		if bits > 0 {
+			out.push(((acc << (to - bits)) & max) as u8);
+		}
+	} else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	Ok(out)
+}
+
+fn bech32_encode(hrp: &str, data: &[u8]) -> String
+{
+	//converting from 8 to 5 bits with padding can't fail
+	let data = convert_bits(data, 8, 5, true).unwrap();
+
+	let mut values = bech32_hrp_expand(hrp);
+	values.extend_from_slice(&data);
+	values.extend_from_slice(&[0u8; 6]);
+
+	let polymod = bech32_polymod(&values) ^ 1;
+
+	let mut out = String::from(hrp);
+	out.push('1');
+
+	for v in data {
+		out.push(BECH32_CHARSET[v as usize] as char);
+	}
+
+	for i in 0..6 {
+		out.push(BECH32_CHARSET[((polymod >> (5 * (5 - i))) & 31) as usize] as char);
+	}
+
+	out
+}
+
+fn bech32_decode(s: &str) -> Result<(String, Vec<u8>), Error>
+{
+	let s = s.to_ascii_lowercase();
+
+	let sep = s.rfind('1').ok_or(Error::AgeInvalidFormat)?;
+
+	let (hrp, data) = (&s[..sep], &s[sep + 1..]);
+
+	if hrp.is_empty() || data.len() < 6 || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let values = data
+		.bytes()
+		.map(|c| {
+			BECH32_CHARSET
+				.iter()
+				.position(|b| *b == c)
+				.map(|p| p as u8)
+				.ok_or(Error::AgeInvalidFormat)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let mut check = bech32_hrp_expand(hrp);
+	check.extend_from_slice(&values);
+
+	if bech32_polymod(&check) != 1 {
+		return Err(Error::AgeInvalidFormat);
+	}
+
+	let bytes = convert_bits(&values[..values.len() - 6], 5, 8, false)?;
+
+	Ok((String::from(hrp), bytes))
+}
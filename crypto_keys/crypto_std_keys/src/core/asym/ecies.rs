@@ -4,6 +4,8 @@ use hkdf::Hkdf;
 use rand_core::{CryptoRng, RngCore};
 use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
 use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+#[cfg(feature = "full")]
+use sha2::Digest;
 use sha2::Sha256;
 use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
 
@@ -17,6 +19,10 @@ const HKDF_INFO: &[u8; 13] = b"ecies-ed25519";
 
 const PUBLIC_KEY_LENGTH: usize = 32;
 
+//the length of the derived key in bits for the jose concat kdf
+#[cfg(feature = "full")]
+const JOSE_KEY_DATA_LEN: u32 = 256;
+
 #[derive(Clone)]
 pub struct EciesPk([u8; 32]);
 
@@ -177,6 +183,56 @@ fn hkdf_sha256(ikm: &[u8]) -> AesKey
 	out
 }
 
+//__________________________________________________________________________________________________
+//jose ecdh-es
+
+/**
+ECDH-ES key agreement from JOSE (RFC 7518 section 4.6) with direct use of the derived key.
+
+Returns the ephemeral public key for the epk header and the content encryption key.
+ */
+#[cfg(feature = "full")]
+pub(crate) fn jose_ecdh_es_encapsulate(receiver: &EciesPk, enc: &str) -> ([u8; 32], AesKey)
+{
+	let (ep_sk, ep_pk) = generate_keypair_internally(&mut get_rand());
+
+	let shared = ep_sk.diffie_hellman(&PublicKey::from(receiver.0));
+
+	(ep_pk.to_bytes(), concat_kdf(shared.as_bytes(), enc))
+}
+
+#[cfg(feature = "full")]
+pub(crate) fn jose_ecdh_es_decapsulate(receiver: &EciesSk, ep_pk: &[u8], enc: &str) -> Result<AesKey, Error>
+{
+	let ep_pk: [u8; 32] = ep_pk
+		.try_into()
+		.map_err(|_| Error::DecryptionFailedCiphertextShort)?;
+
+	let shared = StaticSecret::from(receiver.0).diffie_hellman(&PublicKey::from(ep_pk));
+
+	Ok(concat_kdf(shared.as_bytes(), enc))
+}
+
+#[cfg(feature = "full")]
+fn concat_kdf(z: &[u8], alg_id: &str) -> AesKey
+{
+	//one round of sha256 is enough for a 256 bit key. apu and apv are empty.
+	let mut hasher = Sha256::new();
+
+	hasher.update(1u32.to_be_bytes());
+	hasher.update(z);
+	hasher.update((alg_id.len() as u32).to_be_bytes());
+	hasher.update(alg_id.as_bytes());
+	hasher.update(0u32.to_be_bytes());
+	hasher.update(0u32.to_be_bytes());
+	hasher.update(JOSE_KEY_DATA_LEN.to_be_bytes());
+
+	let mut out = [0u8; 32];
+	out.copy_from_slice(&hasher.finalize());
+
+	out
+}
+
 #[cfg(test)]
 mod test
 {
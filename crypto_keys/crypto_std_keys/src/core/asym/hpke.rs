@@ -0,0 +1,399 @@
+//! HPKE (RFC 9180) in base mode with DHKEM(X25519, HKDF-SHA256), HKDF-SHA256 and AES-256-GCM.
+//!
+//! The ciphertext is the encapsulated key (the ephemeral public key) followed by the aead output (ciphertext || tag).
+//! This is the same layout as the single-shot seal of other hpke implementations.
+//!
+//! The info and aad of the hpke context are used by encrypt_with_context and decrypt_with_context.
+//! The util PublicKey and SecretKey expose them with encrypt_with_user_key_with_context and decrypt_with_context.
+//! The normal encrypt and decrypt of the key traits use an empty info and aad.
+//!
+//! Only the DHKEM(X25519) suite is implemented. A post-quantum hybrid kem as hpke suite is deferred.
+//! For post-quantum encryption use the ecies ML-KEM hybrid keys or the X-Wing keys of the rec keys.
+
+use alloc::vec::Vec;
+
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::{Aes256Gcm, Key};
+use hkdf::Hkdf;
+use rand_core::{CryptoRng, RngCore};
+use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
+use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+use sha2::Sha256;
+use x25519_dalek::{EphemeralSecret, PublicKey, SharedSecret, StaticSecret};
+
+use crate::core::asym::ecies::generate_static_keypair_internally;
+use crate::get_rand;
+
+pub const HPKE_OUTPUT: &str = "HPKE-X25519-SHA256-AES256GCM";
+
+const ENC_LENGTH: usize = 32;
+const AEAD_KEY_LENGTH: usize = 32;
+const AEAD_NONCE_LENGTH: usize = 12;
+const AEAD_TAG_LENGTH: usize = 16;
+
+const VERSION_LABEL: &[u8] = b"HPKE-v1";
+const MODE_BASE: u8 = 0x00;
+
+//kem id 0x0020
+const KEM_SUITE_ID: &[u8] = b"KEM\x00\x20";
+//kem id 0x0020, kdf id 0x0001, aead id 0x0002
+const HPKE_SUITE_ID: &[u8] = b"HPKE\x00\x20\x00\x01\x00\x02";
+
+#[derive(Clone)]
+pub struct HpkePk([u8; 32]);
+
+try_from_bytes_owned_single_value!(HpkePk);
+crypto_alg_str_impl!(HpkePk, HPKE_OUTPUT);
+as_ref_bytes_single_value!(HpkePk);
+
+impl Into<crate::core::asym::PublicKey> for HpkePk
+{
+	fn into(self) -> crate::core::asym::PublicKey
+	{
+		crate::core::asym::PublicKey::Hpke(self)
+	}
+}
+
+impl HpkePk
+{
+	/**
+	Seal the data with the hpke info and aad context.
+
+	The same info and aad must be used to open the ciphertext.
+	 */
+	pub fn encrypt_with_context(&self, data: &[u8], info: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		encrypt_internally(&self.0.into(), data, info, aad, &mut get_rand())
+	}
+}
+
+impl Pk for HpkePk
+{
+	fn sign_public_key<S: SignK>(&self, sign_key: &S) -> Result<S::Signature, Error>
+	{
+		sign_key.sign_only(self.0)
+	}
+
+	fn verify_public_key<V: VerifyK>(&self, verify_key: &V, sig: &V::Signature) -> Result<bool, Error>
+	{
+		verify_key.verify_only(sig, &self.0)
+	}
+
+	fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.encrypt_with_context(data, &[], &[])
+	}
+}
+
+pub struct HpkeSk([u8; 32]);
+
+try_from_bytes_owned_single_value!(HpkeSk);
+crypto_alg_str_impl!(HpkeSk, HPKE_OUTPUT);
+as_ref_bytes_single_value!(HpkeSk);
+
+impl Into<crate::core::asym::SecretKey> for HpkeSk
+{
+	fn into(self) -> crate::core::asym::SecretKey
+	{
+		crate::core::asym::SecretKey::Hpke(self)
+	}
+}
+
+impl HpkeSk
+{
+	/**
+	Open a ciphertext with the hpke info and aad context from the encryption.
+	 */
+	pub fn decrypt_with_context(&self, ciphertext: &[u8], info: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		decrypt_internally(&self.0.into(), ciphertext, info, aad)
+	}
+}
+
+impl Sk for HpkeSk
+{
+	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		master_key.encrypt(&self.0)
+	}
+
+	fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		self.decrypt_with_context(ciphertext, &[], &[])
+	}
+}
+
+pub struct HpkeKeyPair;
+
+impl StaticKeyPair for HpkeKeyPair
+{
+	type SecretKey = HpkeSk;
+	type PublicKey = HpkePk;
+
+	fn generate_static_keypair() -> Result<(Self::SecretKey, Self::PublicKey), Error>
+	{
+		let (sk, pk) = generate_static_keypair_internally(&mut get_rand());
+
+		Ok((HpkeSk(sk.to_bytes()), HpkePk(pk.to_bytes())))
+	}
+}
+
+//__________________________________________________________________________________________________
+//internally function
+
+fn encrypt_internally<R: CryptoRng + RngCore>(receiver_pub: &PublicKey, data: &[u8], info: &[u8], aad: &[u8], rng: &mut R) -> Result<Vec<u8>, Error>
+{
+	let ep_sk = EphemeralSecret::random_from_rng(rng);
+	let ep_pk = PublicKey::from(&ep_sk);
+
+	let dh = ep_sk.diffie_hellman(receiver_pub);
+
+	let shared_secret = extract_and_expand(&dh, &ep_pk, receiver_pub).ok_or(Error::EncryptionFailed)?;
+
+	let (key, nonce) = key_schedule(&shared_secret, info);
+
+	let aead = Aes256Gcm::new(Key::from_slice(&key));
+
+	let encrypted = aead
+		.encrypt(
+			GenericArray::from_slice(&nonce),
+			Payload {
+				msg: data,
+				aad,
+			},
+		)
+		.map_err(|_| Error::EncryptionFailed)?;
+
+	//the encapsulated key in front of the aead output
+	let mut cipher_text = Vec::with_capacity(ENC_LENGTH + encrypted.len());
+	cipher_text.extend_from_slice(ep_pk.as_bytes());
+	cipher_text.extend_from_slice(&encrypted);
+
+	Ok(cipher_text)
+}
+
+fn decrypt_internally(receiver_sec: &StaticSecret, ciphertext: &[u8], info: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+{
+	if ciphertext.len() < ENC_LENGTH + AEAD_TAG_LENGTH {
+		return Err(Error::DecryptionFailedCiphertextShort);
+	}
+
+	let (enc, encrypted) = ciphertext.split_at(ENC_LENGTH);
+
+	let ep_pk_bytes: [u8; ENC_LENGTH] = enc
+		.try_into()
+		.map_err(|_| Error::DecryptionFailedCiphertextShort)?;
+	let ep_pk = PublicKey::from(ep_pk_bytes);
+
+	let dh = receiver_sec.diffie_hellman(&ep_pk);
+
+	let shared_secret = extract_and_expand(&dh, &ep_pk, &PublicKey::from(receiver_sec)).ok_or(Error::DecryptionFailed)?;
+
+	let (key, nonce) = key_schedule(&shared_secret, info);
+
+	let aead = Aes256Gcm::new(Key::from_slice(&key));
+
+	aead.decrypt(
+		GenericArray::from_slice(&nonce),
+		Payload {
+			msg: encrypted,
+			aad,
+		},
+	)
+	.map_err(|_| Error::DecryptionFailed)
+}
+
+/**
+The dhkem shared secret. Returns None for a low order ephemeral or receiver key (all zero dh output).
+ */
+fn extract_and_expand(dh: &SharedSecret, ep_pk: &PublicKey, receiver_pub: &PublicKey) -> Option<[u8; 32]>
+{
+	if !dh.was_contributory() {
+		return None;
+	}
+
+	let mut kem_context = [0u8; 2 * ENC_LENGTH];
+	kem_context[..ENC_LENGTH].copy_from_slice(ep_pk.as_bytes());
+	kem_context[ENC_LENGTH..].copy_from_slice(receiver_pub.as_bytes());
+
+	let eae_prk = labeled_extract(KEM_SUITE_ID, &[], b"eae_prk", dh.as_bytes());
+
+	let mut shared_secret = [0u8; 32];
+	labeled_expand(
+		&eae_prk,
+		KEM_SUITE_ID,
+		b"shared_secret",
+		&kem_context,
+		&mut shared_secret,
+	);
+
+	Some(shared_secret)
+}
+
+/**
+The base mode key schedule without psk. Only one message is sealed per context, so the base nonce is used directly.
+ */
+fn key_schedule(shared_secret: &[u8], info: &[u8]) -> ([u8; AEAD_KEY_LENGTH], [u8; AEAD_NONCE_LENGTH])
+{
+	let psk_id_hash = labeled_extract_bytes(HPKE_SUITE_ID, &[], b"psk_id_hash", &[]);
+	let info_hash = labeled_extract_bytes(HPKE_SUITE_ID, &[], b"info_hash", info);
+
+	let mut key_schedule_context = Vec::with_capacity(1 + psk_id_hash.len() + info_hash.len());
+	key_schedule_context.push(MODE_BASE);
+	key_schedule_context.extend_from_slice(&psk_id_hash);
+	key_schedule_context.extend_from_slice(&info_hash);
+
+	let secret = labeled_extract(HPKE_SUITE_ID, shared_secret, b"secret", &[]);
+
+	let mut key = [0u8; AEAD_KEY_LENGTH];
+	let mut nonce = [0u8; AEAD_NONCE_LENGTH];
+
+	labeled_expand(&secret, HPKE_SUITE_ID, b"key", &key_schedule_context, &mut key);
+	labeled_expand(
+		&secret,
+		HPKE_SUITE_ID,
+		b"base_nonce",
+		&key_schedule_context,
+		&mut nonce,
+	);
+
+	(key, nonce)
+}
+
+fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Hkdf<Sha256>
+{
+	let labeled_ikm = [VERSION_LABEL, suite_id, label, ikm].concat();
+
+	Hkdf::<Sha256>::new(Some(salt), &labeled_ikm)
+}
+
+fn labeled_extract_bytes(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Vec<u8>
+{
+	let labeled_ikm = [VERSION_LABEL, suite_id, label, ikm].concat();
+
+	let (prk, _) = Hkdf::<Sha256>::extract(Some(salt), &labeled_ikm);
+
+	prk.to_vec()
+}
+
+fn labeled_expand(prk: &Hkdf<Sha256>, suite_id: &[u8], label: &[u8], info: &[u8], out: &mut [u8])
+{
+	let length = (out.len() as u16).to_be_bytes();
+
+	//the output is max 32 bytes, this can't fail
+	prk.expand_multi_info(&[length.as_slice(), VERSION_LABEL, suite_id, label, info], out)
+		.unwrap();
+}
+
+#[cfg(test)]
+mod test
+{
+	use core::str::from_utf8;
+
+	use sentc_crypto_core::Error::{DecryptionFailed, DecryptionFailedCiphertextShort};
+
+	use super::*;
+
+	fn from_hex(hex: &str) -> Vec<u8>
+	{
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_key_gen()
+	{
+		let _ = HpkeKeyPair::generate_static_keypair().unwrap();
+	}
+
+	#[test]
+	fn test_encrypt_and_decrypt()
+	{
+		let (sk, pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		assert_eq!(encrypted.len(), ENC_LENGTH + text.len() + AEAD_TAG_LENGTH);
+
+		let decrypted = sk.decrypt(&encrypted).unwrap();
+
+		let decrypted_text = from_utf8(&decrypted).unwrap();
+
+		assert_eq!(text, decrypted_text);
+	}
+
+	#[test]
+	fn test_encrypt_and_decrypt_with_context()
+	{
+		let (sk, pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk
+			.encrypt_with_context(text.as_bytes(), b"info", b"aad")
+			.unwrap();
+
+		let decrypted = sk
+			.decrypt_with_context(&encrypted, b"info", b"aad")
+			.unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		assert!(matches!(
+			sk.decrypt_with_context(&encrypted, b"other info", b"aad"),
+			Err(DecryptionFailed)
+		));
+		assert!(matches!(
+			sk.decrypt_with_context(&encrypted, b"info", b"other aad"),
+			Err(DecryptionFailed)
+		));
+		assert!(matches!(sk.decrypt(&encrypted), Err(DecryptionFailed)));
+	}
+
+	#[test]
+	fn test_decrypt_from_other_implementation()
+	{
+		//sealed with the python cryptography hpke suite (X25519, HKDF_SHA256, AES_256_GCM) and info "sentc test"
+		let sk: [u8; 32] = core::array::from_fn(|i| i as u8);
+		let sk = HpkeSk(sk);
+
+		let encrypted =
+			from_hex("28b94b883c12ac86258be387f91ffbcd416f8d456cddff4df65cb830a4d8d846936f9a7521d9d9446ebc903246e56223e73a5fb90b3dc47a0d33");
+
+		let decrypted = sk
+			.decrypt_with_context(&encrypted, b"sentc test", &[])
+			.unwrap();
+
+		assert_eq!(decrypted, b"hello hpke");
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_key()
+	{
+		let (_sk, pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+
+		let (sk, _pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+
+		let encrypted = pk.encrypt(b"Hello world").unwrap();
+
+		assert!(matches!(sk.decrypt(&encrypted), Err(DecryptionFailed)));
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_ciphertext()
+	{
+		let (sk, pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+
+		let encrypted = pk.encrypt(b"Hello world").unwrap();
+
+		assert!(matches!(
+			sk.decrypt(&encrypted[..ENC_LENGTH + 10]),
+			Err(DecryptionFailedCiphertextShort)
+		));
+	}
+}
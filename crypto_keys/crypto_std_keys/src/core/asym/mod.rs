@@ -5,11 +5,17 @@ use sentc_crypto_core::Error;
 
 use crate::core::asym::ecies::{EciesPk, EciesSk};
 use crate::core::asym::ecies_kyber_hybrid::{EciesKyberHybridPk, EciesKyberHybridSk};
+use crate::core::asym::ecies_ml_kem_hybrid::{EciesMlKemHybridPk, EciesMlKemHybridSk};
+use crate::core::asym::hpke::{HpkePk, HpkeSk};
 use crate::core::asym::pqc_kyber::{KyberPk, KyberSk};
+use crate::core::asym::pqc_ml_kem::{MlKemPk, MlKemSk};
 
 pub(crate) mod ecies;
 pub(crate) mod ecies_kyber_hybrid;
+pub(crate) mod ecies_ml_kem_hybrid;
+pub(crate) mod hpke;
 pub(crate) mod pqc_kyber;
+pub(crate) mod pqc_ml_kem;
 
 macro_rules! deref_macro {
     ($self:expr, $method:ident $(, $args:expr)*) => {
@@ -17,6 +23,9 @@ macro_rules! deref_macro {
             Self::Ecies(inner) => inner.$method($($args),*),
             Self::Kyber(inner) => inner.$method($($args),*),
 			Self::EciesKyberHybrid(inner) => inner.$method($($args),*),
+			Self::Hpke(inner) => inner.$method($($args),*),
+			Self::MlKem(inner) => inner.$method($($args),*),
+			Self::EciesMlKemHybrid(inner) => inner.$method($($args),*),
         }
     };
 }
@@ -34,7 +43,7 @@ macro_rules! crypto_alg_impl {
 }
 
 macro_rules! get_inner_key {
-	($st:ty,$t:ident) => {
+	($st:ty,$t:ident,$m:ident) => {
 		impl $st
 		{
 			pub fn ecies_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
@@ -51,6 +60,21 @@ macro_rules! get_inner_key {
 			{
 				Ok(Self::EciesKyberHybrid($t::from_bytes_owned(bytes_x, bytes_k)?))
 			}
+
+			pub fn hpke_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::Hpke(bytes.try_into()?))
+			}
+
+			pub fn ml_kem_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::MlKem(bytes.try_into()?))
+			}
+
+			pub fn ecies_ml_kem_hybrid_from_bytes_owned(bytes_x: Vec<u8>, bytes_k: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::EciesMlKemHybrid($m::from_bytes_owned(bytes_x, bytes_k)?))
+			}
 		}
 	};
 }
@@ -61,9 +85,12 @@ pub enum PublicKey
 	Ecies(EciesPk),
 	Kyber(KyberPk),
 	EciesKyberHybrid(EciesKyberHybridPk),
+	Hpke(HpkePk),
+	MlKem(MlKemPk),
+	EciesMlKemHybrid(EciesMlKemHybridPk),
 }
 
-get_inner_key!(PublicKey, EciesKyberHybridPk);
+get_inner_key!(PublicKey, EciesKyberHybridPk, EciesMlKemHybridPk);
 crypto_alg_impl!(PublicKey);
 
 impl Pk for PublicKey
@@ -74,6 +101,9 @@ impl Pk for PublicKey
 			PublicKey::Ecies(k) => k.sign_public_key(sign_key)?,
 			PublicKey::Kyber(k) => k.sign_public_key(sign_key)?,
 			PublicKey::EciesKyberHybrid(k) => k.sign_public_key(sign_key)?,
+			PublicKey::Hpke(k) => k.sign_public_key(sign_key)?,
+			PublicKey::MlKem(k) => k.sign_public_key(sign_key)?,
+			PublicKey::EciesMlKemHybrid(k) => k.sign_public_key(sign_key)?,
 		};
 
 		Ok(out)
@@ -95,9 +125,12 @@ pub enum SecretKey
 	Ecies(EciesSk),
 	Kyber(KyberSk),
 	EciesKyberHybrid(EciesKyberHybridSk),
+	Hpke(HpkeSk),
+	MlKem(MlKemSk),
+	EciesMlKemHybrid(EciesMlKemHybridSk),
 }
 
-get_inner_key!(SecretKey, EciesKyberHybridSk);
+get_inner_key!(SecretKey, EciesKyberHybridSk, EciesMlKemHybridSk);
 crypto_alg_impl!(SecretKey);
 
 impl Sk for SecretKey
@@ -125,6 +158,9 @@ impl SkComposer for SecretKey
 			ecies::ECIES_OUTPUT => Self::Ecies(decrypted_bytes.try_into()?),
 			pqc_kyber::KYBER_OUTPUT => Self::Kyber(decrypted_bytes.try_into()?),
 			ecies_kyber_hybrid::ECIES_KYBER_HYBRID_OUTPUT => Self::EciesKyberHybrid(decrypted_bytes.try_into()?),
+			hpke::HPKE_OUTPUT => Self::Hpke(decrypted_bytes.try_into()?),
+			pqc_ml_kem::ML_KEM_OUTPUT => Self::MlKem(decrypted_bytes.try_into()?),
+			ecies_ml_kem_hybrid::ECIES_ML_KEM_HYBRID_OUTPUT => Self::EciesMlKemHybrid(decrypted_bytes.try_into()?),
 			_ => return Err(Error::AlgNotFound),
 		};
 
@@ -139,12 +175,18 @@ impl StaticKeyPair for SecretKey
 
 	fn generate_static_keypair() -> Result<(Self::SecretKey, Self::PublicKey), Error>
 	{
+		#[cfg(feature = "ecies_ml_kem_hybrid")]
+		let (sk, pk) = ecies_ml_kem_hybrid::EciesMlKemHybridKeyPair::generate_static_keypair()?;
+
 		#[cfg(feature = "ecies_kyber_hybrid")]
 		let (sk, pk) = ecies_kyber_hybrid::EciesKyberHybridKeyPair::generate_static_keypair()?;
 
 		#[cfg(feature = "ecies")]
 		let (sk, pk) = ecies::EciesKeyPair::generate_static_keypair()?;
 
+		#[cfg(feature = "hpke")]
+		let (sk, pk) = hpke::HpkeKeyPair::generate_static_keypair()?;
+
 		Ok((sk.into(), pk.into()))
 	}
 }
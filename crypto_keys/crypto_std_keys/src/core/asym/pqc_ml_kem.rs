@@ -0,0 +1,261 @@
+use alloc::vec::Vec;
+
+use ml_kem::kem::{Decapsulate, Encapsulate};
+use ml_kem::{Ciphertext, Encoded, EncodedSizeUser, KemCore, MlKem768};
+use rand_core::{CryptoRng, RngCore};
+use sentc_crypto_core::cryptomat::{Pk, SignK, Sk, StaticKeyPair, SymKey, VerifyK};
+use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, try_from_bytes_owned_single_value, Error};
+
+use crate::core::sym::aes_gcm::{raw_decrypt as aes_decrypt, raw_encrypt as aes_encrypt, AesKey};
+use crate::get_rand;
+
+pub const ML_KEM_OUTPUT: &str = "ML_KEM_768";
+
+pub(super) const ML_KEM_PUBLIC_KEY_LENGTH: usize = 1184;
+pub(super) const ML_KEM_SECRET_KEY_LENGTH: usize = 2400;
+const ML_KEM_CIPHERTEXT_LENGTH: usize = 1088;
+
+type EncapsulationKey = <MlKem768 as KemCore>::EncapsulationKey;
+type DecapsulationKey = <MlKem768 as KemCore>::DecapsulationKey;
+
+#[derive(Clone)]
+pub struct MlKemPk([u8; ML_KEM_PUBLIC_KEY_LENGTH]);
+try_from_bytes_owned_single_value!(MlKemPk);
+crypto_alg_str_impl!(MlKemPk, ML_KEM_OUTPUT);
+as_ref_bytes_single_value!(MlKemPk);
+
+impl Into<crate::core::asym::PublicKey> for MlKemPk
+{
+	fn into(self) -> crate::core::asym::PublicKey
+	{
+		crate::core::asym::PublicKey::MlKem(self)
+	}
+}
+
+impl Pk for MlKemPk
+{
+	fn sign_public_key<S: SignK>(&self, sign_key: &S) -> Result<S::Signature, Error>
+	{
+		sign_key.sign_only(self.0)
+	}
+
+	fn verify_public_key<V: VerifyK>(&self, verify_key: &V, sig: &V::Signature) -> Result<bool, Error>
+	{
+		verify_key.verify_only(sig, &self.0)
+	}
+
+	fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		encrypt_internally(&self.0, data, &mut get_rand())
+	}
+}
+
+pub struct MlKemSk([u8; ML_KEM_SECRET_KEY_LENGTH]);
+try_from_bytes_owned_single_value!(MlKemSk);
+crypto_alg_str_impl!(MlKemSk, ML_KEM_OUTPUT);
+as_ref_bytes_single_value!(MlKemSk);
+
+impl Into<crate::core::asym::SecretKey> for MlKemSk
+{
+	fn into(self) -> crate::core::asym::SecretKey
+	{
+		crate::core::asym::SecretKey::MlKem(self)
+	}
+}
+
+impl Sk for MlKemSk
+{
+	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		master_key.encrypt(&self.0)
+	}
+
+	fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		decrypt_internally(&self.0, ciphertext)
+	}
+}
+
+pub struct MlKemKeyPair;
+
+impl StaticKeyPair for MlKemKeyPair
+{
+	type SecretKey = MlKemSk;
+	type PublicKey = MlKemPk;
+
+	fn generate_static_keypair() -> Result<(Self::SecretKey, Self::PublicKey), Error>
+	{
+		let (sk, pk) = generate_keypair_internally(&mut get_rand());
+
+		Ok((MlKemSk(sk), MlKemPk(pk)))
+	}
+}
+
+//__________________________________________________________________________________________________
+
+pub(super) fn generate_keypair_internally<R: CryptoRng + RngCore>(rng: &mut R) -> ([u8; ML_KEM_SECRET_KEY_LENGTH], [u8; ML_KEM_PUBLIC_KEY_LENGTH])
+{
+	let (dk, ek) = MlKem768::generate(rng);
+
+	let mut sk = [0u8; ML_KEM_SECRET_KEY_LENGTH];
+	sk.copy_from_slice(&dk.as_bytes());
+
+	let mut pk = [0u8; ML_KEM_PUBLIC_KEY_LENGTH];
+	pk.copy_from_slice(&ek.as_bytes());
+
+	(sk, pk)
+}
+
+fn encapsulate<R: CryptoRng + RngCore>(receiver_pub: &[u8; ML_KEM_PUBLIC_KEY_LENGTH], rng: &mut R) -> Result<(Vec<u8>, AesKey), Error>
+{
+	let ek = Encoded::<EncapsulationKey>::try_from(&receiver_pub[..]).map_err(|_| Error::EncryptionFailed)?;
+	let ek = EncapsulationKey::from_bytes(&ek);
+
+	let (ciphertext, shared_secret) = ek.encapsulate(rng).map_err(|_| Error::EncryptionFailed)?;
+
+	let mut key = [0u8; 32];
+	key.copy_from_slice(&shared_secret);
+
+	Ok((ciphertext.to_vec(), key))
+}
+
+fn decapsulate(receiver_sec: &[u8; ML_KEM_SECRET_KEY_LENGTH], ciphertext: &[u8]) -> Result<AesKey, Error>
+{
+	let dk = Encoded::<DecapsulationKey>::try_from(&receiver_sec[..]).map_err(|_| Error::DecryptionFailed)?;
+	let dk = DecapsulationKey::from_bytes(&dk);
+
+	let ciphertext = Ciphertext::<MlKem768>::try_from(ciphertext).map_err(|_| Error::DecryptionFailed)?;
+
+	//ml-kem uses implicit rejection, a wrong key results in a wrong shared secret and fails at aes
+	let shared_secret = dk
+		.decapsulate(&ciphertext)
+		.map_err(|_| Error::DecryptionFailed)?;
+
+	let mut key = [0u8; 32];
+	key.copy_from_slice(&shared_secret);
+
+	Ok(key)
+}
+
+pub(super) fn encrypt_internally<R: CryptoRng + RngCore>(
+	receiver_pub: &[u8; ML_KEM_PUBLIC_KEY_LENGTH],
+	data: &[u8],
+	rng: &mut R,
+) -> Result<Vec<u8>, Error>
+{
+	let (ciphertext, shared_secret_alice) = encapsulate(receiver_pub, rng)?;
+
+	let encrypted = aes_encrypt(&shared_secret_alice, data)?;
+
+	let mut cipher_text = Vec::with_capacity(ML_KEM_CIPHERTEXT_LENGTH + encrypted.len());
+	cipher_text.extend_from_slice(&ciphertext);
+	cipher_text.extend_from_slice(&encrypted);
+
+	Ok(cipher_text)
+}
+
+pub(super) fn decrypt_internally(receiver_sec: &[u8; ML_KEM_SECRET_KEY_LENGTH], ciphertext: &[u8]) -> Result<Vec<u8>, Error>
+{
+	if ciphertext.len() <= ML_KEM_CIPHERTEXT_LENGTH {
+		return Err(Error::DecryptionFailedCiphertextShort);
+	}
+
+	let shared_secret_bob = decapsulate(receiver_sec, &ciphertext[..ML_KEM_CIPHERTEXT_LENGTH])?;
+
+	let encrypted = &ciphertext[ML_KEM_CIPHERTEXT_LENGTH..];
+	let decrypted = aes_decrypt(&shared_secret_bob, encrypted)?;
+
+	Ok(decrypted)
+}
+
+#[cfg(test)]
+mod test
+{
+	use core::str::from_utf8;
+
+	use sentc_crypto_core::Error::{DecryptionFailed, DecryptionFailedCiphertextShort};
+
+	use super::*;
+
+	fn from_hex(hex: &str) -> Vec<u8>
+	{
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_key_gen()
+	{
+		let _ = MlKemKeyPair::generate_static_keypair().unwrap();
+	}
+
+	#[test]
+	fn test_encrypt_and_decrypt()
+	{
+		let (sk, pk) = MlKemKeyPair::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		let decrypted = sk.decrypt(&encrypted).unwrap();
+
+		assert_eq!(text.as_bytes(), decrypted);
+
+		let decrypted_text = from_utf8(&decrypted).unwrap();
+
+		assert_eq!(text, decrypted_text);
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_key()
+	{
+		let (_sk, pk) = MlKemKeyPair::generate_static_keypair().unwrap();
+
+		let (sk, _pk) = MlKemKeyPair::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		let decrypted_result = sk.decrypt(&encrypted);
+
+		assert!(matches!(decrypted_result, Err(DecryptionFailed)));
+	}
+
+	#[test]
+	fn test_not_decrypt_with_wrong_ciphertext()
+	{
+		let (sk, pk) = MlKemKeyPair::generate_static_keypair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let encrypted = pk.encrypt(text.as_bytes()).unwrap();
+
+		//too short ciphertext: text must be min 32 long, output was 88 long
+		let encrypted = &encrypted[..(encrypted.len() - 56)];
+
+		let decrypted_result = sk.decrypt(encrypted);
+
+		assert!(matches!(decrypted_result, Err(DecryptionFailedCiphertextShort)));
+	}
+
+	#[test]
+	fn test_decapsulate_fips_203_vector()
+	{
+		//expanded decapsulation key, ciphertext and shared secret of another fips 203 implementation
+		let sk: [u8; ML_KEM_SECRET_KEY_LENGTH] = from_hex(SK).try_into().unwrap();
+
+		let shared_secret = decapsulate(&sk, &from_hex(CT)).unwrap();
+
+		assert_eq!(shared_secret.to_vec(), from_hex(SS));
+	}
+
+	const SK: &str = "27d2a77f33756f61208ef113abe82595873d4abc730e5b5d679529bf6a4ceb6383427231a8612f41550515acba52e48ead8b942833bbe6865d13d14a79d2c5c3e07f0a056d8de7aadfcaba058c493c80b37cab8c562753bb3ba6b6ec8297f885eaa7540d530015a84406e55b1366b577e236ce58a26d8a1eb5a44d542323c2167d9bf4a47f985699ca05bae43b8dec617f02380a3890afd4b8c7ec7ede26553a025f3ce5bc5d7a62130304235cb1ad4836b566b5b863bd9bdb45a2844a7047b6c8d383e448525e040b4dc8a2b48c6c37c96d62d43f3fd88e2881c40a205c9e248f652b592781a779f86880f2a147b67863f391cc1a5a908c0095e07212291e2ef8a36eb9a9c0c6073225b34703a4af049382c47573da68fde9245ad444e31b1fbdb521f1f61f37bc0cef292067e670d28a1ffd904f6f1190a996918a13037a6cabf3c373bf8296cd37ab33ba7746809cc3f8ade1b3639bd57bfcc69650aaaf1de198fc4c0463299e52c461780cc428fc5d04a5c51850cba6c2a5274340675793dda09be44c29e6395c65f85d2a0a7c6df411e6911b1f2cb6c351cd2e875f51b638be776097e93e2f2b2f83da0beef4aa85ba9e763ab64502a0ca5222e9eab5b3b7088ed52060e8c8269b943a71ab0ae1c5b1b687d2e019cf8036bcf9bf6e7bac3aaa36e41660faa4540f2648cd93a189ec5c2dea70bacaaa4ffc906f90810ea1b67bf24f2c78cf6ba881aaea61c0652bff95b1bae4426d1773b9cc2ca82c21e38c636e3b1c523244986b0be8a83f5dd5cf2d54762fb3c5ebf59b8e885302b1ce47033edf760f4e029be40b6d566b19dd758acd5c7412878131244f90172c53f26663c21d905301d48baf91c917cc7779e9d8802cc10d89a3705099a2ad3a3a8896743c1144698093be257dacb66dc785228b912c8d965d14aa28342c3ac4a93fefa532b20945ddc1020139c14d638b908c4ddde9a0645b95b2e4414d40bb79f04413830f15a873c28bb7059c2741002015f20408f058e715b0bf995b5380b7dd325a056ab97e659a2be0cdf6c33731c683a634b771e8c92a139aee4bb0e49c7077321d42fc199f7c1f298ca625d223a5c263a03cc48159b7812665b78637e4e18720b2c29a6b99f42766a4cbc4dc508ba94ba83b89c3a5c78f8bb26bbd9b79beb8c8182490f5793ee5b96013b74b7e169e29d162f1315464ea7d72436d89b755161192c81cc2dd1c8b8bba795ef426ee1cc01c37aaa37b2cff8b0a378b47cbd0b4d49398cfc2712959699fa0bd8cd84666acc61f541b84fa96b9c854e4e75e9144addb44b8566a57dfbb545ce423c03346f2b2c1a91780d152a8de1a4d4c9cacde7392c996888cc2399c02c38b3353adf8acab283924da00a05b76e738c72c930d6cba09ae168990faa1fef2226e780861d416eff402f4f759fc648ab1f97100109087f96e4b148d2cb31e4805314ea0cd95fb023eac0d989474ba4201d7b41d26f5394b217eea5b34b71a8b37931c0e594271e0b7c733257240233e7ba735603e425a87dee77079e37cb28a21764594ce5350d8da2b62a07174943032ec89c98809c73b6423d30c1d283a766a64d89703c3d629b497828d48320c346210797a298aa10d423c8dda069d02bc59e6cdf03a096b8b3da4cab9b80ca4a14907672ccef1ec4faf234a0bc5b7e9d473f2b3133b3b26a1d175cb67a7805919699c02f76531b99c5f89180704bb4ca4535c5b8972679c660a07c5e514b87009c862eb8f5157695efb3fc40a9def6b81c1cc02a249ae4f094ad0d9bd3485c1c1c68080520a7c8c632032cee738154e5c5176c07da56024776a430fe76eacf665a3f7b832102215bc82f10939c8355704336a8fac1d81e4bb0485aa5d7c74d6b59bbe5c5e972a0d8bac411b55b5d5557cd680a1a8f71b4eb86bc48c9a0509731a54bd9d7290b27963e4372dc9b199cfdcac0b01acd28a62395112e4c43648d622c48c8234d01440e8cc376c927f23a5afc9ac0474c662274e424525c8552ece3b3fe26516de901bc7d515bde89558e626c95c80b93342f8010004f39e6c6c94871c5e344cab3966c835f9a96a59afd31c40286b38b1c1a78470bab947518934453ce86736a919f1f5a6d510a86f5454fc3980cb5c765bd2bd5f7b36b1410d6635c8ceb47c4dda0d76a28eac939c71c3024804866c71626658442163c2c22117e50acefce6378a985652302a4ef0c2ce0cc716b7796e2b6b2e3777dfa1ac3da259a31b5a9b530f8cb638a81a62ac301849abaf95a7301bda30068909bfdb7e67dbccbb38a5551a25b1a3a0f685748ad5753d8880f0016c627486166384c5571fe2365900364d038311e2d875db366686932b5ec602430a369e87a6ef5c338786657825bd4c057aceb923eb0935e6905e63b4ced7f80857a773dd64b150d26612ea9ac12052db2017bf1843ccb4b3281b690dc728adfa85c00281b8e3c09287335f856b4fc2892f69a2f57921ada01914c40988662d57769662a786351b9b66493dab79594d986de2100d65ba0ff4ea58b81538d24a4435a258fac25404aa7f41f658b1385065e158dcb60115732720f40459aaac15e406953a90ac52997d1ccd070060efc65db9e653354467fad56ec713c86e7540c423acf2669f52fa6f4ac6888d871ef3e847c029a8aafbb92e17b24aa079b1f419ba6175b442afb11909d4a56b70a0335b28739218aa7c9348e2c3c2f3eb3d15a41e6417c0dd94bfeb21419b311a7bb13a180bbe833218a9a6b17447cc85f225859587a73077049acbcfd44d0f025438e15d1538270d586e1bf83192a9459cf63c0e972f85297679831ecf121509851cb8340f6f107b0fa1a0efd1b36a8189bc085c4f5cb784e553f41b918f80397ce1956f785bee377ca9aa8be6998ada30c26b7c3d8c6b55254cc96203b20c42aee0ac4e1ebb408e49a9e3f879d0ab0785eb7025425d1305a2299c015e120d163b0e19494ce57253d0246d182745cb8197ab7438b3c1bb7972bec5a306eba3567855c014699fef65ae54c770a0d85c18400cf642aedc660777ba4b138502bd5a7812f621f84a48296b98dd4322b6f15828b8a8f0e00a8ba44a53c3a8b143571b0740abd567daf1cde9c79c204b6d5e259d1766a31bbbcb4e6a05cf4502176b301c1c2f41247750157bcec85e809b30a4d60d7747cdd0f5b99aa8c826987517793aaa8080a0b124a8558df72bbe37b75f4edbb6be8216d6c633fb2b2280e25113d8695e43481c3eeb397eb192505229b67a201ea893c3e2cb32da8bc342fa4dea0578a24e16d8f8f9383a95b77050f4d9fd2f5733eec1d63ef3c23ebf9918173669a7202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f";
+
+	const CT: &str = "2481dcc55f93c94770b355fb1f5a4befd73c8cb53b036f9bde9db28146fff322d686edaedbf3eb15282810ec4641bfb3112d4db798b335b3ae6ab5ada2a32671af8443618eba01c095279aaf965d59cf0dce0117afa40375c38925026a324de6a6915a96dba38f04aaa080aaed0d1ef670792f19b32bfca7305ff095513c6eed7cea3476f890163f1930b041621d71e07e519bb9c9b0b3db690da649e6ef3a0ea238f28cb26da28b8cd5a4eebc9629ac0373bb7b8d89e0215bced7396925d85a8efb6d26ae9fc88b890046f0d8cc281584f5bec52bf6c120fe0d5b41caf9653a0678c9cbf916fb05915305280e05de42e83ca87a3558a1121cad3aa1a3d5470c47946f584747171d341a274311a39658bf332424fc160d6b785e230066aa8a39f71ce754ebd86631537940fc6cd960096be8e2b6e385192102f1e91922922e2f7c64ca70511ba283f50eeceee76bcdbe2e1a8d6cac9f67d738442ec8b756b9cc49788120ff96078a8af1c38c80a813a7adfcb53500bd9ccbb7db12a53bfe37c5b54c007c319d61314c66e0c161b4b48caeab9581360c92465e44f45f4ea75ea445185eefee5bf5b97e6269204d42313107a13754ce878699dea5ad142b712ce3ec5ef9bd6fc689e25ceb142ceb8dc1ded287e603f0456000d90e76ed163ec28e1afeca0871d02237ac35ba6b833726d11a91418125094b1c34dffdf5be8b7eae6358bfa63ceab4e050f80c33412ad129aefa6d02cc5f198e461afcbb7da7071a60b8bc5d1d26a1926ce4ac102a0e59ede4c18adc277e8fe2b5b603f2477d5db7f9b6b528d0a27a8b185e9355bde40a45b2a65fe1258fffbfe1a85e059201969a6da4aacb8d4e601ba6f55834769f0ba0306a28b89f6ea5a053e1f9381f9d970a7c8a612fd0a2672fd18158363df8a20da58533d450266dd9afeb064aea60ab4eef9018a2f17ffdea8f303e71eaa839609ff1000c16949a3858a2a089672796eda5d2a6079af19e6384aa3b7e50aec7c93599d2177d48bc89eb12ace7fc185ab33e4a2c3645c6702dd3120a73c51eb2216921f02ffe468f6fde25b51e689249c6e3d2c40669927b84bf18d6bd1c39d1418ebf13f4c3e9e30483600195f5663e50af801cc879ef527ee96da7d2c317206a5f81835df3544f97f4ffffdbd4ec0ab08875f15a8ec50148c0b4e98e1089e0d605be12df6550d9623c3d925fb7eb52b744698f293b4a7bc8cef1e16b548e37d254af4ec1ed779b48cc7b033733f19deffa87ef6c58a190b1f3ffa20f26fd7afc75294e8d14867c53ddc8d9595515dfc18b1ec718ff3d5320128d417a4d2a9b7b2372ecb02644b1f8de7bc524a9930a201cbb52b89b9074cfdec94e780ee1ab01dc478f8d53d0a5dfefe99bbf7f549881bde985d14d24ab272c71ed92cc4238bdf12f6ecb9211f7bf1784341a15fc0b4c70dd2aa88c7a3c5f20501a18c4fc986fb948c5f5c6d6d1c6f49a2ca55eab9630cc1afaae7cc0365b4a4624000706046ad9405342e1a4b821a026ddcefaf11e3f";
+
+	const SS: &str = "bee635347e01ec40c82980f1ed3cbd68c2e9495bede8e484df3a2ecacc216a0b";
+}
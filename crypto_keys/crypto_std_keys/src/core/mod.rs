@@ -1,3 +1,5 @@
+#[cfg(feature = "age")]
+pub mod age;
 pub(crate) mod asym;
 pub(crate) mod hmac;
 pub(crate) mod pw_hash;
@@ -7,7 +9,10 @@ pub(crate) mod sym;
 
 pub use self::asym::ecies::{EciesKeyPair, EciesPk, EciesSk, ECIES_OUTPUT};
 pub use self::asym::ecies_kyber_hybrid::{EciesKyberHybridKeyPair, EciesKyberHybridPk, EciesKyberHybridSk, ECIES_KYBER_HYBRID_OUTPUT};
+pub use self::asym::ecies_ml_kem_hybrid::{EciesMlKemHybridKeyPair, EciesMlKemHybridPk, EciesMlKemHybridSk, ECIES_ML_KEM_HYBRID_OUTPUT};
+pub use self::asym::hpke::{HpkeKeyPair, HpkePk, HpkeSk, HPKE_OUTPUT};
 pub use self::asym::pqc_kyber::{KyberKeyPair, KyberPk, KyberSk, KYBER_OUTPUT};
+pub use self::asym::pqc_ml_kem::{MlKemKeyPair, MlKemPk, MlKemSk, ML_KEM_OUTPUT};
 pub use self::asym::{PublicKey, SecretKey};
 pub use self::hmac::hmac_sha256::{HmacSha256Key, HMAC_SHA256_OUTPUT};
 pub use self::hmac::HmacKey;
@@ -28,7 +33,16 @@ pub use self::sign::ed25519_dilithium_hybrid::{
 	Ed25519DilithiumHybridVerifyKey,
 	ED25519_DILITHIUM_HYBRID_OUTPUT,
 };
+pub use self::sign::ed25519_ml_dsa_hybrid::{
+	Ed25519MlDsaHybridKeyPair,
+	Ed25519MlDsaHybridSig,
+	Ed25519MlDsaHybridSignK,
+	Ed25519MlDsaHybridVerifyKey,
+	ED25519_ML_DSA_HYBRID_OUTPUT,
+};
 pub use self::sign::pqc_dilithium::DILITHIUM_OUTPUT;
+pub use self::sign::pqc_ml_dsa::{MlDsaKeyPair, MlDsaSig, MlDsaSignKey, MlDsaVerifyKey, ML_DSA_OUTPUT};
+pub use self::sign::pqc_slh_dsa::{SlhDsaKeyPair, SlhDsaSig, SlhDsaSignKey, SlhDsaVerifyKey, SLH_DSA_OUTPUT};
 pub use self::sign::{SignKey, Signature, VerifyKey};
 pub use self::sortable::SortKeys;
 pub use self::sym::aes_gcm::{Aes256GcmKey, AES_GCM_OUTPUT};
@@ -6,11 +6,17 @@ use sha2::Digest;
 
 use crate::core::sign::ed25519::{Ed25519Sig, Ed25519SignK, Ed25519VerifyK};
 use crate::core::sign::ed25519_dilithium_hybrid::{Ed25519DilithiumHybridSig, Ed25519DilithiumHybridSignK, Ed25519DilithiumHybridVerifyKey};
+use crate::core::sign::ed25519_ml_dsa_hybrid::{Ed25519MlDsaHybridSig, Ed25519MlDsaHybridSignK, Ed25519MlDsaHybridVerifyKey};
 use crate::core::sign::pqc_dilithium::{DilithiumSig, DilithiumSignKey, DilithiumVerifyKey};
+use crate::core::sign::pqc_ml_dsa::{MlDsaSig, MlDsaSignKey, MlDsaVerifyKey};
+use crate::core::sign::pqc_slh_dsa::{SlhDsaSig, SlhDsaSignKey, SlhDsaVerifyKey};
 
 pub(crate) mod ed25519;
 pub(crate) mod ed25519_dilithium_hybrid;
+pub(crate) mod ed25519_ml_dsa_hybrid;
 pub(crate) mod pqc_dilithium;
+pub(crate) mod pqc_ml_dsa;
+pub(crate) mod pqc_slh_dsa;
 
 macro_rules! deref_macro {
     ($self:expr, $method:ident $(, $args:expr)*) => {
@@ -18,6 +24,9 @@ macro_rules! deref_macro {
             Self::Ed25519(inner) => inner.$method($($args),*),
             Self::Dilithium(inner) => inner.$method($($args),*),
 			Self::Ed25519DilithiumHybrid(inner) => inner.$method($($args),*),
+			Self::MlDsa(inner) => inner.$method($($args),*),
+			Self::Ed25519MlDsaHybrid(inner) => inner.$method($($args),*),
+			Self::SlhDsa(inner) => inner.$method($($args),*),
         }
     };
 }
@@ -35,7 +44,7 @@ macro_rules! crypto_alg_impl {
 }
 
 macro_rules! get_inner_key {
-	($st:ty,$t:ident) => {
+	($st:ty,$t:ident,$m:ident) => {
 		impl $st
 		{
 			pub fn ed25519_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
@@ -52,6 +61,21 @@ macro_rules! get_inner_key {
 			{
 				Ok(Self::Ed25519DilithiumHybrid($t::from_bytes_owned(bytes_x, bytes_k)?))
 			}
+
+			pub fn ml_dsa_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::MlDsa(bytes.try_into()?))
+			}
+
+			pub fn ed25519_ml_dsa_hybrid_from_bytes_owned(bytes_x: Vec<u8>, bytes_k: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::Ed25519MlDsaHybrid($m::from_bytes_owned(bytes_x, bytes_k)?))
+			}
+
+			pub fn slh_dsa_from_bytes_owned(bytes: Vec<u8>) -> Result<Self, Error>
+			{
+				Ok(Self::SlhDsa(bytes.try_into()?))
+			}
 		}
 	};
 }
@@ -61,9 +85,12 @@ pub enum SignKey
 	Ed25519(Ed25519SignK),
 	Dilithium(DilithiumSignKey),
 	Ed25519DilithiumHybrid(Ed25519DilithiumHybridSignK),
+	MlDsa(MlDsaSignKey),
+	Ed25519MlDsaHybrid(Ed25519MlDsaHybridSignK),
+	SlhDsa(SlhDsaSignKey),
 }
 
-get_inner_key!(SignKey, Ed25519DilithiumHybridSignK);
+get_inner_key!(SignKey, Ed25519DilithiumHybridSignK, Ed25519MlDsaHybridSignK);
 crypto_alg_impl!(SignKey);
 
 impl SignK for SignKey
@@ -86,6 +113,9 @@ impl SignK for SignKey
 			Self::Ed25519(inner) => inner.sign_only(data)?.into(),
 			Self::Dilithium(inner) => inner.sign_only(data)?.into(),
 			Self::Ed25519DilithiumHybrid(inner) => inner.sign_only(data)?.into(),
+			Self::MlDsa(inner) => inner.sign_only(data)?.into(),
+			Self::Ed25519MlDsaHybrid(inner) => inner.sign_only(data)?.into(),
+			Self::SlhDsa(inner) => inner.sign_only(data)?.into(),
 		};
 
 		Ok(out)
@@ -99,12 +129,18 @@ impl SignKeyPair for SignKey
 
 	fn generate_key_pair() -> Result<(Self::SignKey, Self::VerifyKey), Error>
 	{
+		#[cfg(feature = "ed25519_ml_dsa_hybrid")]
+		let (sk, vk) = ed25519_ml_dsa_hybrid::Ed25519MlDsaHybridKeyPair::generate_key_pair()?;
+
 		#[cfg(feature = "ed25519_dilithium_hybrid")]
 		let (sk, vk) = ed25519_dilithium_hybrid::Ed25519DilithiumHybridKeyPair::generate_key_pair()?;
 
 		#[cfg(feature = "ed25519")]
 		let (sk, vk) = ed25519::Ed25519KeyPair::generate_key_pair()?;
 
+		#[cfg(feature = "slh_dsa")]
+		let (sk, vk) = pqc_slh_dsa::SlhDsaKeyPair::generate_key_pair()?;
+
 		Ok((sk.into(), vk.into()))
 	}
 }
@@ -121,6 +157,9 @@ impl SignKeyComposer for SignKey
 			ed25519::ED25519_OUTPUT => Self::Ed25519(key.try_into()?),
 			pqc_dilithium::DILITHIUM_OUTPUT => Self::Dilithium(key.try_into()?),
 			ed25519_dilithium_hybrid::ED25519_DILITHIUM_HYBRID_OUTPUT => Self::Ed25519DilithiumHybrid(key.try_into()?),
+			pqc_ml_dsa::ML_DSA_OUTPUT => Self::MlDsa(key.try_into()?),
+			ed25519_ml_dsa_hybrid::ED25519_ML_DSA_HYBRID_OUTPUT => Self::Ed25519MlDsaHybrid(key.try_into()?),
+			pqc_slh_dsa::SLH_DSA_OUTPUT => Self::SlhDsa(key.try_into()?),
 			_ => return Err(Error::AlgNotFound),
 		};
 
@@ -133,9 +172,16 @@ pub enum VerifyKey
 	Ed25519(Ed25519VerifyK),
 	Dilithium(DilithiumVerifyKey),
 	Ed25519DilithiumHybrid(Ed25519DilithiumHybridVerifyKey),
+	MlDsa(MlDsaVerifyKey),
+	Ed25519MlDsaHybrid(Ed25519MlDsaHybridVerifyKey),
+	SlhDsa(SlhDsaVerifyKey),
 }
 
-get_inner_key!(VerifyKey, Ed25519DilithiumHybridVerifyKey);
+get_inner_key!(
+	VerifyKey,
+	Ed25519DilithiumHybridVerifyKey,
+	Ed25519MlDsaHybridVerifyKey
+);
 crypto_alg_impl!(VerifyKey);
 
 impl VerifyK for VerifyKey
@@ -153,6 +199,9 @@ impl VerifyK for VerifyKey
 			(Self::Ed25519(inner), Signature::Ed25519(s)) => inner.verify_only(s, data),
 			(Self::Dilithium(inner), Signature::Dilithium(s)) => inner.verify_only(s, data),
 			(Self::Ed25519DilithiumHybrid(inner), Signature::Ed25519DilithiumHybrid(s)) => inner.verify_only(s, data),
+			(Self::MlDsa(inner), Signature::MlDsa(s)) => inner.verify_only(s, data),
+			(Self::Ed25519MlDsaHybrid(inner), Signature::Ed25519MlDsaHybrid(s)) => inner.verify_only(s, data),
+			(Self::SlhDsa(inner), Signature::SlhDsa(s)) => inner.verify_only(s, data),
 			_ => Err(Error::AlgNotFound),
 		}
 	}
@@ -168,6 +217,9 @@ pub enum Signature
 	Ed25519(Ed25519Sig),
 	Dilithium(DilithiumSig),
 	Ed25519DilithiumHybrid(Ed25519DilithiumHybridSig),
+	MlDsa(MlDsaSig),
+	Ed25519MlDsaHybrid(Ed25519MlDsaHybridSig),
+	SlhDsa(SlhDsaSig),
 }
 
 impl Signature
@@ -178,13 +230,16 @@ impl Signature
 			ed25519::ED25519_OUTPUT => ed25519::split_sig_and_data(data_with_sign),
 			pqc_dilithium::DILITHIUM_OUTPUT => pqc_dilithium::split_sig_and_data(data_with_sign),
 			ed25519_dilithium_hybrid::ED25519_DILITHIUM_HYBRID_OUTPUT => ed25519_dilithium_hybrid::split_sig_and_data(data_with_sign),
+			pqc_ml_dsa::ML_DSA_OUTPUT => pqc_ml_dsa::split_sig_and_data(data_with_sign),
+			ed25519_ml_dsa_hybrid::ED25519_ML_DSA_HYBRID_OUTPUT => ed25519_ml_dsa_hybrid::split_sig_and_data(data_with_sign),
+			pqc_slh_dsa::SLH_DSA_OUTPUT => pqc_slh_dsa::split_sig_and_data(data_with_sign),
 			_ => Err(Error::AlgNotFound),
 		}
 	}
 }
 
 crypto_alg_impl!(Signature);
-get_inner_key!(Signature, Ed25519DilithiumHybridSig);
+get_inner_key!(Signature, Ed25519DilithiumHybridSig, Ed25519MlDsaHybridSig);
 
 impl Into<Vec<u8>> for Signature
 {
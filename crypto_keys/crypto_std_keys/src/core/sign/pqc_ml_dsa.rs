@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use hmac::digest::Digest;
+use ml_dsa::{EncodedSignature, EncodedSigningKey, EncodedVerifyingKey, KeyGen, MlDsa65, Signature as MlDsaSignature, SigningKey, VerifyingKey};
+use rand_core::{CryptoRng, RngCore};
+use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyPair, SymKey, VerifyK};
+use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, into_bytes_single_value, try_from_bytes_owned_single_value, Error};
+
+use crate::core::sign::{SignKey, Signature, VerifyKey};
+use crate::get_rand;
+
+pub const ML_DSA_OUTPUT: &str = "ML_DSA_65";
+
+pub(super) const PUBLICKEYBYTES: usize = 1952;
+pub(super) const SECRETKEYBYTES: usize = 4032;
+pub(super) const SIGNBYTES: usize = 3309;
+
+pub struct MlDsaSig([u8; SIGNBYTES]);
+crypto_alg_str_impl!(MlDsaSig, ML_DSA_OUTPUT);
+try_from_bytes_owned_single_value!(MlDsaSig);
+as_ref_bytes_single_value!(MlDsaSig);
+into_bytes_single_value!(MlDsaSig);
+
+impl Into<Signature> for MlDsaSig
+{
+	fn into(self) -> Signature
+	{
+		Signature::MlDsa(self)
+	}
+}
+
+impl Sig for MlDsaSig {}
+
+pub struct MlDsaSignKey([u8; SECRETKEYBYTES]);
+try_from_bytes_owned_single_value!(MlDsaSignKey);
+crypto_alg_str_impl!(MlDsaSignKey, ML_DSA_OUTPUT);
+as_ref_bytes_single_value!(MlDsaSignKey);
+
+impl Into<SignKey> for MlDsaSignKey
+{
+	fn into(self) -> SignKey
+	{
+		SignKey::MlDsa(self)
+	}
+}
+
+impl SignK for MlDsaSignKey
+{
+	type Signature = MlDsaSig;
+
+	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		master_key.encrypt(&self.0)
+	}
+
+	fn sign(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let sig = sign_internally(&self.0, data)?;
+
+		let mut output = Vec::with_capacity(sig.len() + data.len());
+		output.extend_from_slice(&sig);
+		output.extend_from_slice(data);
+
+		Ok(output)
+	}
+
+	fn sign_only<D: AsRef<[u8]>>(&self, data: D) -> Result<Self::Signature, Error>
+	{
+		let sig = sign_internally(&self.0, data.as_ref())?;
+
+		Ok(MlDsaSig(sig))
+	}
+}
+
+pub struct MlDsaVerifyKey([u8; PUBLICKEYBYTES]);
+try_from_bytes_owned_single_value!(MlDsaVerifyKey);
+crypto_alg_str_impl!(MlDsaVerifyKey, ML_DSA_OUTPUT);
+as_ref_bytes_single_value!(MlDsaVerifyKey);
+
+impl Into<VerifyKey> for MlDsaVerifyKey
+{
+	fn into(self) -> VerifyKey
+	{
+		VerifyKey::MlDsa(self)
+	}
+}
+
+impl VerifyK for MlDsaVerifyKey
+{
+	type Signature = MlDsaSig;
+
+	fn verify<'a>(&self, data_with_sig: &'a [u8]) -> Result<(&'a [u8], bool), Error>
+	{
+		let (sig, data) = split_sig_and_data(data_with_sig)?;
+
+		Ok((data, verify_internally(&self.0, sig, data)?))
+	}
+
+	fn verify_only(&self, sig: &Self::Signature, data: &[u8]) -> Result<bool, Error>
+	{
+		verify_internally(&self.0, &sig.0, data)
+	}
+
+	fn create_hash<D: Digest>(&self, hasher: &mut D)
+	{
+		hasher.update(self.0)
+	}
+}
+
+pub struct MlDsaKeyPair;
+
+impl SignKeyPair for MlDsaKeyPair
+{
+	type SignKey = MlDsaSignKey;
+	type VerifyKey = MlDsaVerifyKey;
+
+	fn generate_key_pair() -> Result<(Self::SignKey, Self::VerifyKey), Error>
+	{
+		let (sk, pk) = generate_key_pair_internally(&mut get_rand());
+
+		Ok((MlDsaSignKey(sk), MlDsaVerifyKey(pk)))
+	}
+}
+
+pub(crate) fn split_sig_and_data(data_with_sig: &[u8]) -> Result<(&[u8], &[u8]), Error>
+{
+	sentc_crypto_core::split_sig_and_data(data_with_sig, SIGNBYTES)
+}
+
+//__________________________________________________________________________________________________
+//internally function
+
+pub(super) fn generate_key_pair_internally<R: CryptoRng + RngCore>(rng: &mut R) -> ([u8; SECRETKEYBYTES], [u8; PUBLICKEYBYTES])
+{
+	let keys = MlDsa65::key_gen(rng);
+
+	let mut sk = [0u8; SECRETKEYBYTES];
+	sk.copy_from_slice(&keys.signing_key().encode());
+
+	let mut pk = [0u8; PUBLICKEYBYTES];
+	pk.copy_from_slice(&keys.verifying_key().encode());
+
+	(sk, pk)
+}
+
+pub(super) fn sign_internally(sign_key: &[u8; SECRETKEYBYTES], data: &[u8]) -> Result<[u8; SIGNBYTES], Error>
+{
+	let sign_key = EncodedSigningKey::<MlDsa65>::try_from(&sign_key[..]).map_err(|_| Error::InitSignFailed)?;
+	let sign_key = SigningKey::<MlDsa65>::decode(&sign_key);
+
+	//hedged signing with an empty context string like the fips 204 default
+	let sig = sign_key
+		.sign_randomized(data, &[], &mut get_rand())
+		.map_err(|_| Error::InitSignFailed)?;
+
+	let mut out = [0u8; SIGNBYTES];
+	out.copy_from_slice(&sig.encode());
+
+	Ok(out)
+}
+
+pub(super) fn verify_internally(verify_key: &[u8; PUBLICKEYBYTES], sig: &[u8], data: &[u8]) -> Result<bool, Error>
+{
+	let verify_key = EncodedVerifyingKey::<MlDsa65>::try_from(&verify_key[..]).map_err(|_| Error::InitVerifyFailed)?;
+	let verify_key = VerifyingKey::<MlDsa65>::decode(&verify_key);
+
+	let sig = match EncodedSignature::<MlDsa65>::try_from(sig)
+		.ok()
+		.and_then(|sig| MlDsaSignature::<MlDsa65>::decode(&sig))
+	{
+		Some(sig) => sig,
+		None => return Ok(false),
+	};
+
+	Ok(verify_key.verify_with_context(data, &[], &sig))
+}
+
+#[cfg(test)]
+mod test
+{
+	use sentc_crypto_core::user::safety_number;
+	use sentc_crypto_core::Error::DataToSignTooShort;
+
+	use super::*;
+
+	fn from_hex(hex: &str) -> Vec<u8>
+	{
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_generate_keypair()
+	{
+		let _ = MlDsaKeyPair::generate_key_pair().unwrap();
+	}
+
+	#[test]
+	fn test_sign_and_verify()
+	{
+		let (sk, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let (data, check) = vk.verify(&data_with_sig).unwrap();
+
+		assert!(check);
+		assert_eq!(data, text.as_bytes());
+	}
+
+	#[test]
+	fn test_wrong_verify()
+	{
+		let (_sk, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+		let (sk, _vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let (data, check) = vk.verify(&data_with_sig).unwrap();
+
+		assert!(!check);
+		assert_eq!(data, text.as_bytes());
+	}
+
+	#[test]
+	fn test_too_short_sig_bytes()
+	{
+		let (sk, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let data_with_sig = &data_with_sig[..31];
+
+		let check_result = vk.verify(data_with_sig);
+
+		assert!(matches!(check_result, Err(DataToSignTooShort)));
+	}
+
+	#[test]
+	fn test_wrong_sig_bytes()
+	{
+		let (sk, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let data_with_sig = &data_with_sig[..SIGNBYTES + 2];
+
+		let (_data, check) = vk.verify(data_with_sig).unwrap();
+
+		assert!(!check);
+	}
+
+	#[test]
+	fn test_verify_fips_204_vector()
+	{
+		//signature with an empty context of another fips 204 implementation
+		let vk: [u8; PUBLICKEYBYTES] = from_hex(VK).try_into().unwrap();
+		let sig = from_hex(SIG);
+
+		assert!(verify_internally(&vk, &sig, b"hello ml-dsa").unwrap());
+		assert!(!verify_internally(&vk, &sig, b"hello ml-dsa!").unwrap());
+	}
+
+	#[test]
+	fn test_safety_number()
+	{
+		let (_, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+
+		let number = safety_number(&vk, "123", None, None);
+
+		assert_eq!(number.len(), 32);
+	}
+
+	#[test]
+	fn test_combined_safety_number()
+	{
+		let (_, vk) = MlDsaKeyPair::generate_key_pair().unwrap();
+		let (_, vk1) = MlDsaKeyPair::generate_key_pair().unwrap();
+
+		let number = safety_number(&vk, "123", Some(&vk1), Some("321"));
+
+		assert_eq!(number.len(), 32);
+
+		//test the other way around
+
+		let number_2 = safety_number(&vk1, "321", Some(&vk), Some("123"));
+
+		assert_eq!(number_2.len(), 32);
+
+		assert_ne!(number, number_2);
+	}
+
+	const VK: &str = "48683d91978e31eb3dddb8b0473482d2b88a5f625949fd8f58a561e696bd4c27d05b38dbb2edf01e664efd81be1ea893688ce68aa2d51c5958f8bbc6eb4e89ee67d2c0320954d57212cac7229ff1d6eaf03928bd51511f8d88d847736c7de2730d5978e5410713160978867711bf5539a0bfc4c350c2be572baf0ee2e2fb16ccfea08028d99ac49aebb75937ddce111cdab62fff3cea8ba2233d1e56fbc5c5a1e726de63fadd2af016b119177fa3d971a2d9277173fce55b67745af0b7c21d597dbeb93e6a32f341c49a5a8be9e825088d1f2aa45155d6c8ae15367e4eb003b8fdf7851071949739f9fff09023eaf45104d2a84a45906eed4671a44dc28d27987bb55df69e9e8561f61a80a72699503865fed9b7ee72a8e17a19c408144f4b29afef7031c3a6d8571610b42c9f421245a88f197e16812b031159b65b9687e5b3e934c5225ae98a79ba73d2b399d73510effad19e53b8450f0ba8fce1012fd98d260a74aaaa13fae249a006b1c34f5ba0b882f26378222fb36f2283c243f0ffeb5f1bb414a0a70d55e3d40a56b6cbc88ae1f03b7b2882d98deea28e145c9dedfd8eaf1cef2ed94a8b050f8964f46d1ea0d0c2a43e0dda6182adbf4f6ed175b6742257859bf22f3a417ecf1f9d89317b5e539d587af16b9e1313e04514ffa64ba8b3ff2b8321f8811cb3fb022c8f644e70a4b80a2fbfee604abb7379091ea8e6c5c74dfc0283666b40c0793870028204a136bf5da9568eb798d349038bdb0c11e03445e7847cb5069c75cf28ac601c7799d958210ddbcb226e51afef9f1de47b073873d6d3f97456bede085082e74a298b2cd48f4b3093155f366c8fa601c6af858dfa32c08491b2a29887f90335949a5d6edaa679882a3a95d6bf6d970a221f4b9d3d8cbf384af81aac95e2b3294e04789ac83727a5dc04559f96af41d8a053516feeeebc52746eb6ab2819e09108710d835f011fa63065872ad334d5cdffb2b2310507e92fc993ae317da97f4f309cdaf0f67ed99d90215576083849f953b246d7fedb3fdb67679850a5ad404e64147fb7cf4f6aeddd05afb4b834968d1fe88014960dce5d942236526e12a478d69e5fbe6970310b308c06845018cfc7b2ab430a13a6b1ac7bb02cccbb3d911ac2f11068613fbe029bfdce02cf5cd38950ed72c83944edfbc75615af87f864c051f3c55456c5412863a40c06d1dab562bdff0571b8d3c3917bbd300880bba5e998239b95fa91b7d6416d4f398b3adbcd30983ed3592b4d9ef7d4236fd00f50d98aa53a235ac4172720f77d96172672980cfe8ff7a5a702783edc2ba31b2259015a112fc7f468a9c2f9464039002d30ef678b4cb798bc116216bf7a9a7c18ba03b7b58fd07515d3115049d3614be7a07e744300750df1d2c58753389059eafc3d785ccdd31c07648bedc03a5c3b8ad46d064d59c13d57374729fc4e295362e2a5191204530428bc1522afa28ff5fe1655e304ca5bc8c27ad0e0c6a39dd4df28956c14b38cc93682cefe402bbd5e82d29c464e44eb5d37b48fc568dfe0cc6e8e16baea05e5135590f19294e73e8367b0216dbb815030b9de55913f08039c42351c59e5515dd5af8e089a15e625e8f6dee639386c46497d7a263288774de581a7de9629b41b4424141f978fb8331208efdec3c6e0de39bc57063f3dcd6c470373c08891ea29cbc7cc6d6483b8889083ace86aa7b51b1c2cfe6e2ad18d97ce36fbc56ea42fae97e6a7ac114864478c366df1ebb1e7b11a9098504fd5975bdf1f49dc70002b63c1739a9d263fbad4073f6a9f6c2b8af4b4c332a103a0cffa5deeb2d062ca3c215fd360026be7c5164f4a4424ef74948804d66f46487732c8202c795478647b4ea71d627c086024cca354a41f0877b38f19b3774ad2095c8da53b069e21c76ae2d2007e16719ed40080d334f7da52e9f5a5990439caf083a95b833f02ad10a08c1a6d0f260c007285bd4a2f47703a5aef465287d253b18ac22514316210ff566814b10f87a293d6f199d3c3959990d0c1268b4f50d5f9fcefbbf237bd0c28b80182d6659741f14f10bfbb21bba12ab620aa2396f56c0686b4ea9017990224216b2fe8ad76c4a9148eef9a86a3635a6aa77bc1dcfb6fba59a77dfda9b7530dc0ca8648c8d973738e01bab8f08b4905e84aa4641bd602410cd97520265f2f231f2b35e15eb2fa04d2bd94d5a77abaf1e0e161010a990087f5b46ea988b2bc0512fda0fa923dadd6c45c5301d09483673265b5ab2e10f4ba520f6bbad564a5c3d5e27bdb080f7d20e13296a3181954c39c649c943ebe17df5c1f7aae0a8fe126c477585a5d4d648a0d008b6af5e8cd31be69a9296d4f3fd25ed86f221e4b93f65f5929967533624b9235750c30707550b58536d109a7131c5a5bbe4a5715567c12534aec7660761eebb9fae2891c774589b80e566ad557ddef7367196b7227ea9870ef09ddfec79d6b9319a6879b5205d76bf7aba5acf33afb59d17fc54e68383d6be5a08e9b66da53dcde008bb294b8582bd132cdcc49959fdbc21e52721880c8ad0352c79f03a43bbd84c4cdfdc6c529005e1e7cd9a349a7168a35569ba5dea818968d5a91466bd6e64e20bf62417198afc4e81c28dd77ed4028232398b52fbde86bc84f475b9016710ce2aabc11a06b4dbac901ec16cf365ca3f2d53813948a693a0f93e79c46ca5d5a6dca3d28ca50ad18bd13fca55059dd9b185f79f9c47196a4e81b2104bc460a051e02f2e8444f";
+
+	const SIG: &str = "6cfb4cfbccd26c13d4c3cec794bb3e61e5cfd27447c29675a1e277c1bbecbe396f7ff2a6ffd0800e4ea751401c0d746444fc316de35272b6246b11b5c32e7b781448d85a8b6c8434ae94d59cff87701dfc0022c983abbb89fe74bb98cb6446aa53743281ee282b41557d365590acb899c48c0a2cda5ca98ac62bc25688ed7f014fb35aecc39dd1371b943d0912ba12d8d9bed0d5033a225071e97db6241d8268175c15d9e75256d00a4338bd3d848215997d7235bb7bd1f82144e317579c44860e2a34932dc01ed7088e72709e916a547cf8cf8aa312b61bf857530e455772bf2437740af71eb3c8c6d9a39c818da0533406b5f0821398a8d50ee5c5ac09a04324a4fd69e284285b93ef0c5063099bf9ee2085c5094f675593cee4ef19eefdb5a1c76d6d8c52586924141fe4234d19aa9a967295724f4e714971f1954ca78d0b69db254de1f35331fa7cb992ae35b47a522a88d92df3c17787343ab8851e96bc7e1f12bd26e49547bdfe450dcb9491b21e1cf6b6826244fe3bac1af467a35690b7706678a4ef4620d5c60136ffbd75ccfa11236c7a97c3f769bedde4d9bb1c6b963896fe0cd1e272eed54a195ceaac8baf947c1dbb5ea121ee97f1033be8c53a037fb3fe7327839a08397f894f3946dc4cd844dbeba745f06ac06f7e97e0844a702b2ecb0191463c02c94f76890b1bb48911a7109460575816574f38eb9321d11c83d00f7794d7c7322b6d9dc96985072b02c58f23395794dd7bfaffad790c84fcce16993c18237f7d42006798e2291bff2318afabb7fd7a19118e30dc4459e2ca00936a32fd225eb0912d88f520211c7023d20d4cb932ce1bb22d90bd7f9ec75ba71bdd08cfceea408a918d62bcb75d3d021d7422320dab594e861207073e3fa8cb9a2276ecd7b92c727baa0aef95889ebe48154f19191bb94c90980923e8752015bb43a2e058c58ec35623a3b111c178afcad19d8bfb1f5bdc51360b19eef14378c055d92aca4490ffac508e5c359e3294a2adbc7b635a20116f529ee640ae025c56e0fb121be8679c70ea7eca53b238fcb4838cd061e42e4db60eaf3e62d8ed2bb0713c1b9e1ff89906b89ec3522b560fd045f9c4c9f0433f1f589c9d240d5115ce64c92e3085aaf765655d5bba8cc925a181c8396b276fa31d5209e72d9a09272c93431795ee84b9eefbe58205a1920b0006ae2f507ae600e242b3e30e1a5c1983d13e542a217d372b9652902172f7647d9b600560bdce8b0763a9deb990b86ba2e89d303a73ecb062896e40b4bd7abc9dbebfab83e0f569930d6a40931e46a2d745057fd46725e7d36db7611e7a824956d1a2a26745c0d4a42a49c3b886e0bf26a9e383fcf8d08681bc4a1008e51adaf5d0e7fb6aa38b4cd39ca4cf9adfe953311f352ace27f607bb404922d2323de10d4d441f1aaf75e136e027f13ec7e064a005244153a101c937f652116034a06ccfc706bbbfa01813fd2d189e7c83e93ca4bb0878eeb57024ba0debbcbe095b43294d0fe8539c1b7a998e44b47a8b7447c2cd9d13a18f83a9d7959f0acaa3132c33afd7292485dcb6387a5a03ad9098d769dddb5d866557d0ebc996f695b3f38347c8fc813b39dcd6c8aab5bb2d5bd114cdc1b0cbadc7ed71d6fb61ef34b5854a0f26b19bd5639a2c98784e47659696c77d44132798b7551781e371a00bb054bff77f10ddffc914599ddf9e80fa453ea39675a333217f1db5faedcc494ffb4602be5b922b581ed3eb71eb168c77c8ed3330267924c6dcfdefb2dfdf494c67524e692d6693c9ba2953a8308726677c4125526c7da25673257afbc15a493d40d5fbc30dd95eb73d40ac7007136abb884496678774eca5ccbee2ba8cfa7facc7430072e0a711f1fe63cd10dc9f633077c23fd718d97a153c00e6bf35ad63b59cb89e78ce139a98e33acf50c19df420b8ef2d9dfcb4d5d86ce3c8822bf3ca11ae70f391181b3ae0b31ddb56fe27f1a7ad9fbdcea7a15fdfa72123c50fb360f3467e3e3277ea41958501aa1c7c2f83072db7496618612490ba097691501e05c5831737ada57ef7554eb651c444d071a2d0b1aa4fe79222f5b6125faec7e592034bc7e4de7c4dcef90c3d588b0ab26f11f6bd8794fa0b8b2a03bcd7cff9cf54224aa079b2cc69cb534bde30d803d64b95b4b62ea4f67809314e2178dd622f77bea8abb35e8d7bdfd0695cdda2fa81ea6f47b668ed0eb6ac603ee2e2b0e3c05297ea3ecdf39be1c0cef2e7e562c04828d0e8918ca5acbf995d939b14a0bef19cd9ae54f9e83b33d49e1529559887d87d682227cc0329dca6ee7167f6e5b04d0f638a04c8957af50ed908361a18d47f5b210a49ef8dda7a795a74c3613f943bbee73505d6099a871988ce75a6200453a5c332e16629c74e035a79a393465fc18860c058db35deb7e1f558e1596bcbc3c3fec2210c32e871a58cba54ca9e8df080b19cb661030690477d46cb1d44eb604419bc3a5ef494567e75c032b96865d844b4af44b7a48f7164feb5a7bfa3b2cd23e3db28e656617a52553714a9fcd1cbc85db878a3d4c8cf24d0d22f3c69d498eb49f0ea5ad167227eacdb4c2217006f234122febf8c7335bb3072a2fcf0355d54ac07f7d89bc458c251ed46068277748b3cbdad2115d1b6c6299405d2ca0c9babd9b7ab697c0b8d21782b51370961eab7d036f7c4b0174184f9abf8cccda4671da567dd54f11cca4573902544293c423e5ecc12a45ccc955bc8c913e5dcb1086ea1afb42f6faf4e3c38c29864a8d318393e3e06a3f136848064e24da8bd792877da95e5d55c195f48721ed5b857effbfb748fefff79f795463d65027b0b8b77f8b40c07c173d9b4c483077f7c744d1cfc93d502572003f3da28fbcce92ce8b64d7e6dad4fb20f5146a53d1d3d443c0039a6b62d627ed2dc17661c411201033077a8e0149e5ccc88e83b6f537fb39df24042340b748d8ebce00c5a63d45d1a78e23410aad6e92bdcc97c28d74a0d71a2e57749ac72950bef30e68e49c0891cda603eafd4df86eb88aa9adfa06d60fa0ff30e2d2963d0a7ed32e3cf6e42a81c09e8ed7e5d1040d8275ed23ea0251ee0ff88ced4fb2e48e8a0afacc79ce07971886b3bdee98c30753af598c574cb922d3bda9e65aa1a6eae3d83cbf85dbb256fa58612efb5ab3dd16619a4a6e39df31852bf61306736f43cd169847505d97723cdfdd99c2365d8c09da2df15c413f7c0c4deeff38c70101a0d3689905497f39ef645bb6cf180ad1ff6f88cc154b22738f9db24b18ae1d2013a37abfec4a9610666eaa4b2cf6255a91e399ea50ea35a3e42579b84c796473cb2f6f88cbe2049184b281632ccc1e15470a82f6c3083fd2c90638fb8a7c174879024bf326cf2be17bfeb11e797c6a58cb22af1e9d183c1a6036eb69dafcb04910067f37297828778a5cd3e175c12cccba3678d6876086a4734cfb7a6fd9093a2905e49ca54a45c1cabe63357458c8762eba35404a94ec854b79424eeac1df086baaaa05d59823223bb888509ba3720e415973ebe3368aa8e19987a4e07877051ad00c6933f6cbd7ca3126b13b4c4d481db0e4d4257528320d9b9c8764a856f94412f8a51ace4a991e23e8f989281d47d6b8ee91819602dc8df02818683409a1da7f5971684c2a34f7f98b9915dbe6cc3faf9ee789eb5bd324e56970f35b7bf1185debbd87264f3a811bbe0e89ab339a19854bf5df706450061a7a60d2ab0aa9d4c79cc4a68b31d2414ec545f6ed5f57e3079b18485d637e43a3150a1b1fc67acc1a2c91fa8c64b7a034855cc185f04b509291d177de0ea236f0ebac168f5bc6602a41fd85bdbfe53f8ae0db6a9e497d6150cea8f48721f4d0d2a169c2893f24e89dd54e06a7522620dd65eb268a516e55f381d7b6bcf77bc7d33f7c427c8e882ac5945ba08261579af7039d201695a1495cc2d8bf9e835d9b897194a6eb7cc27c8d1886e3192a6f38fb9ac6832788a27630dd68073b00de03c69ecf94cc27caa84d923e81934afde97a0c8810c58dbfaafae8a6120f14b0d1a831865196bef6cc6b8505976a2279e3fd79ef1efb7566e97564093ec161987ea544cf06037a4e3fa785534902b1f64b0c5ff43e7471135284d4d272f328b82b71c2344190634e5c61a06c59c393ff5fa8fd0ad941742842f9fcfbe27c10df7e8832f9e8f394cd5997d09c14ecfbc93f2f3550496393b8adbf2a7599b1a09d9d86d623d34969ec70d345b8510ccb8511a984bba76ec165f7822c95d3bc609f6abd5017eb6653b6b0358c7880ab04217f130d442de65f087e5f8c6f8fdba63e19a573c61bee827c8621d9ed5dddc2eca664194af5aa5f97539922f39e68ab8d78266527644a9b6a3bc7d85b7a115474f0439556bce9136ea230611737bfd85be590193bdff44e99eae3af8106da4774c19d6cdf2830372250880ae2cdaf8cea84509c607f085d3780d64cbc5db89c389d35ca098305b4dcc25751e6ac9c7a1dd95a6c9794d101b5278aab21f5e7623b1bc7d0f73cd8f5cd25b5eddc28321b519217a9ffec799d7ea7ba11bf354eb80d3a36448ce2cc7a22c7b0fde1325819ac565b2a6ee174e7a098aa2f93cb1db3b77d2242a4e6c6f8e94f706305f93aef60000000000000000000000000000000000000000000000000000000003070a0d151b";
+}
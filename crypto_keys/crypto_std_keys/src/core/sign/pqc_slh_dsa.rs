@@ -0,0 +1,300 @@
+use alloc::vec::Vec;
+
+use hmac::digest::Digest;
+use rand_core::{CryptoRng, RngCore};
+use sentc_crypto_core::cryptomat::{Sig, SignK, SignKeyPair, SymKey, VerifyK};
+use sentc_crypto_core::{as_ref_bytes_single_value, crypto_alg_str_impl, into_bytes_single_value, try_from_bytes_owned_single_value, Error};
+use slh_dsa::signature::{Keypair, RandomizedSigner, Verifier};
+use slh_dsa::{Sha2_128s, Signature as SlhDsaSignature, SigningKey, VerifyingKey};
+
+use crate::core::sign::{SignKey, Signature, VerifyKey};
+use crate::get_rand;
+
+pub const SLH_DSA_OUTPUT: &str = "SLH_DSA_SHA2_128S";
+
+const PUBLICKEYBYTES: usize = 32;
+const SECRETKEYBYTES: usize = 64;
+const SIGNBYTES: usize = 7856;
+
+pub struct SlhDsaSig([u8; SIGNBYTES]);
+crypto_alg_str_impl!(SlhDsaSig, SLH_DSA_OUTPUT);
+try_from_bytes_owned_single_value!(SlhDsaSig);
+as_ref_bytes_single_value!(SlhDsaSig);
+into_bytes_single_value!(SlhDsaSig);
+
+impl Into<Signature> for SlhDsaSig
+{
+	fn into(self) -> Signature
+	{
+		Signature::SlhDsa(self)
+	}
+}
+
+impl Sig for SlhDsaSig {}
+
+pub struct SlhDsaSignKey([u8; SECRETKEYBYTES]);
+try_from_bytes_owned_single_value!(SlhDsaSignKey);
+crypto_alg_str_impl!(SlhDsaSignKey, SLH_DSA_OUTPUT);
+as_ref_bytes_single_value!(SlhDsaSignKey);
+
+impl Into<SignKey> for SlhDsaSignKey
+{
+	fn into(self) -> SignKey
+	{
+		SignKey::SlhDsa(self)
+	}
+}
+
+impl SignK for SlhDsaSignKey
+{
+	type Signature = SlhDsaSig;
+
+	fn encrypt_by_master_key<M: SymKey>(&self, master_key: &M) -> Result<Vec<u8>, Error>
+	{
+		master_key.encrypt(&self.0)
+	}
+
+	fn sign(&self, data: &[u8]) -> Result<Vec<u8>, Error>
+	{
+		let sig = sign_internally(&self.0, data)?;
+
+		let mut output = Vec::with_capacity(sig.len() + data.len());
+		output.extend_from_slice(&sig);
+		output.extend_from_slice(data);
+
+		Ok(output)
+	}
+
+	fn sign_only<D: AsRef<[u8]>>(&self, data: D) -> Result<Self::Signature, Error>
+	{
+		let sig = sign_internally(&self.0, data.as_ref())?;
+
+		Ok(SlhDsaSig(sig))
+	}
+}
+
+pub struct SlhDsaVerifyKey([u8; PUBLICKEYBYTES]);
+try_from_bytes_owned_single_value!(SlhDsaVerifyKey);
+crypto_alg_str_impl!(SlhDsaVerifyKey, SLH_DSA_OUTPUT);
+as_ref_bytes_single_value!(SlhDsaVerifyKey);
+
+impl Into<VerifyKey> for SlhDsaVerifyKey
+{
+	fn into(self) -> VerifyKey
+	{
+		VerifyKey::SlhDsa(self)
+	}
+}
+
+impl VerifyK for SlhDsaVerifyKey
+{
+	type Signature = SlhDsaSig;
+
+	fn verify<'a>(&self, data_with_sig: &'a [u8]) -> Result<(&'a [u8], bool), Error>
+	{
+		let (sig, data) = split_sig_and_data(data_with_sig)?;
+
+		Ok((data, verify_internally(&self.0, sig, data)?))
+	}
+
+	fn verify_only(&self, sig: &Self::Signature, data: &[u8]) -> Result<bool, Error>
+	{
+		verify_internally(&self.0, &sig.0, data)
+	}
+
+	fn create_hash<D: Digest>(&self, hasher: &mut D)
+	{
+		hasher.update(self.0)
+	}
+}
+
+pub struct SlhDsaKeyPair;
+
+impl SignKeyPair for SlhDsaKeyPair
+{
+	type SignKey = SlhDsaSignKey;
+	type VerifyKey = SlhDsaVerifyKey;
+
+	fn generate_key_pair() -> Result<(Self::SignKey, Self::VerifyKey), Error>
+	{
+		let (sk, pk) = generate_key_pair_internally(&mut get_rand());
+
+		Ok((SlhDsaSignKey(sk), SlhDsaVerifyKey(pk)))
+	}
+}
+
+pub(crate) fn split_sig_and_data(data_with_sig: &[u8]) -> Result<(&[u8], &[u8]), Error>
+{
+	sentc_crypto_core::split_sig_and_data(data_with_sig, SIGNBYTES)
+}
+
+//__________________________________________________________________________________________________
+//internally function
+
+fn generate_key_pair_internally<R: CryptoRng + RngCore>(rng: &mut R) -> ([u8; SECRETKEYBYTES], [u8; PUBLICKEYBYTES])
+{
+	let sign_key = SigningKey::<Sha2_128s>::new(rng);
+
+	let mut sk = [0u8; SECRETKEYBYTES];
+	sk.copy_from_slice(&sign_key.to_bytes());
+
+	let mut pk = [0u8; PUBLICKEYBYTES];
+	pk.copy_from_slice(&Keypair::verifying_key(&sign_key).to_bytes());
+
+	(sk, pk)
+}
+
+fn sign_internally(sign_key: &[u8; SECRETKEYBYTES], data: &[u8]) -> Result<[u8; SIGNBYTES], Error>
+{
+	let sign_key = SigningKey::<Sha2_128s>::try_from(&sign_key[..]).map_err(|_| Error::InitSignFailed)?;
+
+	//randomized signing with an empty context string like the fips 205 default
+	let sig = sign_key
+		.try_sign_with_rng(&mut get_rand(), data)
+		.map_err(|_| Error::InitSignFailed)?;
+
+	let mut out = [0u8; SIGNBYTES];
+	out.copy_from_slice(&sig.to_bytes());
+
+	Ok(out)
+}
+
+fn verify_internally(verify_key: &[u8; PUBLICKEYBYTES], sig: &[u8], data: &[u8]) -> Result<bool, Error>
+{
+	let verify_key = VerifyingKey::<Sha2_128s>::try_from(&verify_key[..]).map_err(|_| Error::InitVerifyFailed)?;
+
+	let sig = match SlhDsaSignature::<Sha2_128s>::try_from(sig) {
+		Ok(sig) => sig,
+		Err(_) => return Ok(false),
+	};
+
+	Ok(verify_key.verify(data, &sig).is_ok())
+}
+
+#[cfg(test)]
+mod test
+{
+	use sentc_crypto_core::user::safety_number;
+	use sentc_crypto_core::Error::DataToSignTooShort;
+
+	use super::*;
+
+	fn from_hex(hex: &str) -> Vec<u8>
+	{
+		(0..hex.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_generate_keypair()
+	{
+		let _ = SlhDsaKeyPair::generate_key_pair().unwrap();
+	}
+
+	#[test]
+	fn test_sign_and_verify()
+	{
+		let (sk, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let (data, check) = vk.verify(&data_with_sig).unwrap();
+
+		assert!(check);
+		assert_eq!(data, text.as_bytes());
+	}
+
+	#[test]
+	fn test_wrong_verify()
+	{
+		let (_sk, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+		let (sk, _vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let (data, check) = vk.verify(&data_with_sig).unwrap();
+
+		assert!(!check);
+		assert_eq!(data, text.as_bytes());
+	}
+
+	#[test]
+	fn test_too_short_sig_bytes()
+	{
+		let (sk, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let data_with_sig = &data_with_sig[..31];
+
+		let check_result = vk.verify(data_with_sig);
+
+		assert!(matches!(check_result, Err(DataToSignTooShort)));
+	}
+
+	#[test]
+	fn test_wrong_sig_bytes()
+	{
+		let (sk, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+		let text = "Hello world üöäéèßê°";
+
+		let data_with_sig = sk.sign(text.as_bytes()).unwrap();
+
+		let data_with_sig = &data_with_sig[..SIGNBYTES + 2];
+
+		let (_data, check) = vk.verify(data_with_sig).unwrap();
+
+		assert!(!check);
+	}
+
+	#[test]
+	fn test_verify_fips_205_vector()
+	{
+		//signature with an empty context of another fips 205 implementation
+		let vk: [u8; PUBLICKEYBYTES] = from_hex(VK).try_into().unwrap();
+		let sig = from_hex(SIG);
+
+		assert!(verify_internally(&vk, &sig, b"hello slh-dsa").unwrap());
+		assert!(!verify_internally(&vk, &sig, b"hello slh-dsa!").unwrap());
+	}
+
+	#[test]
+	fn test_safety_number()
+	{
+		let (_, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+
+		let number = safety_number(&vk, "123", None, None);
+
+		assert_eq!(number.len(), 32);
+	}
+
+	#[test]
+	fn test_combined_safety_number()
+	{
+		let (_, vk) = SlhDsaKeyPair::generate_key_pair().unwrap();
+		let (_, vk1) = SlhDsaKeyPair::generate_key_pair().unwrap();
+
+		let number = safety_number(&vk, "123", Some(&vk1), Some("321"));
+
+		assert_eq!(number.len(), 32);
+
+		//test the other way around
+
+		let number_2 = safety_number(&vk1, "321", Some(&vk), Some("123"));
+
+		assert_eq!(number_2.len(), 32);
+
+		assert_ne!(number, number_2);
+	}
+
+	const VK: &str = "2993877380b586123676ef66757767cf6f35ba34509fc258b1f4620b639648e7";
+
+	const SIG: &str = "15fb4012ad99a425ce81afeeb9ff65a340957ad1a1e58361261fd519424811f6dd7bff29bdbe0bcaef6dae830b9c589bcba889a35b762948fe3572b9f28a1e6d117702754fc977f619127d22913fdf04c9fb56da440f697c98aac361aa7000359e4e7a7745b321542c5e246c594167e589352656f81bd8ef6a924d13bf0b328cd2ae62c196622ee62b824725ce63d05e70a603a004d540d76420df749bec29ff7dc4f502f978819f0f002364f6acfad53acdd287600ed53c6dbc43a7126530572bbe69850747dd6c57dcb692ae441b3ccf4a56c8e51cdf1555956c5b2ad8336fbe85b1c0ff6d17aa61e2e8fca422e54496e4b19d02656a7704227ec89e45a87fbf2e7f49af2e400cd226c1a105f69d70e0f49344fb717c306db019660722c4d7af6aee8c38a736421fac8e5390bb6aee43dc47111d41ddd5d6b18b0477b3c45f5b6f7890b4bc038a6bc7d6b1b333d5bb6a840004be1d233552646e1ad7dd0474154a06bde98fa92e11468e0f1b4fadbed90dbea320fba3ac9a013dbe07146db63311d82e3c8f48a1b25f6356cd1ae674d1f8ca886afac8cd382c46b069fbf3dc3901478def53a7bf51f28427997829b8455104aea5f6705cc0aaa28006dc169874de708ccfe3ee2b01cf4a22830935bc16cb4b41cf19e36ba1ed9d6f6cfd77187298044056c5bed96807f266c8eb465f12a6db88acae8d5d81e2f9a64f04714caca58888c6a52da79dbc5b981b7f5b8107a3e4c8cbcfde62e46ee4316e568f49fae73dc6ff61fda68f0c70e5da43ac87bc18af830777b8bf8051abbb64fc370ea1dacf86862baf54059211d5d5557783de26aef1b03f9efcdfcdbb7b57f965a572edddf593302cff2298a933d40fec8a6e2a92487f5e0b0a080164bcf36c7e74f976810458dd54afa045980f140e9eb3991c7b211aea211e346022c77b90a29665ba99b2d91a4b37e2b9c81ada83bd0f6f6227a658ebdda2d63a60193cb74cdd445d3675c1e5d3882e8188ab5415fb86b520f5eaa188ba1ec4bca42ddab14051bdecfe530bf58de6602efcf2d37e553475c0673d0838bc277dadcd3bacccc0468c4ec6f795bb8a96c81f99b0fd3e46761a84e6a7bd8bad17e114f7757da9908d1ff1887f59778ca22dac4b64ae68bf9ee33b577d0e81efbd1ef9cacb52cfa2ef3172914f45b50819e56f75301ffeb58b239f630e2d5686f1f9bffd3351cd32b5e7d379c79c794da7f31b8a2b741d902934573b390b88578b30e0d9fe5610d97579fe1cfc4d16fa05fcf24eb2585b13d2a700ecf1a26ced03fca6ae6c2db808c2dd9249f92a19849bbe29f47be1d10651e829857e1fa930696fabeb82d540ce07d1dad3e7869d567ad71b84fdd3c2c2bc7b6fc56beadb7c18a0cbe79503f7978167450144e8b6ecc4743002625c06d38729c4a5954afa529353d55c5713738ab2e87f42400d5d7f2af31b9057bb8bbb57bb90b7bb12bf3d3513e5dff8f13b7f8006cdb5ae75a75534b61bd95d2edc38a79b22581ac65939e20a914e94a35e6652c920db1d8ec3607d9d21b9b662d7745f6e64095f934dceed9b093bb281cf6fce68478e7bd6818d0471bfeb27e641d203fb409cba4f7a2b9790e72e7b29b1dbbc463d9ea29531f169d4c3c87404c0e422581737b3489025c23ad6acbd0487de3c31585672c679365c3bf6084f58093eda1108a9dcb9389bb77abeaef2d07f410749e64ebfea5b46b47d8311ec51871365791748991f50b845e0a8374277a05aed1a8dd7976230c692854b570eb07c5c3c4ba9c9ae93b9ab0c20b103670fb722dfa640631f3e7642986cc508885cac16f066e942130f6d0509d1e8f91b219c3bc50bfdd3989a57c3965e9d19f5c1b25dadb8f04fb0cc707e462d26a5e8bf90f6b568c2b6722cfb9b8c1d4d0acc3613991f6e55ca78086dd65dd091e4f6a3460a546f0fb2834e5dde62fee6e9e48eb82aca3238da9fd11e44694f8437f52de5b9e65c3b152be6e9eb1f1bcd7f8935dfbbb111ff7bf588901dc411e048cb4826f599ed1c1a3aaedc6024011c087a45c1026cba4d723ef652d11983c3dfe043d7d1608a7d154c6e2798a64b283f7e9e0d7b0cd4b2fff07fb1508c86fe9e08b82d21ae798d9363b0bf3122062c0cedeb36e66dc5fd2e09155f66fda5c1771925ee24a540a2a44106bd50e680b6838015b028653cb900c68f49eaad7a4708ec6b5d09d94948f228879c20775e3d776a902687b9ae7811769e8515bf81e1c795cf17b61ebf1921b0a3fbbd3b517a08685fe3d01941a8d6a28669ff3e2c42fd9ce327d6e2b6da48181d51c0205b33cebfbd982e18a20f99e51812ec6d6ff7d7cabef651985dca73fc1ae0da56c0580713deb088d934f6e5fce8d0601a1136ab9b51efb7ac2d7d0491b1307c6492aede586dc9e6d09bf109b38558cb41343ebb6e7df1ede231c70016b756a4df5bfa299394ca3278251abeae7888e966e9fa439e0758aa4ac0fc565858c1f66373c6d116d8c9ad182b96c2932122caff45f73a7bf00081a9a002eca88e7f0f06080ab3c6a7795aac8a99c64a94d0e02763771e441bb18f29f4ba75f856c92d931eadbbebefae9308e184ab44fd1204eaa91f23fe8ede1fbb6fc99aac3e0b02b55632d4fb91afc9e8c140e703a8ea0f7847ae7294817331a9d4a5473594ad3645d7e673dff8c29a7d4f9a5a611cab20edabb3a3501a2c46b87694f2c3e590b40c1ad942ed6915c471d91001636dfcb67829a0e8e52453f2aec152236e6f60e2b0b47165d11a9b7ee17b13ac13b9b29efcfa9ae67037cbd4db474b96f0ea3a2461a0861e612c45a6a899ab85f3d2e7da6fd42fccf933e93b7db4317a43d60e140ed655a7322815f4e80995a277a0112e90724ad3d48f03817391853ddb79765c505e52124450e955d787f51d10bd0f34c1d8e484a37c86f645ac488192b3e268d4a0560d9de5b476382d6677af098fd576a67bbe8949f2ca07e07697d00f55af64fdc736d20a0ed348d9b0ffbc1567b70e82359cf228e6cb07b5d0848d81b59326c4f0ec526a585de8d8166d1883ef86bb321151e917ebc8bec05d1660074b0306349bb06bc13cd1915927a827a88f0d9398c5965a9b758e40fdbe0fddf6b5cc38051b9aba406ee321d60de388a4d23a6f5c5a8fb7e686d874c75bf29f7b3d0f98388c12f0d5249753c7662fbb3eed9247d46d0e3a2270386e453a71b65e69b7da0c204fe9c6b7d536c2d7ea54d7f4913ae5cf4808c821ae04e945ff20176adf4e5cd2fc4584e0f5adea4f3655a582fc23f8a6e1350a846f2bdb219c2db3749c640ec66ef9f92164083b848ede477e48ad7ed1de479d0fc1fa1f8f49e090c3671517f4a9149e8a1e4bca19f20eac058fe792f5610d9932ad69dd2b441b2c74a73540b285d022dcf46c16c7f315e495e26f3f49e947d454a94acdb38b3a56fd5c4d36d8d83fb7cfb78716bae0722ba395379035ce5d602db5d2128ac7ca8425dded28af05829fe17634f23ec38b997ff7fa207cfef087defe335dfafc3ee4d634557a1eb19554288633c44169234f443a9ddd042e9b6da3470a0e6778f48b4485bb12e6b7f8b4eff2d3101b59f34cc9a9bc2f11f82185c5397065555882ca7afeee78c466b36543416f3628a2d20fd8555326dae469cb40b57bc721f0cb50092e0cf0a2b1ea4abb3c3fcb306d8e2720c2c51be3dcc6a0cebe8f4c44f1ce634abd139d461ad19f0d60458c1d6c55695027d8ba217ed5538b37ac142a6c17abcdd573e0e98bdf67c87dc51c466dd4694ea03e38a2f6907508ba11aab387b7e60933328a3261dfad57eadd59557490d986ef5432daa117254599f9979a03861705e49fc36265ed4ef941eb2b714d9e51053f4b590cd01d657efb204223a80efd901036afc32a3a0ea7035c66a5fa42cf501812c489e1ad0677e9a8eb3032a8eb294cbf9ea57ef6acb57bfff1687d65093335a3f96a0a67664bd19b7526545dd8c39598fa46ea4e7434bd83691be7d42a191cb347118cb71e48d4342542214611037269f84af5f72bc2b2be194164cfe4f93244eaac072791c44415a92f6ef71c81295b5b130c076eb4ded2af3bcd2a82fdabe10b4571e59a595d06f9f14d9926e837efcf1f153ca76f64c2c255ae1e0e39a3e1581c06774b60561b35c40bbcf76872572657aa4e6fb444c65ee66f2e9e6dfeaf97dc05862e3bf3bc34b029381867523f227e6c288c137acf2f4a7527b57d14c9a453c1ec4bf6f1c8f1fd00057358c06f7ceb0e5ccd698470b93f3f456c5da9a4eb7507236702fb30453289e7f6179f954aae8c4dbcf0f03129abeda657aba7205663dd5d590462f633b58da72beeaa0bf752520c7842db0144691fb9bf4beabeb23dcd52d16c7ec19aa6e46f560c706e567066563be5a0a4a0a2a9eb302be69a62788ce22d99e92271081a11ef2e5b69e032e51e076bd3109e79a28cb8fe017e7ede11361b6ab45832dbfa9788038306a5c4bf50374d54a3efed5fe1556774146d1f2a0e582141184532bcf3be9b2476aa1a0d8e6b0091af27d5313a38716c13f7af40a0cb687f84fc89b206b97f844a859831fc54ab9eff27ecac6e5bb0d49cfd18c1b8fe16f55193b6af7f2d5c823b679d02ad272d1c70b3d299eb5c2a4471c178c95e1dffa28f15e2c0bc93d55dafae067818679b4ed32c54bbd6d625e87c792f3890a2988a00869774db1b0f4463f6eb5addd52235dde04db69246fdc55ea8696b4c420d0e7d5dbb8cd0ec04baf4de4796d1d202120c262872fb3b05379adec4f1bf67121a8a4b2fda59fa311095245b557b18722858c69e10a6fbe82adfda3fe4ba1ea7e5a8a1ba6abc4eeac8a8c84a3565aaa4144835fb7a0669b247a1bd2da2cb4b6074f65e544b1b8fa7645d3c51c14d9c0efa76baa38aee9a4f7449cb0f429123a96db0a6d6f812b2c867abd746051f3e46bd5f52f1bf3325eaf1028f34f73d70b10b066ce2aac902af25ecc56a626546349cc9bef67b2d5deb55787dadfb3f13447ab169e705ab359e1a0522a4e370c5c662397a440a60509362c6a4a66ee0586be428a694528233dd7219ab3f4aad57f3d6f48b05d85ffa9a0ba0e797d7b7a44d30416b9c529139df54400f8b0365ee9578dac192296a8f7b55f1e6eaa7ed1833fd64c0ced933e9e84db63ba8ca5ea54c9e0d133fc1d528f512bfd0b512c3e97d81fc463d484c576531adbd0ce5077044848a0d2ea90309144e22daf4a1c3ecf4bf5b019d8dc03472a3da6af6aa3d5fc89519f955af3af4ccd3f4e9fc160fadc30db9fdabee9eb084bb292e47a9d4da2994a2881d06e7df5505eb66a7cac573ffa83c059072ac7a972d5931f0fb5887d85d418d92983c0a4682b75b37fd434114b5b5b8f4d691ec567e8ef9b5a776f6db82affd12919f66483189e981248041dd07a6c86ec69143eab2345de542030d8e67a524f58b3ea6de973818d634c71eaf064416f91f4af43131a997caa11ed9da7a8b384be588a171269debedc57e2f5bb6c2ad006f553c7d8bb86180515d50760b13474dcf2331b44f8c77f6c0d945e7b5950dfe80f79dc448dfd19ac67515eacb991012f04d2d93dc2f2568831bb53350d3a8a8fc1f2353b66ef83e36229c3c0950b0eaff3f2cc216151dd359d36ff192b69b171456c2338eaf19a7d1ede3037152c8e2ad043c668bef00f787a5238f97a9b29081cac4cbe91bb4d6aca7fedff4a709eaa0c7989cf784fdcbe70bcf12a906a0f0bc961f45de3ba9ecf392f48f05338e6d2000dca0e952fbcd6e4821cd666e3a999d9e2f50b2538148ce29c8a078bacf5aa421afef7a74866388a5fe05f8b57b927d4e0d7ca4d4e766ad3f15adb3a6ea039004aca23b32a6d8a6204fe654a2bd3588ddfe22a2b9d2fe7162ff6f2b61263cd7ec69da9e67304c553268bdd64ad227964161e1606016da636c8af055a54157cd87462731ed7fb0e907cf11a847c672dec39d02ac39e4f21f41662e1869ed79095af6b6d133ab38a7c5b101a443a0288b1532ec741edb045bef95cb570f8f0d9905163011804bf31a05bd44787c3da08fee11eedd2492657f7de65db3d4af38b03041212c6751336aaec83117f77d3649e449701b6b0bf1b34cd25a79406fafc12cd463ef390134f3bb74c067c7aa505826bb2ebaea4879332508bbe180a7b390b3bffbf14bd779a1ac65abc897e0ae261fa7afd48f545593852775544e064aa377251eca1e195f0a85adba9914fe55300b2d469eeff3af5411457c6057a3174f9d39acad8980fb2cce9e5ec379ce6774723140bbf021e7b7a08db3cf1db48f8ffeb62f2d8ec95c1d2a087e3a813f65868e4badb8e0ff3876a2b5b1d7a9babf4f91a97492db627077fccea31841c3564fa6165d61a1d3578b290920b529d095cdb0f7f59f0e3319ceafb9ecc6d1529156b1fefe477b466fe4bdfea06efb229b6ef0e0de5219dba93141c32a65bf08cbf358ca94102222d99170a075ad7dcbd033960b657d282f40f3b9426103680f32522685332524b7c4f365c0ba3751fb70ac441d28e4c03f0be8d95134c07ef18b6ff31e20ab880c195ce192d8d1e1d8c73721a4f998db2743e8b2772652e30d8b9f7085060d71fbb675c8463d7b517a55d093f46baf0ffab82ac811b434507c49b406dfb6f5470f2b49c60fb5b6a64c40e47621768b786e384b68c98901e96243df6870f644beda10daf93b3292b9fad5ec1c0e757f891e2f72fa999f1298890982de6bbc47e9dde32e3ae853c518e2c7998797e3cdf3914ef4810d77dcff68b9a899f56eb817f72c8e92218bb3d15aadfd04e275072f8829805256ae69f76b511f907b6d5443623e69d54ee85e0790b9c85187e966d879fdff9d7526a28f59befb8d8aa45f4f5335215be3d788f519699ba3bb5823f7f0243ae32ac232aeda5df99291665ea0605be78b1dff4e9e77a956f44461e25c4cc84144f64b1e4348b4ec34640a1d84ce5c4c1284fe24b5123061dfd2c4bda09276a48ce6c07fbbd5ac8b7e8bc6617acf8ceb56a2c8193e4a3a78803ef48bc298ea81b55420c57117472b56ee70f6468b9ef60b0a4ea2bb2f4205419bdf6190bf4d89d867463d2d0b45e0d72798ee5fe1498c6063facf4040ff89d5c0ffa0befd2b4be9659b069722089aab4ed9e14d66a4f6dc6e2f69c39b737509fd221b369b39e60c523a328461763ca43787fa650d71eec38eb5d81475e393329251711fda66c706f185fe12331423523356ad7b0f84fd51c3dc3b8937103e8b14a7608ad1fcbc8e7f435ba48e724c1e20cc5ec943240813807c30dcb81f826e53e852c40525d6245613dc70ad94dd6fc5df06fe0dd683a9505827eb3120060f7b059d99cd058d9c7c2057b6c71745c099e02a72d27b4b7ace012c4f9153f68e494d60f33f24253a1084baff32990a1a5d5f5a6cdc6d0c7c8cbafd2f408e950b2e6cb25388eff105420bea02c8e964f165be79d42e93472b6fe1ce84c78893472dc629c700fe771a1f40b0da7b48c4ff07fce1a3b979763ced18db17b8cde03bd10e01f2f210ac4a43bcc6667c45b46c083ea391d18740f29eed4e17348031b44f7bf352ce042a767f6b6763c5d8629918017618ddb82f961825b455fd0ecf1f051daa63f23d8ad1b6199f78e8261638300bdbe13279962eed988c215a03e177bf6e8739a2561e3e595518f9efa96dd45419fb5a5b539b11bbd9cea8193150fbdd6e5377f5752350f1eaae81b4416c5b4a9fbf00be9f68b2c73af4bf673e7b732c2e5c42c691f5534c7f2eb707ec36830488f48bc07280016d8f55c0e10fceef1c6e212e60982c63abca6a5059293b119cd291b80cd609d9a050e75543dec0436d61ed4c2e9f1e75ceb436b36e013655dfdb813768d34b8b1dbf01dbb0f23a730fbabc23503e13adcfb5b1cdf3ccd62072f2d35eacc06610782f39eaed7023037dd72ecdb4f20be4230ebac8e5848af650b2f571703cc18b6c319e3b14a0cc9e805e5fecdb8766c869518881363fd4c9f8c3ffa7c262beec54ce8d3386f2dfb19917a8b8dac1ccab26e72f068243c713f0cfca38b793122d04db0bf261720d9c40fb692f09c05b97ce929468812c88d9ec8fd6ebc4d2d088b510f12ca6243ed8cd3a34fad17dd7a9af085591239aa974baf73bfa1b6ba8b957f18ec974fa52ba8eb2aa49b622704e8f4d7ef18ebe7d9e02592f106411afef10df0da64f16e139c137cca658b69c873c059ad4c1a59f0df9202e174f9bbc3f5d96961d356f2451811200b0c7d16a9b7b14f0786b5ae87dcc6befc2bbdd48721a23f0aa2c330de509a8828fd8954b6b28a3c7433412c6e81c4b3f4722b287873a64f0ffec5cc7b2694930d7e26159b2df7d995a987428e538fbd85d1872072e082bc0fdef5e5a7c1bdf817d6df47387857de0c90296b5feb62eb9b73d6004375a69b1722cbb54bca3198bd672c03b7f090ae2b7974a9d4167d8338972b181b5eef1fe67776e0b14731d60f98fe8967e0a94882ebcce5a07255ae6514bf3c5c13b9533f53399ff4c2eb5f96015b2042ca08a7dc4f96b25f5c85e7abc630aa8056c359b7e30a70511edef840a2dc0203b71b03b5adb736effc02b638c9af100806a816774b396e56c6f3817900690b3868474fa38fa99f36b44e0b4c293c16d9cf874732ab479d3b20409cdbf5c5ec3c6b0af0b97de5a28a51ccc2683596ea0e9ee1b5464e0dbb3ea707c4b7f4f1d30504e91169637a17f611e18c0da8723f7fd484efc5dbe0cc53e1934af02d90bfdf6eeb544786a8bbf66fe7a2f8574a0854336a4e40d62682e1dc5d94d3a4fbc5fd9fd9175ca84c2052819113b38fed0052914a4016d0f520c3adc384aab6100cd2777305bf8968efdb390dd41bf4a0409a526fc4a80820e7cc46fa5d633b4ff34de39ad810c0594d39cb38f63539b9dcf791f7a9ef58b791315dfa8ecbe479e4a5778a2e03efdb5395771dae6eb732f30253c418948394c764f1e1b3c04cd6c47e705297bea4253524e1a8fe20ecffb2bc60f2080595d0100b5cf82ae5b34db31541aa082b8b69b5a242c8379cd7dc5798d20d4544f2e27bba746c5fcacd40f23a3d93c32738eca92e1281367601ec6f98e624947936a64b1b79a7a83ee197ad2f43cabaae6dbb33bdcada8e83ac19741e241717d0f72005f2a72e3b2529d032c0da2e1fc56dd036774d8315dbc5bb97019be45de41fa5979eb3e716fe76ea718a6ce1de1a6e95cc0b94714a5c465046661897ec8f169182b1341be87dcef1acbe20a66caea8cf90db8938d75f816018bd0ec839f6645423f5a878a664b5bd5f4498fcf3d1bf3a5a97ee1652b1996111fa3c928885f48261ae7ffad3be705f78c537aed8e1f98e39402981194bf134b98915e9acd22463ea384582ac7cfff75f9fdf9e1321ee37b687604c75c8212b174e2af614811cd24473af9845e38323f3483f19ceeb36c5c0a0a73cd61f1335d9ced33f8e68a46933f422b4cd39d096f5ecab392e8bc84362841e678267a42862bd17a6417b0632e0861e4af92e900ed496ad243221a02912aae175485f8df35c542895349fa6d7a566d5ae43169a07373274ed8eeb0cd189df09bd4a1adf382f9b2925e349f84f370cdb19bffd66e508a251d5bed09f79491edfac4146baf2e10de16134836176aa600fcbbf5893559f3ea97f4d2c98085599a2a236dd3a81f5bafdd170ccd94f46d1481eae60711b26d5d809171f63fcc1351b9952a0ab739c2136408ffb916596c242c95a48ee49296332c1df8a0ff06cc49d1b06a038b85b3a6a77aab14ef2629ab4c6c21536f0c576697840b5f27587c85e016360fac420403214df637fad5a9fa0b7c0b720d1a872ed0c7ea4abb29bc87f4c153f927aef677f886a87592558606623255ef4b2f6d2c7f11d3c751f596c39a1ce833b3dad8d63c0cd87a71685f05d375dfdc0dd6e27904d53b26aab17e8a577c42264bdecfa14dd29b48a974a60ef7a60e3bcf357a98706de0f614679dd0f7e91d9283087eddaef40ec96f6c50db2ddac3cb56d378eee9618ef2c5af7a563bb18e9166ab53d6265bfbd1b202e249cfe9690a0f79530a72ce41addd4cebab8080a744b250c1a40fd810187544aa837ba00f26fdbeae49c2a501a1094d9ddbbaa435900bda8264ed52f1dffe1dd596a11d8bc36a3e9142231916510a7ee065a66bd93b303367aa7ecf36c6cf1d88439c5494bed1c1a3e16236183b67df266d19f862e489b91312067ffd5108f9c78b56630a8cc37d6ab808885cc6733fb9010b635a815d95bdddd6e543f0a9f88aa002d012c6a67fa051d5667e215e8d1002c9733856807805b3c9699dc05f3b9c09efa2c01641cd2a6b6f1fa250fe68d5dc0380f640eca788bf781fb3a8eaf2efd7358dd1474c3160ed311db81696ea54c3e0da6dc54a18f40b1add18963157239ccd5b1eba08418ee505d1322f97cf85a039b1959affad222bf2b3213f00fb8990d3afb7fc97953a13ede8a0b043b9430517036568fc75fafe85f0e932d30a0d38361bb99243d354e574154e43109b6dac0120642643b1de57649197d17a8b47c1d484c6f5eba8cccc0a9d5dbd2e45895b359e042e7ab53ec3ece4c5c419984a55e8dd3131917a86272dedd9789271902d9e0ba5e4910422772f558c10e7df309d380f760b4cbe33d6cb8753c9b17e72db64ec7515ab458c3352236facf920905ee7714afeceb51fc3f3de936ec19c1b635c6f3b133617ef10a416482ee2bacf889aa1d1f83ea4c411762a41d3c655911062f6e94de8341e70a6d4c09423d0db437644f81f6e2d21754e5fec3af3359206cebf992a56554585eed347d7185b663db40cb7a01615704ff6d57489845f4dd8726d1a16b38e89f00decc337baf59dc502c49be915c24dd8d4d4af3679fd636eba220e005eed3b2dbb7d8db67ca8448a1ac863547b6ce562bcc46013c9113349a118e27080660b130f42a889955934ccb21e9b1cee1e056053ebb98c22a3e7d1254dde4eadd270b00bf3dae669776426f08581f45c8dc154518f3c298647512d0cbce9c843d37cc771abfd2bbc659e098acdad87c10422a0afe7e97f33904746804fa5eda8963971344a4009";
+}
@@ -83,6 +83,18 @@ pub fn raw_decrypt(key: &AesKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error>
 	decrypt_internally(key, ciphertext, None)
 }
 
+#[cfg(feature = "full")]
+pub fn raw_encrypt_with_aad(key: &AesKey, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+{
+	encrypt_internally(key, data, Some(aad), &mut get_rand())
+}
+
+#[cfg(feature = "full")]
+pub fn raw_decrypt_with_aad(key: &AesKey, ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, Error>
+{
+	decrypt_internally(key, ciphertext, Some(aad))
+}
+
 pub fn raw_generate() -> Result<AesKey, Error>
 {
 	generate_key_internally(&mut get_rand())
@@ -1,14 +1,15 @@
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use base64ct::{Base64, Encoding};
 use sentc_crypto_common::crypto::EncryptedHead;
-use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_common::user::UserPublicKeyData;
 use sentc_crypto_core::cryptomat::Sk;
-use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SignKWrapper, SkCryptoWrapper, VerifyKFromUserKeyWrapper};
+use sentc_crypto_utils::cryptomat::{verify_with_policy, PkFromUserKeyWrapper, SignKWrapper, SignaturePolicy, SkCryptoWrapper};
 use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::{put_head_and_encrypted_data, split_head_and_encrypted_data};
 
-use crate::core::{PublicKey as CorePk, Signature};
+use crate::core::{PublicKey as CorePk, SecretKey as CoreSk};
 use crate::util::export::import_public_key_from_pem_with_alg;
 use crate::util::{PublicKey, SecretKey, VerifyKey};
 
@@ -40,31 +41,99 @@ impl PkFromUserKeyWrapper for PublicKey
 	}
 }
 
-impl SkCryptoWrapper for SecretKey
+impl PublicKey
 {
-	fn decrypt_raw(&self, encrypted_data: &[u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	/**
+	Encrypt the data with the hpke info and aad context (rfc 9180).
+
+	Only hpke keys support the context, other keys fail with AlgNotFound.
+	The context is not in the head, the receiver must know it to decrypt the data with SecretKey::decrypt_with_context.
+	 */
+	pub fn encrypt_raw_with_user_key_with_context(
+		reply_public_key: &UserPublicKeyData,
+		data: &[u8],
+		info: &[u8],
+		aad: &[u8],
+	) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
 	{
-		match &head.sign {
-			None => Ok(self.key.decrypt(encrypted_data)?),
-			Some(h) => {
-				match verify_key {
-					None => {
-						let (_, encrypted_data_without_sig) = Signature::split_sig_and_data(&h.alg, encrypted_data)?;
-						Ok(self.key.decrypt(encrypted_data_without_sig)?)
-					},
-					Some(vk) => {
-						let encrypted_data_without_sig = VerifyKey::verify_with_user_key(vk, encrypted_data, h)?;
-						Ok(self.key.decrypt(encrypted_data_without_sig)?)
-					},
-				}
+		let encrypted = match Self::from_user_key(reply_public_key)? {
+			CorePk::Hpke(k) => k.encrypt_with_context(data, info, aad)?,
+			_ => return Err(SdkUtilError::AlgNotFound),
+		};
+
+		Ok((
+			EncryptedHead {
+				id: reply_public_key.public_key_id.to_string(),
+				sign: None,
 			},
+			encrypted,
+		))
+	}
+
+	pub fn encrypt_with_user_key_with_context(
+		reply_public_key: &UserPublicKeyData,
+		data: &[u8],
+		info: &[u8],
+		aad: &[u8],
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, data) = Self::encrypt_raw_with_user_key_with_context(reply_public_key, data, info, aad)?;
+
+		put_head_and_encrypted_data(&head, &data)
+	}
+}
+
+impl SecretKey
+{
+	/**
+	Decrypt the data with the hpke info and aad context from the encryption.
+
+	Only hpke keys support the context, other keys fail with AlgNotFound.
+	 */
+	pub fn decrypt_raw_with_context<'p>(
+		&self,
+		encrypted_data: &[u8],
+		head: &EncryptedHead,
+		info: &[u8],
+		aad: &[u8],
+		policy: impl Into<SignaturePolicy<'p>>,
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let encrypted_data = verify_with_policy::<VerifyKey>(encrypted_data, head.sign.as_ref(), &self.key_id, policy.into())?;
+
+		match &self.key {
+			CoreSk::Hpke(k) => Ok(k.decrypt_with_context(encrypted_data, info, aad)?),
+			_ => Err(SdkUtilError::AlgNotFound),
 		}
 	}
 
-	fn decrypt_string(&self, encrypted_data_with_head: &str, verify_key: Option<&UserVerifyKeyData>) -> Result<String, SdkUtilError>
+	pub fn decrypt_with_context<'p>(
+		&self,
+		encrypted_data_with_head: &[u8],
+		info: &[u8],
+		aad: &[u8],
+		policy: impl Into<SignaturePolicy<'p>>,
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
+
+		self.decrypt_raw_with_context(encrypted_data, &head, info, aad, policy)
+	}
+}
+
+impl SkCryptoWrapper for SecretKey
+{
+	fn decrypt_raw<'p>(&self, encrypted_data: &[u8], head: &EncryptedHead, policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let encrypted_data = verify_with_policy::<VerifyKey>(encrypted_data, head.sign.as_ref(), &self.key_id, policy.into())?;
+
+		Ok(self.key.decrypt(encrypted_data)?)
+	}
+
+	fn decrypt_string<'p>(&self, encrypted_data_with_head: &str, policy: impl Into<SignaturePolicy<'p>>) -> Result<String, SdkUtilError>
 	{
 		let encrypted = Base64::decode_vec(encrypted_data_with_head).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)?;
-		let decrypted = self.decrypt(&encrypted, verify_key)?;
+		let decrypted = self.decrypt(&encrypted, policy)?;
 
 		String::from_utf8(decrypted).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)
 	}
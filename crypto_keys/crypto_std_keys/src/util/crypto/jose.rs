@@ -0,0 +1,149 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_core::cryptomat::CryptoAlg;
+use sentc_crypto_utils::cryptomat::PkFromUserKeyWrapper;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::jose::{
+	JosePk,
+	JoseSignKey,
+	JoseSk,
+	JoseSymKey,
+	JoseVerifyKey,
+	Jwe,
+	JweHeader,
+	Jwk,
+	JWE_ALG_ECDH_ES,
+	JWE_ENC_A256GCM,
+	JWS_ALG_EDDSA,
+};
+
+use crate::core::asym::ecies::{jose_ecdh_es_decapsulate, jose_ecdh_es_encapsulate};
+use crate::core::sym::aes_gcm::{raw_decrypt_with_aad, raw_encrypt_with_aad};
+use crate::core::{PublicKey as CorePk, SecretKey as CoreSk, SignKey as CoreSignKey, ED25519_OUTPUT};
+use crate::util::{PublicKey, SecretKey, SignKey, SymmetricKey, VerifyKey};
+
+const AES_IV_LENGTH: usize = 12;
+const AES_TAG_LENGTH: usize = 16;
+
+//the aes gcm output is: iv || ciphertext || tag
+fn split_aead(encrypted: &[u8]) -> Result<(&[u8], &[u8], &[u8]), SdkUtilError>
+{
+	if encrypted.len() < AES_IV_LENGTH + AES_TAG_LENGTH {
+		return Err(SdkUtilError::DecodeEncryptedDataFailed);
+	}
+
+	let (iv, rest) = encrypted.split_at(AES_IV_LENGTH);
+	let (ciphertext, tag) = rest.split_at(rest.len() - AES_TAG_LENGTH);
+
+	Ok((iv, ciphertext, tag))
+}
+
+fn join_aead(iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>
+{
+	[iv, ciphertext, tag].concat()
+}
+
+impl JoseSymKey for SymmetricKey
+{
+	fn split_aead<'a>(&self, encrypted: &'a [u8]) -> Result<(&'a [u8], &'a [u8], &'a [u8]), SdkUtilError>
+	{
+		split_aead(encrypted)
+	}
+
+	fn join_aead(&self, iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>
+	{
+		join_aead(iv, ciphertext, tag)
+	}
+}
+
+impl JosePk for PublicKey
+{
+	fn encrypt_jwe_with_user_key(reply_public_key: &UserPublicKeyData, data: &[u8]) -> Result<String, SdkUtilError>
+	{
+		let public_key = Self::from_user_key(reply_public_key)?;
+
+		let CorePk::Ecies(public_key) = public_key else {
+			return Err(SdkUtilError::JoseAlgNotSupported(
+				reply_public_key.public_key_alg.clone(),
+			));
+		};
+
+		let (ep_pk, key) = jose_ecdh_es_encapsulate(&public_key, JWE_ENC_A256GCM);
+
+		let mut header = JweHeader::new(JWE_ALG_ECDH_ES, &reply_public_key.public_key_id);
+		header.epk = Some(Jwk {
+			kty: "OKP".to_string(),
+			crv: "X25519".to_string(),
+			x: Base64UrlUnpadded::encode_string(&ep_pk),
+		});
+
+		let mut jwe = Jwe::protected(header)?;
+
+		let encrypted = raw_encrypt_with_aad(&key, data, jwe.aad())?;
+		let (iv, ciphertext, tag) = split_aead(&encrypted)?;
+
+		jwe.iv = iv.to_vec();
+		jwe.ciphertext = ciphertext.to_vec();
+		jwe.tag = tag.to_vec();
+
+		jwe.to_compact()
+	}
+}
+
+impl JoseSk for SecretKey
+{
+	fn decrypt_jwe(&self, jwe: &str) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let jwe = Jwe::parse(jwe)?;
+		jwe.header.check(JWE_ALG_ECDH_ES)?;
+
+		let CoreSk::Ecies(sk) = &self.key else {
+			return Err(SdkUtilError::JoseAlgNotSupported(self.key.get_alg_str().to_string()));
+		};
+
+		let epk = jwe
+			.header
+			.epk
+			.as_ref()
+			.ok_or(SdkUtilError::JoseInvalidFormat)?;
+
+		if epk.kty != "OKP" || epk.crv != "X25519" {
+			return Err(SdkUtilError::JoseAlgNotSupported(epk.crv.clone()));
+		}
+
+		let ep_pk = Base64UrlUnpadded::decode_vec(&epk.x).map_err(|_| SdkUtilError::JoseInvalidFormat)?;
+
+		let key = jose_ecdh_es_decapsulate(sk, &ep_pk, JWE_ENC_A256GCM)?;
+
+		Ok(raw_decrypt_with_aad(
+			&key,
+			&join_aead(&jwe.iv, &jwe.ciphertext, &jwe.tag),
+			jwe.aad(),
+		)?)
+	}
+}
+
+impl JoseSignKey for SignKey
+{
+	fn jws_alg(&self) -> Result<&'static str, SdkUtilError>
+	{
+		match self.key {
+			CoreSignKey::Ed25519(_) => Ok(JWS_ALG_EDDSA),
+			_ => Err(SdkUtilError::JoseAlgNotSupported(self.key.get_alg_str().to_string())),
+		}
+	}
+}
+
+impl JoseVerifyKey for VerifyKey
+{
+	fn jws_alg(alg: &str) -> Result<&'static str, SdkUtilError>
+	{
+		match alg {
+			ED25519_OUTPUT => Ok(JWS_ALG_EDDSA),
+			_ => Err(SdkUtilError::JoseAlgNotSupported(alg.to_string())),
+		}
+	}
+}
@@ -1,4 +1,5 @@
 mod asym;
+mod jose;
 mod searchable;
 mod sign;
 mod sortable;
@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use sentc_crypto_common::crypto::SignHead;
@@ -8,6 +8,7 @@ use sentc_crypto_utils::cryptomat::{SignKCryptoWrapper, VerifyKFromUserKeyWrappe
 use sentc_crypto_utils::error::SdkUtilError;
 
 use crate::core::{Signature, VerifyKey as CoreVk};
+use crate::util::export::{import_sig_from_string, sig_to_string};
 use crate::util::{import_verify_key_from_pem_with_alg, SignKey, VerifyKey};
 
 impl VerifyKFromUserKeyWrapper for VerifyKey
@@ -23,6 +24,11 @@ impl VerifyKFromUserKeyWrapper for VerifyKey
 	{
 		import_verify_key_from_pem_with_alg(&verify_key.verify_key_pem, &verify_key.verify_key_alg)
 	}
+
+	fn sig_from_string(sig: &str, alg: &str) -> Result<Signature, SdkUtilError>
+	{
+		import_sig_from_string(sig, alg)
+	}
 }
 
 impl SignKCryptoWrapper for SignKey
@@ -39,4 +45,17 @@ impl SignKCryptoWrapper for SignKey
 			sig,
 		))
 	}
+
+	fn sign_detached(&self, data: &[u8]) -> Result<(SignHead, String), SdkUtilError>
+	{
+		let sig = self.key.sign_only(data)?;
+
+		Ok((
+			SignHead {
+				id: self.key_id.clone(),
+				alg: self.key.get_alg_str().to_string(),
+			},
+			sig_to_string(&sig),
+		))
+	}
 }
@@ -3,9 +3,8 @@ use alloc::vec::Vec;
 
 use base64ct::{Base64, Encoding};
 use sentc_crypto_common::crypto::EncryptedHead;
-use sentc_crypto_common::user::UserVerifyKeyData;
 use sentc_crypto_core::cryptomat::SymKey;
-use sentc_crypto_utils::cryptomat::{SignKWrapper, SymKeyCrypto};
+use sentc_crypto_utils::cryptomat::{SignKWrapper, SignaturePolicy, SymKeyCrypto};
 use sentc_crypto_utils::error::SdkUtilError;
 
 use crate::util::{SymmetricKey, VerifyKey};
@@ -71,22 +70,22 @@ impl SymKeyCrypto for SymmetricKey
 		))
 	}
 
-	fn decrypt_raw(&self, encrypted_data: &[u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	fn decrypt_raw<'p>(&self, encrypted_data: &[u8], head: &EncryptedHead, policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>
 	{
-		let data_to_decrypt = Self::prepare_decrypt(encrypted_data, head, verify_key)?;
+		let data_to_decrypt = Self::prepare_decrypt(encrypted_data, head, &self.key_id, policy)?;
 
 		Ok(self.key.decrypt(data_to_decrypt)?)
 	}
 
-	fn decrypt_raw_with_aad(
+	fn decrypt_raw_with_aad<'p>(
 		&self,
 		encrypted_data: &[u8],
 		aad: &[u8],
 		head: &EncryptedHead,
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<Vec<u8>, SdkUtilError>
 	{
-		let data_to_decrypt = Self::prepare_decrypt(encrypted_data, head, verify_key)?;
+		let data_to_decrypt = Self::prepare_decrypt(encrypted_data, head, &self.key_id, policy)?;
 
 		Ok(self.key.decrypt_with_aad(data_to_decrypt, aad)?)
 	}
@@ -119,25 +118,25 @@ impl SymKeyCrypto for SymmetricKey
 		Ok(Base64::encode_string(&encrypted))
 	}
 
-	fn decrypt_string(&self, encrypted_data_with_head: &str, verify_key: Option<&UserVerifyKeyData>) -> Result<String, SdkUtilError>
+	fn decrypt_string<'p>(&self, encrypted_data_with_head: &str, policy: impl Into<SignaturePolicy<'p>>) -> Result<String, SdkUtilError>
 	{
 		let encrypted = Base64::decode_vec(encrypted_data_with_head).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)?;
 
-		let decrypted = self.decrypt(&encrypted, verify_key)?;
+		let decrypted = self.decrypt(&encrypted, policy)?;
 
 		String::from_utf8(decrypted).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)
 	}
 
-	fn decrypt_string_with_aad(
+	fn decrypt_string_with_aad<'p>(
 		&self,
 		encrypted_data_with_head: &str,
 		aad: &str,
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<String, SdkUtilError>
 	{
 		let encrypted = Base64::decode_vec(encrypted_data_with_head).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)?;
 
-		let decrypted = self.decrypt_with_aad(&encrypted, aad.as_bytes(), verify_key)?;
+		let decrypted = self.decrypt_with_aad(&encrypted, aad.as_bytes(), policy)?;
 
 		String::from_utf8(decrypted).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)
 	}
@@ -10,10 +10,16 @@ use crate::core::{
 	VerifyKey,
 	DILITHIUM_OUTPUT,
 	ECIES_KYBER_HYBRID_OUTPUT,
+	ECIES_ML_KEM_HYBRID_OUTPUT,
 	ECIES_OUTPUT,
 	ED25519_DILITHIUM_HYBRID_OUTPUT,
+	ED25519_ML_DSA_HYBRID_OUTPUT,
 	ED25519_OUTPUT,
+	HPKE_OUTPUT,
 	KYBER_OUTPUT,
+	ML_DSA_OUTPUT,
+	ML_KEM_OUTPUT,
+	SLH_DSA_OUTPUT,
 };
 use crate::util::HybridPublicKeyExportFormat;
 
@@ -36,6 +42,22 @@ pub fn import_public_key_from_pem_with_alg(public_key: &str, alg: &str) -> Resul
 
 			Ok(PublicKey::ecies_kyber_hybrid_from_bytes_owned(bytes_x, bytes_k)?)
 		},
+		HPKE_OUTPUT => {
+			let bytes = import_key_from_pem(public_key)?;
+			Ok(PublicKey::hpke_from_bytes_owned(bytes)?)
+		},
+		ML_KEM_OUTPUT => {
+			let bytes = import_key_from_pem(public_key)?;
+			Ok(PublicKey::ml_kem_from_bytes_owned(bytes)?)
+		},
+		ECIES_ML_KEM_HYBRID_OUTPUT => {
+			let key: HybridPublicKeyExportFormat = serde_json::from_str(public_key).map_err(SdkUtilError::JsonParseFailed)?;
+
+			let bytes_x = import_key_from_pem(&key.x)?;
+			let bytes_k = import_key_from_pem(&key.k)?;
+
+			Ok(PublicKey::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?)
+		},
 		_ => Err(SdkUtilError::AlgNotFound),
 	}
 }
@@ -61,6 +83,22 @@ pub fn import_verify_key_from_pem_with_alg(verify_key: &str, alg: &str) -> Resul
 				bytes_x, bytes_k,
 			)?)
 		},
+		ML_DSA_OUTPUT => {
+			let bytes = import_key_from_pem(verify_key)?;
+			Ok(VerifyKey::ml_dsa_from_bytes_owned(bytes)?)
+		},
+		ED25519_ML_DSA_HYBRID_OUTPUT => {
+			let key: HybridPublicKeyExportFormat = serde_json::from_str(verify_key).map_err(SdkUtilError::JsonParseFailed)?;
+
+			let bytes_x = import_key_from_pem(&key.x)?;
+			let bytes_k = import_key_from_pem(&key.k)?;
+
+			Ok(VerifyKey::ed25519_ml_dsa_hybrid_from_bytes_owned(bytes_x, bytes_k)?)
+		},
+		SLH_DSA_OUTPUT => {
+			let bytes = import_key_from_pem(verify_key)?;
+			Ok(VerifyKey::slh_dsa_from_bytes_owned(bytes)?)
+		},
 		_ => Err(SdkUtilError::AlgNotFound),
 	}
 }
@@ -84,6 +122,22 @@ pub fn import_sig_from_string(sig: &str, alg: &str) -> Result<Signature, SdkUtil
 
 			Ok(Signature::ed25519_dilithium_hybrid_from_bytes_owned(x, k)?)
 		},
+		ML_DSA_OUTPUT => {
+			let bytes = Base64::decode_vec(sig).map_err(|_| SdkUtilError::DecodePublicKeyFailed)?;
+			Ok(Signature::ml_dsa_from_bytes_owned(bytes)?)
+		},
+		ED25519_ML_DSA_HYBRID_OUTPUT => {
+			let key: HybridPublicKeyExportFormat = serde_json::from_str(sig).map_err(SdkUtilError::JsonParseFailed)?;
+
+			let x = Base64::decode_vec(&key.x).map_err(|_| SdkUtilError::DecodePublicKeyFailed)?;
+			let k = Base64::decode_vec(&key.k).map_err(|_| SdkUtilError::DecodePublicKeyFailed)?;
+
+			Ok(Signature::ed25519_ml_dsa_hybrid_from_bytes_owned(x, k)?)
+		},
+		SLH_DSA_OUTPUT => {
+			let bytes = Base64::decode_vec(sig).map_err(|_| SdkUtilError::DecodePublicKeyFailed)?;
+			Ok(Signature::slh_dsa_from_bytes_owned(bytes)?)
+		},
 		_ => Err(SdkUtilError::AlgNotFound),
 	}
 }
@@ -105,6 +159,20 @@ pub fn sig_to_string(sig: &Signature) -> String
 			})
 			.unwrap()
 		},
+		Signature::MlDsa(s) => Base64::encode_string(s.as_ref()),
+		Signature::Ed25519MlDsaHybrid(s) => {
+			let (x, k) = s.get_raw_keys();
+
+			let x = Base64::encode_string(x);
+			let k = Base64::encode_string(k);
+
+			serde_json::to_string(&HybridPublicKeyExportFormat {
+				x,
+				k,
+			})
+			.unwrap()
+		},
+		Signature::SlhDsa(s) => Base64::encode_string(s.as_ref()),
 	}
 }
 
@@ -120,6 +188,20 @@ pub fn export_raw_public_key_to_pem(key: &PublicKey) -> Result<String, SdkUtilEr
 			let x = export_key_to_pem(x)?;
 			let k = export_key_to_pem(k)?;
 
+			serde_json::to_string(&HybridPublicKeyExportFormat {
+				x,
+				k,
+			})
+			.map_err(|_| SdkUtilError::JsonToStringFailed)
+		},
+		PublicKey::Hpke(k) => export_key_to_pem(k.as_ref()),
+		PublicKey::MlKem(k) => export_key_to_pem(k.as_ref()),
+		PublicKey::EciesMlKemHybrid(key) => {
+			let (x, k) = key.get_raw_keys();
+
+			let x = export_key_to_pem(x)?;
+			let k = export_key_to_pem(k)?;
+
 			serde_json::to_string(&HybridPublicKeyExportFormat {
 				x,
 				k,
@@ -146,5 +228,19 @@ pub fn export_raw_verify_key_to_pem(key: &VerifyKey) -> Result<String, SdkUtilEr
 			})
 			.map_err(|_| SdkUtilError::JsonToStringFailed)
 		},
+		VerifyKey::MlDsa(k) => export_key_to_pem(k.as_ref()),
+		VerifyKey::Ed25519MlDsaHybrid(key) => {
+			let (x, k) = key.get_raw_keys();
+
+			let x = export_key_to_pem(x)?;
+			let k = export_key_to_pem(k)?;
+
+			serde_json::to_string(&HybridPublicKeyExportFormat {
+				x,
+				k,
+			})
+			.map_err(|_| SdkUtilError::JsonToStringFailed)
+		},
+		VerifyKey::SlhDsa(k) => export_key_to_pem(k.as_ref()),
 	}
 }
@@ -29,16 +29,42 @@ use sentc_crypto_utils::{
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "full")]
-pub use self::crypto::{HmacKey, SortableKey};
+pub use self::crypto::{HmacFormatExport, HmacKey, SortableKey};
 use crate::core::{
 	PublicKey as CorePublicKey,
 	SecretKey as CoreSecretKey,
 	SignKey as CoreSignKey,
 	SymmetricKey as CoreSymmetricKey,
 	VerifyKey as CoreVerifyKey,
+	DILITHIUM_OUTPUT,
+	ECIES_KYBER_HYBRID_OUTPUT,
+	ECIES_ML_KEM_HYBRID_OUTPUT,
+	ED25519_DILITHIUM_HYBRID_OUTPUT,
+	ED25519_ML_DSA_HYBRID_OUTPUT,
+	KYBER_OUTPUT,
+	ML_DSA_OUTPUT,
+	ML_KEM_OUTPUT,
+	SLH_DSA_OUTPUT,
 };
 use crate::util::export::{export_raw_verify_key_to_pem, sig_to_string};
 
+/**
+The post-quantum (pure and hybrid) asymmetric and sign algorithms of this crate.
+
+Use it as post_quantum list of the crypto policy.
+ */
+pub const POST_QUANTUM_ALGS: &[&str] = &[
+	KYBER_OUTPUT,
+	ECIES_KYBER_HYBRID_OUTPUT,
+	ML_KEM_OUTPUT,
+	ECIES_ML_KEM_HYBRID_OUTPUT,
+	DILITHIUM_OUTPUT,
+	ED25519_DILITHIUM_HYBRID_OUTPUT,
+	ML_DSA_OUTPUT,
+	ED25519_ML_DSA_HYBRID_OUTPUT,
+	SLH_DSA_OUTPUT,
+];
+
 //__________________________________________________________________________________________________
 
 pub struct SymmetricKey
@@ -175,10 +201,25 @@ pub enum SecretKeyFormatExport
 		key: String, key_id: EncryptionKeyPairId
 	},
 
+	MlKem
+	{
+		key: String, key_id: EncryptionKeyPairId
+	},
+
 	EciesKyberHybrid
 	{
 		x: String, k: String, key_id: EncryptionKeyPairId
 	},
+
+	EciesMlKemHybrid
+	{
+		x: String, k: String, key_id: EncryptionKeyPairId
+	},
+
+	Hpke
+	{
+		key: String, key_id: EncryptionKeyPairId
+	},
 }
 
 impl From<SecretKey> for SecretKeyFormatExport
@@ -202,6 +243,22 @@ impl From<SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreSecretKey::MlKem(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlKem {
+					key,
+					key_id: value.key_id,
+				}
+			},
+			CoreSecretKey::Hpke(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::Hpke {
+					key,
+					key_id: value.key_id,
+				}
+			},
 			CoreSecretKey::EciesKyberHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -214,6 +271,18 @@ impl From<SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreSecretKey::EciesMlKemHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::EciesMlKemHybrid {
+					k,
+					x,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -239,6 +308,22 @@ impl<'a> From<&'a SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CoreSecretKey::MlKem(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlKem {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
+			CoreSecretKey::Hpke(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::Hpke {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
 			CoreSecretKey::EciesKyberHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -251,6 +336,18 @@ impl<'a> From<&'a SecretKey> for SecretKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CoreSecretKey::EciesMlKemHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::EciesMlKemHybrid {
+					k,
+					x,
+					key_id: value.key_id.clone(),
+				}
+			},
 		}
 	}
 }
@@ -286,6 +383,30 @@ impl TryInto<SecretKey> for SecretKeyFormatExport
 					key_id,
 				})
 			},
+			Self::MlKem {
+				key_id,
+				key,
+			} => {
+				//to bytes via base64
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(SecretKey {
+					key: CoreSecretKey::ml_kem_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
+			Self::Hpke {
+				key_id,
+				key,
+			} => {
+				//to bytes via base64
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(SecretKey {
+					key: CoreSecretKey::hpke_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
 			Self::EciesKyberHybrid {
 				key_id,
 				x,
@@ -299,6 +420,19 @@ impl TryInto<SecretKey> for SecretKeyFormatExport
 					key: CoreSecretKey::ecies_kyber_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::EciesMlKemHybrid {
+				key_id,
+				x,
+				k,
+			} => {
+				let bytes_x = Base64::decode_vec(&x).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+				let bytes_k = Base64::decode_vec(&k).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(SecretKey {
+					key_id,
+					key: CoreSecretKey::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
@@ -348,10 +482,25 @@ pub enum PublicKeyFormatExport
 		key: String, key_id: EncryptionKeyPairId
 	},
 
+	MlKem
+	{
+		key: String, key_id: EncryptionKeyPairId
+	},
+
 	EciesKyberHybrid
 	{
 		x: String, k: String, key_id: EncryptionKeyPairId
 	},
+
+	EciesMlKemHybrid
+	{
+		x: String, k: String, key_id: EncryptionKeyPairId
+	},
+
+	Hpke
+	{
+		key: String, key_id: EncryptionKeyPairId
+	},
 }
 
 impl From<PublicKey> for PublicKeyFormatExport
@@ -375,6 +524,22 @@ impl From<PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CorePublicKey::MlKem(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlKem {
+					key,
+					key_id: value.key_id,
+				}
+			},
+			CorePublicKey::Hpke(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::Hpke {
+					key,
+					key_id: value.key_id,
+				}
+			},
 			CorePublicKey::EciesKyberHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -387,6 +552,18 @@ impl From<PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CorePublicKey::EciesMlKemHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::EciesMlKemHybrid {
+					k,
+					x,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -412,6 +589,22 @@ impl<'a> From<&'a PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CorePublicKey::MlKem(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlKem {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
+			CorePublicKey::Hpke(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::Hpke {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
 			CorePublicKey::EciesKyberHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -424,6 +617,18 @@ impl<'a> From<&'a PublicKey> for PublicKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CorePublicKey::EciesMlKemHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::EciesMlKemHybrid {
+					k,
+					x,
+					key_id: value.key_id.clone(),
+				}
+			},
 		}
 	}
 }
@@ -459,6 +664,30 @@ impl TryInto<PublicKey> for PublicKeyFormatExport
 					key_id,
 				})
 			},
+			Self::MlKem {
+				key_id,
+				key,
+			} => {
+				//to bytes via base64
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(PublicKey {
+					key: CorePublicKey::ml_kem_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
+			Self::Hpke {
+				key_id,
+				key,
+			} => {
+				//to bytes via base64
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(PublicKey {
+					key: CorePublicKey::hpke_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
 			Self::EciesKyberHybrid {
 				key_id,
 				x,
@@ -472,6 +701,19 @@ impl TryInto<PublicKey> for PublicKeyFormatExport
 					key: CorePublicKey::ecies_kyber_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::EciesMlKemHybrid {
+				key_id,
+				x,
+				k,
+			} => {
+				let bytes_x = Base64::decode_vec(&x).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+				let bytes_k = Base64::decode_vec(&k).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(PublicKey {
+					key_id,
+					key: CorePublicKey::ecies_ml_kem_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
@@ -511,10 +753,25 @@ pub enum SignKeyFormatExport
 		key: String, key_id: SignKeyPairId
 	},
 
+	MlDsa
+	{
+		key: String, key_id: SignKeyPairId
+	},
+
+	SlhDsa
+	{
+		key: String, key_id: SignKeyPairId
+	},
+
 	Ed25519DilithiumHybrid
 	{
 		x: String, k: String, key_id: SignKeyPairId
 	},
+
+	Ed25519MlDsaHybrid
+	{
+		x: String, k: String, key_id: SignKeyPairId
+	},
 }
 
 impl From<SignKey> for SignKeyFormatExport
@@ -538,6 +795,22 @@ impl From<SignKey> for SignKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreSignKey::MlDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlDsa {
+					key,
+					key_id: value.key_id,
+				}
+			},
+			CoreSignKey::SlhDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::SlhDsa {
+					key,
+					key_id: value.key_id,
+				}
+			},
 			CoreSignKey::Ed25519DilithiumHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -550,6 +823,18 @@ impl From<SignKey> for SignKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreSignKey::Ed25519MlDsaHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::Ed25519MlDsaHybrid {
+					x,
+					k,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -575,6 +860,22 @@ impl<'a> From<&'a SignKey> for SignKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CoreSignKey::MlDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlDsa {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
+			CoreSignKey::SlhDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::SlhDsa {
+					key,
+					key_id: value.key_id.clone(),
+				}
+			},
 			CoreSignKey::Ed25519DilithiumHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -587,6 +888,18 @@ impl<'a> From<&'a SignKey> for SignKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CoreSignKey::Ed25519MlDsaHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::Ed25519MlDsaHybrid {
+					x,
+					k,
+					key_id: value.key_id.clone(),
+				}
+			},
 		}
 	}
 }
@@ -620,6 +933,28 @@ impl TryInto<SignKey> for SignKeyFormatExport
 					key: CoreSignKey::dilithium_from_bytes_owned(bytes)?,
 				})
 			},
+			Self::MlDsa {
+				key,
+				key_id,
+			} => {
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingSignKeyFailed)?;
+
+				Ok(SignKey {
+					key_id,
+					key: CoreSignKey::ml_dsa_from_bytes_owned(bytes)?,
+				})
+			},
+			Self::SlhDsa {
+				key,
+				key_id,
+			} => {
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingSignKeyFailed)?;
+
+				Ok(SignKey {
+					key_id,
+					key: CoreSignKey::slh_dsa_from_bytes_owned(bytes)?,
+				})
+			},
 			Self::Ed25519DilithiumHybrid {
 				x,
 				k,
@@ -633,6 +968,19 @@ impl TryInto<SignKey> for SignKeyFormatExport
 					key: CoreSignKey::ed25519_dilithium_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::Ed25519MlDsaHybrid {
+				x,
+				k,
+				key_id,
+			} => {
+				let bytes_x = Base64::decode_vec(&x).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+				let bytes_k = Base64::decode_vec(&k).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(SignKey {
+					key_id,
+					key: CoreSignKey::ed25519_ml_dsa_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
@@ -663,10 +1011,25 @@ pub enum VerifyKeyFormatExport
 		key: String, key_id: SignKeyPairId
 	},
 
+	MlDsa
+	{
+		key: String, key_id: SignKeyPairId
+	},
+
+	SlhDsa
+	{
+		key: String, key_id: SignKeyPairId
+	},
+
 	Ed25519DilithiumHybrid
 	{
 		x: String, k: String, key_id: SignKeyPairId
 	},
+
+	Ed25519MlDsaHybrid
+	{
+		x: String, k: String, key_id: SignKeyPairId
+	},
 }
 
 impl From<VerifyKey> for VerifyKeyFormatExport
@@ -690,6 +1053,22 @@ impl From<VerifyKey> for VerifyKeyFormatExport
 					key,
 				}
 			},
+			CoreVerifyKey::MlDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlDsa {
+					key_id: value.key_id,
+					key,
+				}
+			},
+			CoreVerifyKey::SlhDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::SlhDsa {
+					key_id: value.key_id,
+					key,
+				}
+			},
 			CoreVerifyKey::Ed25519DilithiumHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -702,6 +1081,18 @@ impl From<VerifyKey> for VerifyKeyFormatExport
 					key_id: value.key_id,
 				}
 			},
+			CoreVerifyKey::Ed25519MlDsaHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::Ed25519MlDsaHybrid {
+					x,
+					k,
+					key_id: value.key_id,
+				}
+			},
 		}
 	}
 }
@@ -727,6 +1118,22 @@ impl<'a> From<&'a VerifyKey> for VerifyKeyFormatExport
 					key,
 				}
 			},
+			CoreVerifyKey::MlDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::MlDsa {
+					key_id: value.key_id.clone(),
+					key,
+				}
+			},
+			CoreVerifyKey::SlhDsa(k) => {
+				let key = Base64::encode_string(k.as_ref());
+
+				Self::SlhDsa {
+					key_id: value.key_id.clone(),
+					key,
+				}
+			},
 			CoreVerifyKey::Ed25519DilithiumHybrid(key) => {
 				let (x, k) = key.get_raw_keys();
 
@@ -739,6 +1146,18 @@ impl<'a> From<&'a VerifyKey> for VerifyKeyFormatExport
 					key_id: value.key_id.clone(),
 				}
 			},
+			CoreVerifyKey::Ed25519MlDsaHybrid(key) => {
+				let (x, k) = key.get_raw_keys();
+
+				let x = Base64::encode_string(x);
+				let k = Base64::encode_string(k);
+
+				Self::Ed25519MlDsaHybrid {
+					x,
+					k,
+					key_id: value.key_id.clone(),
+				}
+			},
 		}
 	}
 }
@@ -772,6 +1191,28 @@ impl TryInto<VerifyKey> for VerifyKeyFormatExport
 					key_id,
 				})
 			},
+			Self::MlDsa {
+				key,
+				key_id,
+			} => {
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(VerifyKey {
+					key: CoreVerifyKey::ml_dsa_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
+			Self::SlhDsa {
+				key,
+				key_id,
+			} => {
+				let bytes = Base64::decode_vec(&key).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(VerifyKey {
+					key: CoreVerifyKey::slh_dsa_from_bytes_owned(bytes)?,
+					key_id,
+				})
+			},
 			Self::Ed25519DilithiumHybrid {
 				x,
 				k,
@@ -785,6 +1226,19 @@ impl TryInto<VerifyKey> for VerifyKeyFormatExport
 					key: CoreVerifyKey::ed25519_dilithium_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
 				})
 			},
+			Self::Ed25519MlDsaHybrid {
+				x,
+				k,
+				key_id,
+			} => {
+				let bytes_x = Base64::decode_vec(&x).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+				let bytes_k = Base64::decode_vec(&k).map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?;
+
+				Ok(VerifyKey {
+					key_id,
+					key: CoreVerifyKey::ed25519_ml_dsa_hybrid_from_bytes_owned(bytes_x, bytes_k)?,
+				})
+			},
 		}
 	}
 }
@@ -0,0 +1,341 @@
+#![cfg(feature = "age")]
+
+use sentc_crypto_core::cryptomat::StaticKeyPair;
+use sentc_crypto_core::Error;
+use sentc_crypto_std_keys::core::age::{
+	decrypt,
+	encrypt,
+	AgeDecryptor,
+	AgeEncryptor,
+	AgeIdentity,
+	AgeRecipient,
+	AgeX25519Identity,
+	AgeX25519Recipient,
+};
+use sentc_crypto_std_keys::core::SecretKey;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+//known answer files written with fixed keys, nonces and file keys after the age v1 spec (age-encryption.org/v1).
+const KAT_PLAINTEXT: &[u8] = b"sentc age known answer test\n";
+
+const KAT_IDENTITY: &str = "AGE-SECRET-KEY-1GPQ5YS6YG4RYWJZFFF95CN2WFAG9Z5JN2324V46CT9D9KHZATE0S5FAZ64";
+const KAT_RECIPIENT: &str = "age10xnrrmk7r0uunrcjqvkdatwsu7s8jwv0c7rt3rxggmkgntu955dq25yyzj";
+
+const KAT_X25519_HEADER: &str = "\
+age-encryption.org/v1
+-> X25519 Z13VdO13iTELPS52gfN5C0ZsdzsVIf7PNld5WDcepS8
+s2j4nc6f6JVoGH00RlCTb7exvonI3LIhftSE6+g5IRg
+--- TcucpaKlSuDTndWLuiGfUKl/1572OqPctfJJMuj8lJ8
+";
+
+const KAT_X25519_PAYLOAD: &str = "\
+808182838485868788898a8b8c8d8e8f412d536a91027d62e62445a06b6c2ba070bed8cc594cb67b2cda7be0b7deec94577ca74dd406b56d4f9ac714";
+
+const KAT_PASSPHRASE: &[u8] = b"sentc passphrase";
+
+const KAT_SCRYPT_HEADER: &str = "\
+age-encryption.org/v1
+-> scrypt oKGio6SlpqeoqaqrrK2urw 10
+6XkCp49GIGw8lL7nk0AKX+Pp1FaM5JbQJNXKy0g9Cp0
+--- i9O6Y4gFmJjwmmXEinHPZHHL4prKGTPlnd0cfvSuESU
+";
+
+const KAT_SCRYPT_PAYLOAD: &str = "\
+b0b1b2b3b4b5b6b7b8b9babbbcbdbebf1f0b3cd8d3e0604c1402f7a17832cf75c55ae4111f8974cc1eacbdbee9524e8c28292c322e5407e23b9ce1ca";
+
+fn identity() -> (AgeX25519Identity, AgeX25519Recipient)
+{
+	let (sk, pk) = SecretKey::generate_static_keypair().unwrap();
+
+	(
+		AgeX25519Identity::from_secret_key(&sk).unwrap(),
+		AgeX25519Recipient::from_public_key(&pk).unwrap(),
+	)
+}
+
+fn kat_file(header: &str, payload: &str) -> Vec<u8>
+{
+	let payload: Vec<u8> = (0..payload.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&payload[i..i + 2], 16).unwrap())
+		.collect();
+
+	[header.as_bytes(), &payload].concat()
+}
+
+#[test]
+fn test_x25519_known_answer()
+{
+	let identity = AgeX25519Identity::from_bech32(KAT_IDENTITY).unwrap();
+
+	assert_eq!(identity.to_bech32(), KAT_IDENTITY);
+	assert_eq!(identity.to_recipient().to_bech32(), KAT_RECIPIENT);
+
+	let file = kat_file(KAT_X25519_HEADER, KAT_X25519_PAYLOAD);
+
+	let decrypted = decrypt(&file, &[AgeIdentity::X25519(identity)]).unwrap();
+
+	assert_eq!(decrypted, KAT_PLAINTEXT);
+}
+
+#[test]
+fn test_scrypt_known_answer()
+{
+	let file = kat_file(KAT_SCRYPT_HEADER, KAT_SCRYPT_PAYLOAD);
+
+	let decrypted = decrypt(
+		&file,
+		&[AgeIdentity::Scrypt {
+			passphrase: KAT_PASSPHRASE,
+			max_work_factor: 10,
+		}],
+	)
+	.unwrap();
+
+	assert_eq!(decrypted, KAT_PLAINTEXT);
+}
+
+#[test]
+fn test_x25519_round_trip()
+{
+	let (identity, recipient) = identity();
+
+	let text = b"hello age";
+
+	let file = encrypt(text, &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	assert!(file.starts_with(b"age-encryption.org/v1\n-> X25519 "));
+
+	let decrypted = decrypt(&file, &[AgeIdentity::X25519(identity)]).unwrap();
+
+	assert_eq!(decrypted, text);
+}
+
+#[test]
+fn test_recipient_matches_identity()
+{
+	let (identity, recipient) = identity();
+
+	assert_eq!(identity.to_recipient().to_bech32(), recipient.to_bech32());
+}
+
+#[test]
+fn test_bech32_encoding()
+{
+	let (identity, recipient) = identity();
+
+	let recipient_str = recipient.to_bech32();
+	let identity_str = identity.to_bech32();
+
+	assert!(recipient_str.starts_with("age1"));
+	assert_eq!(recipient_str.len(), 62);
+	assert!(identity_str.starts_with("AGE-SECRET-KEY-1"));
+	assert_eq!(identity_str.len(), 74);
+
+	let recipient = AgeX25519Recipient::from_bech32(&recipient_str).unwrap();
+	let identity = AgeX25519Identity::from_bech32(&identity_str).unwrap();
+
+	assert_eq!(recipient.to_bech32(), recipient_str);
+	assert_eq!(identity.to_bech32(), identity_str);
+
+	//wrong checksum
+	let mut wrong = recipient_str.clone();
+	let last = if wrong.ends_with('q') { 'p' } else { 'q' };
+	wrong.pop();
+	wrong.push(last);
+
+	assert!(matches!(
+		AgeX25519Recipient::from_bech32(&wrong),
+		Err(Error::AgeInvalidFormat)
+	));
+
+	//identity string is not a recipient
+	assert!(matches!(
+		AgeX25519Recipient::from_bech32(&identity_str.to_lowercase()),
+		Err(Error::AgeInvalidFormat)
+	));
+}
+
+#[test]
+fn test_multiple_recipients()
+{
+	let (identity_1, recipient_1) = identity();
+	let (identity_2, recipient_2) = identity();
+
+	let file = encrypt(
+		b"hello",
+		&[AgeRecipient::X25519(recipient_1), AgeRecipient::X25519(recipient_2)],
+	)
+	.unwrap();
+
+	assert_eq!(decrypt(&file, &[AgeIdentity::X25519(identity_1)]).unwrap(), b"hello");
+	assert_eq!(decrypt(&file, &[AgeIdentity::X25519(identity_2)]).unwrap(), b"hello");
+}
+
+#[test]
+fn test_no_identity_matched()
+{
+	let (_identity, recipient) = identity();
+	let (other, _) = identity();
+
+	let file = encrypt(b"hello", &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	assert!(matches!(
+		decrypt(&file, &[AgeIdentity::X25519(other)]),
+		Err(Error::AgeNoIdentityMatched)
+	));
+}
+
+#[test]
+fn test_scrypt_round_trip()
+{
+	let file = encrypt(
+		b"hello",
+		&[AgeRecipient::Scrypt {
+			passphrase: b"passphrase",
+			work_factor: 10,
+		}],
+	)
+	.unwrap();
+
+	let decrypted = decrypt(
+		&file,
+		&[AgeIdentity::Scrypt {
+			passphrase: b"passphrase",
+			max_work_factor: 10,
+		}],
+	)
+	.unwrap();
+
+	assert_eq!(decrypted, b"hello");
+
+	assert!(matches!(
+		decrypt(
+			&file,
+			&[AgeIdentity::Scrypt {
+				passphrase: b"wrong",
+				max_work_factor: 10,
+			}],
+		),
+		Err(Error::AgeNoIdentityMatched)
+	));
+
+	//work factor over the limit
+	assert!(matches!(
+		decrypt(
+			&file,
+			&[AgeIdentity::Scrypt {
+				passphrase: b"passphrase",
+				max_work_factor: 9,
+			}],
+		),
+		Err(Error::AgeInvalidFormat)
+	));
+}
+
+#[test]
+fn test_scrypt_must_be_the_only_recipient()
+{
+	let (_, recipient) = identity();
+
+	assert!(matches!(
+		encrypt(
+			b"hello",
+			&[
+				AgeRecipient::X25519(recipient),
+				AgeRecipient::Scrypt {
+					passphrase: b"passphrase",
+					work_factor: 10,
+				},
+			],
+		),
+		Err(Error::AgeInvalidFormat)
+	));
+}
+
+#[test]
+fn test_empty_file()
+{
+	let (identity, recipient) = identity();
+
+	let file = encrypt(b"", &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	assert_eq!(decrypt(&file, &[AgeIdentity::X25519(identity)]).unwrap(), b"");
+}
+
+#[test]
+fn test_stream_multiple_chunks()
+{
+	let (identity, recipient) = identity();
+
+	//exactly two full chunks and a few bytes more
+	let data: Vec<u8> = (0..CHUNK_SIZE * 2 + 10).map(|i| i as u8).collect();
+
+	let (mut encryptor, mut file) = AgeEncryptor::new(&[AgeRecipient::X25519(recipient)]).unwrap();
+
+	for part in data.chunks(1000) {
+		file.extend_from_slice(&encryptor.update(part).unwrap());
+	}
+
+	file.extend_from_slice(&encryptor.finish().unwrap());
+
+	let (mut decryptor, header_len) = AgeDecryptor::new(&file, &[AgeIdentity::X25519(identity)]).unwrap();
+
+	let mut decrypted = Vec::new();
+
+	for part in file[header_len..].chunks(777) {
+		decrypted.extend_from_slice(&decryptor.update(part).unwrap());
+	}
+
+	decrypted.extend_from_slice(&decryptor.finish().unwrap());
+
+	assert_eq!(decrypted, data);
+}
+
+#[test]
+fn test_stream_full_last_chunk()
+{
+	let (identity, recipient) = identity();
+
+	let data = vec![1u8; CHUNK_SIZE * 2];
+
+	let file = encrypt(&data, &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	assert_eq!(decrypt(&file, &[AgeIdentity::X25519(identity)]).unwrap(), data);
+}
+
+#[test]
+fn test_truncated_stream()
+{
+	let (identity, recipient) = identity();
+
+	let data = vec![1u8; CHUNK_SIZE + 10];
+
+	let file = encrypt(&data, &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	//remove the last chunk, the first chunk is not marked as final
+	let truncated = &file[..file.len() - 26];
+
+	assert!(decrypt(truncated, &[AgeIdentity::X25519(identity)]).is_err());
+}
+
+#[test]
+fn test_modified_header()
+{
+	let (identity, recipient) = identity();
+
+	let file = encrypt(b"hello", &[AgeRecipient::X25519(recipient)]).unwrap();
+
+	//add an unknown stanza before the mac, the file key is still found but the mac must fail
+	let pos = file.windows(4).position(|w| w == b"--- ").unwrap();
+
+	let mut modified = file[..pos].to_vec();
+	modified.extend_from_slice(b"-> grease\n\n");
+	modified.extend_from_slice(&file[pos..]);
+
+	assert!(matches!(
+		decrypt(&modified, &[AgeIdentity::X25519(identity)]),
+		Err(Error::AgeHeaderMacInvalid)
+	));
+}
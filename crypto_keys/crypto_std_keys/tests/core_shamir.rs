@@ -0,0 +1,41 @@
+use sentc_crypto_core::shamir::{combine_shares, split_secret};
+use sentc_crypto_core::Error;
+
+#[test]
+fn test_split_and_combine()
+{
+	let secret = b"this is a secret";
+
+	let shares = split_secret(secret, 3, 5).unwrap();
+
+	assert_eq!(shares.len(), 5);
+
+	assert_eq!(combine_shares(&shares[..3]).unwrap(), secret);
+	assert_eq!(combine_shares(&shares[2..]).unwrap(), secret);
+	assert_eq!(combine_shares(&[&shares[4], &shares[0], &shares[2]]).unwrap(), secret);
+}
+
+#[test]
+fn test_not_enough_shares()
+{
+	let shares = split_secret(b"secret", 3, 5).unwrap();
+
+	assert!(matches!(
+		combine_shares(&shares[..2]),
+		Err(Error::ShamirNotEnoughShares)
+	));
+}
+
+#[test]
+fn test_invalid_shares()
+{
+	let shares = split_secret(b"secret", 2, 3).unwrap();
+
+	assert!(matches!(
+		combine_shares(&[&shares[0], &shares[0]]),
+		Err(Error::ShamirInvalidShare)
+	));
+
+	assert!(split_secret(b"secret", 4, 3).is_err());
+	assert!(split_secret(b"secret", 0, 3).is_err());
+}
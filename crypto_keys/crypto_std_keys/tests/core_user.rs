@@ -5,7 +5,21 @@ extern crate alloc;
 use core::str::from_utf8;
 
 use sentc_crypto_core::cryptomat::{ClientRandomValue, DeriveMasterKeyForAuth, Pk, SignK, Sk, VerifyK};
-use sentc_crypto_core::user::{change_password, done_login, password_reset, prepare_login, register, safety_number, LoginDoneOutput};
+use sentc_crypto_core::user::{
+	change_password,
+	done_login,
+	numeric_safety_number,
+	password_reset,
+	prepare_login,
+	recover_with_recovery_key,
+	recovery_key_to_bytes,
+	register,
+	register_with_recovery_key,
+	safety_number,
+	safety_number_qr_payload,
+	verify_safety_number_qr_payload,
+	LoginDoneOutput,
+};
 use sentc_crypto_std_keys::core::{PwHasherGetter, SecretKey, SignKey, SymmetricKey, VerifyKey};
 
 #[test]
@@ -165,10 +179,78 @@ fn test_password_reset()
 			assert_eq!(x, x1);
 			assert_eq!(k, k1);
 		},
+		(SecretKey::Hpke(sk), SecretKey::Hpke(sk1)) => {
+			assert_eq!(sk.as_ref(), sk1.as_ref())
+		},
+		(SecretKey::MlKem(sk), SecretKey::MlKem(sk1)) => {
+			assert_eq!(sk.as_ref(), sk1.as_ref())
+		},
+		(SecretKey::EciesMlKemHybrid(sk), SecretKey::EciesMlKemHybrid(sk1)) => {
+			let (x, k) = sk.get_raw_keys();
+			let (x1, k1) = sk1.get_raw_keys();
+
+			assert_eq!(x, x1);
+			assert_eq!(k, k1);
+		},
 		_ => panic!("Keys not the same format"),
 	}
 }
 
+#[test]
+fn test_recover_with_recovery_key()
+{
+	let password = "abc*èéöäüê";
+	let new_password = "abcdfg";
+
+	let (out, recovery) = register_with_recovery_key::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>(password).unwrap();
+
+	//the user types the key in lower case and without dashes
+	let typed_key = recovery.recovery_key.replace('-', "").to_lowercase();
+
+	let recovery_salt = recovery.client_random_value.generate_salt("");
+
+	let recover_out = recover_with_recovery_key::<PwHasherGetter>(
+		&typed_key,
+		new_password,
+		&recovery_salt,
+		&recovery.encrypted_master_key,
+		recovery.derived_alg,
+	)
+	.unwrap();
+
+	//login with the new password must decrypt the old key pairs
+	let new_salt = recover_out.client_random_value.generate_salt("");
+	let prep_login_out = prepare_login::<PwHasherGetter>(new_password, &new_salt, recover_out.derived_alg).unwrap();
+
+	let login_out = done_login::<SecretKey, SignKey>(
+		&prep_login_out.master_key_encryption_key,
+		&recover_out.encrypted_master_key,
+		&out.encrypted_private_key,
+		out.keypair_encrypt_alg,
+		&out.encrypted_sign_key,
+		out.keypair_sign_alg,
+	)
+	.unwrap();
+
+	let text = "Hello world üöäéèßê°";
+	let encrypted = out.public_key.encrypt(text.as_bytes()).unwrap();
+	let decrypted = login_out.private_key.decrypt(&encrypted).unwrap();
+
+	assert_eq!(from_utf8(&decrypted).unwrap(), text);
+}
+
+#[test]
+fn test_recovery_key_format()
+{
+	let (_, recovery) = register_with_recovery_key::<SymmetricKey, SecretKey, SignKey, PwHasherGetter>("abc").unwrap();
+
+	assert_eq!(recovery.recovery_key.len(), 39);
+	assert_eq!(recovery_key_to_bytes(&recovery.recovery_key).unwrap().len(), 20);
+
+	assert!(recovery_key_to_bytes("abc").is_err());
+	assert!(recovery_key_to_bytes(&recovery.recovery_key.replace('-', "U")).is_err());
+}
+
 fn create_dummy_user_for_safety_number() -> (VerifyKey, LoginDoneOutput<SecretKey, SignKey>)
 {
 	let password = "abc*èéöäüê";
@@ -210,3 +292,52 @@ fn test_safety_number()
 
 	assert_ne!(number_1, number_2);
 }
+
+#[test]
+fn test_numeric_safety_number()
+{
+	let (user_1_key, _user_1) = create_dummy_user_for_safety_number();
+	let (user_2_key, _user_2) = create_dummy_user_for_safety_number();
+
+	let number_1 = numeric_safety_number(&user_1_key, "abc1", &user_2_key, "abc2");
+	let number_2 = numeric_safety_number(&user_2_key, "abc2", &user_1_key, "abc1");
+
+	assert_eq!(number_1.len(), 60);
+	assert!(number_1.chars().all(|c| c.is_ascii_digit()));
+
+	//the order of the identities must not matter
+	assert_eq!(number_1, number_2);
+
+	let number_3 = numeric_safety_number(&user_1_key, "abc1", &user_2_key, "abc3");
+
+	assert_ne!(number_1, number_3);
+}
+
+#[test]
+fn test_safety_number_qr_payload()
+{
+	let (user_1_key, _user_1) = create_dummy_user_for_safety_number();
+	let (user_2_key, _user_2) = create_dummy_user_for_safety_number();
+	let (user_3_key, _user_3) = create_dummy_user_for_safety_number();
+
+	//user 1 shows the code
+	let payload = safety_number_qr_payload(&user_1_key, "abc1", &user_2_key, "abc2");
+
+	assert_eq!(payload.len(), 61);
+
+	//user 2 scans it
+	assert!(verify_safety_number_qr_payload(&payload, &user_2_key, "abc2", &user_1_key, "abc1").unwrap());
+
+	//user 2 has another key for user 1
+	assert!(!verify_safety_number_qr_payload(&payload, &user_2_key, "abc2", &user_3_key, "abc1").unwrap());
+
+	//user 1 scans its own code
+	assert!(!verify_safety_number_qr_payload(&payload, &user_1_key, "abc1", &user_2_key, "abc2").unwrap());
+
+	assert!(verify_safety_number_qr_payload(&payload[1..], &user_2_key, "abc2", &user_1_key, "abc1").is_err());
+
+	let mut wrong_version = payload.clone();
+	wrong_version[0] = 1;
+
+	assert!(verify_safety_number_qr_payload(&wrong_version, &user_2_key, "abc2", &user_1_key, "abc1").is_err());
+}
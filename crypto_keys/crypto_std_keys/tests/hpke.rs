@@ -0,0 +1,76 @@
+#![cfg(feature = "full")]
+
+use sentc_crypto_common::user::UserPublicKeyData;
+use sentc_crypto_core::cryptomat::{CryptoAlg, StaticKeyPair};
+use sentc_crypto_std_keys::core::{EciesKeyPair, HpkeKeyPair, PublicKey as CorePk, SecretKey as CoreSk};
+use sentc_crypto_std_keys::util::export::export_raw_public_key_to_pem;
+use sentc_crypto_std_keys::util::{PublicKey, SecretKey};
+use sentc_crypto_utils::cryptomat::{PkFromUserKeyWrapper, SkCryptoWrapper};
+use sentc_crypto_utils::error::SdkUtilError;
+
+fn key_pair(sk: CoreSk, pk: CorePk) -> (SecretKey, UserPublicKeyData)
+{
+	let public_key = UserPublicKeyData {
+		public_key_pem: export_raw_public_key_to_pem(&pk).unwrap(),
+		public_key_alg: pk.get_alg_str().to_string(),
+		public_key_id: "public_key".to_string(),
+		public_key_sig: None,
+		public_key_sig_key_id: None,
+	};
+
+	let secret_key = SecretKey {
+		key: sk,
+		key_id: "public_key".to_string(),
+	};
+
+	(secret_key, public_key)
+}
+
+#[test]
+fn test_encrypt_and_decrypt_with_context()
+{
+	let (sk, pk) = HpkeKeyPair::generate_static_keypair().unwrap();
+	let (secret_key, public_key) = key_pair(sk.into(), pk.into());
+
+	let text = "123*+^êéèüöß@€&$";
+
+	let encrypted = PublicKey::encrypt_with_user_key_with_context(&public_key, text.as_bytes(), b"info", b"aad").unwrap();
+
+	let decrypted = secret_key
+		.decrypt_with_context(&encrypted, b"info", b"aad", None)
+		.unwrap();
+	assert_eq!(decrypted, text.as_bytes());
+
+	//the context is part of the encryption
+	assert!(secret_key
+		.decrypt_with_context(&encrypted, b"other info", b"aad", None)
+		.is_err());
+	assert!(secret_key
+		.decrypt_with_context(&encrypted, b"info", b"other aad", None)
+		.is_err());
+
+	//the normal decrypt uses an empty context
+	assert!(secret_key.decrypt(&encrypted, None).is_err());
+
+	let encrypted = PublicKey::encrypt_with_user_key_with_context(&public_key, text.as_bytes(), &[], &[]).unwrap();
+	assert_eq!(secret_key.decrypt(&encrypted, None).unwrap(), text.as_bytes());
+}
+
+#[test]
+fn test_context_only_for_hpke()
+{
+	let (sk, pk) = EciesKeyPair::generate_static_keypair().unwrap();
+	let (secret_key, public_key) = key_pair(sk.into(), pk.into());
+
+	assert!(matches!(
+		PublicKey::encrypt_with_user_key_with_context(&public_key, b"hello", b"info", b"aad"),
+		Err(SdkUtilError::AlgNotFound)
+	));
+
+	let encrypted = PublicKey::encrypt_with_user_key(&public_key, b"hello").unwrap();
+
+	assert!(matches!(
+		secret_key.decrypt_with_context(&encrypted, b"info", b"aad", None),
+		Err(SdkUtilError::AlgNotFound)
+	));
+}
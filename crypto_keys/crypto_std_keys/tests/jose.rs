@@ -0,0 +1,270 @@
+#![cfg(feature = "full")]
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_core::cryptomat::{CryptoAlg, SignKeyPair, StaticKeyPair, SymKeyGen};
+use sentc_crypto_std_keys::core::{
+	EciesKeyPair,
+	EciesKyberHybridKeyPair,
+	Ed25519DilithiumHybridKeyPair,
+	Ed25519KeyPair,
+	PublicKey as CorePk,
+	SecretKey as CoreSk,
+	SignKey as CoreSignKey,
+	SymmetricKey as CoreSymmetricKey,
+	VerifyKey as CoreVk,
+};
+use sentc_crypto_std_keys::util::export::{export_raw_public_key_to_pem, export_raw_verify_key_to_pem};
+use sentc_crypto_std_keys::util::{PublicKey, SecretKey, SignKey, SymmetricKey, VerifyKey};
+use sentc_crypto_utils::cryptomat::SymKeyCrypto;
+use sentc_crypto_utils::error::SdkUtilError;
+use sentc_crypto_utils::jose::{JosePk, JoseSignKey, JoseSk, JoseSymKey, JoseVerifyKey};
+
+fn sym_key() -> SymmetricKey
+{
+	SymmetricKey {
+		key: CoreSymmetricKey::generate().unwrap(),
+		key_id: "sym_key".to_string(),
+	}
+}
+
+fn key_pair(sk: CoreSk, pk: CorePk) -> (SecretKey, UserPublicKeyData)
+{
+	let public_key = UserPublicKeyData {
+		public_key_pem: export_raw_public_key_to_pem(&pk).unwrap(),
+		public_key_alg: pk.get_alg_str().to_string(),
+		public_key_id: "public_key".to_string(),
+		public_key_sig: None,
+		public_key_sig_key_id: None,
+	};
+
+	let secret_key = SecretKey {
+		key: sk,
+		key_id: "public_key".to_string(),
+	};
+
+	(secret_key, public_key)
+}
+
+fn sign_key(sk: CoreSignKey, vk: CoreVk) -> (SignKey, UserVerifyKeyData)
+{
+	let verify_key = UserVerifyKeyData {
+		verify_key_pem: export_raw_verify_key_to_pem(&vk).unwrap(),
+		verify_key_alg: vk.get_alg_str().to_string(),
+		verify_key_id: "sign_key".to_string(),
+	};
+
+	let sign_key = SignKey {
+		key: sk,
+		key_id: "sign_key".to_string(),
+	};
+
+	(sign_key, verify_key)
+}
+
+fn decode_header(jwe: &str) -> serde_json::Value
+{
+	let header = jwe.split('.').next().unwrap();
+
+	serde_json::from_slice(&Base64UrlUnpadded::decode_vec(header).unwrap()).unwrap()
+}
+
+#[test]
+fn test_sym_key_compact()
+{
+	let key = sym_key();
+
+	let jwe = key.encrypt_jwe(b"hello jose").unwrap();
+
+	assert_eq!(jwe.split('.').count(), 5);
+
+	let header = decode_header(&jwe);
+	assert_eq!(header["alg"], "dir");
+	assert_eq!(header["enc"], "A256GCM");
+	assert_eq!(header["kid"], "sym_key");
+
+	assert_eq!(key.decrypt_jwe(&jwe).unwrap(), b"hello jose");
+
+	//the protected header is the aad
+	let mut parts: Vec<&str> = jwe.split('.').collect();
+	let other_header = Base64UrlUnpadded::encode_string(br#"{"alg":"dir","enc":"A256GCM","kid":"other"}"#);
+	parts[0] = &other_header;
+
+	assert!(key.decrypt_jwe(&parts.join(".")).is_err());
+}
+
+#[test]
+fn test_sym_key_export_and_import()
+{
+	let key = sym_key();
+
+	let (head, encrypted) = key.encrypt_raw(b"hello jose").unwrap();
+
+	let jwe = key.export_jwe(&head, &encrypted).unwrap();
+
+	let value: serde_json::Value = serde_json::from_str(&jwe).unwrap();
+	assert_eq!(value["unprotected"]["alg"], "dir");
+	assert_eq!(value["unprotected"]["kid"], "sym_key");
+
+	assert_eq!(key.decrypt_jwe(&jwe).unwrap(), b"hello jose");
+
+	let (imported_head, imported) = key.import_jwe(&jwe).unwrap();
+
+	assert_eq!(imported_head.id, head.id);
+	assert_eq!(imported, encrypted);
+	assert_eq!(
+		key.decrypt_raw(&imported, &imported_head, None).unwrap(),
+		b"hello jose"
+	);
+}
+
+#[test]
+fn test_sym_key_signed_data_not_exported()
+{
+	let key = sym_key();
+	let (sk, vk) = Ed25519KeyPair::generate_key_pair().unwrap();
+	let (sign_key, _) = sign_key(sk.into(), vk.into());
+
+	let (head, encrypted) = key.encrypt_raw_with_sign(b"hello", &sign_key).unwrap();
+
+	assert!(matches!(
+		key.export_jwe(&head, &encrypted),
+		Err(SdkUtilError::JoseSignedDataNotSupported)
+	));
+}
+
+#[test]
+fn test_ecdh_es()
+{
+	let (sk, pk) = EciesKeyPair::generate_static_keypair().unwrap();
+	let (secret_key, public_key) = key_pair(sk.into(), pk.into());
+
+	let jwe = PublicKey::encrypt_jwe_with_user_key(&public_key, b"hello jose").unwrap();
+
+	let header = decode_header(&jwe);
+	assert_eq!(header["alg"], "ECDH-ES");
+	assert_eq!(header["enc"], "A256GCM");
+	assert_eq!(header["kid"], "public_key");
+	assert_eq!(header["epk"]["kty"], "OKP");
+	assert_eq!(header["epk"]["crv"], "X25519");
+
+	//direct key agreement has no encrypted key
+	assert_eq!(jwe.split('.').nth(1).unwrap(), "");
+
+	assert_eq!(secret_key.decrypt_jwe(&jwe).unwrap(), b"hello jose");
+
+	//other key
+	let (sk, pk) = EciesKeyPair::generate_static_keypair().unwrap();
+	let (other_secret_key, _) = key_pair(sk.into(), pk.into());
+
+	assert!(other_secret_key.decrypt_jwe(&jwe).is_err());
+}
+
+#[test]
+fn test_hybrid_keys_not_supported()
+{
+	let (sk, pk) = EciesKyberHybridKeyPair::generate_static_keypair().unwrap();
+	let (secret_key, public_key) = key_pair(sk.into(), pk.into());
+
+	assert!(matches!(
+		PublicKey::encrypt_jwe_with_user_key(&public_key, b"hello"),
+		Err(SdkUtilError::JoseAlgNotSupported(_))
+	));
+
+	let (sk, pk) = EciesKeyPair::generate_static_keypair().unwrap();
+	let (_, ecies_public_key) = key_pair(sk.into(), pk.into());
+	let jwe = PublicKey::encrypt_jwe_with_user_key(&ecies_public_key, b"hello").unwrap();
+
+	assert!(matches!(
+		secret_key.decrypt_jwe(&jwe),
+		Err(SdkUtilError::JoseAlgNotSupported(_))
+	));
+
+	let (sk, vk) = Ed25519DilithiumHybridKeyPair::generate_key_pair().unwrap();
+	let (sign_key, verify_key) = sign_key(sk.into(), vk.into());
+
+	assert!(matches!(
+		sign_key.sign_jws_detached(b"hello"),
+		Err(SdkUtilError::JoseAlgNotSupported(_))
+	));
+
+	let header = Base64UrlUnpadded::encode_string(br#"{"alg":"EdDSA","kid":"sign_key"}"#);
+
+	assert!(matches!(
+		VerifyKey::verify_jws_detached(&format!("{}..AA", header), b"hello", &verify_key),
+		Err(SdkUtilError::JoseAlgNotSupported(_))
+	));
+}
+
+#[test]
+fn test_detached_jws()
+{
+	let (sk, vk) = Ed25519KeyPair::generate_key_pair().unwrap();
+	let (sign_key, verify_key) = sign_key(sk.into(), vk.into());
+
+	let jws = sign_key.sign_jws_detached(b"hello jose").unwrap();
+
+	let parts: Vec<&str> = jws.split('.').collect();
+	assert_eq!(parts.len(), 3);
+	assert_eq!(parts[1], "");
+
+	let header = decode_header(&jws);
+	assert_eq!(header["alg"], "EdDSA");
+	assert_eq!(header["kid"], "sign_key");
+
+	//ed25519 signature
+	assert_eq!(Base64UrlUnpadded::decode_vec(parts[2]).unwrap().len(), 64);
+
+	VerifyKey::verify_jws_detached(&jws, b"hello jose", &verify_key).unwrap();
+
+	assert!(matches!(
+		VerifyKey::verify_jws_detached(&jws, b"other data", &verify_key),
+		Err(SdkUtilError::VerifyFailed)
+	));
+}
+
+//rfc 8037 a.4 and a.5, the payload is detached
+const RFC_8037_ED25519_X: &str = "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo";
+const RFC_8037_JWS: &str = "eyJhbGciOiJFZERTQSJ9..hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+const RFC_8037_PAYLOAD: &[u8] = b"Example of Ed25519 signing";
+
+//static x25519 key of bob and the ephemeral key of alice from rfc 8037 a.6 (rfc 7748 6.1).
+//the cek is the concat kdf of the shared secret 4a5d9d5b...1e161742 and the jwe is encrypted with a fixed iv.
+const RFC_8037_X25519_D: &str = "XasIfmJKikt54X-Lg4AO5m87sSkmGLb9HC-LJ_-I4Os";
+const ECDH_ES_JWE: &str = "eyJhbGciOiJFQ0RILUVTIiwiZW5jIjoiQTI1NkdDTSIsImtpZCI6InB1YmxpY19rZXkiLCJlcGsiOnsia3R5IjoiT0tQIiwiY3J2IjoiWDI1NTE5IiwieCI6ImhTRHdDWWt3cDFSMGkzM2N0RDczV2cyX09nMG1PQnIwNjZTcGpxcWJUbW8ifX0..G0vT6xs8Tn_So-iw.6LQDCjlIafQ6pOG-ao_eJfEvaewsc8vRo8shLVm2TJVxDv0iW5uyXiMoHY2Z_xvW3ansbiCCuN8UWDgS5oI147ri6OqxZwpHz-tuCQKS.1t9LItFreyxvmdJsd-3Q3A";
+const ECDH_ES_PLAINTEXT: &str = "You can trust us to stick with you through thick and thin–to the bitter end.";
+
+#[test]
+fn test_rfc_8037_jws()
+{
+	let vk = CoreVk::ed25519_from_bytes_owned(Base64UrlUnpadded::decode_vec(RFC_8037_ED25519_X).unwrap()).unwrap();
+
+	let verify_key = UserVerifyKeyData {
+		verify_key_pem: export_raw_verify_key_to_pem(&vk).unwrap(),
+		verify_key_alg: vk.get_alg_str().to_string(),
+		verify_key_id: "rfc_8037".to_string(),
+	};
+
+	VerifyKey::verify_jws_detached(RFC_8037_JWS, RFC_8037_PAYLOAD, &verify_key).unwrap();
+
+	assert!(matches!(
+		VerifyKey::verify_jws_detached(RFC_8037_JWS, b"Example of Ed25519 signing.", &verify_key),
+		Err(SdkUtilError::VerifyFailed)
+	));
+}
+
+#[test]
+fn test_ecdh_es_known_answer()
+{
+	let secret_key = SecretKey {
+		key: CoreSk::ecies_from_bytes_owned(Base64UrlUnpadded::decode_vec(RFC_8037_X25519_D).unwrap()).unwrap(),
+		key_id: "public_key".to_string(),
+	};
+
+	let header = decode_header(ECDH_ES_JWE);
+	assert_eq!(header["epk"]["x"], "hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo");
+
+	let decrypted = secret_key.decrypt_jwe(ECDH_ES_JWE).unwrap();
+
+	assert_eq!(decrypted, ECDH_ES_PLAINTEXT.as_bytes());
+}
@@ -3,15 +3,18 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::str::FromStr;
 
+use base64ct::{Base64, Base64UrlUnpadded, Encoding};
 use sentc_crypto_common::content_searchable::SearchableCreateOutput;
 use sentc_crypto_common::content_sortable::SortableEncryptOutput;
 use sentc_crypto_common::crypto::{EncryptedHead, SignHead};
 use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
-use sentc_crypto_core::cryptomat::{CryptoAlg, Pk, SearchableKey, SearchableKeyComposer, SortableKey, SortableKeyComposer, VerifyK};
+use sentc_crypto_core::cryptomat::{CryptoAlg, Pk, SearchableKey, SearchableKeyComposer, SignK, SortableKey, SortableKeyComposer, VerifyK};
 
-use crate::cryptomat::{KeyToString, SignKWrapper};
+use crate::cryptomat::{KeyToString, SignKWrapper, SkWrapper, SymKeyWrapper};
 use crate::error::SdkUtilError;
-use crate::{put_head_and_encrypted_data, split_head_and_encrypted_data};
+use crate::head::HeadFormat;
+use crate::policy::{AlgUsage, CryptoPolicy};
+use crate::{put_head_and_encrypted_data, put_head_and_encrypted_data_with_format, split_head_and_encrypted_data};
 
 //searchable
 
@@ -88,6 +91,120 @@ pub trait SearchableKeyWrapper: FromStr + KeyToString
 	}
 
 	fn search_bytes(&self, data: &[u8]) -> Result<String, SdkUtilError>;
+
+	/**
+	Create a blind index of the data which is truncated to the given bits.
+
+	Different values can end up in the same bucket. This hides the frequency of low entropy values (like country or status)
+	but the client must filter out the false positives after decrypting the data.
+	 */
+	fn create_blind_index(&self, data: &str, bits: u16) -> Result<SearchableCreateOutput, SdkUtilError>
+	{
+		let index = self.search_blind_index(data, bits)?;
+
+		Ok(SearchableCreateOutput {
+			hashes: vec![index],
+			alg: self.get_key().get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
+	/**
+	Create a blind index over multiple fields, e.g. country and birth year.
+
+	Each field is prefixed by its length so that different splits of the same bytes won't result in the same index.
+	The order of the fields must be the same for creating and searching.
+	 */
+	fn create_blind_index_compound(&self, fields: &[&str], bits: u16) -> Result<SearchableCreateOutput, SdkUtilError>
+	{
+		let index = self.search_blind_index_compound(fields, bits)?;
+
+		Ok(SearchableCreateOutput {
+			hashes: vec![index],
+			alg: self.get_key().get_alg_str().to_string(),
+			key_id: self.get_id().to_string(),
+		})
+	}
+
+	/**
+	Get the bucket of the data to search for it.
+
+	Every item in this bucket is a candidate and must be checked after decryption.
+	 */
+	fn search_blind_index(&self, data: &str, bits: u16) -> Result<String, SdkUtilError>
+	{
+		if data.is_empty() {
+			return Err(SdkUtilError::SearchableEncryptionDataNotFound);
+		}
+
+		self.blind_index_bytes(data.as_bytes(), bits)
+	}
+
+	fn search_blind_index_compound(&self, fields: &[&str], bits: u16) -> Result<String, SdkUtilError>
+	{
+		if fields.is_empty() {
+			return Err(SdkUtilError::SearchableEncryptionDataNotFound);
+		}
+
+		let mut data = Vec::new();
+
+		for field in fields {
+			data.extend_from_slice(&(field.len() as u32).to_be_bytes());
+			data.extend_from_slice(field.as_bytes());
+		}
+
+		self.blind_index_bytes(&data, bits)
+	}
+
+	/**
+	Get the buckets of multiple values, e.g. to search for one of many status values.
+
+	Values in the same bucket will only return the bucket once.
+	 */
+	fn search_blind_index_candidates(&self, values: &[&str], bits: u16) -> Result<Vec<String>, SdkUtilError>
+	{
+		let mut buckets: Vec<String> = Vec::with_capacity(values.len());
+
+		for value in values {
+			let bucket = self.search_blind_index(value, bits)?;
+
+			if !buckets.contains(&bucket) {
+				buckets.push(bucket);
+			}
+		}
+
+		Ok(buckets)
+	}
+
+	fn blind_index_bytes(&self, data: &[u8], bits: u16) -> Result<String, SdkUtilError>
+	{
+		let hash = self.get_key().encrypt_searchable(data)?;
+
+		let index = truncate_hash(hash, bits)?;
+
+		Ok(Base64UrlUnpadded::encode_string(&index))
+	}
+}
+
+fn truncate_hash(mut hash: Vec<u8>, bits: u16) -> Result<Vec<u8>, SdkUtilError>
+{
+	let bits = bits as usize;
+
+	if bits == 0 || bits > hash.len() * 8 {
+		return Err(SdkUtilError::SearchableEncryptionInvalidTruncation);
+	}
+
+	//keep only the full bytes and mask the rest of the last byte
+	let len = bits.div_ceil(8);
+	hash.truncate(len);
+
+	let rest = bits % 8;
+
+	if rest != 0 {
+		hash[len - 1] &= 0xffu8 << (8 - rest);
+	}
+
+	Ok(hash)
 }
 
 pub trait SearchableKeyComposerWrapper
@@ -142,24 +259,179 @@ pub trait SortableKeyComposerWrapper
 
 //__________________________________________________________________________________________________
 
+/**
+Suffix of the sign alg in the sign head for signatures that are bound to the encrypted head.
+
+Older versions can't split the signature of this alg and will refuse the data instead of ignoring the binding.
+Because of this the bound signature is opt-in (the _with_bound_sign fns), the _with_sign fns still write the old format.
+Both formats are verified.
+ */
+pub const SIGN_HEAD_BOUND_SUFFIX: &str = "+head";
+
+const SIGN_HEAD_BOUND_CONTEXT: &[u8] = b"sentc-signed-head-v2";
+
+/**
+Get the sign alg without the head bound suffix and if the signature is bound to the head.
+ */
+pub fn split_sign_alg(alg: &str) -> (&str, bool)
+{
+	match alg.strip_suffix(SIGN_HEAD_BOUND_SUFFIX) {
+		Some(a) => (a, true),
+		None => (alg, false),
+	}
+}
+
+/**
+The signed payload of a head bound signature.
+
+Every head field is prefixed with its length, so different splits of the same bytes won't result in the same payload.
+ */
+fn bound_sign_payload(key_id: &str, sign_head: &SignHead, data: &[u8]) -> Vec<u8>
+{
+	let mut payload = Vec::with_capacity(SIGN_HEAD_BOUND_CONTEXT.len() + key_id.len() + sign_head.id.len() + sign_head.alg.len() + 12 + data.len());
+
+	payload.extend_from_slice(SIGN_HEAD_BOUND_CONTEXT);
+
+	for field in [key_id, sign_head.id.as_str(), sign_head.alg.as_str()] {
+		payload.extend_from_slice(&(field.len() as u32).to_be_bytes());
+		payload.extend_from_slice(field.as_bytes());
+	}
+
+	payload.extend_from_slice(data);
+
+	payload
+}
+
+/**
+Sign the encrypted data together with the head.
+
+The key id is the id of the key in the encrypted head (the group key or the public key of the receiver).
+The signature can't be moved to another head or receiver without failing the verification.
+The output has the same format as sign_with_head: the signature followed by the data.
+
+Only readers that know the head bound signature can verify or split it, see SIGN_HEAD_BOUND_SUFFIX.
+ */
+pub fn sign_with_encrypted_head(sign_key: &impl SignKWrapper, key_id: &str, data: &[u8]) -> Result<(SignHead, Vec<u8>), SdkUtilError>
+{
+	let key = sign_key.get_key();
+
+	let sign_head = SignHead {
+		id: sign_key.get_id().to_string(),
+		alg: key.get_alg_str().to_string() + SIGN_HEAD_BOUND_SUFFIX,
+	};
+
+	let payload = bound_sign_payload(key_id, &sign_head, data);
+
+	//the output is the sig and the payload, replace the payload with the data
+	let sig_with_payload = key.sign(&payload)?;
+	let sig_len = sig_with_payload.len() - payload.len();
+
+	let mut out = Vec::with_capacity(sig_len + data.len());
+	out.extend_from_slice(&sig_with_payload[..sig_len]);
+	out.extend_from_slice(data);
+
+	Ok((sign_head, out))
+}
+
+/**
+How signed data is handled while decrypting.
+
+An `Option<&UserVerifyKeyData>` can be used everywhere a policy is expected. It is converted to the lenient policy,
+so signed data is accepted without a verify key. To require signatures, pass VerifyIfSigned or Require at the decrypt call.
+The export functions have a _with_policy variant for this.
+
+With the feature strict_signature_policy the option is converted to VerifyIfSigned instead,
+so every call that still passes an option fails for signed data without a verify key.
+ */
+#[derive(Clone, Copy)]
+pub enum SignaturePolicy<'a>
+{
+	/**
+	Verify the signature if a verify key is given, else the signature is just removed.
+	Unsigned data is accepted.
+	 */
+	Lenient(Option<&'a UserVerifyKeyData>),
+	/**
+	Verify the signature if the data is signed and fail if no verify key is given.
+	Unsigned data is accepted.
+	 */
+	VerifyIfSigned(Option<&'a UserVerifyKeyData>),
+	/**
+	The data must be signed by this verify key.
+	 */
+	Require(&'a UserVerifyKeyData),
+}
+
+impl<'a> SignaturePolicy<'a>
+{
+	pub fn verify_key(&self) -> Option<&'a UserVerifyKeyData>
+	{
+		match *self {
+			Self::Lenient(vk) | Self::VerifyIfSigned(vk) => vk,
+			Self::Require(vk) => Some(vk),
+		}
+	}
+}
+
+impl<'a> From<Option<&'a UserVerifyKeyData>> for SignaturePolicy<'a>
+{
+	#[cfg(not(feature = "strict_signature_policy"))]
+	fn from(value: Option<&'a UserVerifyKeyData>) -> Self
+	{
+		Self::Lenient(value)
+	}
+
+	#[cfg(feature = "strict_signature_policy")]
+	fn from(value: Option<&'a UserVerifyKeyData>) -> Self
+	{
+		Self::VerifyIfSigned(value)
+	}
+}
+
+/**
+Check the signature of the data by the policy and return the data without the signature.
+
+The key id must be the id of the decrypting key and not the key id of the head.
+ */
+pub fn verify_with_policy<'a, V: VerifyKFromUserKeyWrapper>(
+	data: &'a [u8],
+	sign_head: Option<&SignHead>,
+	key_id: &str,
+	policy: SignaturePolicy,
+) -> Result<&'a [u8], SdkUtilError>
+{
+	match (sign_head, policy) {
+		(None, SignaturePolicy::Require(_)) => Err(SdkUtilError::SignatureRequired),
+		(None, _) => Ok(data),
+		(Some(h), SignaturePolicy::Lenient(None)) => {
+			let (_, data_without_sig) = V::split_sig_and_data(split_sign_alg(&h.alg).0, data)?;
+			Ok(data_without_sig)
+		},
+		(Some(_), SignaturePolicy::VerifyIfSigned(None)) => Err(SdkUtilError::SignatureVerifyKeyMissing),
+		(Some(h), SignaturePolicy::Lenient(Some(vk))) |
+		(Some(h), SignaturePolicy::VerifyIfSigned(Some(vk))) |
+		(Some(h), SignaturePolicy::Require(vk)) => V::verify_with_user_key(vk, data, h, key_id),
+	}
+}
+
 pub trait SymKeyCrypto
 {
 	type VerifyKey: VerifyKFromUserKeyWrapper;
 
-	fn prepare_decrypt<'a>(encrypted_data: &'a [u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<&'a [u8], SdkUtilError>
-	{
-		match &head.sign {
-			None => Ok(encrypted_data),
-			Some(h) => {
-				match verify_key {
-					Some(vk) => Self::VerifyKey::verify_with_user_key(vk, encrypted_data, h),
-					None => {
-						let (_, encrypted_data_without_sig) = Self::VerifyKey::split_sig_and_data(&h.alg, encrypted_data)?;
-						Ok(encrypted_data_without_sig)
-					},
-				}
-			},
-		}
+	/**
+	Check the signature of the data by the policy.
+
+	The key id is the id of the decrypting key and not the id in the head,
+	so data that was signed for another key fails the verification.
+	 */
+	fn prepare_decrypt<'a, 'p>(
+		encrypted_data: &'a [u8],
+		head: &EncryptedHead,
+		key_id: &str,
+		policy: impl Into<SignaturePolicy<'p>>,
+	) -> Result<&'a [u8], SdkUtilError>
+	{
+		verify_with_policy::<Self::VerifyKey>(encrypted_data, head.sign.as_ref(), key_id, policy.into())
 	}
 
 	fn encrypt_raw(&self, data: &[u8]) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>;
@@ -171,14 +443,47 @@ pub trait SymKeyCrypto
 	fn encrypt_raw_with_aad_with_sign(&self, data: &[u8], aad: &[u8], sign_key: &impl SignKWrapper)
 		-> Result<(EncryptedHead, Vec<u8>), SdkUtilError>;
 
-	fn decrypt_raw(&self, encrypted_data: &[u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>;
+	/**
+	Like encrypt_raw_with_sign but the signature is bound to the id of this key, see sign_with_encrypted_head.
+
+	Older versions can't decrypt this data.
+	 */
+	fn encrypt_raw_with_bound_sign(&self, data: &[u8], sign_key: &impl SignKWrapper) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
+	{
+		let (mut head, encrypted) = self.encrypt_raw(data)?;
+
+		let (sign_head, data_with_sign) = sign_with_encrypted_head(sign_key, &head.id, &encrypted)?;
+		head.sign = Some(sign_head);
+
+		Ok((head, data_with_sign))
+	}
 
-	fn decrypt_raw_with_aad(
+	/**
+	Like encrypt_raw_with_aad_with_sign but the signature is bound to the id of this key.
+	 */
+	fn encrypt_raw_with_aad_with_bound_sign(
+		&self,
+		data: &[u8],
+		aad: &[u8],
+		sign_key: &impl SignKWrapper,
+	) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
+	{
+		let (mut head, encrypted) = self.encrypt_raw_with_aad(data, aad)?;
+
+		let (sign_head, data_with_sign) = sign_with_encrypted_head(sign_key, &head.id, &encrypted)?;
+		head.sign = Some(sign_head);
+
+		Ok((head, data_with_sign))
+	}
+
+	fn decrypt_raw<'p>(&self, encrypted_data: &[u8], head: &EncryptedHead, policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>;
+
+	fn decrypt_raw_with_aad<'p>(
 		&self,
 		encrypted_data: &[u8],
 		aad: &[u8],
 		head: &EncryptedHead,
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<Vec<u8>, SdkUtilError>;
 
 	fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, SdkUtilError>
@@ -209,18 +514,109 @@ pub trait SymKeyCrypto
 		put_head_and_encrypted_data(&head, &encrypted)
 	}
 
-	fn decrypt(&self, encrypted_data_with_head: &[u8], verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	/**
+	Like encrypt but with the json or the compact binary head, see HeadFormat.
+	 */
+	fn encrypt_with_format(&self, data: &[u8], format: HeadFormat) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw(data)?;
+
+		put_head_and_encrypted_data_with_format(&head, &encrypted, format)
+	}
+
+	fn encrypt_with_sign_with_format(&self, data: &[u8], sign_key: &impl SignKWrapper, format: HeadFormat) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw_with_sign(data, sign_key)?;
+
+		put_head_and_encrypted_data_with_format(&head, &encrypted, format)
+	}
+
+	fn encrypt_with_aad_with_format(&self, data: &[u8], aad: &[u8], format: HeadFormat) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw_with_aad(data, aad)?;
+
+		put_head_and_encrypted_data_with_format(&head, &encrypted, format)
+	}
+
+	fn encrypt_with_aad_with_sign_with_format(
+		&self,
+		data: &[u8],
+		aad: &[u8],
+		sign_key: &impl SignKWrapper,
+		format: HeadFormat,
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw_with_aad_with_sign(data, aad, sign_key)?;
+
+		put_head_and_encrypted_data_with_format(&head, &encrypted, format)
+	}
+
+	fn encrypt_string_with_format(&self, data: &str, format: HeadFormat) -> Result<String, SdkUtilError>
+	{
+		let encrypted = self.encrypt_with_format(data.as_bytes(), format)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	fn encrypt_string_with_sign_with_format(&self, data: &str, sign_key: &impl SignKWrapper, format: HeadFormat) -> Result<String, SdkUtilError>
+	{
+		let encrypted = self.encrypt_with_sign_with_format(data.as_bytes(), sign_key, format)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	fn encrypt_with_bound_sign(&self, data: &[u8], sign_key: &impl SignKWrapper) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw_with_bound_sign(data, sign_key)?;
+
+		put_head_and_encrypted_data(&head, &encrypted)
+	}
+
+	fn encrypt_with_aad_with_bound_sign(&self, data: &[u8], aad: &[u8], sign_key: &impl SignKWrapper) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, encrypted) = self.encrypt_raw_with_aad_with_bound_sign(data, aad, sign_key)?;
+
+		put_head_and_encrypted_data(&head, &encrypted)
+	}
+
+	fn decrypt<'p>(&self, encrypted_data_with_head: &[u8], policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>
 	{
 		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
 
-		self.decrypt_raw(encrypted_data, &head, verify_key)
+		self.decrypt_raw(encrypted_data, &head, policy)
 	}
 
-	fn decrypt_with_aad(&self, encrypted_data_with_head: &[u8], aad: &[u8], verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	fn decrypt_with_aad<'p>(
+		&self,
+		encrypted_data_with_head: &[u8],
+		aad: &[u8],
+		policy: impl Into<SignaturePolicy<'p>>,
+	) -> Result<Vec<u8>, SdkUtilError>
 	{
 		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
 
-		self.decrypt_raw_with_aad(encrypted_data, aad, &head, verify_key)
+		self.decrypt_raw_with_aad(encrypted_data, aad, &head, policy)
+	}
+
+	/**
+	Like decrypt but the algorithms of this key and of the signature must be allowed by the crypto policy.
+	 */
+	fn decrypt_with_crypto_policy<'p>(
+		&self,
+		encrypted_data_with_head: &[u8],
+		policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<Vec<u8>, SdkUtilError>
+	where
+		Self: SymKeyWrapper,
+	{
+		crypto_policy.check(AlgUsage::Sym, self.get_key().get_alg_str())?;
+
+		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
+
+		crypto_policy.check_head(&head)?;
+
+		self.decrypt_raw(encrypted_data, &head, policy)
 	}
 
 	fn encrypt_string(&self, data: &str) -> Result<String, SdkUtilError>;
@@ -231,13 +627,13 @@ pub trait SymKeyCrypto
 
 	fn encrypt_string_with_aad_with_sign(&self, data: &str, aad: &str, sign_key: &impl SignKWrapper) -> Result<String, SdkUtilError>;
 
-	fn decrypt_string(&self, encrypted_data_with_head: &str, verify_key: Option<&UserVerifyKeyData>) -> Result<String, SdkUtilError>;
+	fn decrypt_string<'p>(&self, encrypted_data_with_head: &str, policy: impl Into<SignaturePolicy<'p>>) -> Result<String, SdkUtilError>;
 
-	fn decrypt_string_with_aad(
+	fn decrypt_string_with_aad<'p>(
 		&self,
 		encrypted_data_with_head: &str,
 		aad: &str,
-		verify_key: Option<&UserVerifyKeyData>,
+		policy: impl Into<SignaturePolicy<'p>>,
 	) -> Result<String, SdkUtilError>;
 }
 
@@ -245,16 +641,37 @@ pub trait SymKeyCrypto
 
 pub trait SkCryptoWrapper
 {
-	fn decrypt_raw(&self, encrypted_data: &[u8], head: &EncryptedHead, verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>;
+	fn decrypt_raw<'p>(&self, encrypted_data: &[u8], head: &EncryptedHead, policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>;
 
-	fn decrypt(&self, encrypted_data_with_head: &[u8], verify_key: Option<&UserVerifyKeyData>) -> Result<Vec<u8>, SdkUtilError>
+	fn decrypt<'p>(&self, encrypted_data_with_head: &[u8], policy: impl Into<SignaturePolicy<'p>>) -> Result<Vec<u8>, SdkUtilError>
 	{
 		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
 
-		self.decrypt_raw(encrypted_data, &head, verify_key)
+		self.decrypt_raw(encrypted_data, &head, policy)
 	}
 
-	fn decrypt_string(&self, encrypted_data_with_head: &str, verify_key: Option<&UserVerifyKeyData>) -> Result<String, SdkUtilError>;
+	/**
+	Like decrypt but the algorithms of this key and of the signature must be allowed by the crypto policy.
+	 */
+	fn decrypt_with_crypto_policy<'p>(
+		&self,
+		encrypted_data_with_head: &[u8],
+		policy: impl Into<SignaturePolicy<'p>>,
+		crypto_policy: &CryptoPolicy,
+	) -> Result<Vec<u8>, SdkUtilError>
+	where
+		Self: SkWrapper,
+	{
+		crypto_policy.check(AlgUsage::Asym, self.get_key().get_alg_str())?;
+
+		let (head, encrypted_data) = split_head_and_encrypted_data(encrypted_data_with_head)?;
+
+		crypto_policy.check_head(&head)?;
+
+		self.decrypt_raw(encrypted_data, &head, policy)
+	}
+
+	fn decrypt_string<'p>(&self, encrypted_data_with_head: &str, policy: impl Into<SignaturePolicy<'p>>) -> Result<String, SdkUtilError>;
 }
 
 pub trait PkFromUserKeyWrapper
@@ -297,6 +714,26 @@ pub trait PkFromUserKeyWrapper
 		))
 	}
 
+	/**
+	Like encrypt_raw_with_user_key_with_sign but the signature is bound to the public key id of the receiver,
+	see sign_with_encrypted_head.
+
+	Older versions can't decrypt this data.
+	 */
+	fn encrypt_raw_with_user_key_with_bound_sign(
+		reply_public_key: &UserPublicKeyData,
+		data: &[u8],
+		sign_key: &impl SignKWrapper,
+	) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
+	{
+		let (mut head, encrypted) = Self::encrypt_raw_with_user_key(reply_public_key, data)?;
+
+		let (sign_head, data_with_sign) = sign_with_encrypted_head(sign_key, &head.id, &encrypted)?;
+		head.sign = Some(sign_head);
+
+		Ok((head, data_with_sign))
+	}
+
 	fn encrypt_with_user_key(reply_public_key: &UserPublicKeyData, data: &[u8]) -> Result<Vec<u8>, SdkUtilError>
 	{
 		let (head, data) = Self::encrypt_raw_with_user_key(reply_public_key, data)?;
@@ -315,6 +752,59 @@ pub trait PkFromUserKeyWrapper
 		put_head_and_encrypted_data(&head, &data)
 	}
 
+	/**
+	Like encrypt_with_user_key but with the json or the compact binary head, see HeadFormat.
+	 */
+	fn encrypt_with_user_key_with_format(reply_public_key: &UserPublicKeyData, data: &[u8], format: HeadFormat) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, data) = Self::encrypt_raw_with_user_key(reply_public_key, data)?;
+
+		put_head_and_encrypted_data_with_format(&head, &data, format)
+	}
+
+	fn encrypt_with_user_key_with_sign_with_format(
+		reply_public_key: &UserPublicKeyData,
+		data: &[u8],
+		sign_key: &impl SignKWrapper,
+		format: HeadFormat,
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, data) = Self::encrypt_raw_with_user_key_with_sign(reply_public_key, data, sign_key)?;
+
+		put_head_and_encrypted_data_with_format(&head, &data, format)
+	}
+
+	fn encrypt_string_with_user_key_with_format(reply_public_key: &UserPublicKeyData, data: &str, format: HeadFormat)
+		-> Result<String, SdkUtilError>
+	{
+		let encrypted = Self::encrypt_with_user_key_with_format(reply_public_key, data.as_bytes(), format)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	fn encrypt_string_with_user_key_with_sign_with_format(
+		reply_public_key: &UserPublicKeyData,
+		data: &str,
+		sign_key: &impl SignKWrapper,
+		format: HeadFormat,
+	) -> Result<String, SdkUtilError>
+	{
+		let encrypted = Self::encrypt_with_user_key_with_sign_with_format(reply_public_key, data.as_bytes(), sign_key, format)?;
+
+		Ok(Base64::encode_string(&encrypted))
+	}
+
+	fn encrypt_with_user_key_with_bound_sign(
+		reply_public_key: &UserPublicKeyData,
+		data: &[u8],
+		sign_key: &impl SignKWrapper,
+	) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let (head, data) = Self::encrypt_raw_with_user_key_with_bound_sign(reply_public_key, data, sign_key)?;
+
+		put_head_and_encrypted_data(&head, &data)
+	}
+
 	fn encrypt_string_with_user_key(reply_public_key: &UserPublicKeyData, data: &str) -> Result<String, SdkUtilError>;
 
 	fn encrypt_string_with_user_key_with_sign(
@@ -324,6 +814,13 @@ pub trait PkFromUserKeyWrapper
 	) -> Result<String, SdkUtilError>;
 
 	fn from_user_key(reply_public_key: &UserPublicKeyData) -> Result<Self::CorePk, SdkUtilError>;
+
+	fn from_user_key_with_policy(reply_public_key: &UserPublicKeyData, crypto_policy: &CryptoPolicy) -> Result<Self::CorePk, SdkUtilError>
+	{
+		crypto_policy.check_public_key(reply_public_key)?;
+
+		Self::from_user_key(reply_public_key)
+	}
 }
 
 //__________________________________________________________________________________________________
@@ -331,13 +828,30 @@ pub trait PkFromUserKeyWrapper
 pub trait SignKCryptoWrapper
 {
 	fn sign_with_head(&self, data: &[u8]) -> Result<(SignHead, Vec<u8>), SdkUtilError>;
+
+	/**
+	Sign the data without attaching the data to the signature.
+
+	Returns the sign head and the exported signature.
+	 */
+	fn sign_detached(&self, data: &[u8]) -> Result<(SignHead, String), SdkUtilError>;
 }
 
 pub trait VerifyKFromUserKeyWrapper
 {
 	type CoreVk: VerifyK;
 
-	fn verify_with_user_key<'a>(verify_key: &UserVerifyKeyData, data_with_sig: &'a [u8], sign_head: &SignHead) -> Result<&'a [u8], SdkUtilError>
+	/**
+	Verify the signature of the data and return the data without the signature.
+
+	The key id is the id of the key that decrypts the data. For signatures bound to the head, it must be the same id as by signing.
+	 */
+	fn verify_with_user_key<'a>(
+		verify_key: &UserVerifyKeyData,
+		data_with_sig: &'a [u8],
+		sign_head: &SignHead,
+		key_id: &str,
+	) -> Result<&'a [u8], SdkUtilError>
 	{
 		let vk = Self::from_user_key(verify_key)?;
 
@@ -345,7 +859,28 @@ pub trait VerifyKFromUserKeyWrapper
 			return Err(SdkUtilError::SigFoundNotKey);
 		}
 
-		let (encrypted_data_without_sig, check) = vk.verify(data_with_sig)?;
+		let (alg, bound) = split_sign_alg(&sign_head.alg);
+
+		if !bound {
+			let (encrypted_data_without_sig, check) = vk.verify(data_with_sig)?;
+
+			if !check {
+				return Err(SdkUtilError::VerifyFailed);
+			}
+
+			return Ok(encrypted_data_without_sig);
+		}
+
+		//the head fields are not in the data, rebuild the signed payload with the head of the data
+		let (sig, encrypted_data_without_sig) = Self::split_sig_and_data(alg, data_with_sig)?;
+
+		let payload = bound_sign_payload(key_id, sign_head, encrypted_data_without_sig);
+
+		let mut sig_with_payload = Vec::with_capacity(sig.len() + payload.len());
+		sig_with_payload.extend_from_slice(sig);
+		sig_with_payload.extend_from_slice(&payload);
+
+		let (_, check) = vk.verify(&sig_with_payload)?;
 
 		if !check {
 			return Err(SdkUtilError::VerifyFailed);
@@ -354,7 +889,35 @@ pub trait VerifyKFromUserKeyWrapper
 		Ok(encrypted_data_without_sig)
 	}
 
+	/**
+	Verify a signature from sign_detached. The data is not part of the signature.
+	 */
+	fn verify_detached(verify_key: &UserVerifyKeyData, data: &[u8], sig: &str, sign_head: &SignHead) -> Result<(), SdkUtilError>
+	{
+		if verify_key.verify_key_id != sign_head.id {
+			return Err(SdkUtilError::SigFoundNotKey);
+		}
+
+		let vk = Self::from_user_key(verify_key)?;
+		let sig = Self::sig_from_string(sig, &verify_key.verify_key_alg)?;
+
+		if !vk.verify_only(&sig, data)? {
+			return Err(SdkUtilError::VerifyFailed);
+		}
+
+		Ok(())
+	}
+
 	fn split_sig_and_data<'a>(alg: &str, data_with_sign: &'a [u8]) -> Result<(&'a [u8], &'a [u8]), SdkUtilError>;
 
 	fn from_user_key(verify_key: &UserVerifyKeyData) -> Result<Self::CoreVk, SdkUtilError>;
+
+	fn from_user_key_with_policy(verify_key: &UserVerifyKeyData, crypto_policy: &CryptoPolicy) -> Result<Self::CoreVk, SdkUtilError>
+	{
+		crypto_policy.check_verify_key(verify_key)?;
+
+		Self::from_user_key(verify_key)
+	}
+
+	fn sig_from_string(sig: &str, alg: &str) -> Result<<Self::CoreVk as VerifyK>::Signature, SdkUtilError>;
 }
@@ -46,6 +46,12 @@ macro_rules! wrapper_trait {
 	};
 }
 
+/**
+Export a key as string.
+
+Keys that are not in memory (e.g. a private key in a pkcs11 token) export only the reference to the key.
+The FromStr impl of the key must resolve the reference again.
+ */
 pub trait KeyToString
 {
 	fn to_string(self) -> Result<String, SdkUtilError>;
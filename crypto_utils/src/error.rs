@@ -26,6 +26,8 @@ pub enum SdkUtilError
 	ImportVerifyKeyFailed,
 	ImportAuthMasterKeyFailed,
 	ImportKeyFailed,
+	AlgNotAllowed(String),
+	AlgNotPostQuantum(String),
 
 	DerivedKeyWrongFormat,
 	InvalidJwt,
@@ -45,11 +47,25 @@ pub enum SdkUtilError
 	#[cfg(feature = "encryption")]
 	VerifyFailed,
 	#[cfg(feature = "encryption")]
+	SignatureRequired,
+	#[cfg(feature = "encryption")]
+	SignatureVerifyKeyMissing,
+	#[cfg(feature = "encryption")]
 	DecodeEncryptedDataFailed,
 	#[cfg(feature = "encryption")]
+	HeadFieldTooLong,
+	#[cfg(feature = "encryption")]
 	SearchableEncryptionDataNotFound,
 	#[cfg(feature = "encryption")]
 	SearchableEncryptionDataTooLong,
+	#[cfg(feature = "encryption")]
+	SearchableEncryptionInvalidTruncation,
+	#[cfg(feature = "encryption")]
+	JoseInvalidFormat,
+	#[cfg(feature = "encryption")]
+	JoseAlgNotSupported(String),
+	#[cfg(feature = "encryption")]
+	JoseSignedDataNotSupported,
 }
 
 /**
@@ -140,6 +156,32 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 				Error::OpeRangeError => out_error("client_60", "Invalid input range"),
 				Error::OpeStringToLarge => out_error("client_61", "String is too large to process"),
 				Error::OpeHdgInvalidInputs => out_error("client_62", "Invalid inputs"),
+
+				Error::SafetyNumberPayloadInvalid => out_error("client_70", "The safety number payload has a wrong format"),
+				Error::RecoveryKeyInvalidFormat => out_error("client_72", "The recovery key has a wrong format"),
+
+				Error::ShamirInvalidThreshold => {
+					out_error(
+						"client_80",
+						"The threshold must be at least 1 and not bigger than the number of shares",
+					)
+				},
+				Error::ShamirNotEnoughShares => out_error("client_81", "Not enough shares to rebuild the secret"),
+				Error::ShamirInvalidShare => out_error("client_82", "The shares have a wrong format or don't belong together"),
+
+				Error::Pkcs11TokenNotFound => out_error("client_90", "The pkcs11 token was not found or is not opened"),
+				Error::Pkcs11KeyNotFound => out_error("client_91", "The key was not found in the pkcs11 token"),
+				Error::Pkcs11Failed => out_error("client_92", "The pkcs11 token returned an error"),
+
+				Error::AgeInvalidFormat => out_error("client_95", "The age file has a wrong format"),
+				Error::AgeNoIdentityMatched => out_error("client_96", "No identity can open this age file"),
+				Error::AgeHeaderMacInvalid => out_error("client_97", "The age header was modified"),
+				Error::SafetyNumberVersionMismatch => {
+					out_error(
+						"client_71",
+						"The safety number payload was created with another version",
+					)
+				},
 			}
 		},
 		SdkUtilError::AlgNotFound => out_error("client_1", "The algorithms for this action was not found."),
@@ -171,6 +213,18 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 		SdkUtilError::ImportingKeyFromPemFailed => out_error("client_115", "Can't import this key. It has a wrong format"),
 		SdkUtilError::ImportAuthMasterKeyFailed => out_error("client_116", "Can't import auth master key"),
 		SdkUtilError::ImportKeyFailed => out_error("client_109", "Can't import the key"),
+		SdkUtilError::AlgNotAllowed(alg) => {
+			out_error(
+				"client_320",
+				&("This algorithm is not allowed by the crypto policy: ".to_string() + &alg),
+			)
+		},
+		SdkUtilError::AlgNotPostQuantum(alg) => {
+			out_error(
+				"client_321",
+				&("The crypto policy requires a post-quantum algorithm but got: ".to_string() + &alg),
+			)
+		},
 
 		//exporting error
 		SdkUtilError::ExportingPublicKeyFailed => {
@@ -202,8 +256,19 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 		#[cfg(feature = "encryption")]
 		SdkUtilError::VerifyFailed => out_error("client_22", "The verification failed. A wrong verify key was used"),
 		#[cfg(feature = "encryption")]
+		SdkUtilError::SignatureRequired => out_error("client_23", "The data is not signed but a signature is required"),
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SignatureVerifyKeyMissing => out_error("client_24", "The data is signed but no verify key was given"),
+		#[cfg(feature = "encryption")]
 		SdkUtilError::DecodeEncryptedDataFailed => out_error("client_10", "Can't decode the encrypted data"),
 		#[cfg(feature = "encryption")]
+		SdkUtilError::HeadFieldTooLong => {
+			out_error(
+				"client_330",
+				"A field of the head is too long for the binary head format. The maximal length is 65535 bytes.",
+			)
+		},
+		#[cfg(feature = "encryption")]
 		SdkUtilError::SearchableEncryptionDataTooLong => {
 			out_error(
 				"client_300",
@@ -212,6 +277,29 @@ pub fn err_to_msg(error: SdkUtilError) -> String
 		},
 		#[cfg(feature = "encryption")]
 		SdkUtilError::SearchableEncryptionDataNotFound => out_error("client_301", "No data found to hash. Empty Strings are not allowed."),
+		#[cfg(feature = "encryption")]
+		SdkUtilError::SearchableEncryptionInvalidTruncation => {
+			out_error(
+				"client_302",
+				"The truncation of the blind index is not valid. It must be between 1 and the length of the hash in bits.",
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::JoseInvalidFormat => out_error("client_310", "The jwe or jws has a wrong format"),
+		#[cfg(feature = "encryption")]
+		SdkUtilError::JoseAlgNotSupported(alg) => {
+			out_error(
+				"client_311",
+				&("This algorithm has no JOSE registration: ".to_string() + &alg),
+			)
+		},
+		#[cfg(feature = "encryption")]
+		SdkUtilError::JoseSignedDataNotSupported => {
+			out_error(
+				"client_312",
+				"Signed data can't be exported to jwe. Use a detached jws for the signature.",
+			)
+		},
 	}
 }
 
@@ -0,0 +1,232 @@
+//! Compact binary format for the heads of encrypted data.
+//!
+//! The json head is followed by a zero byte to split it from the data.
+//! The binary head starts with a magic byte, the version and the type of the head.
+//! The fields are written in a fixed order, strings are prefixed with their length (u16 big endian)
+//! and optional values with a flag byte. The data follows directly after the head.
+//!
+//! A json head always starts with `{`, so both formats can be detected by the first byte.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use sentc_crypto_common::crypto::{EncryptedHead, SignHead};
+use sentc_crypto_common::file::FileHead;
+
+use crate::error::SdkUtilError;
+
+pub const BINARY_HEAD_MAGIC: u8 = 0xb5;
+pub const BINARY_HEAD_VERSION: u8 = 1;
+
+pub const BINARY_HEAD_TYPE_ENCRYPTED: u8 = 1;
+pub const BINARY_HEAD_TYPE_FILE: u8 = 2;
+pub const BINARY_HEAD_TYPE_ENVELOPE: u8 = 3;
+
+/**
+The format of the head when putting the head and the encrypted data together.
+ */
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum HeadFormat
+{
+	#[default]
+	Json,
+	Binary,
+}
+
+/**
+A head that can be written in the binary format.
+
+Only the fields are written and read. The magic byte, the version and the type are handled by the caller.
+ */
+pub trait BinaryHead: Sized
+{
+	const HEAD_TYPE: u8;
+
+	fn write_fields(&self, writer: &mut HeadWriter) -> Result<(), SdkUtilError>;
+
+	fn read_fields(reader: &mut HeadReader) -> Result<Self, SdkUtilError>;
+}
+
+pub fn is_binary_head(data_with_head: &[u8]) -> bool
+{
+	data_with_head.first() == Some(&BINARY_HEAD_MAGIC)
+}
+
+/**
+Write the head with the magic byte, version and type.
+ */
+pub fn encode_binary_head<T: BinaryHead>(head: &T) -> Result<Vec<u8>, SdkUtilError>
+{
+	let mut writer = HeadWriter {
+		out: Vec::with_capacity(64),
+	};
+
+	writer
+		.out
+		.extend_from_slice(&[BINARY_HEAD_MAGIC, BINARY_HEAD_VERSION, T::HEAD_TYPE]);
+
+	head.write_fields(&mut writer)?;
+
+	Ok(writer.out)
+}
+
+/**
+Read the binary head and return the head and the rest of the data.
+ */
+pub fn decode_binary_head<T: BinaryHead>(data_with_head: &[u8]) -> Result<(T, &[u8]), SdkUtilError>
+{
+	match data_with_head {
+		[BINARY_HEAD_MAGIC, BINARY_HEAD_VERSION, head_type, ..] if *head_type == T::HEAD_TYPE => {},
+		_ => return Err(SdkUtilError::DecodeEncryptedDataFailed),
+	}
+
+	let mut reader = HeadReader {
+		data: &data_with_head[3..],
+	};
+
+	let head = T::read_fields(&mut reader)?;
+
+	Ok((head, reader.data))
+}
+
+pub struct HeadWriter
+{
+	out: Vec<u8>,
+}
+
+impl HeadWriter
+{
+	pub fn write_str(&mut self, value: &str) -> Result<(), SdkUtilError>
+	{
+		let len: u16 = value
+			.len()
+			.try_into()
+			.map_err(|_| SdkUtilError::HeadFieldTooLong)?;
+
+		self.out.extend_from_slice(&len.to_be_bytes());
+		self.out.extend_from_slice(value.as_bytes());
+
+		Ok(())
+	}
+
+	pub fn write_u16(&mut self, value: u16)
+	{
+		self.out.extend_from_slice(&value.to_be_bytes());
+	}
+
+	pub fn write_flag(&mut self, value: bool)
+	{
+		self.out.push(value as u8);
+	}
+}
+
+pub struct HeadReader<'a>
+{
+	data: &'a [u8],
+}
+
+impl<'a> HeadReader<'a>
+{
+	fn take(&mut self, len: usize) -> Result<&'a [u8], SdkUtilError>
+	{
+		if self.data.len() < len {
+			return Err(SdkUtilError::DecodeEncryptedDataFailed);
+		}
+
+		let (value, rest) = self.data.split_at(len);
+		self.data = rest;
+
+		Ok(value)
+	}
+
+	pub fn read_u16(&mut self) -> Result<u16, SdkUtilError>
+	{
+		let bytes = self.take(2)?;
+
+		Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+	}
+
+	pub fn read_str(&mut self) -> Result<String, SdkUtilError>
+	{
+		let len = self.read_u16()? as usize;
+		let bytes = self.take(len)?;
+
+		String::from_utf8(bytes.to_vec()).map_err(|_| SdkUtilError::DecodeEncryptedDataFailed)
+	}
+
+	pub fn read_flag(&mut self) -> Result<bool, SdkUtilError>
+	{
+		match self.take(1)?[0] {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(SdkUtilError::DecodeEncryptedDataFailed),
+		}
+	}
+}
+
+fn write_sign_head(writer: &mut HeadWriter, sign: &Option<SignHead>) -> Result<(), SdkUtilError>
+{
+	match sign {
+		Some(s) => {
+			writer.write_flag(true);
+			writer.write_str(&s.id)?;
+			writer.write_str(&s.alg)
+		},
+		None => {
+			writer.write_flag(false);
+			Ok(())
+		},
+	}
+}
+
+fn read_sign_head(reader: &mut HeadReader) -> Result<Option<SignHead>, SdkUtilError>
+{
+	if !reader.read_flag()? {
+		return Ok(None);
+	}
+
+	Ok(Some(SignHead {
+		id: reader.read_str()?,
+		alg: reader.read_str()?,
+	}))
+}
+
+impl BinaryHead for EncryptedHead
+{
+	const HEAD_TYPE: u8 = BINARY_HEAD_TYPE_ENCRYPTED;
+
+	fn write_fields(&self, writer: &mut HeadWriter) -> Result<(), SdkUtilError>
+	{
+		writer.write_str(&self.id)?;
+		write_sign_head(writer, &self.sign)
+	}
+
+	fn read_fields(reader: &mut HeadReader) -> Result<Self, SdkUtilError>
+	{
+		Ok(Self {
+			id: reader.read_str()?,
+			sign: read_sign_head(reader)?,
+		})
+	}
+}
+
+impl BinaryHead for FileHead
+{
+	const HEAD_TYPE: u8 = BINARY_HEAD_TYPE_FILE;
+
+	fn write_fields(&self, writer: &mut HeadWriter) -> Result<(), SdkUtilError>
+	{
+		writer.write_str(&self.key)?;
+		write_sign_head(writer, &self.sign)?;
+		writer.write_str(&self.sym_key_alg)
+	}
+
+	fn read_fields(reader: &mut HeadReader) -> Result<Self, SdkUtilError>
+	{
+		Ok(Self {
+			key: reader.read_str()?,
+			sign: read_sign_head(reader)?,
+			sym_key_alg: reader.read_str()?,
+		})
+	}
+}
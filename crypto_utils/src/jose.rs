@@ -0,0 +1,507 @@
+//! JOSE serialization of sentc ciphertexts and signatures.
+//!
+//! Only the algorithms with a JOSE registration are supported:
+//! - symmetric keys: `dir` with `A256GCM`
+//! - ecies x25519 (std keys): `ECDH-ES` with `A256GCM`
+//! - rsa oaep (fips keys): `RSA-OAEP` with `A256GCM`
+//! - ed25519: `EdDSA` for detached jws
+//!
+//! The hybrid and post-quantum algorithms have no JOSE registration and return [`SdkUtilError::JoseAlgNotSupported`].
+//!
+//! The compact serialization always has a protected header which is used as aad for the content encryption.
+//! Existing sentc ciphertexts are encrypted without aad,
+//! so they can only be exported to the flattened json serialization with an unprotected header.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use base64ct::{Base64, Base64UrlUnpadded, Encoding};
+use sentc_crypto_common::crypto::{EncryptedHead, SignHead};
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use serde::{Deserialize, Serialize};
+
+use crate::cryptomat::{SignKWrapper, SymKeyWrapper, VerifyKFromUserKeyWrapper};
+use crate::error::SdkUtilError;
+
+pub const JWE_ALG_DIR: &str = "dir";
+pub const JWE_ALG_ECDH_ES: &str = "ECDH-ES";
+pub const JWE_ALG_RSA_OAEP: &str = "RSA-OAEP";
+pub const JWE_ENC_A256GCM: &str = "A256GCM";
+pub const JWS_ALG_EDDSA: &str = "EdDSA";
+
+const A256GCM_IV_LENGTH: usize = 12;
+const A256GCM_TAG_LENGTH: usize = 16;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Jwk
+{
+	pub kty: String,
+	pub crv: String,
+	pub x: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct JweHeader
+{
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub alg: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub enc: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub kid: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub epk: Option<Jwk>,
+}
+
+impl JweHeader
+{
+	pub fn new(alg: &str, kid: &str) -> Self
+	{
+		Self {
+			alg: Some(alg.to_string()),
+			enc: Some(JWE_ENC_A256GCM.to_string()),
+			kid: Some(kid.to_string()),
+			epk: None,
+		}
+	}
+
+	pub fn alg(&self) -> &str
+	{
+		self.alg.as_deref().unwrap_or_default()
+	}
+
+	/**
+	Check the alg and the content encryption of the header.
+	 */
+	pub fn check(&self, alg: &str) -> Result<(), SdkUtilError>
+	{
+		if self.alg() != alg {
+			return Err(SdkUtilError::JoseAlgNotSupported(self.alg().to_string()));
+		}
+
+		match self.enc.as_deref() {
+			Some(JWE_ENC_A256GCM) => Ok(()),
+			Some(enc) => Err(SdkUtilError::JoseAlgNotSupported(enc.to_string())),
+			None => Err(SdkUtilError::JoseInvalidFormat),
+		}
+	}
+
+	fn merge(self, other: Self) -> Self
+	{
+		Self {
+			alg: self.alg.or(other.alg),
+			enc: self.enc.or(other.enc),
+			kid: self.kid.or(other.kid),
+			epk: self.epk.or(other.epk),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+struct FlattenedJwe
+{
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	protected: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	unprotected: Option<JweHeader>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	encrypted_key: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	aad: Option<String>,
+	iv: String,
+	ciphertext: String,
+	tag: String,
+}
+
+/**
+A parsed jwe in compact or flattened json serialization.
+ */
+pub struct Jwe
+{
+	pub header: JweHeader,
+	protected: Option<String>,
+	pub encrypted_key: Vec<u8>,
+	pub iv: Vec<u8>,
+	pub ciphertext: Vec<u8>,
+	pub tag: Vec<u8>,
+}
+
+impl Jwe
+{
+	/**
+	Create a jwe with a protected header. The header is the aad of the content encryption.
+	 */
+	pub fn protected(header: JweHeader) -> Result<Self, SdkUtilError>
+	{
+		let protected = serde_json::to_string(&header).map_err(|_| SdkUtilError::JsonToStringFailed)?;
+
+		Ok(Self {
+			header,
+			protected: Some(Base64UrlUnpadded::encode_string(protected.as_bytes())),
+			encrypted_key: Vec::new(),
+			iv: Vec::new(),
+			ciphertext: Vec::new(),
+			tag: Vec::new(),
+		})
+	}
+
+	/**
+	Create a jwe with an unprotected header. The content encryption has no aad.
+	 */
+	pub fn unprotected(header: JweHeader) -> Self
+	{
+		Self {
+			header,
+			protected: None,
+			encrypted_key: Vec::new(),
+			iv: Vec::new(),
+			ciphertext: Vec::new(),
+			tag: Vec::new(),
+		}
+	}
+
+	/**
+	The aad of the content encryption. Empty for an unprotected header.
+	 */
+	pub fn aad(&self) -> &[u8]
+	{
+		self.protected.as_deref().unwrap_or_default().as_bytes()
+	}
+
+	/**
+	The kid of the header as head for the sentc decryption.
+	 */
+	pub fn encrypted_head(&self) -> Result<EncryptedHead, SdkUtilError>
+	{
+		Ok(EncryptedHead {
+			id: self
+				.header
+				.kid
+				.clone()
+				.ok_or(SdkUtilError::JoseInvalidFormat)?,
+			sign: None,
+		})
+	}
+
+	pub fn to_compact(&self) -> Result<String, SdkUtilError>
+	{
+		//compact serialization needs the protected header
+		let protected = self
+			.protected
+			.as_ref()
+			.ok_or(SdkUtilError::JoseInvalidFormat)?;
+
+		Ok(format!(
+			"{}.{}.{}.{}.{}",
+			protected,
+			Base64UrlUnpadded::encode_string(&self.encrypted_key),
+			Base64UrlUnpadded::encode_string(&self.iv),
+			Base64UrlUnpadded::encode_string(&self.ciphertext),
+			Base64UrlUnpadded::encode_string(&self.tag)
+		))
+	}
+
+	pub fn to_json(&self) -> Result<String, SdkUtilError>
+	{
+		let jwe = FlattenedJwe {
+			protected: self.protected.clone(),
+			unprotected: if self.protected.is_some() { None } else { Some(self.header.clone()) },
+			encrypted_key: if self.encrypted_key.is_empty() {
+				None
+			} else {
+				Some(Base64UrlUnpadded::encode_string(&self.encrypted_key))
+			},
+			aad: None,
+			iv: Base64UrlUnpadded::encode_string(&self.iv),
+			ciphertext: Base64UrlUnpadded::encode_string(&self.ciphertext),
+			tag: Base64UrlUnpadded::encode_string(&self.tag),
+		};
+
+		serde_json::to_string(&jwe).map_err(|_| SdkUtilError::JsonToStringFailed)
+	}
+
+	/**
+	Parse a jwe. The json serialization is detected by the leading `{`.
+	 */
+	pub fn parse(jwe: &str) -> Result<Self, SdkUtilError>
+	{
+		let jwe = jwe.trim();
+
+		let jwe = if jwe.starts_with('{') {
+			Self::parse_json(jwe)?
+		} else {
+			Self::parse_compact(jwe)?
+		};
+
+		//only A256GCM is supported for the content encryption
+		if jwe.iv.len() != A256GCM_IV_LENGTH || jwe.tag.len() != A256GCM_TAG_LENGTH {
+			return Err(SdkUtilError::JoseInvalidFormat);
+		}
+
+		Ok(jwe)
+	}
+
+	fn parse_compact(jwe: &str) -> Result<Self, SdkUtilError>
+	{
+		let parts: Vec<&str> = jwe.split('.').collect();
+
+		let [protected, encrypted_key, iv, ciphertext, tag] = parts[..] else {
+			return Err(SdkUtilError::JoseInvalidFormat);
+		};
+
+		Ok(Self {
+			header: decode_protected_header(protected)?,
+			protected: Some(protected.to_string()),
+			encrypted_key: decode_part(encrypted_key)?,
+			iv: decode_part(iv)?,
+			ciphertext: decode_part(ciphertext)?,
+			tag: decode_part(tag)?,
+		})
+	}
+
+	fn parse_json(jwe: &str) -> Result<Self, SdkUtilError>
+	{
+		let jwe: FlattenedJwe = serde_json::from_str(jwe).map_err(|_| SdkUtilError::JoseInvalidFormat)?;
+
+		if jwe.aad.is_some() {
+			//additional aad is not used by sentc
+			return Err(SdkUtilError::JoseInvalidFormat);
+		}
+
+		let header = match &jwe.protected {
+			Some(p) => decode_protected_header(p)?,
+			None => JweHeader::default(),
+		};
+
+		let header = header.merge(jwe.unprotected.unwrap_or_default());
+
+		Ok(Self {
+			header,
+			protected: jwe.protected,
+			encrypted_key: match jwe.encrypted_key {
+				Some(k) => decode_part(&k)?,
+				None => Vec::new(),
+			},
+			iv: decode_part(&jwe.iv)?,
+			ciphertext: decode_part(&jwe.ciphertext)?,
+			tag: decode_part(&jwe.tag)?,
+		})
+	}
+}
+
+fn decode_part(part: &str) -> Result<Vec<u8>, SdkUtilError>
+{
+	Base64UrlUnpadded::decode_vec(part).map_err(|_| SdkUtilError::JoseInvalidFormat)
+}
+
+fn decode_protected_header(protected: &str) -> Result<JweHeader, SdkUtilError>
+{
+	serde_json::from_slice(&decode_part(protected)?).map_err(|_| SdkUtilError::JoseInvalidFormat)
+}
+
+/**
+Symmetric keys which can be used for jwe with `dir` and `A256GCM`.
+
+The key impl only needs to split and join its aes gcm output.
+ */
+pub trait JoseSymKey: SymKeyWrapper
+{
+	/**
+	Split the output of the sym key encryption into iv, ciphertext and tag.
+	 */
+	fn split_aead<'a>(&self, encrypted: &'a [u8]) -> Result<(&'a [u8], &'a [u8], &'a [u8]), SdkUtilError>;
+
+	/**
+	Put iv, ciphertext and tag together in the format of the sym key.
+	 */
+	fn join_aead(&self, iv: &[u8], ciphertext: &[u8], tag: &[u8]) -> Vec<u8>;
+
+	/**
+	Export data encrypted by this key to a jwe in flattened json serialization.
+
+	Signed data can't be exported, use a detached jws instead.
+	 */
+	fn export_jwe(&self, head: &EncryptedHead, encrypted: &[u8]) -> Result<String, SdkUtilError>
+	{
+		if head.sign.is_some() {
+			return Err(SdkUtilError::JoseSignedDataNotSupported);
+		}
+
+		let (iv, ciphertext, tag) = self.split_aead(encrypted)?;
+
+		let mut jwe = Jwe::unprotected(JweHeader::new(JWE_ALG_DIR, &head.id));
+		jwe.iv = iv.to_vec();
+		jwe.ciphertext = ciphertext.to_vec();
+		jwe.tag = tag.to_vec();
+
+		jwe.to_json()
+	}
+
+	/**
+	Import a jwe without protected header back to the sentc head and ciphertext.
+	 */
+	fn import_jwe(&self, jwe: &str) -> Result<(EncryptedHead, Vec<u8>), SdkUtilError>
+	{
+		let jwe = Jwe::parse(jwe)?;
+		jwe.header.check(JWE_ALG_DIR)?;
+
+		if !jwe.aad().is_empty() {
+			//sentc ciphertexts are encrypted without aad
+			return Err(SdkUtilError::JoseInvalidFormat);
+		}
+
+		Ok((
+			jwe.encrypted_head()?,
+			self.join_aead(&jwe.iv, &jwe.ciphertext, &jwe.tag),
+		))
+	}
+
+	/**
+	Encrypt the data to a jwe in compact serialization.
+	 */
+	fn encrypt_jwe(&self, data: &[u8]) -> Result<String, SdkUtilError>
+	{
+		let mut jwe = Jwe::protected(JweHeader::new(JWE_ALG_DIR, self.get_id()))?;
+
+		let (_, encrypted) = self.encrypt_raw_with_aad(data, jwe.aad())?;
+		let (iv, ciphertext, tag) = self.split_aead(&encrypted)?;
+
+		jwe.iv = iv.to_vec();
+		jwe.ciphertext = ciphertext.to_vec();
+		jwe.tag = tag.to_vec();
+
+		jwe.to_compact()
+	}
+
+	/**
+	Decrypt a jwe in compact or flattened json serialization.
+	 */
+	fn decrypt_jwe(&self, jwe: &str) -> Result<Vec<u8>, SdkUtilError>
+	{
+		let jwe = Jwe::parse(jwe)?;
+		jwe.header.check(JWE_ALG_DIR)?;
+
+		let encrypted = self.join_aead(&jwe.iv, &jwe.ciphertext, &jwe.tag);
+
+		let head = EncryptedHead {
+			id: self.get_id().to_string(),
+			sign: None,
+		};
+
+		self.decrypt_raw_with_aad(&encrypted, jwe.aad(), &head, None)
+	}
+}
+
+/**
+Public keys which can encrypt to a jwe. The key impl chooses the key management alg.
+ */
+pub trait JosePk
+{
+	fn encrypt_jwe_with_user_key(reply_public_key: &UserPublicKeyData, data: &[u8]) -> Result<String, SdkUtilError>;
+}
+
+/**
+The private key counterpart to [`JosePk`].
+ */
+pub trait JoseSk
+{
+	fn decrypt_jwe(&self, jwe: &str) -> Result<Vec<u8>, SdkUtilError>;
+}
+
+//__________________________________________________________________________________________________
+//jws
+
+#[derive(Serialize, Deserialize)]
+struct JwsHeader
+{
+	alg: String,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	kid: Option<String>,
+}
+
+fn jws_signing_input(header: &str, data: &[u8]) -> Vec<u8>
+{
+	let payload = Base64UrlUnpadded::encode_string(data);
+
+	let mut input = Vec::with_capacity(header.len() + 1 + payload.len());
+	input.extend_from_slice(header.as_bytes());
+	input.push(b'.');
+	input.extend_from_slice(payload.as_bytes());
+
+	input
+}
+
+/**
+Sign keys which can create a detached jws.
+ */
+pub trait JoseSignKey: SignKWrapper
+{
+	/**
+	The jws alg of the key or [`SdkUtilError::JoseAlgNotSupported`].
+	 */
+	fn jws_alg(&self) -> Result<&'static str, SdkUtilError>;
+
+	/**
+	Sign the data and return a jws in compact serialization with detached payload (`header..signature`).
+	 */
+	fn sign_jws_detached(&self, data: &[u8]) -> Result<String, SdkUtilError>
+	{
+		let header = JwsHeader {
+			alg: self.jws_alg()?.to_string(),
+			kid: Some(self.get_id().to_string()),
+		};
+
+		let header = serde_json::to_string(&header).map_err(|_| SdkUtilError::JsonToStringFailed)?;
+		let header = Base64UrlUnpadded::encode_string(header.as_bytes());
+
+		let (_, sig) = self.sign_detached(&jws_signing_input(&header, data))?;
+
+		//the exported signature is base64 of the raw signature
+		let sig = Base64::decode_vec(&sig).map_err(|_| SdkUtilError::JoseInvalidFormat)?;
+
+		Ok(header + ".." + &Base64UrlUnpadded::encode_string(&sig))
+	}
+}
+
+/**
+Verify keys which can verify a detached jws.
+ */
+pub trait JoseVerifyKey: VerifyKFromUserKeyWrapper
+{
+	/**
+	The jws alg for the sentc alg of the verify key or [`SdkUtilError::JoseAlgNotSupported`].
+	 */
+	fn jws_alg(alg: &str) -> Result<&'static str, SdkUtilError>;
+
+	/**
+	Verify a jws with detached payload. The data is the detached payload.
+
+	A jws without kid is checked against the given verify key.
+	 */
+	fn verify_jws_detached(jws: &str, data: &[u8], verify_key: &UserVerifyKeyData) -> Result<(), SdkUtilError>
+	{
+		let parts: Vec<&str> = jws.trim().split('.').collect();
+
+		let [header, "", sig] = parts[..] else {
+			return Err(SdkUtilError::JoseInvalidFormat);
+		};
+
+		let decoded_header: JwsHeader = serde_json::from_slice(&decode_part(header)?).map_err(|_| SdkUtilError::JoseInvalidFormat)?;
+
+		if decoded_header.alg != Self::jws_alg(&verify_key.verify_key_alg)? {
+			return Err(SdkUtilError::JoseAlgNotSupported(decoded_header.alg));
+		}
+
+		let sig = Base64::encode_string(&decode_part(sig)?);
+
+		let sign_head = SignHead {
+			id: decoded_header
+				.kid
+				.unwrap_or_else(|| verify_key.verify_key_id.clone()),
+			alg: verify_key.verify_key_alg.clone(),
+		};
+
+		Self::verify_detached(verify_key, &jws_signing_input(header, data), &sig, &sign_head)
+	}
+}
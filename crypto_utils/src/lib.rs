@@ -20,9 +20,14 @@ pub mod error;
 #[cfg(all(feature = "crypto_full", any(feature = "rustls", feature = "wasm")))]
 pub mod full;
 pub mod group;
+#[cfg(feature = "encryption")]
+pub mod head;
 #[cfg(any(feature = "rustls", feature = "wasm"))]
 pub mod http;
+#[cfg(feature = "encryption")]
+pub mod jose;
 pub mod jwt;
+pub mod policy;
 pub mod user;
 
 pub fn handle_server_response<'de, T: Deserialize<'de>>(res: &'de str) -> Result<T, SdkUtilError>
@@ -91,9 +96,27 @@ pub fn client_random_value_from_string<C: ClientRandomValueComposer>(client_rand
 Get the head and the data.
 
 This can not only be used internally, to get the used key_id
+
+The format of the head (json or binary) is detected by the first byte.
+The head must implement BinaryHead. For own heads that are only written as json use split_head_and_encrypted_json.
+ */
+#[cfg(feature = "encryption")]
+pub fn split_head_and_encrypted_data<'a, T: Deserialize<'a> + head::BinaryHead>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkUtilError>
+{
+	if head::is_binary_head(data_with_head) {
+		return head::decode_binary_head(data_with_head);
+	}
+
+	split_head_and_encrypted_json(data_with_head)
+}
+
+/**
+Get the json head and the data.
+
+Like split_head_and_encrypted_data but without the binary head format, so every deserializable head can be used.
  */
 #[cfg(feature = "encryption")]
-pub fn split_head_and_encrypted_data<'a, T: Deserialize<'a>>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkUtilError>
+pub fn split_head_and_encrypted_json<'a, T: Deserialize<'a>>(data_with_head: &'a [u8]) -> Result<(T, &'a [u8]), SdkUtilError>
 {
 	let mut i = 0usize;
 	for data_itr in data_with_head {
@@ -106,6 +129,10 @@ pub fn split_head_and_encrypted_data<'a, T: Deserialize<'a>>(data_with_head: &'a
 		i += 1;
 	}
 
+	if i == data_with_head.len() {
+		return Err(SdkUtilError::DecodeEncryptedDataFailed);
+	}
+
 	let head = serde_json::from_slice(&data_with_head[..i])?;
 
 	//ignore the zero bytes
@@ -126,6 +153,31 @@ pub fn put_head_and_encrypted_data<T: serde::Serialize>(head: &T, encrypted: &[u
 	Ok(out)
 }
 
+/**
+Put the head and the data together in the given format.
+
+The binary head is smaller and faster to parse than the json head. It can be used for many small encrypted values.
+Both formats are detected when splitting the head.
+ */
+#[cfg(feature = "encryption")]
+pub fn put_head_and_encrypted_data_with_format<T: serde::Serialize + head::BinaryHead>(
+	head: &T,
+	encrypted: &[u8],
+	format: head::HeadFormat,
+) -> Result<Vec<u8>, SdkUtilError>
+{
+	match format {
+		head::HeadFormat::Json => put_head_and_encrypted_data(head, encrypted),
+		head::HeadFormat::Binary => {
+			let mut out = head::encode_binary_head(head)?;
+
+			out.extend_from_slice(encrypted);
+
+			Ok(out)
+		},
+	}
+}
+
 pub fn import_key_from_pem(pem: &str) -> Result<Vec<u8>, SdkUtilError>
 {
 	let (_type_label, data) = pem_rfc7468::decode_vec(pem.as_bytes()).map_err(|_| SdkUtilError::ImportingKeyFromPemFailed)?;
@@ -0,0 +1,148 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[cfg(feature = "encryption")]
+use sentc_crypto_common::crypto::EncryptedHead;
+use sentc_crypto_common::user::{UserPublicKeyData, UserVerifyKeyData};
+use sentc_crypto_core::cryptomat::{Sk, SymKeyComposer};
+
+#[cfg(feature = "encryption")]
+use crate::cryptomat::split_sign_alg;
+use crate::cryptomat::{SignComposerWrapper, StaticKeyComposerWrapper};
+use crate::error::SdkUtilError;
+
+/**
+For what kind of key an algorithm is checked.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlgUsage
+{
+	Sym,
+	Asym,
+	Sign,
+}
+
+/**
+# Allowed algorithms for key import and decryption
+
+Without a policy every algorithm that the key crate knows is accepted.
+A server can then return a group key encrypted by an old classic user key to a user who only expects post-quantum hybrid keys.
+
+- sym, asym and sign restrict the algorithms per usage. None allows every algorithm.
+- post_quantum is the list of the post-quantum algorithms of the used key crate, e.g. POST_QUANTUM_ALGS of the std keys.
+  If set, every asymmetric and sign key must use one of them.
+- deprecated algorithms are still accepted, but on_deprecated is called with them, e.g. to log a warning.
+
+The default policy accepts every algorithm.
+ */
+#[derive(Clone, Copy, Default)]
+pub struct CryptoPolicy<'a>
+{
+	pub sym: Option<&'a [&'a str]>,
+	pub asym: Option<&'a [&'a str]>,
+	pub sign: Option<&'a [&'a str]>,
+	pub post_quantum: Option<&'a [&'a str]>,
+	pub deprecated: &'a [&'a str],
+	pub on_deprecated: Option<fn(AlgUsage, &str)>,
+}
+
+impl<'a> CryptoPolicy<'a>
+{
+	pub fn check(&self, usage: AlgUsage, alg: &str) -> Result<(), SdkUtilError>
+	{
+		let allowed = match usage {
+			AlgUsage::Sym => self.sym,
+			AlgUsage::Asym => self.asym,
+			AlgUsage::Sign => self.sign,
+		};
+
+		if let Some(allowed) = allowed {
+			if !allowed.contains(&alg) {
+				return Err(SdkUtilError::AlgNotAllowed(alg.to_string()));
+			}
+		}
+
+		//symmetric keys are not affected by quantum computers in the same way
+		if let (Some(pq), AlgUsage::Asym | AlgUsage::Sign) = (self.post_quantum, usage) {
+			if !pq.contains(&alg) {
+				return Err(SdkUtilError::AlgNotPostQuantum(alg.to_string()));
+			}
+		}
+
+		if let Some(on_deprecated) = self.on_deprecated {
+			if self.deprecated.contains(&alg) {
+				on_deprecated(usage, alg);
+			}
+		}
+
+		Ok(())
+	}
+
+	pub fn check_public_key(&self, public_key: &UserPublicKeyData) -> Result<(), SdkUtilError>
+	{
+		self.check(AlgUsage::Asym, &public_key.public_key_alg)
+	}
+
+	pub fn check_verify_key(&self, verify_key: &UserVerifyKeyData) -> Result<(), SdkUtilError>
+	{
+		self.check(AlgUsage::Sign, &verify_key.verify_key_alg)
+	}
+
+	/**
+	SymKeyComposer::from_bytes_owned but the alg must be allowed.
+	 */
+	pub fn sym_key_from_bytes_owned<C: SymKeyComposer>(&self, bytes: Vec<u8>, alg: &str) -> Result<C::SymmetricKey, SdkUtilError>
+	{
+		self.check(AlgUsage::Sym, alg)?;
+
+		Ok(C::from_bytes_owned(bytes, alg)?)
+	}
+
+	/**
+	SymKeyComposer::decrypt_key_by_master_key but the algorithms of the private key and the symmetric key must be allowed.
+	 */
+	pub fn decrypt_sym_key_by_private_key<C: SymKeyComposer>(
+		&self,
+		private_key: &impl Sk,
+		encrypted_key: &[u8],
+		alg: &str,
+	) -> Result<C::SymmetricKey, SdkUtilError>
+	{
+		self.check(AlgUsage::Asym, private_key.get_alg_str())?;
+		self.check(AlgUsage::Sym, alg)?;
+
+		Ok(C::decrypt_key_by_master_key(private_key, encrypted_key, alg)?)
+	}
+
+	/**
+	Import the public key with the import_public_key_from_pem_with_alg of the key crate, if the alg is allowed.
+	 */
+	pub fn public_key_from_pem<C: StaticKeyComposerWrapper>(&self, public_key: &str, alg: &str) -> Result<C::InnerPk, SdkUtilError>
+	{
+		self.check(AlgUsage::Asym, alg)?;
+
+		C::pk_inner_from_pem(public_key, alg)
+	}
+
+	/**
+	Import the verify key with the import_verify_key_from_pem_with_alg of the key crate, if the alg is allowed.
+	 */
+	pub fn verify_key_from_pem<C: SignComposerWrapper>(&self, verify_key: &str, alg: &str) -> Result<C::InnerVk, SdkUtilError>
+	{
+		self.check(AlgUsage::Sign, alg)?;
+
+		C::vk_inner_from_pem(verify_key, alg)
+	}
+
+	/**
+	Check the algorithm of the signature in the head. The key of the head is checked with the key that decrypts the data.
+	 */
+	#[cfg(feature = "encryption")]
+	pub fn check_head(&self, head: &EncryptedHead) -> Result<(), SdkUtilError>
+	{
+		match &head.sign {
+			Some(h) => self.check(AlgUsage::Sign, split_sign_alg(&h.alg).0),
+			None => Ok(()),
+		}
+	}
+}
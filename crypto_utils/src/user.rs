@@ -76,6 +76,58 @@ impl<Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper> TryFro
 	}
 }
 
+impl<'a, Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper> TryFrom<&'a DeviceKeyDataInt<Sk, Pk, SiK, Vk>> for DeviceKeyDataExport
+{
+	type Error = SdkUtilError;
+
+	fn try_from(value: &'a DeviceKeyDataInt<Sk, Pk, SiK, Vk>) -> Result<Self, Self::Error>
+	{
+		Ok(Self {
+			private_key: value.private_key.to_string_ref()?,
+			public_key: value.public_key.to_string_ref()?,
+			sign_key: value.sign_key.to_string_ref()?,
+			verify_key: value.verify_key.to_string_ref()?,
+			exported_public_key: value
+				.exported_public_key
+				.to_string()
+				.map_err(|_e| SdkUtilError::JsonToStringFailed)?,
+			exported_verify_key: value
+				.exported_verify_key
+				.to_string()
+				.map_err(|_e| SdkUtilError::JsonToStringFailed)?,
+		})
+	}
+}
+
+impl<Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper> TryInto<DeviceKeyDataInt<Sk, Pk, SiK, Vk>> for DeviceKeyDataExport
+{
+	type Error = SdkUtilError;
+
+	fn try_into(self) -> Result<DeviceKeyDataInt<Sk, Pk, SiK, Vk>, Self::Error>
+	{
+		Ok(DeviceKeyDataInt {
+			private_key: self
+				.private_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingPrivateKeyFailed)?,
+			sign_key: self
+				.sign_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportingSignKeyFailed)?,
+			public_key: self
+				.public_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportPublicKeyFailed)?,
+			verify_key: self
+				.verify_key
+				.parse()
+				.map_err(|_| SdkUtilError::ImportVerifyKeyFailed)?,
+			exported_public_key: UserPublicKeyData::from_string(&self.exported_public_key).map_err(|_| SdkUtilError::ImportingKeyFromPemFailed)?,
+			exported_verify_key: UserVerifyKeyData::from_string(&self.exported_verify_key).map_err(|_| SdkUtilError::ImportingKeyFromPemFailed)?,
+		})
+	}
+}
+
 pub struct UserPreVerifyLogin<Sk: SkWrapper, Pk: PkWrapper, SiK: SignKWrapper, Vk: VerifyKWrapper>
 {
 	pub challenge: String,
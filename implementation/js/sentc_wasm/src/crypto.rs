@@ -113,12 +113,41 @@ pub fn decrypt_raw_symmetric(key: &str, encrypted_data: &[u8], head: &str, verif
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_raw_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	head: &str,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::decrypt_raw_symmetric_with_policy(
+		key,
+		encrypted_data,
+		head,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn encrypt_symmetric(key: &str, data: &[u8], sign_key: Option<String>) -> Result<Vec<u8>, JsValue>
 {
 	Ok(crypto::encrypt_symmetric(key, data, sign_key.as_deref())?)
 }
 
+#[wasm_bindgen]
+pub fn encrypt_symmetric_with_format(key: &str, data: &[u8], sign_key: Option<String>, head_format: String) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::encrypt_symmetric_with_format(
+		key,
+		data,
+		sign_key.as_deref(),
+		head_format.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn decrypt_symmetric(key: &str, encrypted_data: &[u8], verify_key_data: Option<String>) -> Result<Vec<u8>, JsValue>
 {
@@ -129,12 +158,39 @@ pub fn decrypt_symmetric(key: &str, encrypted_data: &[u8], verify_key_data: Opti
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &[u8],
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::decrypt_symmetric_with_policy(
+		key,
+		encrypted_data,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn encrypt_string_symmetric(key: &str, data: &str, sign_key: Option<String>) -> Result<String, JsValue>
 {
 	Ok(crypto::encrypt_string_symmetric(key, data, sign_key.as_deref())?)
 }
 
+#[wasm_bindgen]
+pub fn encrypt_string_symmetric_with_format(key: &str, data: &str, sign_key: Option<String>, head_format: String) -> Result<String, JsValue>
+{
+	Ok(crypto::encrypt_string_symmetric_with_format(
+		key,
+		data,
+		sign_key.as_deref(),
+		head_format.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn decrypt_string_symmetric(key: &str, encrypted_data: &str, verify_key_data: Option<String>) -> Result<String, JsValue>
 {
@@ -145,6 +201,22 @@ pub fn decrypt_string_symmetric(key: &str, encrypted_data: &str, verify_key_data
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_string_symmetric_with_policy(
+	key: &str,
+	encrypted_data: &str,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<String, JsValue>
+{
+	Ok(crypto::decrypt_string_symmetric_with_policy(
+		key,
+		encrypted_data,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn encrypt_raw_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Option<String>) -> Result<CryptoRawOutput, JsValue>
 {
@@ -167,6 +239,24 @@ pub fn decrypt_raw_asymmetric(private_key: &str, encrypted_data: &[u8], head: &s
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_raw_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &[u8],
+	head: &str,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::decrypt_raw_asymmetric_with_policy(
+		private_key,
+		encrypted_data,
+		head,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn encrypt_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Option<String>) -> Result<Vec<u8>, JsValue>
 {
@@ -177,6 +267,22 @@ pub fn encrypt_asymmetric(reply_public_key_data: &str, data: &[u8], sign_key: Op
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn encrypt_asymmetric_with_format(
+	reply_public_key_data: &str,
+	data: &[u8],
+	sign_key: Option<String>,
+	head_format: String,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::encrypt_asymmetric_with_format(
+		reply_public_key_data,
+		data,
+		sign_key.as_deref(),
+		head_format.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn decrypt_asymmetric(private_key: &str, encrypted_data: &[u8], verify_key_data: Option<String>) -> Result<Vec<u8>, JsValue>
 {
@@ -187,6 +293,22 @@ pub fn decrypt_asymmetric(private_key: &str, encrypted_data: &[u8], verify_key_d
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &[u8],
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<Vec<u8>, JsValue>
+{
+	Ok(crypto::decrypt_asymmetric_with_policy(
+		private_key,
+		encrypted_data,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn encrypt_string_asymmetric(reply_public_key_data: &str, data: &str, sign_key: Option<String>) -> Result<String, JsValue>
 {
@@ -197,6 +319,22 @@ pub fn encrypt_string_asymmetric(reply_public_key_data: &str, data: &str, sign_k
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn encrypt_string_asymmetric_with_format(
+	reply_public_key_data: &str,
+	data: &str,
+	sign_key: Option<String>,
+	head_format: String,
+) -> Result<String, JsValue>
+{
+	Ok(crypto::encrypt_string_asymmetric_with_format(
+		reply_public_key_data,
+		data,
+		sign_key.as_deref(),
+		head_format.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn decrypt_string_asymmetric(private_key: &str, encrypted_data: &str, verify_key_data: Option<String>) -> Result<String, JsValue>
 {
@@ -207,6 +345,22 @@ pub fn decrypt_string_asymmetric(private_key: &str, encrypted_data: &str, verify
 	)?)
 }
 
+#[wasm_bindgen]
+pub fn decrypt_string_asymmetric_with_policy(
+	private_key: &str,
+	encrypted_data: &str,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<String, JsValue>
+{
+	Ok(crypto::decrypt_string_asymmetric_with_policy(
+		private_key,
+		encrypted_data,
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)?)
+}
+
 #[wasm_bindgen]
 pub fn generate_non_register_sym_key(master_key: &str) -> Result<NonRegisteredKeyOutput, JsValue>
 {
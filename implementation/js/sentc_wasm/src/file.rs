@@ -123,6 +123,35 @@ pub async fn file_download_and_decrypt_file_part_start(
 	})
 }
 
+#[wasm_bindgen]
+pub async fn file_download_and_decrypt_file_part_start_with_policy(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	content_key: String,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<FileDownloadResult, JsValue>
+{
+	let (file, next_file_key) = util_req_full::file::download_and_decrypt_file_part_start_with_policy(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		content_key.as_str(),
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)
+	.await?;
+
+	//fastest way to convert vec to Uint8Array
+	Ok(FileDownloadResult {
+		next_file_key,
+		file,
+	})
+}
+
 #[wasm_bindgen]
 pub async fn file_download_and_decrypt_file_part(
 	base_url: String,
@@ -150,6 +179,35 @@ pub async fn file_download_and_decrypt_file_part(
 	})
 }
 
+#[wasm_bindgen]
+pub async fn file_download_and_decrypt_file_part_with_policy(
+	base_url: String,
+	url_prefix: Option<String>,
+	auth_token: String,
+	part_id: String,
+	content_key: String,
+	verify_key_data: Option<String>,
+	signature_policy: String,
+) -> Result<FileDownloadResult, JsValue>
+{
+	let (file, next_file_key) = util_req_full::file::download_and_decrypt_file_part_with_policy(
+		base_url,
+		url_prefix,
+		auth_token.as_str(),
+		part_id.as_str(),
+		content_key.as_str(),
+		verify_key_data.as_deref(),
+		signature_policy.as_str(),
+	)
+	.await?;
+
+	//fastest way to convert vec to Uint8Array
+	Ok(FileDownloadResult {
+		next_file_key,
+		file,
+	})
+}
+
 //__________________________________________________________________________________________________
 
 #[wasm_bindgen]